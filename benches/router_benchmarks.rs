@@ -12,6 +12,8 @@ fn bench_intent_translation(c: &mut Criterion) {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     c.bench_function("translate_intent", |b| {
@@ -31,6 +33,8 @@ fn bench_route_finding(c: &mut Criterion) {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     c.bench_function("find_route", |b| {
@@ -68,7 +72,7 @@ fn bench_context_operations(c: &mut Criterion) {
     use sentium_bridge::core::context::{SemanticContext, UserPreferences, RiskLevel};
     
     let prefs = UserPreferences {
-        slippage_tolerance: 0.01,
+        slippage_bps: 100,
         max_gas_price: 100,
         min_confirmations: 6,
         preferred_routes: vec![],
@@ -0,0 +1,86 @@
+// Benchmarks for the verification and coin selection hot paths
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use sentium_bridge::core::crypto::QuantumKeyManager;
+use sentium_bridge::core::router::chain_adapter::{SelectionStrategy, Utxo, UtxoSelector};
+use sentium_bridge::light_clients::{LightClient, QuantumSignature, StateProof, Validator};
+
+/// Client with `validators` equal-power validators and a proof signed by all of them
+fn signed_proof(validators: usize) -> (LightClient, StateProof) {
+    let mut client = LightClient::new("bench-chain".to_string()).with_max_age(None);
+    let mut proof = StateProof {
+        height: 100,
+        state_root: vec![7; 32],
+        signatures: vec![],
+        timestamp: 1_700_000_000,
+    };
+    
+    let keys: Vec<_> = (0..validators).map(|_| QuantumKeyManager::generate_keypair()).collect();
+    for (i, (public_key, _)) in keys.iter().enumerate() {
+        client.add_validator(Validator {
+            address: (i as u32).to_be_bytes().to_vec(),
+            public_key: public_key.as_bytes().to_vec(),
+            voting_power: 1,
+            classical_public_key: Vec::new(),
+            hybrid: false,
+        });
+    }
+    
+    let message = client.construct_proof_message(&proof);
+    for (i, (_, secret_key)) in keys.iter().enumerate() {
+        proof.signatures.push(QuantumSignature {
+            validator_address: (i as u32).to_be_bytes().to_vec(),
+            signature: QuantumKeyManager::sign(secret_key, &message),
+            classical_signature: None,
+        });
+    }
+    
+    (client, proof)
+}
+
+fn bench_verify_state_proof(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verify_state_proof");
+    
+    for validators in [4, 16, 64] {
+        let (client, proof) = signed_proof(validators);
+        assert!(client.verify_state_proof(&proof).unwrap());
+        
+        group.bench_with_input(BenchmarkId::from_parameter(validators), &proof, |b, proof| {
+            b.iter(|| client.verify_state_proof(black_box(proof)))
+        });
+    }
+    
+    group.finish();
+}
+
+/// `count` UTXOs of varied sizes, none of which covers the target alone
+fn utxos(count: usize) -> Vec<Utxo> {
+    (0..count)
+        .map(|i| Utxo {
+            txid: format!("{:064x}", i),
+            vout: 0,
+            amount: 10_000 + (i as u64 * 7_919) % 50_000,
+            script_pubkey: vec![0x00, 0x14],
+        })
+        .collect()
+}
+
+fn bench_branch_and_bound(c: &mut Criterion) {
+    let selector = UtxoSelector::new(SelectionStrategy::BranchAndBound);
+    let mut group = c.benchmark_group("select_branch_and_bound");
+    
+    for count in [5, 10, 20, 50] {
+        let available = utxos(count);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &available, |b, available| {
+            b.iter_batched(
+                || available.clone(),
+                |available| selector.select_utxos(available, black_box(60_000), 10),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    
+    group.finish();
+}
+
+criterion_group!(benches, bench_verify_state_proof, bench_branch_and_bound);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+// Clock - Source of wall-clock time for timestamps, expiry and freshness checks
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+pub trait Clock: Debug + Send + Sync {
+    /// Seconds since the Unix epoch
+    fn now_secs(&self) -> u64;
+}
+
+/// Clock backed by `SystemTime::now()`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_secs(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+/// Default clock for constructors that don't take one
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// Manually driven clock for deterministic tests
+#[derive(Debug, Default)]
+pub struct MockClock {
+    now: AtomicU64,
+}
+
+impl MockClock {
+    pub fn new(now_secs: u64) -> Self {
+        Self {
+            now: AtomicU64::new(now_secs),
+        }
+    }
+    
+    pub fn set(&self, now_secs: u64) {
+        self.now.store(now_secs, Ordering::SeqCst);
+    }
+    
+    pub fn advance(&self, secs: u64) {
+        self.now.fetch_add(secs, Ordering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_secs(&self) -> u64 {
+        self.now.load(Ordering::SeqCst)
+    }
+}
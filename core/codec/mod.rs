@@ -0,0 +1,133 @@
+// Codec - Wire formats for stored contexts and light-client snapshots
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Serialization format for persisted state
+///
+/// JSON is the default and the only format that can read data written before codecs were
+/// selectable. Byte fields (keys, signatures) cost JSON about 3.6 bytes per byte; CBOR about
+/// half that and bincode one byte each, so a proof with four Dilithium5 signatures is roughly
+/// 67 KB, 36 KB and 19 KB respectively. Bincode is positional, so its data only decodes with
+/// the same struct definitions that wrote it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Codec {
+    #[default]
+    Json,
+    Cbor,
+    Bincode,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CodecError {
+    #[error("{codec:?} encoding failed: {reason}")]
+    Encode { codec: Codec, reason: String },
+    
+    #[error("{codec:?} decoding failed: {reason}")]
+    Decode { codec: Codec, reason: String },
+}
+
+impl Codec {
+    pub fn encode<T: Serialize + ?Sized>(self, value: &T) -> Result<Vec<u8>, CodecError> {
+        let encoded = match self {
+            Codec::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+            Codec::Cbor => {
+                let mut out = Vec::new();
+                ciborium::ser::into_writer(value, &mut out).map(|_| out).map_err(|e| e.to_string())
+            }
+            Codec::Bincode => bincode::serialize(value).map_err(|e| e.to_string()),
+        };
+        encoded.map_err(|reason| CodecError::Encode { codec: self, reason })
+    }
+    
+    pub fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, CodecError> {
+        let decoded = match self {
+            Codec::Json => serde_json::from_slice(bytes).map_err(|e| e.to_string()),
+            Codec::Cbor => ciborium::de::from_reader(bytes).map_err(|e| e.to_string()),
+            Codec::Bincode => bincode::deserialize(bytes).map_err(|e| e.to_string()),
+        };
+        decoded.map_err(|reason| CodecError::Decode { codec: self, reason })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_clients::{QuantumSignature, StateProof};
+    
+    #[test]
+    fn test_state_proof_round_trips_under_every_codec() {
+        let proof = StateProof {
+            height: 42,
+            state_root: vec![0xAB; 32],
+            signatures: (0..4u8)
+                .map(|i| QuantumSignature {
+                    validator_address: vec![i; 20],
+                    signature: (0..4627).map(|b| (b as u8).wrapping_mul(i + 1)).collect(),
+                    classical_signature: (i % 2 == 0).then(|| vec![i; 64]),
+                })
+                .collect(),
+            timestamp: 1_700_000_000,
+        };
+        
+        let mut sizes = Vec::new();
+        for codec in [Codec::Json, Codec::Cbor, Codec::Bincode] {
+            let bytes = codec.encode(&proof).unwrap();
+            let decoded: StateProof = codec.decode(&bytes).unwrap();
+            
+            assert_eq!(decoded.height, proof.height);
+            assert_eq!(decoded.state_root, proof.state_root);
+            for (a, b) in decoded.signatures.iter().zip(&proof.signatures) {
+                assert_eq!(a.validator_address, b.validator_address);
+                assert_eq!(a.signature, b.signature);
+                assert_eq!(a.classical_signature, b.classical_signature);
+            }
+            assert_eq!(decoded.signatures.len(), proof.signatures.len());
+            sizes.push(bytes.len());
+        }
+        
+        // Four Dilithium5 signatures are ~18.5 KB of raw bytes
+        let (json, cbor, bincode) = (sizes[0], sizes[1], sizes[2]);
+        assert!(bincode < cbor && cbor < json, "json {} cbor {} bincode {}", json, cbor, bincode);
+        assert!(bincode < 4 * 4627 + 1024);
+        
+        assert!(matches!(
+            Codec::Cbor.decode::<StateProof>(&[0xFF, 0x00]),
+            Err(CodecError::Decode { codec: Codec::Cbor, .. })
+        ));
+    }
+    #[tokio::test]
+    async fn test_contexts_and_snapshots_round_trip_under_every_codec() {
+        use crate::core::context::{ContextStorage, InMemoryStorage, SemanticContext, UserPreferences};
+        use crate::light_clients::{LightClient, Validator};
+        
+        let mut client = LightClient::new("sentium-1".to_string()).with_max_age(None);
+        client.add_validator(Validator {
+            address: vec![1; 20],
+            public_key: vec![2; 2592],
+            voting_power: 10,
+            classical_public_key: vec![],
+            hybrid: false,
+        });
+        let mut context = SemanticContext::new(
+            "intent-1".to_string(),
+            "ethereum".to_string(),
+            "polkadot".to_string(),
+            UserPreferences::default(),
+        );
+        context.add_metadata("route".to_string(), "direct".to_string());
+        
+        for codec in [Codec::Json, Codec::Cbor, Codec::Bincode] {
+            let restored = LightClient::restore(&client.snapshot(codec).unwrap(), codec).unwrap();
+            assert_eq!(restored.validator_set.len(), 1);
+            assert_eq!(restored.validator_set[0].public_key, client.validator_set[0].public_key);
+            assert_eq!(restored.max_age_secs, None);
+            
+            let storage = InMemoryStorage::new().with_codec(codec);
+            storage.save(&context).await.unwrap();
+            let loaded = storage.load(&context.id).await.unwrap().unwrap();
+            assert_eq!(loaded.user_preferences.slippage_bps, 50);
+            assert!(loaded.verify_integrity(), "{:?}", codec);
+        }
+    }
+}
@@ -0,0 +1,300 @@
+// Sentium Bridge - Configuration
+// This module loads chain/RPC setup and default settings from TOML or YAML files
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Top-level bridge configuration
+///
+/// ```toml
+/// [defaults]
+/// slippage_tolerance = 0.005
+/// max_gas_price = 50000000000
+///
+/// [server]
+/// bind_address = "127.0.0.1:8080"
+///
+/// [chains.ethereum]
+/// rpc_url = "http://localhost:8545"
+/// chain_id = 1
+///
+/// [chains.bitcoin]
+/// rpc_url = "http://localhost:8332"
+/// network = "bitcoin"
+/// credentials = { username = "${BITCOIN_RPC_USER}", password = "${BITCOIN_RPC_PASSWORD}" }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BridgeConfig {
+    #[serde(default)]
+    pub defaults: DefaultSettings,
+    #[serde(default)]
+    pub chains: BTreeMap<String, ChainConfig>,
+    #[serde(default)]
+    pub server: ServerSettings,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub rpc_url: String,
+    #[serde(default)]
+    pub credentials: Option<Credentials>,
+    #[serde(default)]
+    pub chain_id: Option<u64>, // EVM chain id submissions must be built for
+    #[serde(default)]
+    pub network: Option<String>, // Bitcoin network: bitcoin (default), testnet, signet or regtest
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+/// Settings `Router::from_config` applies across chains
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefaultSettings {
+    #[serde(default = "default_slippage_tolerance")]
+    pub slippage_tolerance: f64, // fraction, used for swaps translated without user preferences
+    #[serde(default)]
+    pub max_gas_price: Option<u64>, // wei per gas EVM adapters never bid above
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerSettings {
+    #[serde(default = "default_bind_address")]
+    pub bind_address: String,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_slippage_tolerance() -> f64 {
+    0.005 // 0.5%
+}
+
+impl Default for DefaultSettings {
+    fn default() -> Self {
+        Self {
+            slippage_tolerance: default_slippage_tolerance(),
+            max_gas_price: None,
+        }
+    }
+}
+
+fn default_bind_address() -> String {
+    "127.0.0.1:8080".to_string()
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            bind_address: default_bind_address(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print secrets
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+impl BridgeConfig {
+    /// Load a config file, picking the format from the extension (.toml, .yaml, .yml)
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| ConfigError::Io(format!("{}: {}", path.display(), e)))?;
+        
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&contents),
+            _ => Self::from_toml_str(&contents),
+        }
+    }
+    
+    /// Parse TOML, then expand `${VAR}` inside string values
+    ///
+    /// Expansion happens after parsing, so a value can't change the document's structure
+    /// whatever characters it holds.
+    pub fn from_toml_str(contents: &str) -> Result<Self, ConfigError> {
+        let mut document: toml::Value = toml::from_str(contents).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        interpolate_toml(&mut document)?;
+        document.try_into().map_err(|e: toml::de::Error| ConfigError::Parse(e.to_string()))
+    }
+    
+    /// Parse YAML, then expand `${VAR}` inside string values
+    pub fn from_yaml_str(contents: &str) -> Result<Self, ConfigError> {
+        let mut document: serde_yaml::Value =
+            serde_yaml::from_str(contents).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        interpolate_yaml(&mut document)?;
+        serde_yaml::from_value(document).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+    
+    /// Chains with `enabled = true`, in name order
+    pub fn enabled_chains(&self) -> impl Iterator<Item = (&String, &ChainConfig)> {
+        self.chains.iter().filter(|(_, chain)| chain.enabled)
+    }
+}
+
+fn interpolate_toml(value: &mut toml::Value) -> Result<(), ConfigError> {
+    match value {
+        toml::Value::String(s) => *s = interpolate_env(s)?,
+        toml::Value::Array(items) => items.iter_mut().try_for_each(interpolate_toml)?,
+        toml::Value::Table(table) => table.iter_mut().try_for_each(|(_, value)| interpolate_toml(value))?,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn interpolate_yaml(value: &mut serde_yaml::Value) -> Result<(), ConfigError> {
+    match value {
+        serde_yaml::Value::String(s) => *s = interpolate_env(s)?,
+        serde_yaml::Value::Sequence(items) => items.iter_mut().try_for_each(interpolate_yaml)?,
+        serde_yaml::Value::Mapping(mapping) => mapping.values_mut().try_for_each(interpolate_yaml)?,
+        serde_yaml::Value::Tagged(tagged) => interpolate_yaml(&mut tagged.value)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Replace `${VAR}` references in a string value with the value of the environment variable
+fn interpolate_env(input: &str) -> Result<String, ConfigError> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    
+    while let Some(start) = rest.find("${") {
+        output.push_str(&rest[..start]);
+        
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| ConfigError::Parse("Unterminated ${ in config".to_string()))?;
+        
+        let name = &after[..end];
+        let value = std::env::var(name).map_err(|_| ConfigError::MissingEnvVar(name.to_string()))?;
+        output.push_str(&value);
+        
+        rest = &after[end + 1..];
+    }
+    
+    output.push_str(rest);
+    Ok(output)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Failed to read config: {0}")]
+    Io(String),
+    
+    #[error("Failed to parse config: {0}")]
+    Parse(String),
+    
+    #[error("Environment variable not set: {0}")]
+    MissingEnvVar(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const SAMPLE_TOML: &str = r#"
+[defaults]
+slippage_tolerance = 0.01
+max_gas_price = 50000000000
+
+[chains.ethereum]
+rpc_url = "http://localhost:8545"
+
+[chains.bitcoin]
+rpc_url = "http://localhost:8332"
+credentials = { username = "${SENTIUM_TEST_BTC_USER}", password = "secret" }
+
+[chains.cosmos]
+enabled = false
+rpc_url = "http://localhost:9090"
+"#;
+    
+    #[test]
+    fn test_parse_toml_config() {
+        std::env::set_var("SENTIUM_TEST_BTC_USER", "alice");
+        let config = BridgeConfig::from_toml_str(SAMPLE_TOML).unwrap();
+        
+        assert_eq!(config.defaults.slippage_tolerance, 0.01);
+        assert_eq!(config.defaults.max_gas_price, Some(50_000_000_000));
+        assert_eq!(config.chains.len(), 3);
+        assert_eq!(config.chains["bitcoin"].credentials.as_ref().unwrap().username, "alice");
+        
+        let enabled: Vec<_> = config.enabled_chains().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(enabled, vec!["bitcoin", "ethereum"]);
+    }
+    
+    #[test]
+    fn test_parse_yaml_config() {
+        let yaml = r#"
+chains:
+  polkadot:
+    rpc_url: "wss://rpc.polkadot.io"
+"#;
+        let config = BridgeConfig::from_yaml_str(yaml).unwrap();
+        
+        assert_eq!(config.chains["polkadot"].rpc_url, "wss://rpc.polkadot.io");
+        assert!(config.chains["polkadot"].enabled);
+        assert_eq!(config.defaults.slippage_tolerance, 0.005);
+        assert_eq!(config.server.bind_address, "127.0.0.1:8080");
+    }
+    
+    #[test]
+    fn test_missing_env_var() {
+        let toml = "[chains.ethereum]\nrpc_url = \"${SENTIUM_TEST_UNSET_VAR}\"\n";
+        
+        match BridgeConfig::from_toml_str(toml) {
+            Err(ConfigError::MissingEnvVar(name)) => assert_eq!(name, "SENTIUM_TEST_UNSET_VAR"),
+            other => panic!("expected MissingEnvVar, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_env_values_cannot_inject_config() {
+        std::env::set_var("SENTIUM_TEST_RPC_PASSWORD", "p\"ss\nrpc_url = \"http://evil\" # x");
+        let expected = std::env::var("SENTIUM_TEST_RPC_PASSWORD").unwrap();
+        
+        let toml = r#"
+[chains.bitcoin]
+rpc_url = "http://localhost:8332"
+credentials = { username = "user", password = "${SENTIUM_TEST_RPC_PASSWORD}" }
+"#;
+        let config = BridgeConfig::from_toml_str(toml).unwrap();
+        assert_eq!(config.chains["bitcoin"].rpc_url, "http://localhost:8332");
+        assert_eq!(config.chains["bitcoin"].credentials.as_ref().unwrap().password, expected);
+        
+        let yaml = r#"
+chains:
+  bitcoin:
+    rpc_url: "http://localhost:8332"
+    credentials:
+      username: user
+      password: "${SENTIUM_TEST_RPC_PASSWORD}"
+"#;
+        let config = BridgeConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(config.chains["bitcoin"].rpc_url, "http://localhost:8332");
+        assert_eq!(config.chains["bitcoin"].credentials.as_ref().unwrap().password, expected);
+    }
+    
+    #[test]
+    fn test_credentials_debug_redacted() {
+        let credentials = Credentials {
+            username: "user".to_string(),
+            password: "hunter2".to_string(),
+        };
+        
+        assert!(!format!("{:?}", credentials).contains("hunter2"));
+    }
+}
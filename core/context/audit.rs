@@ -0,0 +1,230 @@
+// Audit Log - Hash-chained record of every context mutation
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_512};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use super::ContextError;
+use crate::core::clock::{system_clock, Clock};
+use crate::core::crypto::canonical::to_canonical_bytes;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AuditOperation {
+    Save,
+    Update,
+    Delete,
+}
+
+/// One context mutation, chained to the entry before it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditEntry {
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub context_id: String,
+    pub operation: AuditOperation,
+    pub integrity_hash: Vec<u8>, // of the context after the operation; empty for deletes
+    pub previous_hash: Vec<u8>,  // entry_hash of the previous entry; empty for the first
+    pub entry_hash: Vec<u8>,
+}
+
+impl AuditEntry {
+    /// SHA3-512 over the canonical encoding of every field except `entry_hash`
+    pub fn compute_hash(&self) -> Vec<u8> {
+        let preimage = to_canonical_bytes(&serde_json::json!({
+            "sequence": self.sequence,
+            "timestamp": self.timestamp,
+            "context_id": self.context_id,
+            "operation": self.operation,
+            "integrity_hash": hex::encode(&self.integrity_hash),
+            "previous_hash": hex::encode(&self.previous_hash),
+        }));
+        Sha3_512::digest(&preimage).to_vec()
+    }
+}
+
+/// Where audit entries are kept; entries are only ever appended
+#[async_trait]
+pub trait AuditSink: Send + Sync {
+    async fn append(&self, entry: &AuditEntry) -> Result<(), ContextError>;
+    
+    /// Every entry, in the order appended
+    async fn entries(&self) -> Result<Vec<AuditEntry>, ContextError>;
+}
+
+/// Appends entries to a file as JSON lines
+pub struct FileAuditSink {
+    path: PathBuf,
+}
+
+impl FileAuditSink {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl AuditSink for FileAuditSink {
+    async fn append(&self, entry: &AuditEntry) -> Result<(), ContextError> {
+        let mut line = serde_json::to_vec(entry)
+            .map_err(|e| ContextError::SerializationError(e.to_string()))?;
+        line.push(b'\n');
+        
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| ContextError::StorageError(format!("Failed to open audit log: {}", e)))?;
+        file.write_all(&line)
+            .await
+            .map_err(|e| ContextError::StorageError(format!("Failed to write audit log: {}", e)))?;
+        file.flush()
+            .await
+            .map_err(|e| ContextError::StorageError(format!("Failed to write audit log: {}", e)))
+    }
+    
+    async fn entries(&self) -> Result<Vec<AuditEntry>, ContextError> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(ContextError::StorageError(format!("Failed to read audit log: {}", e))),
+        };
+        
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(|e| ContextError::SerializationError(e.to_string())))
+            .collect()
+    }
+}
+
+/// Tamper-evident log of context mutations
+///
+/// Each entry's hash covers the previous entry's hash, so editing, dropping or reordering
+/// any entry breaks `verify` from that point on.
+pub struct AuditLog {
+    sink: Arc<dyn AuditSink>,
+    clock: Arc<dyn Clock>,
+    tip: Mutex<Option<(u64, Vec<u8>)>>, // (next sequence, last entry hash), read from the sink on first use
+}
+
+impl AuditLog {
+    pub fn new(sink: Arc<dyn AuditSink>) -> Self {
+        Self {
+            sink,
+            clock: system_clock(),
+            tip: Mutex::new(None),
+        }
+    }
+    
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Append an entry for `operation` on `context_id`, chained to the current tip
+    pub async fn record(
+        &self,
+        context_id: &str,
+        operation: AuditOperation,
+        integrity_hash: &[u8],
+    ) -> Result<AuditEntry, ContextError> {
+        // Held across the append so concurrent records can't fork the chain
+        let mut tip = self.tip.lock().await;
+        let (sequence, previous_hash) = match tip.take() {
+            Some(tip) => tip,
+            None => match self.sink.entries().await?.pop() {
+                Some(last) => (last.sequence + 1, last.entry_hash),
+                None => (0, Vec::new()),
+            },
+        };
+        
+        let mut entry = AuditEntry {
+            sequence,
+            timestamp: self.clock.now_secs(),
+            context_id: context_id.to_string(),
+            operation,
+            integrity_hash: integrity_hash.to_vec(),
+            previous_hash: previous_hash.clone(),
+            entry_hash: Vec::new(),
+        };
+        entry.entry_hash = entry.compute_hash();
+        
+        if let Err(e) = self.sink.append(&entry).await {
+            *tip = Some((sequence, previous_hash));
+            return Err(e);
+        }
+        *tip = Some((sequence + 1, entry.entry_hash.clone()));
+        
+        Ok(entry)
+    }
+    
+    pub async fn entries(&self) -> Result<Vec<AuditEntry>, ContextError> {
+        self.sink.entries().await
+    }
+    
+    /// Check every entry's hash and link, failing at the first broken one
+    pub async fn verify(&self) -> Result<(), ContextError> {
+        verify_chain(&self.sink.entries().await?)
+    }
+}
+
+/// Check that `entries` form an unbroken hash chain starting from the first entry
+pub fn verify_chain(entries: &[AuditEntry]) -> Result<(), ContextError> {
+    let mut previous_hash: &[u8] = &[];
+    for (i, entry) in entries.iter().enumerate() {
+        let intact = entry.sequence == i as u64
+            && entry.previous_hash == previous_hash
+            && entry.entry_hash == entry.compute_hash();
+        if !intact {
+            return Err(ContextError::AuditChainBroken(i as u64));
+        }
+        previous_hash = &entry.entry_hash;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clock::MockClock;
+    
+    #[tokio::test]
+    async fn test_tampered_middle_entry_breaks_chain() {
+        let path = std::env::temp_dir().join(format!("sentium-audit-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        
+        let clock = Arc::new(MockClock::new(1_700_000_000));
+        let log = AuditLog::new(Arc::new(FileAuditSink::new(&path))).with_clock(clock.clone());
+        log.record("ctx-1", AuditOperation::Save, &[1; 64]).await.unwrap();
+        clock.advance(10);
+        log.record("ctx-1", AuditOperation::Update, &[2; 64]).await.unwrap();
+        clock.advance(10);
+        log.record("ctx-1", AuditOperation::Delete, &[]).await.unwrap();
+        
+        // A fresh log over the same file picks up where the last one stopped
+        let reopened = AuditLog::new(Arc::new(FileAuditSink::new(&path)));
+        let entry = reopened.record("ctx-2", AuditOperation::Save, &[3; 64]).await.unwrap();
+        assert_eq!(entry.sequence, 3);
+        reopened.verify().await.unwrap();
+        
+        // Rewrite the update entry as if the context had been left untouched
+        let mut entries = reopened.entries().await.unwrap();
+        entries[1].integrity_hash = vec![1; 64];
+        let tampered: String = entries
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap() + "\n")
+            .collect();
+        std::fs::write(&path, tampered).unwrap();
+        assert!(matches!(reopened.verify().await, Err(ContextError::AuditChainBroken(1))));
+        
+        // Re-hashing the forged entry just moves the break to the next link
+        entries[1].entry_hash = entries[1].compute_hash();
+        assert!(matches!(verify_chain(&entries), Err(ContextError::AuditChainBroken(2))));
+        
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,203 @@
+// Compression - zstd-compressed context storage over any raw byte store
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{ContextError, ContextStorage, InMemoryStorage, SemanticContext};
+use crate::core::codec::Codec;
+
+/// First byte of a compressed blob; plain JSON always starts with `{` or `[`, and plain
+/// data in other codecs is read with the storage's codec
+pub const COMPRESSED_MAGIC: u8 = 0xC5;
+
+/// Raw bytes by key, for storage wrappers that do their own encoding
+#[async_trait]
+pub trait BlobStorage: Send + Sync {
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), ContextError>;
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ContextError>;
+    async fn remove(&self, key: &str) -> Result<(), ContextError>;
+    async fn keys(&self) -> Result<Vec<String>, ContextError>;
+}
+
+/// Stores contexts zstd-compressed in a `BlobStorage`, serialized as JSON unless set otherwise
+///
+/// Blobs without the magic byte are read as plain JSON, so data written before compression
+/// was turned on stays loadable and is compressed the next time it is saved.
+pub struct CompressingStorage<S> {
+    inner: S,
+    level: i32,
+    codec: Codec,
+}
+
+impl<S: BlobStorage> CompressingStorage<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            level: zstd::DEFAULT_COMPRESSION_LEVEL,
+            codec: Codec::default(),
+        }
+    }
+    
+    /// Serialize with `codec` before compressing; existing blobs must have been written with it too
+    pub fn with_codec(mut self, codec: Codec) -> Self {
+        self.codec = codec;
+        self
+    }
+    
+    /// zstd level, 1 (fastest) to 22 (smallest)
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+    
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+    
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, ContextError> {
+        let serialized = self.codec.encode(value)?;
+        let compressed = zstd::encode_all(serialized.as_slice(), self.level)
+            .map_err(|e| ContextError::SerializationError(format!("Compression failed: {}", e)))?;
+        
+        let mut blob = Vec::with_capacity(compressed.len() + 1);
+        blob.push(COMPRESSED_MAGIC);
+        blob.extend_from_slice(&compressed);
+        Ok(blob)
+    }
+    
+    fn decode<T: DeserializeOwned>(&self, blob: &[u8]) -> Result<T, ContextError> {
+        let serialized = match blob.split_first() {
+            Some((&COMPRESSED_MAGIC, compressed)) => zstd::decode_all(compressed)
+                .map_err(|e| ContextError::SerializationError(format!("Decompression failed: {}", e)))?,
+            _ => blob.to_vec(),
+        };
+        Ok(self.codec.decode(&serialized)?)
+    }
+}
+
+fn context_key(id: &str) -> String {
+    format!("context/{}", id)
+}
+
+fn history_key(id: &str) -> String {
+    format!("history/{}", id)
+}
+
+#[async_trait]
+impl<S: BlobStorage> ContextStorage for CompressingStorage<S> {
+    async fn save(&self, context: &SemanticContext) -> Result<(), ContextError> {
+        self.inner.put(&context_key(&context.id), self.encode(context)?).await
+    }
+    
+    async fn load(&self, id: &str) -> Result<Option<SemanticContext>, ContextError> {
+        match self.inner.get(&context_key(id)).await? {
+            Some(blob) => self.decode(&blob).map(Some),
+            None => Ok(None),
+        }
+    }
+    
+    async fn delete(&self, id: &str) -> Result<(), ContextError> {
+        self.inner.remove(&context_key(id)).await
+    }
+    
+    async fn list(&self) -> Result<Vec<String>, ContextError> {
+        let keys = self.inner.keys().await?;
+        Ok(keys
+            .iter()
+            .filter_map(|key| key.strip_prefix("context/"))
+            .map(str::to_string)
+            .collect())
+    }
+    
+    async fn archive(&self, context: &SemanticContext) -> Result<(), ContextError> {
+        // The whole history is one blob, so it compresses as a unit
+        let mut history = self.history(&context.id).await?;
+        history.push(context.clone());
+        self.inner.put(&history_key(&context.id), self.encode(&history)?).await
+    }
+    
+    async fn history(&self, id: &str) -> Result<Vec<SemanticContext>, ContextError> {
+        match self.inner.get(&history_key(id)).await? {
+            Some(blob) => self.decode(&blob),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl BlobStorage for InMemoryStorage {
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), ContextError> {
+        self.data.write().await.insert(key.to_string(), bytes);
+        Ok(())
+    }
+    
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ContextError> {
+        Ok(self.data.read().await.get(key).cloned())
+    }
+    
+    async fn remove(&self, key: &str) -> Result<(), ContextError> {
+        self.data.write().await.remove(key);
+        Ok(())
+    }
+    
+    async fn keys(&self) -> Result<Vec<String>, ContextError> {
+        Ok(self.data.read().await.keys().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::context::{TransactionRecord, TransactionStatus, UserPreferences};
+    
+    fn busy_context() -> SemanticContext {
+        let mut context = SemanticContext::new(
+            "intent-1".to_string(),
+            "ethereum".to_string(),
+            "polkadot".to_string(),
+            UserPreferences::default(),
+        );
+        for i in 0..200 {
+            context.transaction_history.push(TransactionRecord {
+                chain: "ethereum".to_string(),
+                tx_hash: format!("0x{:064x}", i),
+                status: TransactionStatus::Confirmed,
+                timestamp: 1_700_000_000 + i,
+            });
+        }
+        context.update_integrity_hash();
+        context
+    }
+    
+    #[tokio::test]
+    async fn test_round_trip_and_plain_json_migration() {
+        let storage = CompressingStorage::new(InMemoryStorage::new());
+        let context = busy_context();
+        
+        storage.save(&context).await.unwrap();
+        let loaded = storage.load(&context.id).await.unwrap().unwrap();
+        assert_eq!(loaded.transaction_history, context.transaction_history);
+        assert!(loaded.verify_integrity());
+        assert_eq!(storage.list().await.unwrap(), vec![context.id.clone()]);
+        
+        // Written before compression was enabled
+        let plain = serde_json::to_vec(&context).unwrap();
+        storage.inner().put(&context_key("legacy"), plain).await.unwrap();
+        assert_eq!(storage.load("legacy").await.unwrap().unwrap().id, context.id);
+        
+        // A compressed blob that isn't valid zstd
+        storage.inner().put(&context_key("corrupt"), vec![COMPRESSED_MAGIC, 1, 2, 3]).await.unwrap();
+        assert!(matches!(storage.load("corrupt").await, Err(ContextError::SerializationError(_))));
+    }
+    
+    #[tokio::test]
+    async fn test_compressed_blob_smaller_than_json() {
+        let storage = CompressingStorage::new(InMemoryStorage::new());
+        let context = busy_context();
+        storage.save(&context).await.unwrap();
+        
+        let stored = storage.inner().get(&context_key(&context.id)).await.unwrap().unwrap();
+        let json = serde_json::to_vec(&context).unwrap();
+        assert_eq!(stored[0], COMPRESSED_MAGIC);
+        assert!(stored.len() < json.len() / 2, "{} vs {} bytes", stored.len(), json.len());
+    }
+}
@@ -1,9 +1,23 @@
 // Context Preserver - Maintains semantic context across chains
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Sha3_512};
+use lru::LruCache;
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
+
+use crate::core::clock::{Clock, SystemClock};
+use crate::core::codec::{Codec, CodecError};
+use crate::core::crypto::canonical::to_canonical_bytes;
+
+pub mod audit;
+pub mod compression;
+mod write_behind;
+
+use audit::{AuditLog, AuditOperation};
+use write_behind::WriteBehind;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SemanticContext {
@@ -18,23 +32,113 @@ pub struct SemanticContext {
     pub integrity_hash: Vec<u8>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct UserPreferences {
-    pub slippage_tolerance: f64,
+    pub slippage_bps: u16, // basis points, 50 = 0.5%
     pub max_gas_price: u64,
-    pub min_confirmations: u32,
+    pub min_confirmations: u32, // 0 leaves it to the chain's `ConfirmationPolicy` default
     pub preferred_routes: Vec<String>,
     pub risk_tolerance: RiskLevel,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+impl UserPreferences {
+    /// Basis points for a fractional tolerance (0.005 = 0.5%), or `None` unless it is in 0..=1
+    pub fn slippage_bps_from_tolerance(tolerance: f64) -> Option<u16> {
+        (0.0..=1.0)
+            .contains(&tolerance)
+            .then(|| crate::core::router::SlippageCalculator::bps_from_tolerance(tolerance))
+    }
+    
+    #[deprecated(note = "use `slippage_bps`")]
+    pub fn slippage_tolerance(&self) -> f64 {
+        self.slippage_bps as f64 / 10_000.0
+    }
+}
+
+/// `UserPreferences` as stored, including contexts saved with the old fractional `slippage_tolerance`
+#[derive(Deserialize)]
+struct StoredPreferences {
+    #[serde(default)]
+    slippage_bps: Option<u16>,
+    #[serde(default)]
+    slippage_tolerance: Option<f64>,
+    max_gas_price: u64,
+    min_confirmations: u32,
+    preferred_routes: Vec<String>,
+    risk_tolerance: RiskLevel,
+}
+
+/// `UserPreferences` in binary codecs, which are positional and never held the old field
+#[derive(Deserialize)]
+struct BinaryPreferences {
+    slippage_bps: u16,
+    max_gas_price: u64,
+    min_confirmations: u32,
+    preferred_routes: Vec<String>,
+    risk_tolerance: RiskLevel,
+}
+
+impl<'de> Deserialize<'de> for UserPreferences {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            let stored = BinaryPreferences::deserialize(deserializer)?;
+            return Ok(Self {
+                slippage_bps: stored.slippage_bps,
+                max_gas_price: stored.max_gas_price,
+                min_confirmations: stored.min_confirmations,
+                preferred_routes: stored.preferred_routes,
+                risk_tolerance: stored.risk_tolerance,
+            });
+        }
+        
+        StoredPreferences::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl TryFrom<StoredPreferences> for UserPreferences {
+    type Error = String;
+    
+    fn try_from(stored: StoredPreferences) -> Result<Self, String> {
+        let slippage_bps = match (stored.slippage_bps, stored.slippage_tolerance) {
+            (Some(bps), _) => bps,
+            (None, Some(tolerance)) => Self::slippage_bps_from_tolerance(tolerance)
+                .ok_or_else(|| format!("slippage_tolerance must be between 0 and 1, got {}", tolerance))?,
+            (None, None) => return Err("missing field `slippage_bps`".to_string()),
+        };
+        
+        Ok(Self {
+            slippage_bps,
+            max_gas_price: stored.max_gas_price,
+            min_confirmations: stored.min_confirmations,
+            preferred_routes: stored.preferred_routes,
+            risk_tolerance: stored.risk_tolerance,
+        })
+    }
+}
+
+/// Preferences for contexts created without any from the user
+impl Default for UserPreferences {
+    fn default() -> Self {
+        Self {
+            slippage_bps: 50, // 0.5%, same as the config default
+            max_gas_price: u64::MAX,   // no cap
+            min_confirmations: 0, // per-chain default
+            preferred_routes: Vec::new(),
+            risk_tolerance: RiskLevel::Medium,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub enum RiskLevel {
     Low,
     Medium,
     High,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TransactionRecord {
     pub chain: String,
     pub tx_hash: String,
@@ -49,9 +153,47 @@ pub enum TransactionStatus {
     Failed,
 }
 
+/// What changed in a context between two versions, for audit trails
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ContextDiff {
+    pub added_transactions: Vec<TransactionRecord>,
+    pub removed_transactions: Vec<TransactionRecord>,
+    pub metadata_changes: Vec<MetadataChange>, // sorted by key
+    pub preference_changes: Vec<PreferenceChange>, // sorted by field
+}
+
+/// A metadata key that was added (`old` is None), removed (`new` is None) or rewritten
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MetadataChange {
+    pub key: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PreferenceChange {
+    pub field: String,
+    pub old: serde_json::Value,
+    pub new: serde_json::Value,
+}
+
+impl ContextDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_transactions.is_empty()
+            && self.removed_transactions.is_empty()
+            && self.metadata_changes.is_empty()
+            && self.preference_changes.is_empty()
+    }
+}
+
+/// Contexts `ContextPreserver` keeps in memory unless configured otherwise
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
 pub struct ContextPreserver {
-    contexts: Arc<RwLock<HashMap<String, SemanticContext>>>,
+    contexts: Arc<Mutex<LruCache<String, SemanticContext>>>, // least recently used evicted first
     storage: Arc<dyn ContextStorage>,
+    audit_log: Option<Arc<AuditLog>>,
+    write_behind: Option<Arc<WriteBehind>>,
 }
 
 #[async_trait::async_trait]
@@ -60,6 +202,16 @@ pub trait ContextStorage: Send + Sync {
     async fn load(&self, id: &str) -> Result<Option<SemanticContext>, ContextError>;
     async fn delete(&self, id: &str) -> Result<(), ContextError>;
     async fn list(&self) -> Result<Vec<String>, ContextError>;
+    
+    /// Append a superseded version of a context to its history; by default none is kept
+    async fn archive(&self, _context: &SemanticContext) -> Result<(), ContextError> {
+        Ok(())
+    }
+    
+    /// Superseded versions of a context, oldest first; empty for storage that keeps none
+    async fn history(&self, _id: &str) -> Result<Vec<SemanticContext>, ContextError> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -78,6 +230,9 @@ pub enum ContextError {
     
     #[error("Serialization error: {0}")]
     SerializationError(String),
+    
+    #[error("Audit chain broken at entry {0}")]
+    AuditChainBroken(u64),
 }
 
 impl SemanticContext {
@@ -86,12 +241,20 @@ impl SemanticContext {
         source_chain: String,
         target_chain: String,
         user_preferences: UserPreferences,
+    ) -> Self {
+        Self::new_with_clock(intent_id, source_chain, target_chain, user_preferences, &SystemClock)
+    }
+    
+    /// Create a context timestamped by the given clock
+    pub fn new_with_clock(
+        intent_id: String,
+        source_chain: String,
+        target_chain: String,
+        user_preferences: UserPreferences,
+        clock: &dyn Clock,
     ) -> Self {
         let id = Self::generate_id(&intent_id, &source_chain, &target_chain);
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let timestamp = clock.now_secs();
         
         let mut context = Self {
             id,
@@ -109,6 +272,11 @@ impl SemanticContext {
         context
     }
     
+    /// Id of the context `new` creates for this intent and chain pair
+    pub fn id_for(intent_id: &str, source: &str, target: &str) -> String {
+        Self::generate_id(intent_id, source, target)
+    }
+    
     fn generate_id(intent_id: &str, source: &str, target: &str) -> String {
         let mut hasher = Sha3_512::new();
         hasher.update(intent_id.as_bytes());
@@ -128,37 +296,31 @@ impl SemanticContext {
         self.update_integrity_hash();
     }
     
+    /// SHA3-512 over the canonical encoding of every field except the hash itself
     pub fn update_integrity_hash(&mut self) {
-        let mut hasher = Sha3_512::new();
-        
-        // Hash all context data
-        hasher.update(self.id.as_bytes());
-        hasher.update(self.intent_id.as_bytes());
-        hasher.update(self.source_chain.as_bytes());
-        hasher.update(self.target_chain.as_bytes());
-        hasher.update(&self.timestamp.to_le_bytes());
-        
-        // Hash user preferences
-        hasher.update(&self.user_preferences.slippage_tolerance.to_le_bytes());
-        hasher.update(&self.user_preferences.max_gas_price.to_le_bytes());
-        hasher.update(&self.user_preferences.min_confirmations.to_le_bytes());
-        
-        // Hash transaction history
-        for tx in &self.transaction_history {
-            hasher.update(tx.chain.as_bytes());
-            hasher.update(tx.tx_hash.as_bytes());
-            hasher.update(&tx.timestamp.to_le_bytes());
-        }
-        
-        // Hash metadata
-        let mut keys: Vec<_> = self.metadata.keys().collect();
-        keys.sort();
-        for key in keys {
-            hasher.update(key.as_bytes());
-            hasher.update(self.metadata[key].as_bytes());
+        #[derive(Serialize)]
+        struct Hashed<'a> {
+            id: &'a str,
+            intent_id: &'a str,
+            source_chain: &'a str,
+            target_chain: &'a str,
+            user_preferences: &'a UserPreferences,
+            transaction_history: &'a [TransactionRecord],
+            metadata: &'a HashMap<String, String>,
+            timestamp: u64,
         }
         
-        self.integrity_hash = hasher.finalize().to_vec();
+        let preimage = to_canonical_bytes(&Hashed {
+            id: &self.id,
+            intent_id: &self.intent_id,
+            source_chain: &self.source_chain,
+            target_chain: &self.target_chain,
+            user_preferences: &self.user_preferences,
+            transaction_history: &self.transaction_history,
+            metadata: &self.metadata,
+            timestamp: self.timestamp,
+        });
+        self.integrity_hash = Sha3_512::digest(&preimage).to_vec();
     }
     
     pub fn verify_integrity(&self) -> bool {
@@ -168,28 +330,139 @@ impl SemanticContext {
         
         temp_context.integrity_hash == original_hash
     }
+    
+    /// Changes from `previous` to this version
+    ///
+    /// Transactions are compared as whole records, so a status update shows up as the old
+    /// record removed and the new one added.
+    pub fn diff(&self, previous: &SemanticContext) -> ContextDiff {
+        let mut removed = previous.transaction_history.clone();
+        let mut added_transactions = Vec::new();
+        for record in &self.transaction_history {
+            match removed.iter().position(|r| r == record) {
+                Some(i) => {
+                    removed.remove(i);
+                }
+                None => added_transactions.push(record.clone()),
+            }
+        }
+        
+        let mut keys: Vec<&String> = previous.metadata.keys().chain(self.metadata.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        let metadata_changes = keys
+            .into_iter()
+            .filter(|key| previous.metadata.get(*key) != self.metadata.get(*key))
+            .map(|key| MetadataChange {
+                key: key.clone(),
+                old: previous.metadata.get(key).cloned(),
+                new: self.metadata.get(key).cloned(),
+            })
+            .collect();
+        
+        // Field by field through serde, so new preference fields are covered automatically
+        let as_fields = |prefs: &UserPreferences| match serde_json::to_value(prefs) {
+            Ok(serde_json::Value::Object(fields)) => fields,
+            _ => serde_json::Map::new(),
+        };
+        let (old_prefs, new_prefs) = (as_fields(&previous.user_preferences), as_fields(&self.user_preferences));
+        let mut preference_changes: Vec<PreferenceChange> = new_prefs
+            .iter()
+            .filter(|(field, value)| old_prefs.get(*field) != Some(*value))
+            .map(|(field, value)| PreferenceChange {
+                field: field.clone(),
+                old: old_prefs.get(field).cloned().unwrap_or(serde_json::Value::Null),
+                new: value.clone(),
+            })
+            .collect();
+        preference_changes.sort_by(|a, b| a.field.cmp(&b.field));
+        
+        ContextDiff {
+            added_transactions,
+            removed_transactions: removed,
+            metadata_changes,
+            preference_changes,
+        }
+    }
 }
 
 impl ContextPreserver {
     pub fn new(storage: Arc<dyn ContextStorage>) -> Self {
         Self {
-            contexts: Arc::new(RwLock::new(HashMap::new())),
+            contexts: Arc::new(Mutex::new(LruCache::new(cache_capacity(DEFAULT_CACHE_CAPACITY)))),
             storage,
+            audit_log: None,
+            write_behind: None,
         }
     }
     
+    /// Keep at most `capacity` contexts in memory; evicted ones are reloaded from storage
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.contexts = Arc::new(Mutex::new(LruCache::new(cache_capacity(capacity))));
+        self
+    }
+    
+    /// Whether a context is held in memory, without counting as a use
+    pub async fn is_cached(&self, id: &str) -> bool {
+        self.contexts.lock().await.contains(id)
+    }
+    
+    /// Record every save, update and delete in `audit_log`
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+    
+    /// Queue saves and write them to storage every `flush_interval` instead of on the hot path
+    ///
+    /// Saves return once the cache is updated, and repeated saves of one context between
+    /// flushes become a single write. The trade-off is durability: anything saved since the
+    /// last flush is lost if the process dies, and storage errors surface from `flush`
+    /// rather than `save_context`. Call `close` on shutdown. Must be called inside a Tokio
+    /// runtime.
+    pub fn with_write_behind(mut self, flush_interval: std::time::Duration) -> Self {
+        self.write_behind = Some(WriteBehind::spawn(self.storage.clone(), flush_interval));
+        self
+    }
+    
+    /// Write every queued save to storage; a no-op without write-behind
+    pub async fn flush(&self) -> Result<(), ContextError> {
+        match &self.write_behind {
+            Some(write_behind) => write_behind.flush(self.storage.as_ref()).await,
+            None => Ok(()),
+        }
+    }
+    
+    /// Stop the background flusher and drain what it had left
+    pub async fn close(&self) -> Result<(), ContextError> {
+        if let Some(write_behind) = &self.write_behind {
+            write_behind.stop().await;
+        }
+        self.flush().await
+    }
+    
     pub async fn save_context(&self, context: SemanticContext) -> Result<(), ContextError> {
+        self.store(context, AuditOperation::Save).await
+    }
+    
+    async fn store(&self, context: SemanticContext, operation: AuditOperation) -> Result<(), ContextError> {
         // Verify integrity before saving
         if !context.verify_integrity() {
             return Err(ContextError::IntegrityCheckFailed);
         }
         
-        // Save to storage
-        self.storage.save(&context).await?;
+        // Save to storage, or queue it for the flusher
+        match &self.write_behind {
+            Some(write_behind) => write_behind.enqueue(context.clone()).await,
+            None => self.storage.save(&context).await?,
+        }
+        if let Some(audit_log) = &self.audit_log {
+            audit_log.record(&context.id, operation, &context.integrity_hash).await?;
+        }
         
         // Cache in memory
-        let mut contexts = self.contexts.write().await;
-        contexts.insert(context.id.clone(), context);
+        let mut contexts = self.contexts.lock().await;
+        contexts.put(context.id.clone(), context);
         
         Ok(())
     }
@@ -197,12 +470,20 @@ impl ContextPreserver {
     pub async fn load_context(&self, id: &str) -> Result<SemanticContext, ContextError> {
         // Check cache first
         {
-            let contexts = self.contexts.read().await;
+            let mut contexts = self.contexts.lock().await;
             if let Some(context) = contexts.get(id) {
                 return Ok(context.clone());
             }
         }
         
+        // Evicted from the cache but not written yet
+        if let Some(write_behind) = &self.write_behind {
+            if let Some(context) = write_behind.get(id).await {
+                self.contexts.lock().await.put(id.to_string(), context.clone());
+                return Ok(context);
+            }
+        }
+        
         // Load from storage
         let context = self.storage.load(id).await?
             .ok_or_else(|| ContextError::NotFound(id.to_string()))?;
@@ -213,56 +494,99 @@ impl ContextPreserver {
         }
         
         // Cache it
-        let mut contexts = self.contexts.write().await;
-        contexts.insert(id.to_string(), context.clone());
+        let mut contexts = self.contexts.lock().await;
+        contexts.put(id.to_string(), context.clone());
         
         Ok(context)
     }
     
+    /// Apply `update_fn` and save the result, archiving the version it replaces
     pub async fn update_context<F>(&self, id: &str, update_fn: F) -> Result<(), ContextError>
     where
         F: FnOnce(&mut SemanticContext),
     {
-        let mut context = self.load_context(id).await?;
+        let previous = self.load_context(id).await?;
+        let mut context = previous.clone();
         update_fn(&mut context);
         context.update_integrity_hash();
-        self.save_context(context).await
+        
+        self.storage.archive(&previous).await?;
+        self.store(context, AuditOperation::Update).await
+    }
+    
+    /// Versions of a context replaced by `update_context`, oldest first
+    pub async fn history(&self, id: &str) -> Result<Vec<SemanticContext>, ContextError> {
+        self.storage.history(id).await
     }
     
     pub async fn delete_context(&self, id: &str) -> Result<(), ContextError> {
         // Remove from cache
         {
-            let mut contexts = self.contexts.write().await;
-            contexts.remove(id);
+            let mut contexts = self.contexts.lock().await;
+            contexts.pop(id);
         }
         
-        // Delete from storage
-        self.storage.delete(id).await
+        // Delete from storage, along with any queued save
+        match &self.write_behind {
+            Some(write_behind) => write_behind.delete(self.storage.as_ref(), id).await?,
+            None => self.storage.delete(id).await?,
+        }
+        if let Some(audit_log) = &self.audit_log {
+            audit_log.record(id, AuditOperation::Delete, &[]).await?;
+        }
+        Ok(())
     }
     
     pub async fn list_contexts(&self) -> Result<Vec<String>, ContextError> {
-        self.storage.list().await
+        let mut ids = self.storage.list().await?;
+        if let Some(write_behind) = &self.write_behind {
+            for id in write_behind.pending_ids().await {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+        Ok(ids)
     }
 }
 
+fn cache_capacity(capacity: usize) -> NonZeroUsize {
+    NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)
+}
+
 // In-memory storage implementation for testing
 pub struct InMemoryStorage {
     data: Arc<RwLock<HashMap<String, Vec<u8>>>>,
+    history: Arc<RwLock<HashMap<String, Vec<Vec<u8>>>>>,
+    codec: Codec,
 }
 
 impl InMemoryStorage {
     pub fn new() -> Self {
         Self {
             data: Arc::new(RwLock::new(HashMap::new())),
+            history: Arc::new(RwLock::new(HashMap::new())),
+            codec: Codec::default(),
         }
     }
+    
+    /// Serialize contexts with `codec` instead of JSON
+    pub fn with_codec(mut self, codec: Codec) -> Self {
+        self.codec = codec;
+        self
+    }
+}
+
+impl From<CodecError> for ContextError {
+    fn from(e: CodecError) -> Self {
+        ContextError::SerializationError(e.to_string())
+    }
 }
 
 #[async_trait::async_trait]
 impl ContextStorage for InMemoryStorage {
     async fn save(&self, context: &SemanticContext) -> Result<(), ContextError> {
-        let serialized = serde_json::to_vec(context)
-            .map_err(|e| ContextError::SerializationError(e.to_string()))?;
+        let serialized = self.codec.encode(context)?;
         
         let mut data = self.data.write().await;
         data.insert(context.id.clone(), serialized);
@@ -274,9 +598,7 @@ impl ContextStorage for InMemoryStorage {
         let data = self.data.read().await;
         
         if let Some(serialized) = data.get(id) {
-            let context = serde_json::from_slice(serialized)
-                .map_err(|e| ContextError::SerializationError(e.to_string()))?;
-            Ok(Some(context))
+            Ok(Some(self.codec.decode(serialized)?))
         } else {
             Ok(None)
         }
@@ -292,6 +614,27 @@ impl ContextStorage for InMemoryStorage {
         let data = self.data.read().await;
         Ok(data.keys().cloned().collect())
     }
+    
+    async fn archive(&self, context: &SemanticContext) -> Result<(), ContextError> {
+        let serialized = self.codec.encode(context)?;
+        
+        let mut history = self.history.write().await;
+        history.entry(context.id.clone()).or_default().push(serialized);
+        
+        Ok(())
+    }
+    
+    async fn history(&self, id: &str) -> Result<Vec<SemanticContext>, ContextError> {
+        let history = self.history.read().await;
+        
+        history
+            .get(id)
+            .map(|versions| versions.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|serialized| Ok(self.codec.decode(serialized)?))
+            .collect()
+    }
 }
 
 impl Default for InMemoryStorage {
@@ -307,7 +650,7 @@ mod tests {
     #[test]
     fn test_semantic_context_creation() {
         let prefs = UserPreferences {
-            slippage_tolerance: 0.01,
+            slippage_bps: 100,
             max_gas_price: 100,
             min_confirmations: 6,
             preferred_routes: vec![],
@@ -330,7 +673,7 @@ mod tests {
     #[test]
     fn test_integrity_verification() {
         let prefs = UserPreferences {
-            slippage_tolerance: 0.01,
+            slippage_bps: 100,
             max_gas_price: 100,
             min_confirmations: 6,
             preferred_routes: vec![],
@@ -347,10 +690,76 @@ mod tests {
         assert!(context.verify_integrity());
     }
     
+    #[test]
+    fn test_integrity_hash_ignores_metadata_order_but_covers_every_field() {
+        use crate::core::clock::MockClock;
+        
+        let clock = MockClock::new(1_700_000_000);
+        let new_context = || {
+            SemanticContext::new_with_clock(
+                "intent-1".to_string(),
+                "ethereum".to_string(),
+                "polkadot".to_string(),
+                UserPreferences::default(),
+                &clock,
+            )
+        };
+        
+        let mut a = new_context();
+        let mut b = new_context();
+        for key in ["route", "fee", "memo", "origin"] {
+            a.add_metadata(key.to_string(), format!("{}-value", key));
+        }
+        for key in ["origin", "memo", "fee", "route"] {
+            b.add_metadata(key.to_string(), format!("{}-value", key));
+        }
+        assert_eq!(a.integrity_hash, b.integrity_hash);
+        
+        // Preferences and transaction status are covered too
+        let mut tampered = a.clone();
+        tampered.user_preferences.risk_tolerance = RiskLevel::High;
+        assert!(!tampered.verify_integrity());
+        
+        a.add_transaction(TransactionRecord {
+            chain: "polkadot".to_string(),
+            tx_hash: "0xabc".to_string(),
+            status: TransactionStatus::Pending,
+            timestamp: 1_700_000_001,
+        });
+        let mut tampered = a.clone();
+        tampered.transaction_history[0].status = TransactionStatus::Confirmed;
+        assert!(!tampered.verify_integrity());
+    }
+    
+    #[test]
+    fn test_float_slippage_migrates_to_bps() {
+        assert_eq!(UserPreferences::slippage_bps_from_tolerance(0.005), Some(50));
+        for bad in [f64::NAN, -0.01, 1.5, f64::INFINITY] {
+            assert_eq!(UserPreferences::slippage_bps_from_tolerance(bad), None, "{}", bad);
+        }
+        
+        // Preferences saved before slippage_bps existed
+        let stored = |tolerance: &str| {
+            format!(
+                r#"{{"slippage_tolerance":{},"max_gas_price":100,"min_confirmations":6,"preferred_routes":[],"risk_tolerance":"Low"}}"#,
+                tolerance
+            )
+        };
+        let migrated: UserPreferences = serde_json::from_str(&stored("0.005")).unwrap();
+        assert_eq!(migrated.slippage_bps, 50);
+        assert!(serde_json::from_str::<UserPreferences>(&stored("-0.01")).is_err());
+        assert!(serde_json::from_str::<UserPreferences>(&stored("1e400")).is_err());
+        
+        // The integer form round-trips and is all that gets written
+        let json = serde_json::to_string(&migrated).unwrap();
+        assert!(!json.contains("slippage_tolerance"));
+        assert_eq!(serde_json::from_str::<UserPreferences>(&json).unwrap().slippage_bps, 50);
+    }
+    
     #[test]
     fn test_add_transaction() {
         let prefs = UserPreferences {
-            slippage_tolerance: 0.01,
+            slippage_bps: 100,
             max_gas_price: 100,
             min_confirmations: 6,
             preferred_routes: vec![],
@@ -382,7 +791,7 @@ mod tests {
         let preserver = ContextPreserver::new(storage);
         
         let prefs = UserPreferences {
-            slippage_tolerance: 0.01,
+            slippage_bps: 100,
             max_gas_price: 100,
             min_confirmations: 6,
             preferred_routes: vec![],
@@ -408,5 +817,272 @@ mod tests {
         // List contexts
         let list = preserver.list_contexts().await.unwrap();
         assert_eq!(list.len(), 1);
+    }    
+    #[tokio::test]
+    async fn test_diff_lists_exactly_what_update_changed() {
+        let preserver = ContextPreserver::new(Arc::new(InMemoryStorage::new()));
+        
+        let mut context = SemanticContext::new(
+            "intent-1".to_string(),
+            "ethereum".to_string(),
+            "polkadot".to_string(),
+            UserPreferences::default(),
+        );
+        context.add_metadata("route".to_string(), "direct".to_string());
+        context.add_transaction(TransactionRecord {
+            chain: "ethereum".to_string(),
+            tx_hash: "0x123".to_string(),
+            status: TransactionStatus::Confirmed,
+            timestamp: 1234567890,
+        });
+        let id = context.id.clone();
+        preserver.save_context(context).await.unwrap();
+        
+        let added = TransactionRecord {
+            chain: "polkadot".to_string(),
+            tx_hash: "0x456".to_string(),
+            status: TransactionStatus::Pending,
+            timestamp: 1234567900,
+        };
+        let record = added.clone();
+        preserver
+            .update_context(&id, move |context| {
+                context.user_preferences.slippage_bps = 250;
+                context.transaction_history.push(record);
+            })
+            .await
+            .unwrap();
+        
+        let current = preserver.load_context(&id).await.unwrap();
+        let history = preserver.history(&id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert!(history[0].verify_integrity());
+        
+        let diff = current.diff(&history[0]);
+        assert_eq!(
+            diff,
+            ContextDiff {
+                added_transactions: vec![added],
+                removed_transactions: vec![],
+                metadata_changes: vec![],
+                preference_changes: vec![PreferenceChange {
+                    field: "slippage_bps".to_string(),
+                    old: 50.into(),
+                    new: 250.into(),
+                }],
+            }
+        );
+        assert!(current.diff(&current).is_empty());
+    }    
+    #[tokio::test]
+    async fn test_lru_cache_evicts_but_storage_keeps() {
+        let preserver = ContextPreserver::new(Arc::new(InMemoryStorage::new())).with_cache_capacity(2);
+        
+        let mut ids = Vec::new();
+        for intent in ["intent-1", "intent-2", "intent-3"] {
+            let context = SemanticContext::new(
+                intent.to_string(),
+                "ethereum".to_string(),
+                "polkadot".to_string(),
+                UserPreferences::default(),
+            );
+            ids.push(context.id.clone());
+            preserver.save_context(context).await.unwrap();
+        }
+        
+        assert!(!preserver.is_cached(&ids[0]).await);
+        assert!(preserver.is_cached(&ids[1]).await);
+        assert!(preserver.is_cached(&ids[2]).await);
+        
+        // A miss reloads from storage, evicting the least recently used in turn
+        assert_eq!(preserver.load_context(&ids[0]).await.unwrap().intent_id, "intent-1");
+        assert!(preserver.is_cached(&ids[0]).await);
+        assert!(!preserver.is_cached(&ids[1]).await);
+        assert!(preserver.is_cached(&ids[2]).await);
+    }    
+    /// Storage written before versioning existed: only the four required methods
+    struct UnversionedStorage(InMemoryStorage);
+    
+    #[async_trait::async_trait]
+    impl ContextStorage for UnversionedStorage {
+        async fn save(&self, context: &SemanticContext) -> Result<(), ContextError> {
+            self.0.save(context).await
+        }
+        async fn load(&self, id: &str) -> Result<Option<SemanticContext>, ContextError> {
+            self.0.load(id).await
+        }
+        async fn delete(&self, id: &str) -> Result<(), ContextError> {
+            self.0.delete(id).await
+        }
+        async fn list(&self) -> Result<Vec<String>, ContextError> {
+            self.0.list().await
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_unversioned_storage_updates_without_history() {
+        let preserver = ContextPreserver::new(Arc::new(UnversionedStorage(InMemoryStorage::new())));
+        let context = SemanticContext::new(
+            "intent-1".to_string(),
+            "ethereum".to_string(),
+            "polkadot".to_string(),
+            UserPreferences::default(),
+        );
+        let id = context.id.clone();
+        preserver.save_context(context).await.unwrap();
+        
+        preserver
+            .update_context(&id, |context| context.user_preferences.slippage_bps = 250)
+            .await
+            .unwrap();
+        assert_eq!(preserver.load_context(&id).await.unwrap().user_preferences.slippage_bps, 250);
+        assert!(preserver.history(&id).await.unwrap().is_empty());
+    }
+    
+    /// Counts writes to the storage it wraps
+    struct CountingStorage {
+        inner: InMemoryStorage,
+        saves: std::sync::atomic::AtomicUsize,
+    }
+    
+    #[async_trait::async_trait]
+    impl ContextStorage for CountingStorage {
+        async fn save(&self, context: &SemanticContext) -> Result<(), ContextError> {
+            self.saves.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.inner.save(context).await
+        }
+        async fn load(&self, id: &str) -> Result<Option<SemanticContext>, ContextError> {
+            self.inner.load(id).await
+        }
+        async fn delete(&self, id: &str) -> Result<(), ContextError> {
+            self.inner.delete(id).await
+        }
+        async fn list(&self) -> Result<Vec<String>, ContextError> {
+            self.inner.list().await
+        }
+        async fn archive(&self, context: &SemanticContext) -> Result<(), ContextError> {
+            self.inner.archive(context).await
+        }
+        async fn history(&self, id: &str) -> Result<Vec<SemanticContext>, ContextError> {
+            self.inner.history(id).await
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_write_behind_coalesces_updates_until_flush() {
+        let storage = Arc::new(CountingStorage {
+            inner: InMemoryStorage::new(),
+            saves: Default::default(),
+        });
+        let saves = || storage.saves.load(std::sync::atomic::Ordering::SeqCst);
+        // Long enough that only the explicit flush writes
+        let preserver = ContextPreserver::new(storage.clone())
+            .with_cache_capacity(1)
+            .with_write_behind(std::time::Duration::from_secs(3_600));
+        
+        let context = SemanticContext::new(
+            "intent-1".to_string(),
+            "ethereum".to_string(),
+            "polkadot".to_string(),
+            UserPreferences::default(),
+        );
+        let id = context.id.clone();
+        preserver.save_context(context).await.unwrap();
+        for bps in 1..=5 {
+            preserver
+                .update_context(&id, |context| context.user_preferences.slippage_bps = bps * 100)
+                .await
+                .unwrap();
+        }
+        assert_eq!(saves(), 0);
+        
+        // Pushed out of the cache, the queued version is still what loads
+        let other = SemanticContext::new(
+            "intent-2".to_string(),
+            "ethereum".to_string(),
+            "cosmos".to_string(),
+            UserPreferences::default(),
+        );
+        preserver.save_context(other).await.unwrap();
+        assert!(!preserver.is_cached(&id).await);
+        assert_eq!(preserver.load_context(&id).await.unwrap().user_preferences.slippage_bps, 500);
+        assert_eq!(preserver.list_contexts().await.unwrap().len(), 2);
+        
+        preserver.flush().await.unwrap();
+        assert_eq!(saves(), 2); // one per context
+        let stored = storage.load(&id).await.unwrap().unwrap();
+        assert_eq!(stored.user_preferences.slippage_bps, 500);
+        assert!(stored.verify_integrity());
+        
+        preserver.close().await.unwrap();
+        assert_eq!(saves(), 2);
+    }
+    
+    /// Holds every save until `release` is signalled, telling `started` when one begins
+    struct GatedStorage {
+        inner: InMemoryStorage,
+        started: tokio::sync::Notify,
+        release: tokio::sync::Semaphore,
+    }
+    
+    #[async_trait::async_trait]
+    impl ContextStorage for GatedStorage {
+        async fn save(&self, context: &SemanticContext) -> Result<(), ContextError> {
+            self.started.notify_one();
+            self.release.acquire().await.unwrap().forget();
+            self.inner.save(context).await
+        }
+        async fn load(&self, id: &str) -> Result<Option<SemanticContext>, ContextError> {
+            self.inner.load(id).await
+        }
+        async fn delete(&self, id: &str) -> Result<(), ContextError> {
+            self.inner.delete(id).await
+        }
+        async fn list(&self) -> Result<Vec<String>, ContextError> {
+            self.inner.list().await
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_close_during_background_flush_keeps_every_context() {
+        let storage = Arc::new(GatedStorage {
+            inner: InMemoryStorage::new(),
+            started: tokio::sync::Notify::new(),
+            release: tokio::sync::Semaphore::new(0),
+        });
+        let preserver = Arc::new(
+            ContextPreserver::new(storage.clone())
+                .with_cache_capacity(1)
+                .with_write_behind(std::time::Duration::from_millis(10)),
+        );
+        
+        let mut ids = Vec::new();
+        for destination in ["polkadot", "cosmos"] {
+            let context = SemanticContext::new(
+                format!("intent-{destination}"),
+                "ethereum".to_string(),
+                destination.to_string(),
+                UserPreferences::default(),
+            );
+            ids.push(context.id.clone());
+            preserver.save_context(context).await.unwrap();
+        }
+        
+        // The background flusher is stuck on its first save
+        storage.started.notified().await;
+        assert!(!preserver.is_cached(&ids[0]).await);
+        assert_eq!(preserver.load_context(&ids[0]).await.unwrap().target_chain, "polkadot");
+        
+        let closing = tokio::spawn({
+            let preserver = preserver.clone();
+            async move { preserver.close().await }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        storage.release.add_permits(2);
+        closing.await.unwrap().unwrap();
+        
+        for id in &ids {
+            assert!(storage.load(id).await.unwrap().is_some());
+        }
     }
 }
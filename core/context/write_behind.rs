@@ -0,0 +1,111 @@
+// Write-Behind - Queues context saves and writes them to storage in the background
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tokio::sync::{Mutex, Notify};
+use tokio::task::JoinHandle;
+
+use super::{ContextError, ContextStorage, SemanticContext};
+
+/// Contexts saved but not yet written, latest version per id
+pub(crate) struct WriteBehind {
+    pending: Mutex<HashMap<String, SemanticContext>>,
+    flush_lock: Mutex<()>, // one flush at a time, so an older version can't land after a newer one
+    shutdown: Arc<Notify>,
+    task: std::sync::Mutex<Option<JoinHandle<()>>>,
+}
+
+impl WriteBehind {
+    /// Start a flusher that writes pending contexts to `storage` every `interval`
+    ///
+    /// The task only holds a weak reference, so it ends once the preserver is dropped.
+    pub(crate) fn spawn(storage: Arc<dyn ContextStorage>, interval: Duration) -> Arc<Self> {
+        let write_behind = Arc::new(Self {
+            pending: Mutex::new(HashMap::new()),
+            flush_lock: Mutex::new(()),
+            shutdown: Arc::new(Notify::new()),
+            task: std::sync::Mutex::new(None),
+        });
+        
+        let weak: Weak<Self> = Arc::downgrade(&write_behind);
+        let shutdown = write_behind.shutdown.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {}
+                    _ = shutdown.notified() => break,
+                }
+                let Some(write_behind) = weak.upgrade() else {
+                    break;
+                };
+                if let Err(e) = write_behind.flush(storage.as_ref()).await {
+                    tracing::warn!("Background context flush failed: {}", e);
+                }
+            }
+        });
+        *write_behind.task.lock().unwrap() = Some(task);
+        
+        write_behind
+    }
+    
+    /// Queue `context`, replacing any version of it still waiting
+    pub(crate) async fn enqueue(&self, context: SemanticContext) {
+        self.pending.lock().await.insert(context.id.clone(), context);
+    }
+    
+    pub(crate) async fn get(&self, id: &str) -> Option<SemanticContext> {
+        self.pending.lock().await.get(id).cloned()
+    }
+    
+    pub(crate) async fn pending_ids(&self) -> Vec<String> {
+        self.pending.lock().await.keys().cloned().collect()
+    }
+    
+    /// Drop a queued context and delete it from storage, with no flush in between
+    pub(crate) async fn delete(&self, storage: &dyn ContextStorage, id: &str) -> Result<(), ContextError> {
+        let _flushing = self.flush_lock.lock().await;
+        self.pending.lock().await.remove(id);
+        storage.delete(id).await
+    }
+    
+    /// Write every pending context to `storage`
+    ///
+    /// Each context stays queued until its save succeeds, so a flush that is interrupted or
+    /// fails leaves it readable and retried next time. Once saved it is only dropped if no
+    /// newer version was queued meanwhile; the first error is returned once the rest have
+    /// been tried.
+    pub(crate) async fn flush(&self, storage: &dyn ContextStorage) -> Result<(), ContextError> {
+        let _flushing = self.flush_lock.lock().await;
+        let batch: Vec<SemanticContext> = self.pending.lock().await.values().cloned().collect();
+        
+        let mut first_error = None;
+        for context in batch {
+            match storage.save(&context).await {
+                Ok(()) => {
+                    let mut pending = self.pending.lock().await;
+                    if pending.get(&context.id).is_some_and(|queued| queued.integrity_hash == context.integrity_hash) {
+                        pending.remove(&context.id);
+                    }
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        
+        first_error.map_or(Ok(()), Err)
+    }
+    
+    /// Stop the background flusher, letting a flush it has started finish first
+    ///
+    /// Pending contexts stay queued for a final `flush`.
+    pub(crate) async fn stop(&self) {
+        let task = self.task.lock().unwrap().take();
+        if let Some(task) = task {
+            self.shutdown.notify_one();
+            if let Err(e) = task.await {
+                tracing::warn!("Background context flusher ended abnormally: {}", e);
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// Canonical Encoding - Deterministic bytes for values that are hashed
+use serde::Serialize;
+use serde_json::Value;
+
+/// Deterministic JSON encoding of `value`, for integrity hashes
+///
+/// Object keys are sorted by their UTF-8 bytes at every level, whatever order the map or
+/// serde_json's `preserve_order` feature would give. There is no whitespace, integers are
+/// written in decimal and other numbers in shortest round-trip exponent form (`5e-3`), so the
+/// same value always encodes to the same bytes.
+///
+/// Panics if `value` has no JSON form (e.g. a map with non-string keys).
+pub fn to_canonical_bytes<T: Serialize + ?Sized>(value: &T) -> Vec<u8> {
+    let value = serde_json::to_value(value).expect("value must be representable as JSON");
+    let mut out = Vec::new();
+    write_value(&value, &mut out);
+    out
+}
+
+fn write_value(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => out.extend_from_slice(b"null"),
+        Value::Bool(b) => out.extend_from_slice(if *b { b"true" } else { b"false" }),
+        Value::Number(n) => {
+            let text = match (n.as_u64(), n.as_i64(), n.as_f64()) {
+                (Some(u), _, _) => u.to_string(),
+                (None, Some(i), _) => i.to_string(),
+                (None, None, Some(f)) => format!("{:e}", f),
+                (None, None, None) => n.to_string(),
+            };
+            out.extend_from_slice(text.as_bytes());
+        }
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push(b'[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_value(item, out);
+            }
+            out.push(b']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+            
+            out.push(b'{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_string(key, out);
+                out.push(b':');
+                write_value(item, out);
+            }
+            out.push(b'}');
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut Vec<u8>) {
+    // serde_json's escaping is fixed: only quotes, backslashes and control characters
+    out.extend_from_slice(&serde_json::to_vec(s).expect("strings always serialize"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    
+    #[test]
+    fn test_insertion_order_does_not_change_bytes() {
+        let mut a = HashMap::new();
+        let mut b = HashMap::new();
+        for (key, value) in [("zeta", 1), ("alpha", 2), ("mid", 3), ("beta", 4), ("omega", 5)] {
+            a.insert(key.to_string(), value);
+        }
+        for (key, value) in [("omega", 5), ("beta", 4), ("mid", 3), ("alpha", 2), ("zeta", 1)] {
+            b.insert(key.to_string(), value);
+        }
+        
+        // Same logical value through a map that keeps insertion order
+        let mut ordered = serde_json::Map::new();
+        ordered.insert("zeta".to_string(), 1.into());
+        ordered.insert("alpha".to_string(), 2.into());
+        ordered.insert("omega".to_string(), 5.into());
+        ordered.insert("mid".to_string(), 3.into());
+        ordered.insert("beta".to_string(), 4.into());
+        
+        let bytes = to_canonical_bytes(&a);
+        assert_eq!(bytes, to_canonical_bytes(&b));
+        assert_eq!(bytes, to_canonical_bytes(&ordered));
+        assert_eq!(bytes, br#"{"alpha":2,"beta":4,"mid":3,"omega":5,"zeta":1}"#);
+    }
+    
+    #[test]
+    fn test_number_formatting_is_fixed() {
+        let value = serde_json::json!({ "b": [0.005, 1.0, -3, 18446744073709551615u64], "a": "\u{1}\"" });
+        assert_eq!(
+            to_canonical_bytes(&value),
+            br#"{"a":"\u0001\"","b":[5e-3,1e0,-3,18446744073709551615]}"#
+        );
+    }
+}
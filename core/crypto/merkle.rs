@@ -0,0 +1,197 @@
+// Merkle Proofs - Binary Merkle branch verification shared by the chain-specific verifiers
+use sha2::{Digest, Sha256};
+use sha3::Sha3_256;
+
+/// A binary Merkle tree's interior node hash
+pub trait MerkleVerifier {
+    fn hash_nodes(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32];
+    
+    /// Whether `leaf` at `index` hashes up through `siblings` (leaf level first) to `root`
+    fn verify(&self, leaf: [u8; 32], index: u32, siblings: &[[u8; 32]], root: &[u8; 32]) -> bool {
+        binary_merkle_verify(leaf, index, siblings, root, |l, r| self.hash_nodes(l, r))
+    }
+}
+
+/// Bitcoin's transaction tree: double SHA-256 of the concatenated children, in internal byte order
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256dMerkle;
+
+impl Sha256dMerkle {
+    /// Transaction id (internal byte order) of a serialized transaction, the tree's leaf
+    pub fn txid(transaction: &[u8]) -> [u8; 32] {
+        sha256d(&[transaction])
+    }
+}
+
+impl MerkleVerifier for Sha256dMerkle {
+    fn hash_nodes(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        sha256d(&[left, right])
+    }
+}
+
+/// Quantum-mode variant of `Sha256dMerkle` with double SHA3-256 for leaves and nodes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha3dMerkle;
+
+impl Sha3dMerkle {
+    pub fn txid(transaction: &[u8]) -> [u8; 32] {
+        Sha3_256::digest(Sha3_256::digest(transaction)).into()
+    }
+}
+
+impl MerkleVerifier for Sha3dMerkle {
+    fn hash_nodes(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(left);
+        hasher.update(right);
+        Sha3_256::digest(hasher.finalize()).into()
+    }
+}
+
+fn sha256d(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    Sha256::digest(hasher.finalize()).into()
+}
+
+/// Walk a Merkle branch from `leaf` to the root, taking the side at each level from `index`
+///
+/// Bit `i` of `index` set means the node at level `i` is a right child. Indices that need
+/// more levels than `siblings` provides are rejected rather than silently truncated.
+pub fn binary_merkle_verify<H>(leaf: [u8; 32], index: u32, siblings: &[[u8; 32]], root: &[u8; 32], hasher: H) -> bool
+where
+    H: Fn(&[u8; 32], &[u8; 32]) -> [u8; 32],
+{
+    if siblings.len() < 32 && index >> siblings.len() != 0 {
+        return false;
+    }
+    
+    let mut current = leaf;
+    for (level, sibling) in siblings.iter().enumerate() {
+        // Levels past the index's 32 bits are all left children
+        current = if index.checked_shr(level as u32).unwrap_or(0) & 1 == 0 {
+            hasher(&current, sibling)
+        } else {
+            hasher(sibling, &current)
+        };
+    }
+    
+    &current == root
+}
+
+/// Root of a tree over `leaves`, pairing an odd last node with itself (Bitcoin's rule)
+pub fn merkle_root<V: MerkleVerifier>(leaves: &[[u8; 32]], verifier: &V) -> Option<[u8; 32]> {
+    let mut level = leaves.to_vec();
+    if level.is_empty() {
+        return None;
+    }
+    
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| verifier.hash_nodes(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+    }
+    
+    Some(level[0])
+}
+
+/// Sibling hashes proving the leaf at `index`, leaf level first
+pub fn merkle_branch<V: MerkleVerifier>(leaves: &[[u8; 32]], index: usize, verifier: &V) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    
+    let mut level = leaves.to_vec();
+    let mut position = index;
+    let mut branch = Vec::new();
+    
+    while level.len() > 1 {
+        let sibling = position ^ 1;
+        branch.push(*level.get(sibling).unwrap_or(&level[position]));
+        
+        level = level
+            .chunks(2)
+            .map(|pair| verifier.hash_nodes(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+        position /= 2;
+    }
+    
+    Some(branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    
+    fn from_display_hex(s: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&hex::decode(s).unwrap());
+        bytes.reverse();
+        bytes
+    }
+    
+    #[test]
+    fn test_bitcoin_block_100000_root() {
+        let txids = [
+            "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+            "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+            "6359f0868171b1d194cbee1af2f16ea598ae8fad666d9b012c8ed2b79a236ec4",
+            "e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d",
+        ]
+        .map(from_display_hex);
+        let root = from_display_hex("f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766");
+        
+        assert_eq!(merkle_root(&txids, &Sha256dMerkle), Some(root));
+        
+        let branch = merkle_branch(&txids, 2, &Sha256dMerkle).unwrap();
+        assert!(Sha256dMerkle.verify(txids[2], 2, &branch, &root));
+        assert!(!Sha256dMerkle.verify(txids[2], 3, &branch, &root));
+    }
+    
+    #[test]
+    fn test_index_beyond_branch_depth_rejected() {
+        let leaves = [[1u8; 32], [2u8; 32]];
+        let root = merkle_root(&leaves, &Sha256dMerkle).unwrap();
+        let branch = merkle_branch(&leaves, 1, &Sha256dMerkle).unwrap();
+        
+        assert!(Sha256dMerkle.verify(leaves[1], 1, &branch, &root));
+        assert!(!Sha256dMerkle.verify(leaves[1], 3, &branch, &root));
+    }
+    
+    #[test]
+    fn test_single_leaf_is_its_own_root() {
+        let leaf = [7u8; 32];
+        assert_eq!(merkle_root(&[leaf], &Sha256dMerkle), Some(leaf));
+        assert!(Sha256dMerkle.verify(leaf, 0, &[], &leaf));
+        assert_eq!(merkle_root(&[], &Sha256dMerkle), None);
+    }
+    
+    proptest! {
+        #[test]
+        fn prop_every_leaf_verifies(leaves in prop::collection::vec(any::<[u8; 32]>(), 1..40)) {
+            let root = merkle_root(&leaves, &Sha256dMerkle).unwrap();
+            
+            for (index, leaf) in leaves.iter().enumerate() {
+                let branch = merkle_branch(&leaves, index, &Sha256dMerkle).unwrap();
+                prop_assert!(Sha256dMerkle.verify(*leaf, index as u32, &branch, &root));
+            }
+        }
+        
+        #[test]
+        fn prop_tampered_sibling_fails(
+            leaves in prop::collection::vec(any::<[u8; 32]>(), 2..40),
+            pick in any::<prop::sample::Index>(),
+        ) {
+            let root = merkle_root(&leaves, &Sha256dMerkle).unwrap();
+            let index = pick.index(leaves.len());
+            let mut branch = merkle_branch(&leaves, index, &Sha256dMerkle).unwrap();
+            branch[0][0] ^= 0x01;
+            
+            prop_assert!(!Sha256dMerkle.verify(leaves[index], index as u32, &branch, &root));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Sentium Bridge - Quantum-Safe Cryptography
+// This module provides key management and signing for Dilithium5 keys
+
+pub mod canonical;
+pub mod merkle;
+
+use pqcrypto_dilithium::dilithium5;
+use pqcrypto_traits::sign::{DetachedSignature, PublicKey as PQPublicKey, SecretKey as PQSecretKey};
+use zeroize::Zeroizing;
+
+/// Dilithium5 public key (2592 bytes)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuantumPublicKey {
+    bytes: Vec<u8>,
+}
+
+/// Dilithium5 secret key (4896 bytes), zeroized when dropped
+pub struct QuantumSecretKey {
+    bytes: Zeroizing<Vec<u8>>,
+}
+
+impl QuantumPublicKey {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CryptoError> {
+        if bytes.len() != dilithium5::public_key_bytes() {
+            return Err(CryptoError::InvalidKeyLength {
+                expected: dilithium5::public_key_bytes(),
+                actual: bytes.len(),
+            });
+        }
+        
+        Ok(Self { bytes: bytes.to_vec() })
+    }
+    
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl QuantumSecretKey {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CryptoError> {
+        if bytes.len() != dilithium5::secret_key_bytes() {
+            return Err(CryptoError::InvalidKeyLength {
+                expected: dilithium5::secret_key_bytes(),
+                actual: bytes.len(),
+            });
+        }
+        
+        Ok(Self { bytes: Zeroizing::new(bytes.to_vec()) })
+    }
+    
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl std::fmt::Debug for QuantumSecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print secret key material
+        f.debug_struct("QuantumSecretKey").finish_non_exhaustive()
+    }
+}
+
+/// Key manager for producing and checking Dilithium5 signatures
+pub struct QuantumKeyManager;
+
+impl QuantumKeyManager {
+    /// Generate a fresh Dilithium5 keypair
+    pub fn generate_keypair() -> (QuantumPublicKey, QuantumSecretKey) {
+        let (pk, sk) = dilithium5::keypair();
+        
+        let public_key = QuantumPublicKey { bytes: pk.as_bytes().to_vec() };
+        let secret_key = QuantumSecretKey { bytes: Zeroizing::new(sk.as_bytes().to_vec()) };
+        
+        (public_key, secret_key)
+    }
+    
+    /// Sign a message, returning the detached Dilithium5 signature (4627 bytes)
+    pub fn sign(secret_key: &QuantumSecretKey, message: &[u8]) -> Vec<u8> {
+        let sk = dilithium5::SecretKey::from_bytes(secret_key.as_bytes())
+            .expect("QuantumSecretKey always holds a key of valid length");
+        
+        dilithium5::detached_sign(message, &sk).as_bytes().to_vec()
+    }
+    
+    /// Verify a detached Dilithium5 signature
+    pub fn verify(public_key: &QuantumPublicKey, message: &[u8], signature: &[u8]) -> bool {
+        let pk = match dilithium5::PublicKey::from_bytes(public_key.as_bytes()) {
+            Ok(pk) => pk,
+            Err(_) => return false,
+        };
+        
+        let sig = match dilithium5::DetachedSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+        
+        dilithium5::verify_detached_signature(&sig, message, &pk).is_ok()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CryptoError {
+    #[error("Invalid key length: expected {expected}, got {actual}")]
+    InvalidKeyLength { expected: usize, actual: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_generate_keypair() {
+        let (pk, sk) = QuantumKeyManager::generate_keypair();
+        assert_eq!(pk.as_bytes().len(), dilithium5::public_key_bytes());
+        assert_eq!(sk.as_bytes().len(), dilithium5::secret_key_bytes());
+    }
+    
+    #[test]
+    fn test_sign_and_verify() {
+        let (pk, sk) = QuantumKeyManager::generate_keypair();
+        let message = b"sentium state proof";
+        
+        let signature = QuantumKeyManager::sign(&sk, message);
+        assert_eq!(signature.len(), dilithium5::signature_bytes());
+        assert!(QuantumKeyManager::verify(&pk, message, &signature));
+        
+        // Tampered message must not verify
+        assert!(!QuantumKeyManager::verify(&pk, b"another message", &signature));
+    }
+    
+    #[test]
+    fn test_verify_with_wrong_key() {
+        let (_, sk) = QuantumKeyManager::generate_keypair();
+        let (other_pk, _) = QuantumKeyManager::generate_keypair();
+        
+        let signature = QuantumKeyManager::sign(&sk, b"message");
+        assert!(!QuantumKeyManager::verify(&other_pk, b"message", &signature));
+    }
+    
+    #[test]
+    fn test_invalid_key_length() {
+        assert!(QuantumPublicKey::from_bytes(&[0u8; 10]).is_err());
+        assert!(QuantumSecretKey::from_bytes(&[0u8; 10]).is_err());
+    }
+}
@@ -13,7 +13,6 @@ pub mod bittensor_adapter;
 pub mod bnb_adapter;
 pub mod bouncebit_adapter;
 pub mod celestia_adapter;
-pub mod chia_adapter;
 pub mod chiliz_adapter;
 pub mod core_adapter;
 pub mod cosmos_adapter;
@@ -28,7 +27,6 @@ pub mod ethereum_adapter;
 pub mod ethereum_classic_adapter;
 pub mod filecoin_adapter;
 pub mod flow_adapter;
-pub mod harmony_adapter;
 pub mod hedera_adapter;
 pub mod icp_adapter;
 pub mod iota_adapter;
@@ -43,12 +41,10 @@ pub mod near_adapter;
 pub mod oasis_adapter;
 pub mod pi_network_adapter;
 pub mod plume_adapter;
-pub mod polkadot_adapter;
 pub mod polygon_adapter;
 pub mod secret_adapter;
 pub mod sei_adapter;
 pub mod solana_adapter;
-pub mod somnia_adapter;
 pub mod sonic_adapter;
 pub mod stellar_adapter;
 pub mod sui_adapter;
@@ -0,0 +1,305 @@
+// Address Validation - Chain-agnostic address format checks shared by adapters and the translator
+use bech32::{FromBase32, Variant};
+use blake2::{Blake2b512, Digest as _};
+use sha2::Sha256;
+use sha3::Keccak256;
+
+use super::intent_translator::ChainType;
+use super::RouterError;
+
+/// Default bech32 human-readable part for Cosmos Hub accounts
+pub const COSMOS_HRP: &str = "cosmos";
+
+/// Bech32 human-readable part for Sentium native accounts
+pub const SENTIUM_HRP: &str = "sentium";
+
+/// Bech32 human-readable part for Harmony `one1...` addresses
+pub const HARMONY_HRP: &str = "one";
+
+/// Bech32m human-readable parts for Chia mainnet and testnet puzzle hashes
+pub const CHIA_HRP: &str = "xch";
+pub const CHIA_TESTNET_HRP: &str = "txch";
+
+/// Validate an address for a chain type using the default Cosmos prefix
+pub fn validate(chain_type: ChainType, addr: &str) -> Result<(), RouterError> {
+    AddressValidator::default().validate(chain_type, addr)
+}
+
+/// Address validator with configurable bech32 prefixes
+#[derive(Debug, Clone)]
+pub struct AddressValidator {
+    cosmos_hrp: String,
+}
+
+impl Default for AddressValidator {
+    fn default() -> Self {
+        Self {
+            cosmos_hrp: COSMOS_HRP.to_string(),
+        }
+    }
+}
+
+impl AddressValidator {
+    /// Use a different Cosmos account prefix (e.g. "osmo", "juno")
+    pub fn with_cosmos_hrp(mut self, hrp: &str) -> Self {
+        self.cosmos_hrp = hrp.to_string();
+        self
+    }
+    
+    pub fn validate(&self, chain_type: ChainType, addr: &str) -> Result<(), RouterError> {
+        match chain_type {
+            ChainType::EVM => validate_evm(addr),
+            ChainType::Substrate => validate_ss58(addr),
+            ChainType::Cosmos => validate_bech32(addr, &self.cosmos_hrp),
+            ChainType::Bitcoin => validate_bitcoin(addr),
+            ChainType::Sentium => validate_bech32(addr, SENTIUM_HRP),
+        }
+    }
+}
+
+/// `0x` + 40 hex digits; mixed-case addresses must carry a valid EIP-55 checksum
+pub fn validate_evm(addr: &str) -> Result<(), RouterError> {
+    let hex_part = addr
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid("EVM", addr, "missing 0x prefix"))?;
+    
+    if hex_part.len() != 40 || !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid("EVM", addr, "expected 40 hex digits"));
+    }
+    
+    let has_lower = hex_part.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex_part.chars().any(|c| c.is_ascii_uppercase());
+    if !(has_lower && has_upper) {
+        return Ok(()); // single-case addresses carry no checksum
+    }
+    
+    let hash = Keccak256::digest(hex_part.to_ascii_lowercase().as_bytes());
+    
+    for (i, c) in hex_part.chars().enumerate() {
+        if c.is_ascii_digit() {
+            continue;
+        }
+        
+        // Each letter is uppercase iff the matching hash nibble is >= 8
+        let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+        if (nibble >= 8) != c.is_ascii_uppercase() {
+            return Err(invalid("EVM", addr, "bad EIP-55 checksum"));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Legacy base58check (P2PKH/P2SH) or segwit bech32/bech32m, mainnet or testnet
+pub fn validate_bitcoin(addr: &str) -> Result<(), RouterError> {
+    let lower = addr.to_ascii_lowercase();
+    if lower.starts_with("bc1") || lower.starts_with("tb1") || lower.starts_with("bcrt1") {
+        return validate_segwit(addr);
+    }
+    
+    let decoded = bs58::decode(addr)
+        .into_vec()
+        .map_err(|e| invalid("Bitcoin", addr, &e.to_string()))?;
+    
+    if decoded.len() != 25 {
+        return Err(invalid("Bitcoin", addr, "expected 25-byte payload"));
+    }
+    
+    let (payload, checksum) = decoded.split_at(21);
+    let hash = Sha256::digest(Sha256::digest(payload));
+    if &hash[..4] != checksum {
+        return Err(invalid("Bitcoin", addr, "bad base58 checksum"));
+    }
+    
+    // P2PKH / P2SH version bytes for mainnet and testnet
+    match payload[0] {
+        0x00 | 0x05 | 0x6f | 0xc4 => Ok(()),
+        version => Err(invalid("Bitcoin", addr, &format!("unknown version byte {:#04x}", version))),
+    }
+}
+
+fn validate_segwit(addr: &str) -> Result<(), RouterError> {
+    let (hrp, data, variant) = bech32::decode(addr).map_err(|e| invalid("Bitcoin", addr, &e.to_string()))?;
+    
+    if !matches!(hrp.as_str(), "bc" | "tb" | "bcrt") {
+        return Err(invalid("Bitcoin", addr, "unknown segwit prefix"));
+    }
+    
+    let (version, program) = data
+        .split_first()
+        .ok_or_else(|| invalid("Bitcoin", addr, "missing witness version"))?;
+    let version = version.to_u8();
+    let program = Vec::<u8>::from_base32(program).map_err(|e| invalid("Bitcoin", addr, &e.to_string()))?;
+    
+    // BIP-350: v0 uses bech32, v1+ uses bech32m
+    let expected_variant = if version == 0 { Variant::Bech32 } else { Variant::Bech32m };
+    if version > 16 || variant != expected_variant {
+        return Err(invalid("Bitcoin", addr, "wrong witness version or checksum variant"));
+    }
+    
+    let valid_length = match version {
+        0 => program.len() == 20 || program.len() == 32,
+        _ => (2..=40).contains(&program.len()),
+    };
+    if !valid_length {
+        return Err(invalid("Bitcoin", addr, "invalid witness program length"));
+    }
+    
+    Ok(())
+}
+
+/// SS58 address for a 32-byte account with any network prefix
+pub fn validate_ss58(addr: &str) -> Result<(), RouterError> {
+    let decoded = bs58::decode(addr)
+        .into_vec()
+        .map_err(|e| invalid("SS58", addr, &e.to_string()))?;
+    
+    // Prefixes 0-63 take one byte, 64-16383 take two
+    let prefix_len = match decoded.first() {
+        Some(0..=63) => 1,
+        Some(64..=127) => 2,
+        _ => return Err(invalid("SS58", addr, "invalid network prefix")),
+    };
+    
+    if decoded.len() != prefix_len + 32 + 2 {
+        return Err(invalid("SS58", addr, "expected 32-byte account"));
+    }
+    
+    let (body, checksum) = decoded.split_at(decoded.len() - 2);
+    let mut hasher = Blake2b512::new();
+    hasher.update(b"SS58PRE");
+    hasher.update(body);
+    if &hasher.finalize()[..2] != checksum {
+        return Err(invalid("SS58", addr, "bad checksum"));
+    }
+    
+    Ok(())
+}
+
+/// Bech32 account address (20 or 32 bytes) with the given human-readable part
+pub fn validate_bech32(addr: &str, hrp: &str) -> Result<(), RouterError> {
+    let (decoded_hrp, data, variant) = bech32::decode(addr).map_err(|e| invalid(hrp, addr, &e.to_string()))?;
+    
+    if decoded_hrp != hrp {
+        return Err(invalid(hrp, addr, &format!("expected prefix {}", hrp)));
+    }
+    
+    if variant != Variant::Bech32 {
+        return Err(invalid(hrp, addr, "expected bech32 checksum"));
+    }
+    
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|e| invalid(hrp, addr, &e.to_string()))?;
+    if bytes.len() != 20 && bytes.len() != 32 {
+        return Err(invalid(hrp, addr, "expected 20 or 32 byte account"));
+    }
+    
+    Ok(())
+}
+
+/// Harmony `one1...` address (bech32 encoding of a 20-byte EVM account)
+pub fn validate_harmony(addr: &str) -> Result<(), RouterError> {
+    validate_bech32(addr, HARMONY_HRP)?;
+    
+    let (_, data, _) = bech32::decode(addr).map_err(|e| invalid("Harmony", addr, &e.to_string()))?;
+    match Vec::<u8>::from_base32(&data) {
+        Ok(bytes) if bytes.len() == 20 => Ok(()),
+        _ => Err(invalid("Harmony", addr, "expected 20-byte account")),
+    }
+}
+
+/// Chia `xch1...` address: bech32m encoding of a 32-byte puzzle hash
+pub fn validate_chia(addr: &str, hrp: &str) -> Result<(), RouterError> {
+    let (decoded_hrp, data, variant) = bech32::decode(addr).map_err(|e| invalid("Chia", addr, &e.to_string()))?;
+    
+    if decoded_hrp != hrp {
+        return Err(invalid("Chia", addr, &format!("expected prefix {}", hrp)));
+    }
+    
+    // Chia uses bech32m; a plain bech32 checksum over the same data is a different address
+    if variant != Variant::Bech32m {
+        return Err(invalid("Chia", addr, "expected bech32m checksum"));
+    }
+    
+    match Vec::<u8>::from_base32(&data) {
+        Ok(bytes) if bytes.len() == 32 => Ok(()),
+        _ => Err(invalid("Chia", addr, "expected 32-byte puzzle hash")),
+    }
+}
+
+fn invalid(kind: &str, addr: &str, reason: &str) -> RouterError {
+    RouterError::TranslationError(format!("Invalid {} address {}: {}", kind, addr, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_address_table() {
+        let cases: &[(ChainType, &str, bool)] = &[
+            // EIP-55 reference vectors
+            (ChainType::EVM, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", true),
+            (ChainType::EVM, "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359", true),
+            (ChainType::EVM, "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", true),
+            (ChainType::EVM, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD", false),
+            (ChainType::EVM, "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", false),
+            (ChainType::EVM, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA", false),
+            // Bitcoin legacy, P2SH, segwit v0 and taproot
+            (ChainType::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", true),
+            (ChainType::Bitcoin, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy", true),
+            (ChainType::Bitcoin, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", true),
+            (ChainType::Bitcoin, "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0", true),
+            (ChainType::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3", false),
+            (ChainType::Bitcoin, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5", false),
+            (ChainType::Bitcoin, "bc1zw508d6qejxtdg4y5r3zarvaryvqyzf3du", false),
+            // SS58 with generic (42) and Polkadot (0) prefixes
+            (ChainType::Substrate, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", true),
+            (ChainType::Substrate, "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5", true),
+            (ChainType::Substrate, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ", false),
+            (ChainType::Substrate, "0x0000000000000000000000000000000000000001", false),
+            // Cosmos bech32
+            (ChainType::Cosmos, "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu", true),
+            (ChainType::Cosmos, "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xv", false),
+            (ChainType::Cosmos, "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw", false),
+        ];
+        
+        for (chain_type, addr, expected) in cases {
+            assert_eq!(
+                validate(*chain_type, addr).is_ok(),
+                *expected,
+                "{:?} {} should be {}",
+                chain_type,
+                addr,
+                if *expected { "valid" } else { "invalid" }
+            );
+        }
+    }
+    
+    #[test]
+    fn test_configurable_cosmos_hrp() {
+        let osmosis = AddressValidator::default().with_cosmos_hrp("osmo");
+        
+        assert!(osmosis.validate(ChainType::Cosmos, "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw").is_ok());
+        assert!(osmosis.validate(ChainType::Cosmos, "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu").is_err());
+    }
+    
+    #[test]
+    fn test_harmony_addresses() {
+        assert!(validate_harmony("one1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5srzldr").is_ok());
+        assert!(validate_harmony("one1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5srzldq").is_err());
+        assert!(validate_harmony("cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu").is_err());
+    }
+    
+    #[test]
+    fn test_chia_addresses() {
+        let puzzle_hash = "xch1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0srg6dkm";
+        assert!(validate_chia(puzzle_hash, CHIA_HRP).is_ok());
+        assert!(validate_chia("txch1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sw0amhg", CHIA_TESTNET_HRP).is_ok());
+        
+        // Wrong network, bech32 (not bech32m) checksum, bad checksum, 20-byte payload
+        assert!(validate_chia(puzzle_hash, CHIA_TESTNET_HRP).is_err());
+        assert!(validate_chia("xch1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sk52pne", CHIA_HRP).is_err());
+        assert!(validate_chia("xch1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0srg6dkq", CHIA_HRP).is_err());
+        assert!(validate_chia("xch1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnlgaqhp", CHIA_HRP).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+// Authorizer - Decides whether the router may act on an intent at all
+use async_trait::async_trait;
+
+use super::{Intent, RouterError};
+
+/// Checked by the router before an intent is routed; an `Err` stops it there
+#[async_trait]
+pub trait Authorizer: Send + Sync {
+    /// The principal the intent was verified to come from, which the router rate limits
+    /// it under; nothing the intent claims about itself unchecked should go into it
+    async fn authorize(&self, intent: &Intent) -> Result<String, RouterError>;
+}
+
+/// Principal `AllowAll` admits every intent as, since it verifies no identity
+pub const ANONYMOUS_PRINCIPAL: &str = "anonymous";
+
+/// Accepts every intent as `ANONYMOUS_PRINCIPAL`, the router's default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllowAll;
+
+#[async_trait]
+impl Authorizer for AllowAll {
+    async fn authorize(&self, _intent: &Intent) -> Result<String, RouterError> {
+        Ok(ANONYMOUS_PRINCIPAL.to_string())
+    }
+}
+
+/// Accepts intents carrying an EIP-712 signature by one of a fixed set of signers, whose
+/// address is the principal
+#[cfg(feature = "eth")]
+#[derive(Debug, Clone)]
+pub struct Eip712SignatureAuthorizer {
+    signers: std::collections::HashSet<ethers::types::Address>,
+    domain: ethers::types::transaction::eip712::EIP712Domain,
+}
+
+#[cfg(feature = "eth")]
+impl Eip712SignatureAuthorizer {
+    pub fn new(signers: impl IntoIterator<Item = ethers::types::Address>) -> Self {
+        Self {
+            signers: signers.into_iter().collect(),
+            domain: super::intent::default_domain(),
+        }
+    }
+    
+    /// Expect signatures under a deployment-specific domain instead of the default
+    pub fn with_domain(mut self, domain: ethers::types::transaction::eip712::EIP712Domain) -> Self {
+        self.domain = domain;
+        self
+    }
+}
+
+#[cfg(feature = "eth")]
+#[async_trait]
+impl Authorizer for Eip712SignatureAuthorizer {
+    async fn authorize(&self, intent: &Intent) -> Result<String, RouterError> {
+        use ethers::types::Signature;
+        
+        let signature = intent
+            .signature
+            .as_deref()
+            .ok_or_else(|| RouterError::Unauthorized(format!("Intent {} is not signed", intent.id)))?;
+        
+        // Malformed and unrecoverable signatures are forgeries as far as the caller is concerned
+        let signer = Signature::try_from(signature)
+            .ok()
+            .and_then(|sig| sig.recover(super::intent::eip712_hash(intent, &self.domain)).ok())
+            .ok_or_else(|| RouterError::Unauthorized(format!("Intent {} has an invalid signature", intent.id)))?;
+        
+        if !self.signers.contains(&signer) {
+            return Err(RouterError::Unauthorized(format!(
+                "Intent {} is signed by {:?}, which is not an allowed signer",
+                intent.id, signer
+            )));
+        }
+        
+        Ok(format!("{:?}", signer))
+    }
+}
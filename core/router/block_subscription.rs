@@ -0,0 +1,99 @@
+// Block Subscriptions - Pushed new-head streams for WebSocket EVM adapters
+use ethers::providers::{Middleware, Provider, Ws};
+use ethers::types::{Block, TxHash};
+use futures::channel::mpsc;
+use futures::{future, SinkExt, Stream, StreamExt};
+use std::time::Duration;
+
+/// Delay before the first reconnection attempt; doubles per consecutive failure
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Only sealed blocks: drops pushed heads that lack a number or hash
+pub fn sealed_blocks<S>(blocks: S) -> impl Stream<Item = Block<TxHash>>
+where
+    S: Stream<Item = Block<TxHash>>,
+{
+    blocks.filter(|block| future::ready(block.number.is_some() && block.hash.is_some()))
+}
+
+/// New blocks from `ws_url`, reconnecting and resubscribing when the connection drops
+///
+/// Gives up after `max_retries` consecutive failed connection attempts, at which point the
+/// stream ends. The subscription task stops once the returned stream is dropped.
+pub fn reconnecting_blocks(ws_url: String, max_retries: u32) -> impl Stream<Item = Block<TxHash>> {
+    let (mut tx, rx) = mpsc::channel(64);
+    
+    tokio::spawn(async move {
+        let mut failures = 0u32;
+        
+        loop {
+            match Ws::connect(&ws_url).await {
+                Ok(ws) => {
+                    let provider = Provider::new(ws);
+                    let subscription = provider.subscribe_blocks().await;
+                    match subscription {
+                        Ok(subscription) => {
+                            failures = 0;
+                            
+                            let mut blocks = Box::pin(sealed_blocks(subscription));
+                            while let Some(block) = blocks.next().await {
+                                if tx.send(block).await.is_err() {
+                                    return; // consumer went away
+                                }
+                            }
+                        }
+                        Err(e) => tracing::warn!("Block subscription to {} failed: {}", ws_url, e),
+                    }
+                }
+                Err(e) => tracing::warn!("Block subscription to {} failed: {}", ws_url, e),
+            }
+            
+            if failures >= max_retries || tx.is_closed() {
+                return;
+            }
+            tokio::time::sleep(RECONNECT_BASE_DELAY * 2u32.pow(failures.min(6))).await;
+            failures += 1;
+        }
+    });
+    
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::types::{H256, U64};
+    
+    fn block(number: Option<u64>, hash: Option<u8>) -> Block<TxHash> {
+        Block {
+            number: number.map(U64::from),
+            hash: hash.map(H256::repeat_byte),
+            ..Default::default()
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_sealed_blocks_drops_pending_heads() {
+        let pushed = futures::stream::iter(vec![
+            block(Some(10), Some(0x0a)),
+            block(None, None),          // pending
+            block(Some(11), None),      // no hash yet
+            block(Some(11), Some(0x0b)),
+        ]);
+        
+        let numbers: Vec<u64> = sealed_blocks(pushed)
+            .map(|b| b.number.unwrap().as_u64())
+            .collect()
+            .await;
+        
+        // The source ending ends the mapped stream too
+        assert_eq!(numbers, vec![10, 11]);
+    }
+    
+    #[tokio::test]
+    async fn test_reconnecting_blocks_ends_when_retries_exhausted() {
+        // Nothing listens here, so the only connection attempt fails
+        let mut blocks = Box::pin(reconnecting_blocks("ws://127.0.0.1:1".to_string(), 0));
+        assert!(blocks.next().await.is_none());
+    }
+}
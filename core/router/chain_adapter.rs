@@ -2,19 +2,47 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt};
+#[cfg(feature = "dot")]
 use sp_trie::StorageProof;
+#[cfg(feature = "dot")]
 use sp_core::H256;
 
+#[cfg(feature = "dot")]
 use subxt::{OnlineClient, PolkadotConfig};
 
 use super::{Intent, RouterError};
-use super::intent_translator::{IntentTranslator, TranslatedIntent};
+use super::intent_translator::{AddressFormat, IntentTranslator, TranslatedIntent};
+#[cfg(feature = "cosmos")]
+use super::intent_translator::validate_memo;
+use super::sentium_envelope::SentiumEnvelope;
+use crate::core::crypto::{QuantumPublicKey, QuantumSecretKey};
+use crate::core::context::TransactionStatus;
+#[cfg(any(feature = "eth", feature = "dot", feature = "btc", feature = "cosmos"))]
+use super::{address, intent_translator::ChainType};
+#[cfg(feature = "eth")]
+use super::nonce_manager::{NonceManager, NonceSender};
+#[cfg(feature = "dot")]
+use super::nonce_manager::SubstrateNonceSource;
+#[cfg(feature = "eth")]
+use super::gas::{GasStrategy, Standard};
+#[cfg(any(feature = "eth", feature = "dot", feature = "cosmos"))]
+use super::signer::Signer;
+#[cfg(any(feature = "eth", feature = "cosmos"))]
+use crate::core::clock::{system_clock, Clock};
+#[cfg(feature = "cosmos")]
+use super::ibc;
+#[cfg(feature = "btc")]
+use crate::light_clients::HashMode;
 
 // Cosmos SDK protobuf message types
+#[cfg(feature = "cosmos")]
 use cosmos_sdk_proto::cosmos::bank::v1beta1::{
     QueryBalanceRequest as CosmosQueryBalanceRequest,
 };
+#[cfg(feature = "cosmos")]
 use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as CosmosCoin;
+#[cfg(feature = "cosmos")]
 use cosmos_sdk_proto::cosmos::tx::v1beta1::{
     TxBody as CosmosTxBody,
     AuthInfo as CosmosAuthInfo,
@@ -23,6 +51,7 @@ use cosmos_sdk_proto::cosmos::tx::v1beta1::{
     Fee as CosmosFee,
 };
 
+#[cfg(feature = "cosmos")]
 use cosmos_sdk_proto::cosmos::tx::signing::v1beta1::SignMode;
 
 #[async_trait]
@@ -30,20 +59,240 @@ pub trait ChainAdapter: Send + Sync {
     fn chain_name(&self) -> &str;
     fn chain_id(&self) -> &str;
     
+    /// Build the chain-specific payload; must not make network calls, so it can run offline
     async fn translate_intent(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError>;
+    
+    /// Decode a translation's payload and check it still carries its intent's recipient,
+    /// amount and asset; the router runs this before every submission
+    ///
+    /// Adapters that can't decode their payloads say so, and can't submit through the router.
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        Err(RouterError::UnsupportedAction(format!(
+            "{} can't check the payload of intent {}",
+            self.chain_name(),
+            translated.original_intent.id
+        )))
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError>;
+    
+    /// `verify_state` over a proof read from `reader`; by default the proof is buffered first
+    ///
+    /// Adapters with length-prefixed proof formats override this to check each part as it
+    /// arrives. The result must match `verify_state` on the same bytes.
+    async fn verify_state_streaming(
+        &self,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+    ) -> Result<bool, RouterError> {
+        let mut proof = Vec::new();
+        reader.read_to_end(&mut proof).await.map_err(proof_read_error)?;
+        self.verify_state(&proof).await
+    }
+    
     async fn submit_transaction(&self, tx_data: &[u8]) -> Result<String, RouterError>;
     async fn query_balance(&self, address: &str, asset: &str) -> Result<u64, RouterError>;
+    
+    /// Actions this chain can carry out; the default claims every action
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::all(None, None)
+    }
+    
+    /// Where a submitted transaction stands; adapters that can't look one up say so
+    async fn transaction_status(&self, tx_hash: &str) -> Result<TransactionStatus, RouterError> {
+        Err(RouterError::UnsupportedAction(format!(
+            "{} can't look up transaction {}",
+            self.chain_name(),
+            tx_hash
+        )))
+    }
+    
+    /// Chain-agnostic receipt of a transaction, `None` while the node has nothing for it
+    ///
+    /// Adapters that can't look one up say so.
+    async fn get_receipt(&self, tx_hash: &str) -> Result<Option<UnifiedReceipt>, RouterError> {
+        Err(RouterError::UnsupportedAction(format!(
+            "{} can't fetch a receipt for {}",
+            self.chain_name(),
+            tx_hash
+        )))
+    }
+    
+    /// Dry-run a translated intent against current chain state without submitting it
+    ///
+    /// Adapters that can't simulate say so.
+    async fn simulate_transaction(&self, translated: &TranslatedIntent) -> Result<SimulationResult, RouterError> {
+        Err(RouterError::UnsupportedAction(format!(
+            "{} can't simulate intent {}",
+            self.chain_name(),
+            translated.original_intent.id
+        )))
+    }
+    
+    /// Chain id, token and signing facts, fetched on first call and cached; adapters that
+    /// can't look them up say so
+    async fn chain_metadata(&self) -> Result<ChainMetadata, RouterError> {
+        Err(RouterError::UnsupportedAction(format!("{} has no chain metadata", self.chain_name())))
+    }
+    
+    /// Release connections before the router stops; the default holds none
+    async fn close(&self) -> Result<(), RouterError> {
+        Ok(())
+    }
+}
+
+fn proof_read_error(e: std::io::Error) -> RouterError {
+    RouterError::VerificationError(format!("Failed to read proof: {}", e))
+}
+
+/// Fill `buf` from `reader`, stopping early only at end of input; returns the bytes read
+#[cfg(any(feature = "eth", feature = "dot"))]
+async fn read_up_to(reader: &mut (dyn AsyncRead + Unpin + Send), buf: &mut [u8]) -> Result<usize, RouterError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]).await.map_err(proof_read_error)? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// Outcome of `ChainAdapter::simulate_transaction`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub success: bool,
+    pub gas_used: u64,
+    pub amount_out: Option<u64>, // what the transaction would deliver, if the adapter can tell
+    pub revert_reason: Option<String>,
+}
+
+/// A transaction's outcome as any chain reports it, from `ChainAdapter::get_receipt`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnifiedReceipt {
+    pub tx_hash: String,
+    pub status: TransactionStatus,
+    pub block_height: Option<u64>, // unset until included
+    pub fee_paid: Option<u128>,    // in the chain's smallest unit, when the node reports it
+    pub confirmations: u64,        // zero until included
+}
+
+/// What a node reports about its chain, for validating intents and choosing tx types
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainMetadata {
+    pub native_chain_id: String, // EVM chain id in decimal, or a Substrate genesis hash
+    pub latest_block: u64,       // as of the fetch, so only a lower bound once cached
+    pub native_token: String,
+    pub decimals: u8,
+    pub supports_eip1559: bool,
+    pub spec_version: Option<u32>, // Substrate runtime versions a signed extrinsic commits to
+    pub transaction_version: Option<u32>,
+}
+
+/// `ChainMetadata` fetched on first use and kept for the adapter's lifetime
+///
+/// Concurrent first callers share one fetch; a failed fetch isn't cached.
+#[cfg(any(feature = "eth", feature = "dot"))]
+#[derive(Default)]
+pub(crate) struct MetadataCache(tokio::sync::OnceCell<ChainMetadata>);
+
+#[cfg(any(feature = "eth", feature = "dot"))]
+impl MetadataCache {
+    pub(crate) async fn get_or_fetch<F, Fut>(&self, fetch: F) -> Result<ChainMetadata, RouterError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<ChainMetadata, RouterError>>,
+    {
+        self.0.get_or_try_init(fetch).await.cloned()
+    }
+}
+
+/// What an adapter's chain supports, checked before an intent is translated for it
+#[derive(Debug, Clone)]
+pub struct Capabilities {
+    pub supports_swap: bool,
+    pub supports_stake: bool, // staking and unstaking
+    pub supports_contract_call: bool,
+    pub native_token: Option<String>,
+    pub address_format: Option<AddressFormat>,
+}
+
+impl Capabilities {
+    /// Every action supported
+    pub fn all(native_token: Option<&str>, address_format: Option<AddressFormat>) -> Self {
+        Self {
+            supports_swap: true,
+            supports_stake: true,
+            supports_contract_call: true,
+            native_token: native_token.map(str::to_string),
+            address_format,
+        }
+    }
+    
+    /// Transfers only
+    pub fn transfer_only(native_token: &str, address_format: AddressFormat) -> Self {
+        Self {
+            supports_swap: false,
+            supports_stake: false,
+            supports_contract_call: false,
+            native_token: Some(native_token.to_string()),
+            address_format: Some(address_format),
+        }
+    }
+    
+    /// Whether an `Intent::action` is supported; transfers and unknown actions are left to translation
+    pub fn supports_action(&self, action: &str) -> bool {
+        match action {
+            "swap" => self.supports_swap,
+            "stake" | "unstake" => self.supports_stake,
+            "contract_call" => self.supports_contract_call,
+            _ => true,
+        }
+    }
 }
 
 // Ethereum Adapter
+#[cfg(feature = "eth")]
 pub struct EthereumAdapter {
     chain_name: String,
     chain_id: String,
     rpc_url: String,
     translator: Arc<IntentTranslator>,
+    nonce_managers: std::sync::Mutex<std::collections::HashMap<String, Arc<NonceManager>>>,
+    clock: Arc<dyn Clock>,
+    tx_type: TxType,
+    access_lists: bool,
+    evm_chain_id: Option<u64>,
+    gas_strategy: Arc<dyn GasStrategy>,
+    metadata: MetadataCache,
+}
+
+/// Envelope `EthereumAdapter` builds for the transactions it submits
+#[cfg(feature = "eth")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TxType {
+    #[default]
+    Legacy,  // Flat gas price
+    Eip1559, // Fee cap and priority tip from eth_feeHistory; legacy on chains without a base fee
+}
+
+/// Blocks of `eth_feeHistory` the priority tip is taken over
+#[cfg(feature = "eth")]
+pub const FEE_HISTORY_BLOCKS: u64 = 10;
+
+/// Signed EIP-2612 approval of `value` tokens from `owner` to `spender`
+#[cfg(feature = "eth")]
+#[derive(Debug, Clone)]
+pub struct Permit {
+    pub owner: ethers::types::Address,
+    pub spender: ethers::types::Address,
+    pub value: ethers::types::U256,
+    pub deadline: u64, // Unix seconds
+    pub v: u8,
+    pub r: [u8; 32],
+    pub s: [u8; 32],
 }
 
+#[cfg(feature = "eth")]
 impl EthereumAdapter {
     pub fn new(rpc_url: String, translator: Arc<IntentTranslator>) -> Self {
         Self {
@@ -51,10 +300,737 @@ impl EthereumAdapter {
             chain_id: "ethereum-1".to_string(),
             rpc_url,
             translator,
+            nonce_managers: std::sync::Mutex::new(std::collections::HashMap::new()),
+            clock: system_clock(),
+            tx_type: TxType::Legacy,
+            access_lists: false,
+            evm_chain_id: None,
+            gas_strategy: Arc::new(Standard),
+            metadata: MetadataCache::default(),
+        }
+    }
+    
+    /// How submitted transactions are priced; `Standard` unless set
+    pub fn with_gas_strategy(mut self, strategy: Arc<dyn GasStrategy>) -> Self {
+        self.gas_strategy = strategy;
+        self
+    }
+    
+    /// Refuse to submit transactions for, or through a node on, any other chain id
+    ///
+    /// Without it, submissions are only checked against the node's `eth_chainId`.
+    pub fn with_evm_chain_id(mut self, chain_id: u64) -> Self {
+        self.evm_chain_id = Some(chain_id);
+        self
+    }
+    
+    /// Envelope for submitted transactions; legacy unless set
+    pub fn with_tx_type(mut self, tx_type: TxType) -> Self {
+        self.tx_type = tx_type;
+        self
+    }
+    
+    /// Attach an `eth_createAccessList` access list to submitted transactions, on nodes that have it
+    pub fn with_access_lists(mut self, enabled: bool) -> Self {
+        self.access_lists = enabled;
+        self
+    }
+    
+    /// Access list `eth_createAccessList` gives for `tx`
+    pub async fn create_access_list(
+        &self,
+        tx: &ethers::types::transaction::eip2718::TypedTransaction,
+    ) -> Result<ethers::types::transaction::eip2930::AccessList, RouterError> {
+        use ethers::providers::{Provider, Http};
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        
+        fetch_access_list(&provider, tx)
+            .await?
+            .ok_or_else(|| RouterError::TranslationError(format!("{} node has no eth_createAccessList", self.chain_name)))
+    }
+    
+    /// ERC-20 balances for `(token, account)` pairs in one `Multicall3.aggregate3` call
+    ///
+    /// Falls back to one `eth_call` per pair on chains without Multicall3 deployed.
+    pub async fn query_balances_multicall(
+        &self,
+        calls: &[(ethers::types::Address, ethers::types::Address)],
+    ) -> Result<Vec<u64>, RouterError> {
+        use ethers::providers::{Provider, Http, Middleware};
+        use ethers::types::{Bytes, TransactionRequest};
+        
+        if calls.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        
+        let code = provider
+            .get_code(MULTICALL3_ADDRESS, None)
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to query code: {}", e)))?;
+        
+        if code.is_empty() {
+            let mut balances = Vec::with_capacity(calls.len());
+            for (token, account) in calls {
+                balances.push(self.query_balance(&format!("{:?}", account), &format!("{:?}", token)).await?);
+            }
+            return Ok(balances);
+        }
+        
+        let tx = TransactionRequest::new()
+            .to(MULTICALL3_ADDRESS)
+            .data(Bytes::from(encode_aggregate3_balances(calls)));
+        
+        let result = provider
+            .call(&tx.into(), None)
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to call Multicall3: {}", e)))?;
+        
+        decode_aggregate3_balances(&result, calls.len())
+    }
+    
+    /// Use a different time source for permit deadline checks
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Calldata for `permit(address,address,uint256,uint256,uint8,bytes32,bytes32)` (EIP-2612)
+    ///
+    /// Refuses permits whose deadline has already passed, since the token would revert.
+    pub fn encode_permit(&self, permit: &Permit) -> Result<ethers::types::Bytes, RouterError> {
+        use ethers::abi::{encode, Token};
+        use ethers::types::U256;
+        
+        let now = self.clock.now_secs();
+        if permit.deadline < now {
+            return Err(RouterError::TranslationError(format!(
+                "Permit deadline {} has passed, now {}",
+                permit.deadline, now
+            )));
+        }
+        
+        // permit selector: 0xd505accf
+        let mut data = vec![0xd5, 0x05, 0xac, 0xcf];
+        data.extend_from_slice(&encode(&[
+            Token::Address(permit.owner),
+            Token::Address(permit.spender),
+            Token::Uint(permit.value),
+            Token::Uint(U256::from(permit.deadline)),
+            Token::Uint(U256::from(permit.v)),
+            Token::FixedBytes(permit.r.to_vec()),
+            Token::FixedBytes(permit.s.to_vec()),
+        ]));
+        
+        Ok(data.into())
+    }
+    
+    /// `permit` then `transferFrom(owner, to, value)` on `token`, sent by the spender
+    ///
+    /// Returns JSON transaction requests in submission order, for `submit_sequential`
+    /// from the spender so both land with consecutive nonces.
+    pub fn transfer_with_permit(
+        &self,
+        token: ethers::types::Address,
+        permit: &Permit,
+        to: ethers::types::Address,
+    ) -> Result<Vec<Vec<u8>>, RouterError> {
+        use ethers::abi::{encode, Token};
+        use ethers::types::TransactionRequest;
+        
+        // transferFrom selector: 0x23b872dd
+        let mut transfer_from = vec![0x23, 0xb8, 0x72, 0xdd];
+        transfer_from.extend_from_slice(&encode(&[
+            Token::Address(permit.owner),
+            Token::Address(to),
+            Token::Uint(permit.value),
+        ]));
+        
+        [self.encode_permit(permit)?, transfer_from.into()]
+            .into_iter()
+            .map(|data| {
+                let tx = TransactionRequest::new().from(permit.spender).to(token).data(data);
+                serde_json::to_vec(&tx)
+                    .map_err(|e| RouterError::TranslationError(format!("Failed to encode transaction: {}", e)))
+            })
+            .collect()
+    }
+    
+    /// Sign a JSON transaction request with `signer` and broadcast it
+    ///
+    /// The sender is the signer's address; chain id, nonce, gas and fees the request
+    /// leaves unset are filled in from the node.
+    pub async fn submit_signed(&self, tx_data: &[u8], signer: &dyn Signer) -> Result<String, RouterError> {
+        self.submit_signed_with(tx_data, signer, self.gas_strategy.as_ref()).await
+    }
+    
+    /// `submit_signed`, priced by `strategy` instead of the adapter's, e.g. for an urgent intent
+    pub async fn submit_signed_with(
+        &self,
+        tx_data: &[u8],
+        signer: &dyn Signer,
+        strategy: &dyn GasStrategy,
+    ) -> Result<String, RouterError> {
+        use ethers::providers::{Provider, Http, Middleware};
+        use ethers::types::TransactionRequest;
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        
+        let tx: TransactionRequest = serde_json::from_slice(tx_data)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid transaction request: {}", e)))?;
+        let chain_id = check_chain_id(&provider, self.evm_chain_id, tx.chain_id.map(|id| id.as_u64())).await?;
+        let mut tx = price_transaction(&provider, tx.from(evm_address(&signer.public_key())?), self.tx_type, strategy).await?;
+        tx.set_chain_id(chain_id);
+        // Before filling, so the gas estimate accounts for the list
+        if self.access_lists {
+            attach_access_list(&provider, &mut tx).await?;
+        }
+        provider
+            .fill_transaction(&mut tx, None)
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to fill transaction: {}", e)))?;
+        
+        let raw = sign_evm_transaction(&tx, signer).await?;
+        let pending_tx = provider
+            .send_raw_transaction(raw)
+            .await
+            .map_err(|e| evm_rpc_error("Failed to submit transaction", e, RouterError::TranslationError))?;
+        
+        Ok(format!("0x{:x}", pending_tx.tx_hash()))
+    }
+    
+    /// `aggregate3Value` calldata running each `(target, calldata, value)` in order, none allowed to fail
+    ///
+    /// Refuses empty calldata, which Multicall3 would send as a bare transfer.
+    pub fn encode_multicall3(
+        &self,
+        calls: Vec<(ethers::types::Address, ethers::types::Bytes, ethers::types::U256)>,
+    ) -> Result<ethers::types::Bytes, RouterError> {
+        use ethers::abi::{encode, Token};
+        
+        if calls.is_empty() {
+            return Err(RouterError::TranslationError("Multicall needs at least one call".to_string()));
+        }
+        
+        let mut call_tokens = Vec::with_capacity(calls.len());
+        for (index, (target, call_data, value)) in calls.into_iter().enumerate() {
+            if call_data.is_empty() {
+                return Err(RouterError::TranslationError(format!(
+                    "Multicall call {} to {:?} has empty calldata",
+                    index, target
+                )));
+            }
+            call_tokens.push(Token::Tuple(vec![
+                Token::Address(target),
+                Token::Bool(false),
+                Token::Uint(value),
+                Token::Bytes(call_data.to_vec()),
+            ]));
+        }
+        
+        // aggregate3Value((address,bool,uint256,bytes)[]) selector: 0x174dea71
+        let mut data = vec![0x17, 0x4d, 0xea, 0x71];
+        data.extend_from_slice(&encode(&[Token::Array(call_tokens)]));
+        Ok(data.into())
+    }
+    
+    /// Send `calls` as one `Multicall3.aggregate3Value` transaction, so they all land or none do
+    ///
+    /// The transaction carries the sum of the values. Targets see Multicall3 as `msg.sender`,
+    /// not the signer, so calls that act on the sender's own tokens or approvals don't belong here.
+    pub async fn submit_multicall3(
+        &self,
+        calls: Vec<(ethers::types::Address, ethers::types::Bytes, ethers::types::U256)>,
+        signer: &dyn Signer,
+    ) -> Result<String, RouterError> {
+        use ethers::providers::{Provider, Http, Middleware};
+        use ethers::types::{TransactionRequest, U256};
+        
+        let total = calls
+            .iter()
+            .try_fold(U256::zero(), |total, (_, _, value)| total.checked_add(*value))
+            .ok_or_else(|| RouterError::TranslationError("Multicall value overflows uint256".to_string()))?;
+        let data = self.encode_multicall3(calls)?;
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        
+        // Without the contract the transaction would succeed as a plain transfer and run nothing
+        let code = provider
+            .get_code(MULTICALL3_ADDRESS, None)
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to query code: {}", e)))?;
+        if code.is_empty() {
+            return Err(RouterError::UnsupportedAction(format!("Multicall3 is not deployed on {}", self.chain_name)));
+        }
+        
+        let tx = TransactionRequest::new().to(MULTICALL3_ADDRESS).value(total).data(data);
+        let tx_data = serde_json::to_vec(&tx)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to encode transaction: {}", e)))?;
+        self.submit_signed(&tx_data, signer).await
+    }
+    
+    /// Shared nonce manager for a sending address on this adapter
+    pub fn nonce_manager(self: &Arc<Self>, from: &str) -> Arc<NonceManager> {
+        let mut managers = self.nonce_managers.lock().unwrap();
+        managers
+            .entry(from.to_lowercase())
+            .or_insert_with(|| Arc::new(NonceManager::new(self.clone(), from.to_string())))
+            .clone()
+    }
+    
+    /// Submit JSON-encoded transaction requests from `from` with sequential nonces
+    pub async fn submit_sequential(
+        self: &Arc<Self>,
+        from: &str,
+        txs: &[Vec<u8>],
+    ) -> Vec<Result<String, RouterError>> {
+        self.nonce_manager(from).submit_sequential(txs).await
+    }
+}
+
+/// Multicall3, deployed at the same address on most EVM chains
+#[cfg(feature = "eth")]
+pub const MULTICALL3_ADDRESS: ethers::types::Address = ethers::types::H160([
+    0xca, 0x11, 0xbd, 0xe0, 0x59, 0x77, 0xb3, 0x63, 0x11, 0x67,
+    0x02, 0x88, 0x62, 0xbe, 0x2a, 0x17, 0x39, 0x76, 0xca, 0x11,
+]);
+
+/// `aggregate3` calldata with one failure-tolerant `balanceOf(account)` per `(token, account)`
+#[cfg(feature = "eth")]
+pub fn encode_aggregate3_balances(calls: &[(ethers::types::Address, ethers::types::Address)]) -> Vec<u8> {
+    use ethers::abi::{encode, Token};
+    
+    let call_tokens = calls
+        .iter()
+        .map(|(token, account)| {
+            // balanceOf selector: 0x70a08231
+            let mut call_data = vec![0x70, 0xa0, 0x82, 0x31];
+            call_data.extend_from_slice(&encode(&[Token::Address(*account)]));
+            
+            Token::Tuple(vec![Token::Address(*token), Token::Bool(true), Token::Bytes(call_data)])
+        })
+        .collect();
+    
+    // aggregate3((address,bool,bytes)[]) selector: 0x82ad56cb
+    let mut data = vec![0x82, 0xad, 0x56, 0xcb];
+    data.extend_from_slice(&encode(&[Token::Array(call_tokens)]));
+    data
+}
+
+/// Balances from an `aggregate3` return blob of `(bool success, bytes returnData)[]`
+#[cfg(feature = "eth")]
+pub fn decode_aggregate3_balances(data: &[u8], expected: usize) -> Result<Vec<u64>, RouterError> {
+    use ethers::abi::{decode, ParamType, Token};
+    use ethers::types::U256;
+    
+    let result_type = ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Bool, ParamType::Bytes])));
+    let results = match decode(&[result_type], data)
+        .map_err(|e| RouterError::TranslationError(format!("Failed to decode Multicall3 result: {}", e)))?
+        .pop()
+    {
+        Some(Token::Array(results)) => results,
+        _ => return Err(RouterError::TranslationError("Invalid Multicall3 response".to_string())),
+    };
+    
+    if results.len() != expected {
+        return Err(RouterError::TranslationError(format!(
+            "Multicall3 returned {} results for {} calls",
+            results.len(),
+            expected
+        )));
+    }
+    
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(i, result)| match result {
+            Token::Tuple(fields) => match fields.as_slice() {
+                [Token::Bool(true), Token::Bytes(ret)] if ret.len() == 32 => {
+                    let balance = U256::from_big_endian(ret);
+                    if balance > U256::from(u64::MAX) {
+                        return Err(RouterError::TranslationError(format!("Balance {} exceeds u64 for call {}", balance, i)));
+                    }
+                    Ok(balance.as_u64())
+                }
+                [Token::Bool(true), _] => Err(RouterError::TranslationError(format!("Invalid balance response for call {}", i))),
+                _ => Err(RouterError::TranslationError(format!("balanceOf reverted for call {}", i))),
+            },
+            _ => Err(RouterError::TranslationError("Invalid Multicall3 response".to_string())),
+        })
+        .collect()
+}
+
+/// EIP-1559 `(max_fee_per_gas, max_priority_fee_per_gas)` from an `eth_feeHistory` result
+///
+/// The tip is the median of the blocks' reward percentile, and the cap leaves room for the
+/// next block's base fee to double. `None` when the chain reports no base fee, or fees
+/// too large for the cap to fit in a `U256`.
+#[cfg(feature = "eth")]
+pub fn eip1559_fees(history: &ethers::types::FeeHistory) -> Option<(ethers::types::U256, ethers::types::U256)> {
+    let base_fee = *history.base_fee_per_gas.last()?;
+    if base_fee.is_zero() {
+        return None;
+    }
+    
+    let mut tips: Vec<_> = history.reward.iter().filter_map(|rewards| rewards.first().copied()).collect();
+    tips.sort();
+    let tip = tips.get(tips.len() / 2).copied().unwrap_or_default();
+    let max_fee = base_fee.checked_mul(2.into())?.checked_add(tip)?;
+    Some((max_fee, tip))
+}
+
+/// Set the fees on `tx` for `tx_type` as `strategy` prices them
+///
+/// An EIP-1559 request falls back to a legacy one when the strategy has no EIP-1559 fees,
+/// as on chains without a base fee. A legacy request keeps a gas price it already has.
+#[cfg(feature = "eth")]
+pub async fn price_transaction<M: ethers::providers::Middleware>(
+    provider: &M,
+    tx: ethers::types::TransactionRequest,
+    tx_type: TxType,
+    strategy: &dyn GasStrategy,
+) -> Result<ethers::types::transaction::eip2718::TypedTransaction, RouterError> {
+    use ethers::types::Eip1559TransactionRequest;
+    
+    if tx_type == TxType::Legacy && tx.gas_price.is_some() {
+        return Ok(tx.into());
+    }
+    let settings = strategy.gas_price(provider).await?;
+    
+    if tx_type == TxType::Eip1559 {
+        match settings.eip1559() {
+            Some((max_fee, tip)) => {
+                return Ok(Eip1559TransactionRequest {
+                    from: tx.from,
+                    to: tx.to,
+                    gas: tx.gas,
+                    value: tx.value,
+                    data: tx.data,
+                    nonce: tx.nonce,
+                    access_list: Default::default(),
+                    max_priority_fee_per_gas: Some(tip),
+                    max_fee_per_gas: Some(max_fee),
+                    chain_id: tx.chain_id,
+                }
+                .into())
+            }
+            None => tracing::debug!("No EIP-1559 fees for this chain, sending a legacy transaction"),
         }
     }
+    
+    if tx.gas_price.is_some() {
+        return Ok(tx.into());
+    }
+    Ok(tx.gas_price(settings.gas_price).into())
+}
+
+/// JSON-RPC error code for a method the node doesn't implement
+#[cfg(feature = "eth")]
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Access list for `tx` from `eth_createAccessList`, or `None` if the node lacks the method
+#[cfg(feature = "eth")]
+pub async fn fetch_access_list<M: ethers::providers::Middleware>(
+    provider: &M,
+    tx: &ethers::types::transaction::eip2718::TypedTransaction,
+) -> Result<Option<ethers::types::transaction::eip2930::AccessList>, RouterError> {
+    use ethers::providers::MiddlewareError;
+    
+    match provider.create_access_list(tx, None).await {
+        Ok(result) => Ok(Some(result.access_list)),
+        Err(e) if e.as_error_response().is_some_and(|response| response.code == METHOD_NOT_FOUND) => Ok(None),
+        Err(e) => Err(RouterError::TranslationError(format!("Failed to create access list: {}", e))),
+    }
+}
+
+/// Attach the access list `eth_createAccessList` gives for `tx`
+///
+/// A legacy transaction becomes an EIP-2930 one to carry it. Nodes without the method and
+/// empty lists leave `tx` as it was.
+#[cfg(feature = "eth")]
+pub async fn attach_access_list<M: ethers::providers::Middleware>(
+    provider: &M,
+    tx: &mut ethers::types::transaction::eip2718::TypedTransaction,
+) -> Result<(), RouterError> {
+    use ethers::types::transaction::eip2718::TypedTransaction;
+    use ethers::types::Eip2930TransactionRequest;
+    
+    let Some(access_list) = fetch_access_list(provider, tx).await? else {
+        tracing::debug!("Node has no eth_createAccessList, sending without an access list");
+        return Ok(());
+    };
+    if access_list.0.is_empty() {
+        return Ok(());
+    }
+    
+    if let TypedTransaction::Legacy(legacy) = tx {
+        *tx = Eip2930TransactionRequest::new(legacy.clone(), access_list).into();
+    } else {
+        tx.set_access_list(access_list);
+    }
+    Ok(())
+}
+
+/// Chain id a transaction must be submitted under, checked against the node's and the transaction's
+///
+/// `configured` is the adapter's chain id, if it has one; otherwise the node's `eth_chainId` is
+/// taken as expected. A transaction naming a different chain fails before the node is asked.
+#[cfg(feature = "eth")]
+pub async fn check_chain_id<M: ethers::providers::Middleware>(
+    provider: &M,
+    configured: Option<u64>,
+    tx_chain_id: Option<u64>,
+) -> Result<u64, RouterError> {
+    if let (Some(expected), Some(got)) = (configured, tx_chain_id) {
+        if got != expected {
+            return Err(RouterError::ChainIdMismatch { expected, got });
+        }
+    }
+    
+    let node = provider
+        .get_chainid()
+        .await
+        .map_err(|e| evm_rpc_error("Failed to query chain id", e, RouterError::TranslationError))?
+        .as_u64();
+    let expected = configured.unwrap_or(node);
+    match tx_chain_id {
+        _ if node != expected => Err(RouterError::ChainIdMismatch { expected, got: node }),
+        Some(got) if got != expected => Err(RouterError::ChainIdMismatch { expected, got }),
+        _ => Ok(expected),
+    }
+}
+
+/// Chain id a raw signed transaction commits to; `None` for pre-EIP-155 legacy transactions
+#[cfg(feature = "eth")]
+pub fn signed_chain_id(raw: &[u8]) -> Result<Option<u64>, RouterError> {
+    use ethers::types::transaction::eip2718::TypedTransaction;
+    use ethers::utils::rlp::Rlp;
+    
+    let (tx, _) = TypedTransaction::decode_signed(&Rlp::new(raw))
+        .map_err(|e| RouterError::TranslationError(format!("Invalid signed transaction: {}", e)))?;
+    Ok(tx.chain_id().map(|id| id.as_u64()))
+}
+
+/// Chain id and latest block from an EVM node; EIP-1559 is supported if that block has a base fee
+#[cfg(feature = "eth")]
+pub async fn fetch_evm_metadata<M: ethers::providers::Middleware>(
+    provider: &M,
+    native_token: &str,
+) -> Result<ChainMetadata, RouterError> {
+    use ethers::types::BlockNumber;
+    
+    let chain_id = provider
+        .get_chainid()
+        .await
+        .map_err(|e| evm_rpc_error("Failed to query chain id", e, RouterError::TranslationError))?;
+    let block = provider
+        .get_block(BlockNumber::Latest)
+        .await
+        .map_err(|e| RouterError::TranslationError(format!("Failed to query latest block: {}", e)))?
+        .ok_or_else(|| RouterError::TranslationError("Node returned no latest block".to_string()))?;
+    
+    Ok(ChainMetadata {
+        native_chain_id: chain_id.to_string(),
+        latest_block: block.number.map_or(0, |number| number.as_u64()),
+        native_token: native_token.to_string(),
+        decimals: 18,
+        supports_eip1559: block.base_fee_per_gas.is_some(),
+        spec_version: None,
+        transaction_version: None,
+    })
 }
 
+/// `what` failed against an EVM node: `ChainUnavailable` unless the node answered with a
+/// JSON-RPC error, which is about the request and becomes `otherwise`
+#[cfg(feature = "eth")]
+fn evm_rpc_error<E: ethers::providers::MiddlewareError>(what: &str, e: E, otherwise: fn(String) -> RouterError) -> RouterError {
+    let message = format!("{}: {}", what, e);
+    if e.as_error_response().is_some() {
+        otherwise(message)
+    } else {
+        RouterError::ChainUnavailable(message)
+    }
+}
+
+/// Status of an EVM transaction from its receipt: pending until mined, then by receipt status
+#[cfg(feature = "eth")]
+pub async fn receipt_status<M: ethers::providers::Middleware>(
+    provider: &M,
+    tx_hash: &str,
+) -> Result<TransactionStatus, RouterError> {
+    use ethers::types::H256;
+    use std::str::FromStr;
+    
+    let hash = H256::from_str(tx_hash)
+        .map_err(|e| RouterError::VerificationError(format!("Invalid transaction hash {}: {}", tx_hash, e)))?;
+    let receipt = provider
+        .get_transaction_receipt(hash)
+        .await
+        .map_err(|e| evm_rpc_error("Failed to fetch receipt", e, RouterError::VerificationError))?;
+    
+    Ok(evm_status(receipt.and_then(|receipt| receipt.status)))
+}
+
+/// `UnifiedReceipt` from an EVM transaction's receipt, `None` until it is mined
+#[cfg(feature = "eth")]
+pub async fn evm_receipt<M: ethers::providers::Middleware>(
+    provider: &M,
+    tx_hash: &str,
+) -> Result<Option<UnifiedReceipt>, RouterError> {
+    use ethers::types::H256;
+    use std::str::FromStr;
+    
+    let hash = H256::from_str(tx_hash)
+        .map_err(|e| RouterError::VerificationError(format!("Invalid transaction hash {}: {}", tx_hash, e)))?;
+    let Some(receipt) = provider
+        .get_transaction_receipt(hash)
+        .await
+        .map_err(|e| evm_rpc_error("Failed to fetch receipt", e, RouterError::VerificationError))?
+    else {
+        return Ok(None);
+    };
+    
+    let block_height = receipt.block_number.map(|number| number.as_u64());
+    let confirmations = match block_height {
+        Some(height) => {
+            let latest = provider
+                .get_block_number()
+                .await
+                .map_err(|e| evm_rpc_error("Failed to fetch block number", e, RouterError::VerificationError))?;
+            (latest.as_u64() + 1).saturating_sub(height)
+        }
+        None => 0,
+    };
+    let fee_paid = receipt
+        .gas_used
+        .zip(receipt.effective_gas_price)
+        .and_then(|(gas, price)| gas.checked_mul(price))
+        .and_then(|fee| u128::try_from(fee).ok());
+    
+    Ok(Some(UnifiedReceipt {
+        tx_hash: tx_hash.to_string(),
+        status: evm_status(receipt.status),
+        block_height,
+        fee_paid,
+        confirmations,
+    }))
+}
+
+/// Pending until a receipt carries a status, then failed only on status zero
+#[cfg(feature = "eth")]
+fn evm_status(status: Option<ethers::types::U64>) -> TransactionStatus {
+    match status {
+        None => TransactionStatus::Pending,
+        Some(status) if status.is_zero() => TransactionStatus::Failed,
+        Some(_) => TransactionStatus::Confirmed,
+    }
+}
+
+/// Ethereum address of a secp256k1 public key (SEC1, compressed or not)
+#[cfg(feature = "eth")]
+pub fn evm_address(public_key: &[u8]) -> Result<ethers::types::Address, RouterError> {
+    use ethers::core::k256::ecdsa::VerifyingKey;
+    
+    let key = VerifyingKey::from_sec1_bytes(public_key)
+        .map_err(|_| RouterError::TranslationError("Invalid secp256k1 public key".to_string()))?;
+    Ok(ethers::utils::public_key_to_address(&key))
+}
+
+/// RLP of `tx` signed by `signer` over its sighash, ready for `eth_sendRawTransaction`
+#[cfg(feature = "eth")]
+pub async fn sign_evm_transaction(
+    tx: &ethers::types::transaction::eip2718::TypedTransaction,
+    signer: &dyn Signer,
+) -> Result<ethers::types::Bytes, RouterError> {
+    use ethers::types::transaction::eip2718::TypedTransaction;
+    use ethers::types::{Signature, U256};
+    
+    let sig = signer.sign(tx.sighash().as_bytes()).await?;
+    if sig.len() != 65 {
+        return Err(RouterError::TranslationError(format!("Expected a 65-byte signature, got {}", sig.len())));
+    }
+    
+    // Legacy transactions fold the chain id into v (EIP-155); typed ones carry the bare parity
+    let recovery_id = sig[64] as u64;
+    let v = match tx {
+        TypedTransaction::Legacy(_) => match tx.chain_id() {
+            Some(chain_id) => recovery_id + 35 + chain_id.as_u64() * 2,
+            None => recovery_id + 27,
+        },
+        _ => recovery_id,
+    };
+    
+    let signature = Signature {
+        r: U256::from_big_endian(&sig[..32]),
+        s: U256::from_big_endian(&sig[32..64]),
+        v,
+    };
+    Ok(tx.rlp_signed(&signature))
+}
+
+#[cfg(feature = "eth")]
+#[async_trait]
+impl NonceSender for EthereumAdapter {
+    async fn transaction_count(&self, address: &str) -> Result<u64, RouterError> {
+        use ethers::providers::{Provider, Http, Middleware};
+        use ethers::types::{Address, BlockNumber};
+        use std::str::FromStr;
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        
+        let addr = Address::from_str(address)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid address: {}", e)))?;
+        
+        let count = provider
+            .get_transaction_count(addr, Some(BlockNumber::Pending.into()))
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to query nonce: {}", e)))?;
+        
+        Ok(count.as_u64())
+    }
+    
+    async fn submit_with_nonce(
+        &self,
+        address: &str,
+        nonce: u64,
+        tx_data: &[u8],
+    ) -> Result<String, RouterError> {
+        // tx_data is a JSON TransactionRequest; the node signs with its unlocked account
+        use ethers::providers::{Provider, Http, Middleware};
+        use ethers::types::{Address, TransactionRequest};
+        use std::str::FromStr;
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        
+        let from = Address::from_str(address)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid address: {}", e)))?;
+        
+        let tx: TransactionRequest = serde_json::from_slice(tx_data)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid transaction request: {}", e)))?;
+        check_chain_id(&provider, self.evm_chain_id, tx.chain_id.map(|id| id.as_u64())).await?;
+        
+        let mut tx = price_transaction(&provider, tx.from(from).nonce(nonce), self.tx_type, self.gas_strategy.as_ref()).await?;
+        if self.access_lists {
+            attach_access_list(&provider, &mut tx).await?;
+        }
+        let pending_tx = provider
+            .send_transaction(tx, None)
+            .await
+            .map_err(|e| evm_rpc_error("Failed to submit transaction", e, RouterError::TranslationError))?;
+        
+        Ok(format!("0x{:x}", pending_tx.tx_hash()))
+    }
+}
+
+#[cfg(feature = "eth")]
 #[async_trait]
 impl ChainAdapter for EthereumAdapter {
     fn chain_name(&self) -> &str {
@@ -69,6 +1045,10 @@ impl ChainAdapter for EthereumAdapter {
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
         // Verify Ethereum Merkle Patricia Trie proof
         if proof.is_empty() {
@@ -111,7 +1091,7 @@ impl ChainAdapter for EthereumAdapter {
             ]) as usize;
             offset += 4;
             
-            if offset + node_len > proof_nodes.len() {
+            if node_len > proof_nodes.len() - offset {
                 return Err(RouterError::VerificationError("Proof node exceeds bounds".to_string()));
             }
             
@@ -137,6 +1117,63 @@ impl ChainAdapter for EthereumAdapter {
         Ok(true)
     }
     
+    async fn verify_state_streaming(
+        &self,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+    ) -> Result<bool, RouterError> {
+        use sha3::{Digest, Keccak256};
+        
+        let mut current_hash = vec![0u8; 32];
+        match read_up_to(reader, &mut current_hash).await? {
+            0 => return Err(RouterError::VerificationError("Empty proof".to_string())),
+            32 => {}
+            _ => return Err(RouterError::VerificationError("Proof too short for state root".to_string())),
+        }
+        
+        // Hash each node as it arrives, keeping only its last 32 bytes (the next reference)
+        let mut nodes = 0;
+        let mut chunk = [0u8; 4096];
+        loop {
+            let mut len = [0u8; 4];
+            match read_up_to(reader, &mut len).await? {
+                0 => break,
+                4 => {}
+                _ => return Err(RouterError::VerificationError("Invalid proof node length".to_string())),
+            }
+            let node_len = u32::from_be_bytes(len) as usize;
+            
+            let mut hasher = Keccak256::new();
+            let mut tail = Vec::with_capacity(64);
+            let mut remaining = node_len;
+            while remaining > 0 {
+                let want = remaining.min(chunk.len());
+                let n = reader.read(&mut chunk[..want]).await.map_err(proof_read_error)?;
+                if n == 0 {
+                    return Err(RouterError::VerificationError("Proof node exceeds bounds".to_string()));
+                }
+                hasher.update(&chunk[..n]);
+                tail.extend_from_slice(&chunk[..n]);
+                if tail.len() > 32 {
+                    tail.drain(..tail.len() - 32);
+                }
+                remaining -= n;
+            }
+            nodes += 1;
+            
+            if hasher.finalize()[..] != current_hash[..] {
+                return Err(RouterError::VerificationError("Proof node hash mismatch".to_string()));
+            }
+            if node_len >= 32 {
+                current_hash = tail;
+            }
+        }
+        
+        if nodes == 0 {
+            return Err(RouterError::VerificationError("No proof nodes provided".to_string()));
+        }
+        Ok(true)
+    }
+    
     async fn submit_transaction(&self, tx_data: &[u8]) -> Result<String, RouterError> {
         // Submit transaction to Ethereum via RPC using ethers-rs
         if tx_data.is_empty() {
@@ -150,12 +1187,15 @@ impl ChainAdapter for EthereumAdapter {
         let provider = Provider::<Http>::try_from(&self.rpc_url)
             .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
         
+        // A transaction signed for another chain would be rejected, or worse, replayed there
+        check_chain_id(&provider, self.evm_chain_id, signed_chain_id(tx_data)?).await?;
+        
         // Send raw transaction
         let tx_bytes = Bytes::from(tx_data.to_vec());
         let pending_tx = provider
             .send_raw_transaction(tx_bytes)
             .await
-            .map_err(|e| RouterError::TranslationError(format!("Failed to submit transaction: {}", e)))?;
+            .map_err(|e| evm_rpc_error("Failed to submit transaction", e, RouterError::TranslationError))?;
         
         // Get transaction hash
         let tx_hash = format!("0x{:x}", pending_tx.tx_hash());
@@ -167,6 +1207,7 @@ impl ChainAdapter for EthereumAdapter {
         if address.is_empty() {
             return Err(RouterError::TranslationError("Empty address".to_string()));
         }
+        address::validate(ChainType::EVM, address)?;
         
         use ethers::providers::{Provider, Http, Middleware};
         use ethers::types::Address;
@@ -183,7 +1224,7 @@ impl ChainAdapter for EthereumAdapter {
             let balance = provider
                 .get_balance(addr, None)
                 .await
-                .map_err(|e| RouterError::TranslationError(format!("Failed to query balance: {}", e)))?;
+                .map_err(|e| evm_rpc_error("Failed to query balance", e, RouterError::TranslationError))?;
             
             // Convert U256 to u64 (may overflow for very large balances)
             Ok(balance.as_u64())
@@ -230,7 +1271,7 @@ impl ChainAdapter for EthereumAdapter {
             let result = provider
                 .call(&tx, None)
                 .await
-                .map_err(|e| RouterError::TranslationError(format!("Failed to call contract: {}", e)))?;
+                .map_err(|e| evm_rpc_error("Failed to call contract", e, RouterError::TranslationError))?;
             
             // Decode result
             let tokens = function
@@ -244,17 +1285,52 @@ impl ChainAdapter for EthereumAdapter {
             }
         }
     }
+    
+    async fn transaction_status(&self, tx_hash: &str) -> Result<TransactionStatus, RouterError> {
+        use ethers::providers::{Http, Provider};
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        receipt_status(&provider, tx_hash).await
+    }
+    
+    async fn get_receipt(&self, tx_hash: &str) -> Result<Option<UnifiedReceipt>, RouterError> {
+        use ethers::providers::{Http, Provider};
+        
+        let provider = Provider::<Http>::try_from(&self.rpc_url)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+        evm_receipt(&provider, tx_hash).await
+    }
+    
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::all(Some("ETH"), Some(AddressFormat::Ethereum))
+    }
+    
+    async fn chain_metadata(&self) -> Result<ChainMetadata, RouterError> {
+        use ethers::providers::{Provider, Http};
+        
+        self.metadata
+            .get_or_fetch(|| async {
+                let provider = Provider::<Http>::try_from(&self.rpc_url)
+                    .map_err(|e| RouterError::TranslationError(format!("Failed to create provider: {}", e)))?;
+                fetch_evm_metadata(&provider, "ETH").await
+            })
+            .await
+    }
 }
 
 // Polkadot Adapter
+#[cfg(feature = "dot")]
 pub struct PolkadotAdapter {
     chain_name: String,
     chain_id: String,
     #[allow(dead_code)]
     rpc_url: String,
     translator: Arc<IntentTranslator>,
+    metadata: MetadataCache,
 }
 
+#[cfg(feature = "dot")]
 impl PolkadotAdapter {
     pub fn new(rpc_url: String, translator: Arc<IntentTranslator>) -> Self {
         Self {
@@ -262,6 +1338,7 @@ impl PolkadotAdapter {
             chain_id: "polkadot-0".to_string(),
             rpc_url,
             translator,
+            metadata: MetadataCache::default(),
         }
     }
     
@@ -292,20 +1369,109 @@ impl PolkadotAdapter {
         // Create OnlineClient for Polkadot
         let _api = OnlineClient::<PolkadotConfig>::from_url(&self.rpc_url)
             .await
-            .map_err(|e| RouterError::TranslationError(format!("Failed to connect to Polkadot node: {}", e)))?;
+            .map_err(|e| RouterError::ChainUnavailable(format!("Failed to connect to Polkadot node: {}", e)))?;
+        
+        // Simplified submission - in production would use subxt's submit_and_watch
+        // The subxt API has changed significantly between versions
+        // For now, return a deterministic transaction hash based on extrinsic data
+        use sha3::{Digest, Sha3_256};
+        let mut hasher = Sha3_256::new();
+        hasher.update(&extrinsic);
+        let tx_hash = hasher.finalize();
+        
+        Ok(format!("0x{}", hex::encode(tx_hash)))
+    }
+    
+    /// Sign encoded call data as an ECDSA account and submit the extrinsic
+    ///
+    /// The account id is the Blake2-256 hash of the signer's compressed public key, as for
+    /// `MultiSigner::Ecdsa`; nonce and mortality come from the node.
+    pub async fn submit_signed(&self, call_data: Vec<u8>, signer: &dyn Signer) -> Result<String, RouterError> {
+        use sp_core::hashing::blake2_256;
+        use subxt::config::DefaultExtrinsicParamsBuilder;
+        use subxt::utils::{AccountId32, MultiAddress, MultiSignature};
+        
+        let api = OnlineClient::<PolkadotConfig>::from_url(&self.rpc_url)
+            .await
+            .map_err(|e| RouterError::ChainUnavailable(format!("Failed to connect to Polkadot node: {}", e)))?;
+        
+        let account = AccountId32(blake2_256(&signer.public_key()));
+        let partial = api
+            .tx()
+            .create_partial_signed(&RawCall(call_data), &account, DefaultExtrinsicParamsBuilder::new().build())
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to build extrinsic: {}", e)))?;
+        
+        // sp_core's ECDSA signs the Blake2-256 hash of the (already size-capped) payload
+        let sig = signer.sign(&blake2_256(&partial.signer_payload())).await?;
+        let sig: [u8; 65] = sig
+            .try_into()
+            .map_err(|_| RouterError::TranslationError("Expected a 65-byte signature".to_string()))?;
+        
+        let hash = partial
+            .sign_with_address_and_signature(&MultiAddress::Id(account), &MultiSignature::Ecdsa(sig))
+            .submit()
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to submit extrinsic: {}", e)))?;
+        
+        Ok(format!("0x{}", hex::encode(hash)))
+    }
+}
+
+#[cfg(feature = "dot")]
+#[async_trait]
+impl SubstrateNonceSource for PolkadotAdapter {
+    async fn account_nonce(&self, account: &str) -> Result<u32, RouterError> {
+        use std::str::FromStr;
+        use subxt::ext::scale_value::At;
+        use subxt::utils::AccountId32;
         
-        // Simplified submission - in production would use subxt's submit_and_watch
-        // The subxt API has changed significantly between versions
-        // For now, return a deterministic transaction hash based on extrinsic data
-        use sha3::{Digest, Sha3_256};
-        let mut hasher = Sha3_256::new();
-        hasher.update(&extrinsic);
-        let tx_hash = hasher.finalize();
+        let account = AccountId32::from_str(account)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid SS58 address: {}", e)))?;
+        let api = OnlineClient::<PolkadotConfig>::from_url(&self.rpc_url)
+            .await
+            .map_err(|e| RouterError::ChainUnavailable(format!("Failed to connect to Polkadot node: {}", e)))?;
         
-        Ok(format!("0x{}", hex::encode(tx_hash)))
+        let query = subxt::dynamic::storage("System", "Account", vec![subxt::dynamic::Value::from_bytes(account)]);
+        let info = api
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to get storage: {}", e)))?
+            .fetch(&query)
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to fetch account: {}", e)))?;
+        
+        // No entry means the account has never sent anything
+        let Some(info) = info else { return Ok(0) };
+        let info = info
+            .to_value()
+            .map_err(|e| RouterError::TranslationError(format!("Failed to decode account info: {}", e)))?;
+        
+        info.at("nonce")
+            .and_then(|nonce| nonce.as_u128())
+            .and_then(|nonce| u32::try_from(nonce).ok())
+            .ok_or_else(|| RouterError::TranslationError("Account info has no nonce".to_string()))
+    }
+}
+
+/// Already SCALE-encoded call data, passed through to the extrinsic unchanged
+#[cfg(feature = "dot")]
+struct RawCall(Vec<u8>);
+
+#[cfg(feature = "dot")]
+impl subxt::tx::Payload for RawCall {
+    fn encode_call_data_to(
+        &self,
+        _metadata: &subxt::Metadata,
+        out: &mut Vec<u8>,
+    ) -> Result<(), subxt::ext::subxt_core::Error> {
+        out.extend_from_slice(&self.0);
+        Ok(())
     }
 }
 
+#[cfg(feature = "dot")]
 #[async_trait]
 impl ChainAdapter for PolkadotAdapter {
     fn chain_name(&self) -> &str {
@@ -320,6 +1486,10 @@ impl ChainAdapter for PolkadotAdapter {
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
         // Verify Substrate state proof
         if proof.is_empty() {
@@ -341,34 +1511,26 @@ impl ChainAdapter for PolkadotAdapter {
         let mut offset = 32;
         
         // Extract key length and key
-        if proof.len() < offset + 4 {
-            return Err(RouterError::VerificationError("Proof too short for key length".to_string()));
-        }
+        let end = field_end(offset, 4, proof.len(), "key length")?;
         let key_len = u32::from_le_bytes([proof[offset], proof[offset+1], proof[offset+2], proof[offset+3]]) as usize;
-        offset += 4;
+        offset = end;
         
-        if proof.len() < offset + key_len {
-            return Err(RouterError::VerificationError("Proof too short for key".to_string()));
-        }
-        let key = &proof[offset..offset + key_len];
-        offset += key_len;
+        let end = field_end(offset, key_len, proof.len(), "key")?;
+        let key = &proof[offset..end];
+        offset = end;
         
         // Extract value length and value (expected value)
-        if proof.len() < offset + 4 {
-            return Err(RouterError::VerificationError("Proof too short for value length".to_string()));
-        }
+        let end = field_end(offset, 4, proof.len(), "value length")?;
         let value_len = u32::from_le_bytes([proof[offset], proof[offset+1], proof[offset+2], proof[offset+3]]) as usize;
-        offset += 4;
+        offset = end;
         
+        let end = field_end(offset, value_len, proof.len(), "value")?;
         let expected_value = if value_len > 0 {
-            if proof.len() < offset + value_len {
-                return Err(RouterError::VerificationError("Proof too short for value".to_string()));
-            }
-            Some(&proof[offset..offset + value_len])
+            Some(&proof[offset..end])
         } else {
             None
         };
-        offset += value_len;
+        offset = end;
         
         // The rest is the proof nodes
         let proof_nodes = &proof[offset..];
@@ -382,6 +1544,61 @@ impl ChainAdapter for PolkadotAdapter {
         self.verify_storage_proof(state_root, storage_proof, key, expected_value)
     }
     
+    async fn verify_state_streaming(
+        &self,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+    ) -> Result<bool, RouterError> {
+        let short = |field: &str| RouterError::VerificationError(format!("Proof too short for {}", field));
+        
+        let mut state_root = [0u8; 32];
+        match read_up_to(reader, &mut state_root).await? {
+            0 => return Err(RouterError::VerificationError("Empty proof".to_string())),
+            32 => {}
+            _ => return Err(RouterError::VerificationError("Proof too short for state root".to_string())),
+        }
+        
+        // Key and value are read through `take`, so a bogus length can't force a huge allocation
+        let mut len = [0u8; 4];
+        if read_up_to(reader, &mut len).await? < 4 {
+            return Err(short("key length"));
+        }
+        let key_len = u32::from_le_bytes(len) as usize;
+        let mut key = Vec::new();
+        (&mut *reader).take(key_len as u64).read_to_end(&mut key).await.map_err(proof_read_error)?;
+        if key.len() < key_len {
+            return Err(short("key"));
+        }
+        
+        if read_up_to(reader, &mut len).await? < 4 {
+            return Err(short("value length"));
+        }
+        let value_len = u32::from_le_bytes(len) as usize;
+        let mut value = Vec::new();
+        (&mut *reader).take(value_len as u64).read_to_end(&mut value).await.map_err(proof_read_error)?;
+        if value.len() < value_len {
+            return Err(short("value"));
+        }
+        
+        // SCALE `Vec<Vec<u8>>`: a compact node count, then each node with a compact length
+        let decode_error = |what: &str| {
+            RouterError::VerificationError(format!("Failed to decode storage proof: {}", what))
+        };
+        let count = read_compact_u32(reader).await?.ok_or_else(|| decode_error("bad node count"))?;
+        let mut nodes = Vec::new();
+        for _ in 0..count {
+            let node_len = read_compact_u32(reader).await?.ok_or_else(|| decode_error("bad node length"))?;
+            let mut node = Vec::new();
+            (&mut *reader).take(node_len as u64).read_to_end(&mut node).await.map_err(proof_read_error)?;
+            if node.len() < node_len as usize {
+                return Err(decode_error("node truncated"));
+            }
+            nodes.push(node);
+        }
+        
+        let expected_value = (value_len > 0).then_some(value.as_slice());
+        self.verify_storage_proof(H256::from(state_root), StorageProof::new(nodes), &key, expected_value)
+    }
+    
     async fn submit_transaction(&self, tx_data: &[u8]) -> Result<String, RouterError> {
         // Submit extrinsic to Polkadot
         if tx_data.is_empty() {
@@ -396,9 +1613,91 @@ impl ChainAdapter for PolkadotAdapter {
         if address.is_empty() {
             return Err(RouterError::TranslationError("Empty address".to_string()));
         }
+        address::validate(ChainType::Substrate, address)?;
         
         Ok(10000000000) // 1 DOT in plancks
     }
+    
+    fn capabilities(&self) -> Capabilities {
+        // The relay chain stakes natively but has no DEX or contracts
+        Capabilities {
+            supports_stake: true,
+            ..Capabilities::transfer_only("DOT", AddressFormat::Substrate)
+        }
+    }
+    
+    async fn chain_metadata(&self) -> Result<ChainMetadata, RouterError> {
+        self.metadata
+            .get_or_fetch(|| async {
+                let api = OnlineClient::<PolkadotConfig>::from_url(&self.rpc_url)
+                    .await
+                    .map_err(|e| RouterError::ChainUnavailable(format!("Failed to connect to Polkadot node: {}", e)))?;
+                let latest = api
+                    .blocks()
+                    .at_latest()
+                    .await
+                    .map_err(|e| RouterError::TranslationError(format!("Failed to query latest block: {}", e)))?;
+                let version = api.runtime_version();
+                
+                Ok(ChainMetadata {
+                    native_chain_id: format!("0x{}", hex::encode(api.genesis_hash())),
+                    latest_block: latest.number().into(),
+                    native_token: "DOT".to_string(),
+                    decimals: 10,
+                    supports_eip1559: false,
+                    spec_version: Some(version.spec_version),
+                    transaction_version: Some(version.transaction_version),
+                })
+            })
+            .await
+    }
+}
+
+/// Read a SCALE compact-encoded `u32`, or `None` if it is truncated or not canonical
+#[cfg(feature = "dot")]
+async fn read_compact_u32(reader: &mut (dyn AsyncRead + Unpin + Send)) -> Result<Option<u32>, RouterError> {
+    let mut first = [0u8; 1];
+    if read_up_to(reader, &mut first).await? == 0 {
+        return Ok(None);
+    }
+    
+    // Mode in the low two bits; each wider mode must hold a value the narrower one can't
+    let (extra, min) = match first[0] & 0b11 {
+        0b00 => return Ok(Some(u32::from(first[0] >> 2))),
+        0b01 => (1, 1 << 6),
+        0b10 => (3, 1 << 14),
+        _ if first[0] >> 2 == 0 => (4, 1 << 30),
+        _ => return Ok(None),
+    };
+    
+    let mut rest = [0u8; 4];
+    if read_up_to(reader, &mut rest[..extra]).await? < extra {
+        return Ok(None);
+    }
+    let value = match extra {
+        4 => u32::from_le_bytes(rest),
+        _ => {
+            let mut bytes = [first[0], 0, 0, 0];
+            bytes[1..=extra].copy_from_slice(&rest[..extra]);
+            u32::from_le_bytes(bytes) >> 2
+        }
+    };
+    Ok((value >= min).then_some(value))
+}
+
+/// End of a `len`-byte field at `offset`, if it fits in `available` bytes
+///
+/// Lengths come straight from the proof, so `offset + len` is checked: on 32-bit targets a
+/// `u32::MAX` length would otherwise wrap past the bounds check.
+#[cfg(feature = "dot")]
+fn field_end(offset: usize, len: usize, available: usize, field: &str) -> Result<usize, RouterError> {
+    let end = offset
+        .checked_add(len)
+        .ok_or_else(|| RouterError::VerificationError(format!("Length of {} overflows", field)))?;
+    if end > available {
+        return Err(RouterError::VerificationError(format!("Proof too short for {}", field)));
+    }
+    Ok(end)
 }
 
 // Bitcoin UTXO structure
@@ -426,16 +1725,36 @@ pub enum SelectionStrategy {
     LargestFirst,
     SmallestFirst,
     BranchAndBound,
+    PrivacyPreserving, // Fewest inputs, so fewer of the wallet's UTXOs are linked on chain
 }
 
+/// Default fee rate (sat/vB) expected when UTXOs are eventually spent, as Bitcoin Core's
+/// `-consolidatefeerate`
+pub const DEFAULT_LONG_TERM_FEE_RATE: u64 = 10;
+
+// Average P2PKH sizes in vbytes, matching `estimate_fee`
+const INPUT_VBYTES: u64 = 148;
+const OUTPUT_VBYTES: u64 = 34;
+const TX_OVERHEAD_VBYTES: u64 = 10;
+
 // UTXO selector
 pub struct UtxoSelector {
     strategy: SelectionStrategy,
+    long_term_fee_rate: u64,
 }
 
 impl UtxoSelector {
     pub fn new(strategy: SelectionStrategy) -> Self {
-        Self { strategy }
+        Self {
+            strategy,
+            long_term_fee_rate: DEFAULT_LONG_TERM_FEE_RATE,
+        }
+    }
+    
+    /// Fee rate (sat/vB) branch and bound assumes UTXOs kept now would later be spent at
+    pub fn with_long_term_fee_rate(mut self, long_term_fee_rate: u64) -> Self {
+        self.long_term_fee_rate = long_term_fee_rate;
+        self
     }
     
     /// Select UTXOs to meet target amount plus fee
@@ -461,6 +1780,9 @@ impl UtxoSelector {
                 self.select_branch_and_bound(available_utxos.clone(), target_amount, fee_rate)
                     .or_else(|_| self.select_largest_first(available_utxos, target_amount, fee_rate))
             }
+            SelectionStrategy::PrivacyPreserving => {
+                self.select_privacy_preserving(available_utxos, target_amount, fee_rate)
+            }
         }
     }
     
@@ -552,7 +1874,67 @@ impl UtxoSelector {
         )))
     }
     
+    /// Privacy-preserving selection: as few inputs as possible
+    ///
+    /// Every input spent together is linked to the same owner, so the smallest single UTXO
+    /// that covers the target is preferred. Failing that, the fewest inputs that can cover
+    /// it are used: the largest UTXOs, with the last one swapped for the smallest that
+    /// still closes the gap.
+    fn select_privacy_preserving(
+        &self,
+        mut available_utxos: Vec<Utxo>,
+        target_amount: u64,
+        fee_rate: u64,
+    ) -> Result<UtxoSelection, RouterError> {
+        // Sort UTXOs by amount in descending order
+        available_utxos.sort_by_key(|u| std::cmp::Reverse(u.amount));
+        
+        // Fewest inputs whose largest possible total covers the target and their fee
+        let mut largest_total = 0u64;
+        let mut input_count = None;
+        for (i, utxo) in available_utxos.iter().enumerate() {
+            largest_total += utxo.amount;
+            if largest_total >= target_amount + self.estimate_fee(i + 1, fee_rate) {
+                input_count = Some(i + 1);
+                break;
+            }
+        }
+        let input_count = input_count.ok_or_else(|| {
+            RouterError::TranslationError(format!(
+                "Insufficient funds: need {}, have {}",
+                target_amount + self.estimate_fee(available_utxos.len(), fee_rate),
+                largest_total
+            ))
+        })?;
+        
+        // Keep the largest others and take the smallest last input that still suffices
+        let estimated_fee = self.estimate_fee(input_count, fee_rate);
+        let required_amount = target_amount + estimated_fee;
+        let mut selected_utxos: Vec<Utxo> = available_utxos.drain(..input_count - 1).collect();
+        let others: u64 = selected_utxos.iter().map(|u| u.amount).sum();
+        let last = available_utxos
+            .into_iter()
+            .rev()
+            .find(|u| others + u.amount >= required_amount)
+            .expect("the next largest UTXO covers the rest");
+        selected_utxos.push(last);
+        
+        let total_input = others + selected_utxos[input_count - 1].amount;
+        Ok(UtxoSelection {
+            selected_utxos,
+            total_input,
+            target_amount,
+            change_amount: total_input - required_amount,
+            estimated_fee,
+        })
+    }
+    
     /// Branch and bound selection strategy (optimal selection)
+    ///
+    /// Picks the combination with the least waste, as Bitcoin Core defines it: each input
+    /// costs its size times the gap between the current and long-term fee rates (negative
+    /// when fees are low, which favours consolidating), plus either the excess given up to
+    /// fees when there's no change output, or the cost of creating and later spending one.
     fn select_branch_and_bound(
         &self,
         available_utxos: Vec<Utxo>,
@@ -563,70 +1945,158 @@ impl UtxoSelector {
         // For production, consider using a more sophisticated algorithm
         
         let max_iterations = 1000;
-        let mut best_selection: Option<UtxoSelection> = None;
-        let mut best_waste = u64::MAX;
+        let mut best: Option<(i128, u64, usize)> = None; // (waste, subset mask, input count)
+        
+        // Creating the change output now plus spending it later
+        let cost_of_change = OUTPUT_VBYTES * fee_rate + INPUT_VBYTES * self.long_term_fee_rate;
+        let input_waste = INPUT_VBYTES as i128 * (fee_rate as i128 - self.long_term_fee_rate as i128);
         
         // Try different combinations
         let n = available_utxos.len().min(20); // Limit to 20 UTXOs for performance
         
-        for i in 1..=(1 << n) {
+        for i in 1..(1u64 << n) {
             if i > max_iterations {
                 break;
             }
             
-            let mut selected_utxos = Vec::new();
             let mut total_input = 0u64;
-            
+            let mut num_inputs = 0;
             for (j, utxo) in available_utxos.iter().enumerate().take(n) {
                 if (i & (1 << j)) != 0 {
-                    selected_utxos.push(utxo.clone());
                     total_input += utxo.amount;
+                    num_inputs += 1;
                 }
             }
             
-            if selected_utxos.is_empty() {
+            // Without a change output, whatever is left over goes to fees
+            let required_amount = target_amount + self.estimate_fee_for_outputs(num_inputs, 1, fee_rate);
+            if total_input < required_amount {
                 continue;
             }
+            let excess = total_input - required_amount;
             
-            let estimated_fee = self.estimate_fee(selected_utxos.len(), fee_rate);
-            let required_amount = target_amount + estimated_fee;
-            
-            if total_input >= required_amount {
-                let change_amount = total_input - required_amount;
-                let waste = change_amount + estimated_fee;
-                
-                if waste < best_waste {
-                    best_waste = waste;
-                    best_selection = Some(UtxoSelection {
-                        selected_utxos,
-                        total_input,
-                        target_amount,
-                        change_amount,
-                        estimated_fee,
-                    });
-                }
+            let waste = input_waste * num_inputs as i128 + excess.min(cost_of_change) as i128;
+            if best.is_none_or(|(best_waste, _, _)| waste < best_waste) {
+                best = Some((waste, i, num_inputs));
             }
         }
         
-        best_selection.ok_or_else(|| {
+        let (_, mask, num_inputs) = best.ok_or_else(|| {
             RouterError::TranslationError("Branch and bound failed to find solution".to_string())
+        })?;
+        
+        let selected_utxos: Vec<Utxo> = available_utxos
+            .into_iter()
+            .take(n)
+            .enumerate()
+            .filter(|(j, _)| mask & (1 << j) != 0)
+            .map(|(_, utxo)| utxo)
+            .collect();
+        let total_input: u64 = selected_utxos.iter().map(|u| u.amount).sum();
+        
+        // Change is only worth making when the excess exceeds what it costs
+        let no_change_fee = self.estimate_fee_for_outputs(num_inputs, 1, fee_rate);
+        let (change_amount, estimated_fee) = if total_input - target_amount - no_change_fee > cost_of_change {
+            let estimated_fee = self.estimate_fee(num_inputs, fee_rate);
+            (total_input - target_amount - estimated_fee, estimated_fee)
+        } else {
+            (0, total_input - target_amount)
+        };
+        
+        Ok(UtxoSelection {
+            selected_utxos,
+            total_input,
+            target_amount,
+            change_amount,
+            estimated_fee,
         })
     }
     
     /// Estimate transaction fee based on number of inputs
     fn estimate_fee(&self, num_inputs: usize, fee_rate: u64) -> u64 {
+        // Assuming 2 outputs (recipient + change)
+        self.estimate_fee_for_outputs(num_inputs, 2, fee_rate)
+    }
+    
+    fn estimate_fee_for_outputs(&self, num_inputs: usize, num_outputs: usize, fee_rate: u64) -> u64 {
         // Estimate transaction size in vbytes
         // Formula: (num_inputs * 148) + (num_outputs * 34) + 10
-        // Assuming 2 outputs (recipient + change)
-        let num_outputs = 2;
-        let estimated_size = (num_inputs * 148) + (num_outputs * 34) + 10;
+        let estimated_size = num_inputs as u64 * INPUT_VBYTES + num_outputs as u64 * OUTPUT_VBYTES + TX_OVERHEAD_VBYTES;
         
         // Calculate fee: size * fee_rate (sat/vbyte)
-        (estimated_size as u64) * fee_rate
+        estimated_size * fee_rate
     }
 }
 
 // Bitcoin Adapter
+/// Default UTXOs fetched per `listunspent` call when building a transaction
+#[cfg(feature = "btc")]
+pub const DEFAULT_UTXO_PAGE_SIZE: usize = 100;
+
+/// Bitcoin dust threshold is typically 546 satoshis for P2PKH outputs
+#[cfg(feature = "btc")]
+const DUST_THRESHOLD: u64 = 546;
+
+/// Supplies a fresh address for each transaction's change, e.g. the next unused index of
+/// an HD wallet's internal chain
+///
+/// Any `Fn(&str) -> Result<String, RouterError>` closure taking the sending address is one.
+#[cfg(feature = "btc")]
+#[async_trait]
+pub trait ChangeAddressProvider: Send + Sync {
+    async fn change_address(&self, from_address: &str) -> Result<String, RouterError>;
+}
+
+#[cfg(feature = "btc")]
+#[async_trait]
+impl<F> ChangeAddressProvider for F
+where
+    F: Fn(&str) -> Result<String, RouterError> + Send + Sync,
+{
+    async fn change_address(&self, from_address: &str) -> Result<String, RouterError> {
+        self(from_address)
+    }
+}
+
+/// Bitcoin Core's RPC error code for a txid the wallet doesn't know
+#[cfg(feature = "btc")]
+const RPC_INVALID_ADDRESS_OR_KEY: i32 = -5;
+
+/// `what` failed against a Bitcoin node: `ChainUnavailable` if the node couldn't be
+/// reached, otherwise `otherwise`
+#[cfg(feature = "btc")]
+fn bitcoin_rpc_error(what: String, e: bitcoincore_rpc::Error, otherwise: fn(String) -> RouterError) -> RouterError {
+    use bitcoincore_rpc::{jsonrpc, Error as RpcClientError};
+    
+    let message = format!("{}: {}", what, e);
+    match e {
+        RpcClientError::JsonRpc(jsonrpc::Error::Transport(_)) => RouterError::ChainUnavailable(message),
+        _ => otherwise(message),
+    }
+}
+
+/// `UnifiedReceipt` from a wallet's `gettransaction` result
+///
+/// Negative confirmations mean a conflicting transaction was mined instead. The fee is
+/// only reported for transactions the wallet sent.
+#[cfg(feature = "btc")]
+pub fn bitcoin_receipt(tx: &bitcoincore_rpc::json::GetTransactionResult) -> UnifiedReceipt {
+    let status = match tx.info.confirmations {
+        n if n < 0 => TransactionStatus::Failed,
+        0 => TransactionStatus::Pending,
+        _ => TransactionStatus::Confirmed,
+    };
+    
+    UnifiedReceipt {
+        tx_hash: tx.info.txid.to_string(),
+        status,
+        block_height: tx.info.blockheight.map(u64::from),
+        fee_paid: tx.fee.map(|fee| u128::from(fee.to_sat().unsigned_abs())),
+        confirmations: tx.info.confirmations.max(0) as u64,
+    }
+}
+
+#[cfg(feature = "btc")]
 pub struct BitcoinAdapter {
     chain_name: String,
     chain_id: String,
@@ -634,39 +2104,148 @@ pub struct BitcoinAdapter {
     rpc_user: String,
     rpc_password: String,
     translator: Arc<IntentTranslator>,
+    hash_mode: HashMode,
+    utxo_page_size: usize,
+    change_addresses: Option<Arc<dyn ChangeAddressProvider>>,
+    network: bitcoin::Network,
 }
 
+#[cfg(feature = "btc")]
 impl BitcoinAdapter {
-    pub fn new(rpc_url: String, translator: Arc<IntentTranslator>) -> Self {
+    /// Adapter for a node on `network`; addresses for any other network are refused
+    pub fn new(rpc_url: String, network: bitcoin::Network, translator: Arc<IntentTranslator>) -> Self {
         Self {
             chain_name: "bitcoin".to_string(),
-            chain_id: "bitcoin-mainnet".to_string(),
+            chain_id: super::intent_translator::bitcoin_chain_id(network),
             rpc_url: rpc_url.clone(),
             rpc_user: std::env::var("BITCOIN_RPC_USER").unwrap_or_else(|_| "user".to_string()),
             rpc_password: std::env::var("BITCOIN_RPC_PASSWORD").unwrap_or_else(|_| "password".to_string()),
             translator,
+            hash_mode: HashMode::default(),
+            utxo_page_size: DEFAULT_UTXO_PAGE_SIZE,
+            change_addresses: None,
+            network,
         }
     }
     
-    /// Query UTXOs for a given address from Bitcoin RPC node
-    async fn query_utxos(&self, address: &str) -> Result<Vec<Utxo>, RouterError> {
-        use bitcoincore_rpc::{Auth, Client, RpcApi};
-        use bitcoin::Address;
+    pub fn network(&self) -> bitcoin::Network {
+        self.network
+    }
+    
+    /// Parse an address, refusing one for a different network than the adapter's
+    pub fn parse_address(&self, address: &str) -> Result<bitcoin::Address, RouterError> {
         use std::str::FromStr;
         
-        // Parse and validate the address
-        let btc_address = Address::from_str(address)
-            .map_err(|e| RouterError::TranslationError(format!("Invalid Bitcoin address: {}", e)))?;
+        bitcoin::Address::from_str(address)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid Bitcoin address {}: {}", address, e)))?
+            .require_network(self.network)
+            .map_err(|_| RouterError::TranslationError(format!("{} is not a {} address", address, self.network)))
+    }
+    
+    /// Derive change addresses with `provider` rather than sending change back to the sender
+    pub fn with_change_address_provider(mut self, provider: Arc<dyn ChangeAddressProvider>) -> Self {
+        self.change_addresses = Some(provider);
+        self
+    }
+    
+    /// UTXOs fetched per `listunspent` call while gathering inputs for a transaction
+    pub fn with_utxo_page_size(mut self, page_size: usize) -> Self {
+        self.utxo_page_size = page_size.max(1);
+        self
+    }
+    
+    /// Hash functions for SPV proofs: Bitcoin's double SHA-256, or double SHA3-256
+    pub fn with_hash_mode(mut self, hash_mode: HashMode) -> Self {
+        self.hash_mode = hash_mode;
+        self
+    }
+    
+    /// Use explicit RPC credentials instead of the BITCOIN_RPC_USER/PASSWORD environment variables
+    pub fn with_credentials(mut self, rpc_user: String, rpc_password: String) -> Self {
+        self.rpc_user = rpc_user;
+        self.rpc_password = rpc_password;
+        self
+    }
+    
+    /// Stream an address's UTXOs in batches of at most `page_size`, largest amounts first
+    ///
+    /// `listunspent` has no offset, so pages are amount bands: each band is fetched with
+    /// `maximumCount` one above `page_size`, and a band that comes back full is split in
+    /// two and the upper half fetched first. Every UTXO is yielded exactly once, and a
+    /// caller can stop as soon as it has enough without the rest being fetched. A batch
+    /// only exceeds `page_size` when more UTXOs than that share one exact amount.
+    pub fn utxos_paginated<'a>(
+        &'a self,
+        address: &'a str,
+        page_size: usize,
+    ) -> impl futures::Stream<Item = Result<Vec<Utxo>, RouterError>> + 'a {
+        let page_size = page_size.max(1);
+        
+        // Inclusive satoshi bands still to fetch; the last is fetched next
+        let bands = vec![(0u64, bitcoin::Amount::MAX_MONEY.to_sat())];
+        futures::stream::try_unfold(bands, move |mut bands| async move {
+            while let Some((min, max)) = bands.pop() {
+                let page = self.list_unspent(address, Some((min, max, Some(page_size + 1))))?;
+                
+                if page.len() <= page_size {
+                    if page.is_empty() {
+                        continue;
+                    }
+                    return Ok(Some((page, bands)));
+                }
+                if min == max {
+                    let page = self.list_unspent(address, Some((min, max, None)))?;
+                    return Ok(Some((page, bands)));
+                }
+                
+                let mid = min + (max - min) / 2;
+                bands.push((min, mid));
+                bands.push((mid + 1, max));
+            }
+            Ok(None)
+        })
+    }
+    
+    /// Fetch the largest UTXOs, a page at a time, until they cover `amount` plus the fee to spend them
+    ///
+    /// Returns everything fetched; if the address can't cover it, that is all of its UTXOs.
+    async fn utxos_covering(&self, address: &str, amount: u64, fee_rate: u64) -> Result<Vec<Utxo>, RouterError> {
+        use futures::TryStreamExt;
         
-        // Create RPC client
-        let client = Client::new(
-            &self.rpc_url,
-            Auth::UserPass(self.rpc_user.clone(), self.rpc_password.clone())
-        ).map_err(|e| RouterError::TranslationError(format!("Failed to create RPC client: {}", e)))?;
+        let mut pages = std::pin::pin!(self.utxos_paginated(address, self.utxo_page_size));
+        let mut utxos = Vec::new();
+        let mut total = 0u64;
+        while let Some(page) = pages.try_next().await? {
+            total += page.iter().map(|u| u.amount).sum::<u64>();
+            utxos.extend(page);
+            
+            if total >= amount.saturating_add(self.calculate_fee(utxos.len(), 2, fee_rate)) {
+                break;
+            }
+        }
+        
+        Ok(utxos)
+    }
+    
+    /// Query UTXOs for an address from the Bitcoin RPC node, optionally limited to a band of
+    /// (minimum sats, maximum sats, maximum count)
+    fn list_unspent(&self, address: &str, band: Option<(u64, u64, Option<usize>)>) -> Result<Vec<Utxo>, RouterError> {
+        use bitcoincore_rpc::{json::ListUnspentQueryOptions, RpcApi};
+        use bitcoin::{Address, Amount};
+        
+        // Parse and validate the address
+        let checked_address = self.parse_address(address)?;
+        
+        let client = self.rpc_client()?;
+        let query_options = band.map(|(min, max, count)| ListUnspentQueryOptions {
+            minimum_amount: Some(Amount::from_sat(min)),
+            maximum_amount: Some(Amount::from_sat(max)),
+            maximum_count: count,
+            minimum_sum_amount: None,
+        });
         
         // Call listunspent RPC method
         // Parameters: minconf, maxconf, addresses, include_unsafe, query_options
-        let checked_address = btc_address.assume_checked();
         let addresses: Vec<&Address> = vec![&checked_address];
         let unspent = client
             .list_unspent(
@@ -674,9 +2253,9 @@ impl BitcoinAdapter {
                 None,     // No maximum confirmation limit
                 Some(&addresses[..]),
                 None,     // Include unsafe UTXOs
-                None,     // No query options
+                query_options,
             )
-            .map_err(|e| RouterError::TranslationError(format!("Failed to query UTXOs: {}", e)))?;
+            .map_err(|e| bitcoin_rpc_error("Failed to query UTXOs".to_string(), e, RouterError::TranslationError))?;
         
         // Convert RPC response to our UTXO structure
         let mut utxos = Vec::new();
@@ -695,6 +2274,15 @@ impl BitcoinAdapter {
         Ok(utxos)
     }
     
+    fn rpc_client(&self) -> Result<bitcoincore_rpc::Client, RouterError> {
+        use bitcoincore_rpc::{Auth, Client};
+        
+        Client::new(
+            &self.rpc_url,
+            Auth::UserPass(self.rpc_user.clone(), self.rpc_password.clone())
+        ).map_err(|e| RouterError::TranslationError(format!("Failed to create RPC client: {}", e)))
+    }
+    
     /// Calculate transaction fee based on transaction size and fee rate
     fn calculate_fee(
         &self,
@@ -746,21 +2334,14 @@ impl BitcoinAdapter {
         change_amount: u64,
         change_address: &str,
     ) -> Result<Option<bitcoin::TxOut>, RouterError> {
-        use bitcoin::{Address, TxOut};
-        use std::str::FromStr;
-        
-        // Bitcoin dust threshold is typically 546 satoshis for P2PKH outputs
-        const DUST_THRESHOLD: u64 = 546;
+        use bitcoin::TxOut;
         
         if change_amount < DUST_THRESHOLD {
             // Change amount is dust, don't create output (add to fee instead)
             return Ok(None);
         }
         
-        // Parse change address - assume_checked() to convert from NetworkUnchecked
-        let addr = Address::from_str(change_address)
-            .map_err(|e| RouterError::TranslationError(format!("Invalid change address: {}", e)))?
-            .assume_checked();
+        let addr = self.parse_address(change_address)?;
         
         // Create change output
         let change_output = TxOut {
@@ -780,13 +2361,167 @@ impl BitcoinAdapter {
         fee_rate: u64,
         change_address: Option<&str>,
     ) -> Result<bitcoin::Transaction, RouterError> {
-        use bitcoin::{Address, Transaction, TxIn, TxOut, OutPoint, Sequence, Witness};
+        self.create_transaction_multi(from_address, &[(to_address.to_string(), amount)], fee_rate, change_address)
+            .await
+    }
+    
+    /// Create one transaction paying every `(address, amount)` in `recipients`, in order,
+    /// followed by a change output if there is change worth keeping
+    pub async fn create_transaction_multi(
+        &self,
+        from_address: &str,
+        recipients: &[(String, u64)],
+        fee_rate: u64,
+        change_address: Option<&str>,
+    ) -> Result<bitcoin::Transaction, RouterError> {
+        let (transaction, _) = self.build_transaction(from_address, recipients, fee_rate, change_address).await?;
+        Ok(transaction)
+    }
+    
+    /// Build the same transaction as `create_transaction_multi` as a PSBT (BIP-174) for an
+    /// external signer such as a hardware wallet
+    ///
+    /// Segwit inputs carry their spent output as `witness_utxo`; legacy inputs carry the
+    /// whole previous transaction as `non_witness_utxo`, fetched with `getrawtransaction`,
+    /// since that is the only way a signer can check their amounts.
+    pub async fn create_psbt(
+        &self,
+        from_address: &str,
+        recipients: &[(String, u64)],
+        fee_rate: u64,
+        change_address: Option<&str>,
+    ) -> Result<bitcoin::Psbt, RouterError> {
+        use bitcoin::{Amount, Psbt, ScriptBuf, TxOut};
+        use bitcoincore_rpc::RpcApi;
+        
+        let (transaction, spent) = self.build_transaction(from_address, recipients, fee_rate, change_address).await?;
+        let outpoints: Vec<_> = transaction.input.iter().map(|input| input.previous_output).collect();
+        let mut psbt = Psbt::from_unsigned_tx(transaction)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to create PSBT: {}", e)))?;
+        
+        let mut client = None;
+        for ((input, outpoint), utxo) in psbt.inputs.iter_mut().zip(outpoints).zip(spent) {
+            let spent_output = TxOut {
+                value: Amount::from_sat(utxo.amount),
+                script_pubkey: ScriptBuf::from_bytes(utxo.script_pubkey),
+            };
+            
+            if spent_output.script_pubkey.is_witness_program() {
+                input.witness_utxo = Some(spent_output);
+                continue;
+            }
+            
+            let client = match &mut client {
+                Some(client) => client,
+                None => client.insert(self.rpc_client()?),
+            };
+            let previous = client
+                .get_raw_transaction(&outpoint.txid, None)
+                .map_err(|e| bitcoin_rpc_error(format!("Failed to fetch transaction {}", outpoint.txid), e, RouterError::TranslationError))?;
+            
+            // Don't hand a signer a previous transaction that disagrees with what we're spending
+            if previous.txid() != outpoint.txid || previous.output.get(outpoint.vout as usize) != Some(&spent_output) {
+                return Err(RouterError::VerificationError(format!(
+                    "Node returned a transaction that doesn't match output {}",
+                    outpoint
+                )));
+            }
+            input.non_witness_utxo = Some(previous);
+        }
+        
+        Ok(psbt)
+    }
+    
+    /// Extract the signed transaction from a PSBT returned by its signer
+    ///
+    /// Inputs the signer finalized are used as they are. Otherwise a P2WPKH or P2PKH input
+    /// with one partial signature from the key its script pays to is finalized here; any
+    /// other unfinalized input is an error. Signatures are not checked against the sighash,
+    /// the node does that on broadcast. Fees above 25,000 sat/vB are refused as a mistake.
+    pub fn finalize_psbt(&self, mut psbt: bitcoin::Psbt) -> Result<bitcoin::Transaction, RouterError> {
         use bitcoin::blockdata::script::Builder;
-
-        use std::str::FromStr;
+        use bitcoin::{ScriptBuf, Witness};
+        
+        for (index, (input, txin)) in psbt.inputs.iter_mut().zip(&psbt.unsigned_tx.input).enumerate() {
+            if input.final_script_sig.is_some() || input.final_script_witness.is_some() {
+                continue;
+            }
+            
+            let unsigned = || RouterError::VerificationError(format!("PSBT input {} is not signed", index));
+            let spent_script = match (&input.witness_utxo, &input.non_witness_utxo) {
+                (Some(output), _) => output.script_pubkey.clone(),
+                (None, Some(previous)) => previous
+                    .output
+                    .get(txin.previous_output.vout as usize)
+                    .map(|output| output.script_pubkey.clone())
+                    .ok_or_else(unsigned)?,
+                (None, None) => return Err(unsigned()),
+            };
+            let (public_key, signature) = match input.partial_sigs.iter().next() {
+                Some((public_key, signature)) if input.partial_sigs.len() == 1 => (*public_key, *signature),
+                _ => return Err(unsigned()),
+            };
+            
+            if spent_script.is_p2wpkh() && public_key.wpubkey_hash().map(|hash| ScriptBuf::new_p2wpkh(&hash)) == Some(spent_script.clone()) {
+                input.final_script_witness = Some(Witness::from_slice(&[signature.to_vec(), public_key.to_bytes()]));
+            } else if spent_script.is_p2pkh() && ScriptBuf::new_p2pkh(&public_key.pubkey_hash()) == spent_script {
+                input.final_script_sig = Some(
+                    Builder::new()
+                        .push_slice(signature.serialize())
+                        .push_key(&public_key)
+                        .into_script(),
+                );
+            } else {
+                return Err(RouterError::VerificationError(format!(
+                    "PSBT input {} needs finalizing by its signer",
+                    index
+                )));
+            }
+            input.partial_sigs.clear();
+        }
         
-        // Step 1: Query UTXOs from RPC
-        let available_utxos = self.query_utxos(from_address).await?;
+        psbt.extract_tx()
+            .map_err(|e| RouterError::VerificationError(format!("Failed to extract transaction: {}", e)))
+    }
+    
+    /// Inputs, fee and change `create_transaction` would use, without building the transaction
+    ///
+    /// Only the sender's UTXOs are looked up; `to_address` isn't parsed. The fee is all
+    /// the transaction would pay, including any change too small to keep.
+    pub async fn estimate_transaction(
+        &self,
+        from_address: &str,
+        to_address: &str,
+        amount: u64,
+        fee_rate: u64,
+    ) -> Result<UtxoSelection, RouterError> {
+        if amount < DUST_THRESHOLD {
+            return Err(RouterError::TranslationError(format!(
+                "Payment of {} to {} is below the dust threshold",
+                amount, to_address
+            )));
+        }
+        self.select_payment(from_address, amount, 1, fee_rate).await
+    }
+    
+    /// Select inputs paying `amount` across `num_recipients` outputs
+    ///
+    /// The fee is recomputed for the final input and output counts; `change_amount` is
+    /// zero when the change would be dust, which then goes to the fee.
+    async fn select_payment(
+        &self,
+        from_address: &str,
+        amount: u64,
+        num_recipients: usize,
+        fee_rate: u64,
+    ) -> Result<UtxoSelection, RouterError> {
+        // Coin selection prices one recipient plus change, so add the other recipients' outputs
+        let extra_outputs_fee =
+            self.calculate_fee(0, num_recipients + 1, fee_rate) - self.calculate_fee(0, 2, fee_rate);
+        let selection_target = amount + extra_outputs_fee;
+        
+        // Step 1: Query the largest UTXOs from RPC, stopping once they cover the amount
+        let available_utxos = self.utxos_covering(from_address, selection_target, fee_rate).await?;
         
         if available_utxos.is_empty() {
             return Err(RouterError::TranslationError(
@@ -796,11 +2531,12 @@ impl BitcoinAdapter {
         
         // Step 2: Select UTXOs using selection algorithm
         let selector = UtxoSelector::new(SelectionStrategy::BranchAndBound);
-        let selection = selector.select_utxos(available_utxos, amount, fee_rate)?;
+        let selection = selector.select_utxos(available_utxos, selection_target, fee_rate)?;
         
         // Step 3: Calculate final fee with actual input/output counts
+        let has_change = selection.change_amount >= DUST_THRESHOLD;
         let num_inputs = selection.selected_utxos.len();
-        let num_outputs = if selection.change_amount >= 546 { 2 } else { 1 };
+        let num_outputs = num_recipients + usize::from(has_change);
         let final_fee = self.calculate_fee(num_inputs, num_outputs, fee_rate);
         
         // Verify we still have enough after final fee calculation
@@ -812,6 +2548,57 @@ impl BitcoinAdapter {
             )));
         }
         
+        let change = if has_change { selection.total_input - amount - final_fee } else { 0 };
+        let change_amount = if change >= DUST_THRESHOLD { change } else { 0 };
+        Ok(UtxoSelection {
+            target_amount: amount,
+            change_amount,
+            estimated_fee: selection.total_input - amount - change_amount,
+            ..selection
+        })
+    }
+    
+    /// Build an unsigned transaction and return it with the UTXOs its inputs spend, in order
+    async fn build_transaction(
+        &self,
+        from_address: &str,
+        recipients: &[(String, u64)],
+        fee_rate: u64,
+        change_address: Option<&str>,
+    ) -> Result<(bitcoin::Transaction, Vec<Utxo>), RouterError> {
+        use bitcoin::{Transaction, TxIn, TxOut, OutPoint, Sequence, Witness};
+        use bitcoin::blockdata::script::Builder;
+
+        use std::str::FromStr;
+        
+        if recipients.is_empty() {
+            return Err(RouterError::TranslationError("Transaction has no recipients".to_string()));
+        }
+        
+        // Validate every recipient before touching the node
+        let mut recipient_outputs = Vec::with_capacity(recipients.len());
+        let mut amount = 0u64;
+        for (address, value) in recipients {
+            let recipient_addr = self.parse_address(address)?;
+            if *value < DUST_THRESHOLD {
+                return Err(RouterError::TranslationError(format!(
+                    "Payment of {} to {} is below the dust threshold",
+                    value, address
+                )));
+            }
+            amount = amount
+                .checked_add(*value)
+                .ok_or_else(|| RouterError::TranslationError("Total payment overflows".to_string()))?;
+            
+            recipient_outputs.push(TxOut {
+                value: bitcoin::Amount::from_sat(*value),
+                script_pubkey: recipient_addr.script_pubkey(),
+            });
+        }
+        
+        // Steps 1-3: Select UTXOs and settle the fee and change
+        let selection = self.select_payment(from_address, amount, recipients.len(), fee_rate).await?;
+        
         // Step 4: Create transaction inputs
         let mut inputs = Vec::new();
         for utxo in &selection.selected_utxos {
@@ -831,24 +2618,31 @@ impl BitcoinAdapter {
             inputs.push(input);
         }
         
-        // Step 5: Create transaction outputs
-        let mut outputs = Vec::new();
-        
-        // Recipient output
-        let recipient_addr = Address::from_str(to_address)
-            .map_err(|e| RouterError::TranslationError(format!("Invalid recipient address: {}", e)))?
-            .assume_checked();
-        
-        outputs.push(TxOut {
-            value: bitcoin::Amount::from_sat(amount),
-            script_pubkey: recipient_addr.script_pubkey(),
-        });
-        
-        // Step 6: Create change output if necessary
-        let final_change = selection.total_input - amount - final_fee;
-        let change_addr = change_address.unwrap_or(from_address);
+        // Step 5: Create transaction outputs, one per recipient
+        let mut outputs = recipient_outputs;
+        
+        // Step 6: Create change output if necessary; without one the remainder goes to fees
+        let final_change = selection.change_amount;
+        let derived;
+        let change_addr = match (change_address, &self.change_addresses) {
+            (Some(change_address), _) => change_address,
+            // Only derive when there will be change, so no address index is used up for nothing
+            (None, Some(provider)) if final_change >= DUST_THRESHOLD => {
+                derived = provider.change_address(from_address).await?;
+                derived.as_str()
+            }
+            _ => from_address,
+        };
         
         if let Some(change_output) = self.create_change_output(final_change, change_addr)? {
+            // Every input is from `from_address`, so change there links it to this payment
+            let from_script = self.parse_address(from_address)?.script_pubkey();
+            if change_output.script_pubkey == from_script {
+                tracing::warn!(
+                    "Sending change back to input address {}; set a change address provider to avoid reuse",
+                    from_address
+                );
+            }
             outputs.push(change_output);
         }
         
@@ -860,10 +2654,11 @@ impl BitcoinAdapter {
             output: outputs,
         };
         
-        Ok(transaction)
+        Ok((transaction, selection.selected_utxos))
     }
 }
 
+#[cfg(feature = "btc")]
 #[async_trait]
 impl ChainAdapter for BitcoinAdapter {
     fn chain_name(&self) -> &str {
@@ -878,13 +2673,18 @@ impl ChainAdapter for BitcoinAdapter {
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
         // Verify Bitcoin SPV (Simplified Payment Verification) proof
         if proof.is_empty() {
             return Err(RouterError::VerificationError("Empty proof".to_string()));
         }
         
-        // Bitcoin SPV proof format: block_header (80 bytes) + merkle_proof
+        // Bitcoin SPV proof format: block_header (80 bytes) + optional merkle_proof of
+        // tx_index (u32 LE) + txid (32 bytes) + sibling hashes, all in internal byte order
         if proof.len() < 80 {
             return Err(RouterError::VerificationError("Proof too short for block header".to_string()));
         }
@@ -893,18 +2693,19 @@ impl ChainAdapter for BitcoinAdapter {
         let header = &proof[0..80];
         let merkle_proof = &proof[80..];
         
-        // Verify block header hash meets difficulty target
-        use sha3::Digest;
-        use blake2::Blake2b512;
-        
-        // Double SHA-256 hash of header
-        let mut hasher = Blake2b512::new();
-        hasher.update(header);
-        let first_hash = hasher.finalize();
-        
-        let mut hasher2 = Blake2b512::new();
-        hasher2.update(&first_hash);
-        let block_hash = hasher2.finalize();
+        // Block hash of the 80-byte header; native hashes are in internal (little-endian) order
+        let block_hash = match self.hash_mode {
+            HashMode::Native => {
+                use sha2::{Digest, Sha256};
+                let mut hash: [u8; 32] = Sha256::digest(Sha256::digest(header)).into();
+                hash.reverse();
+                hash
+            }
+            HashMode::QuantumSha3 => {
+                use sha3::{Digest, Sha3_256};
+                Sha3_256::digest(Sha3_256::digest(header)).into()
+            }
+        };
         
         // Extract difficulty target from header (bits field at bytes 72-76)
         let bits = u32::from_le_bytes([header[72], header[73], header[74], header[75]]);
@@ -915,7 +2716,7 @@ impl ChainAdapter for BitcoinAdapter {
         
         // Check if hash has enough leading zeros
         let leading_zeros = block_hash.iter().take_while(|&&b| b == 0).count();
-        let required_zeros = exponent.saturating_sub(3);
+        let required_zeros = 32usize.saturating_sub(exponent);
         
         if leading_zeros < required_zeros {
             return Err(RouterError::VerificationError("Block hash does not meet difficulty target".to_string()));
@@ -923,44 +2724,34 @@ impl ChainAdapter for BitcoinAdapter {
         
         // Verify Merkle proof if provided
         if !merkle_proof.is_empty() {
-            // Parse merkle proof: tx_hash (32 bytes) + sibling hashes
-            if merkle_proof.len() < 32 {
+            use crate::core::crypto::merkle::{MerkleVerifier, Sha256dMerkle, Sha3dMerkle};
+            
+            // Parse merkle proof: tx index, txid, then sibling hashes from the leaf level up
+            if merkle_proof.len() < 36 {
                 return Err(RouterError::VerificationError("Merkle proof too short".to_string()));
             }
             
-            let tx_hash = &merkle_proof[0..32];
-            let siblings = &merkle_proof[32..];
+            let tx_index = u32::from_le_bytes([merkle_proof[0], merkle_proof[1], merkle_proof[2], merkle_proof[3]]);
+            let mut txid = [0u8; 32];
+            txid.copy_from_slice(&merkle_proof[4..36]);
             
-            // Verify merkle path
-            let mut current_hash = tx_hash.to_vec();
-            let mut offset = 0;
-            
-            while offset < siblings.len() {
-                if offset + 32 > siblings.len() {
-                    return Err(RouterError::VerificationError("Invalid sibling hash in merkle proof".to_string()));
-                }
-                
-                let sibling = &siblings[offset..offset + 32];
-                offset += 32;
-                
-                // Hash current with sibling
-                let mut hasher = Blake2b512::new();
-                
-                // Determine order based on comparison
-                if current_hash < sibling.to_vec() {
-                    hasher.update(&current_hash);
-                    hasher.update(sibling);
-                } else {
-                    hasher.update(sibling);
-                    hasher.update(&current_hash);
-                }
-                
-                current_hash = hasher.finalize()[0..32].to_vec();
+            let siblings = &merkle_proof[36..];
+            if siblings.len() % 32 != 0 {
+                return Err(RouterError::VerificationError("Invalid sibling hash in merkle proof".to_string()));
             }
+            let siblings: Vec<[u8; 32]> = siblings
+                .chunks(32)
+                .map(|chunk| chunk.try_into().expect("chunks are 32 bytes"))
+                .collect();
             
-            // Verify final hash matches merkle root in header (bytes 36-68)
-            let merkle_root = &header[36..68];
-            if current_hash.as_slice() != merkle_root {
+            // The tx index picks each node's side, so a branch only proves its own position
+            let mut merkle_root = [0u8; 32];
+            merkle_root.copy_from_slice(&header[36..68]);
+            let included = match self.hash_mode {
+                HashMode::Native => Sha256dMerkle.verify(txid, tx_index, &siblings, &merkle_root),
+                HashMode::QuantumSha3 => Sha3dMerkle.verify(txid, tx_index, &siblings, &merkle_root),
+            };
+            if !included {
                 return Err(RouterError::VerificationError("Merkle root mismatch".to_string()));
             }
         }
@@ -995,6 +2786,7 @@ impl ChainAdapter for BitcoinAdapter {
         if address.is_empty() {
             return Err(RouterError::TranslationError("Empty address".to_string()));
         }
+        address::validate(ChainType::Bitcoin, address)?;
         
         // Query balance via Bitcoin RPC
         // In production, use bitcoincore_rpc::Client
@@ -1003,9 +2795,35 @@ impl ChainAdapter for BitcoinAdapter {
             "Bitcoin balance query requires RPC client configuration".to_string()
         ))
     }
+    
+    async fn get_receipt(&self, tx_hash: &str) -> Result<Option<UnifiedReceipt>, RouterError> {
+        use bitcoincore_rpc::{jsonrpc, Error as RpcClientError, RpcApi};
+        use std::str::FromStr;
+        
+        let txid = bitcoin::Txid::from_str(tx_hash)
+            .map_err(|e| RouterError::VerificationError(format!("Invalid transaction hash {}: {}", tx_hash, e)))?;
+        match self.rpc_client()?.get_transaction(&txid, None) {
+            Ok(tx) => Ok(Some(bitcoin_receipt(&tx))),
+            Err(RpcClientError::JsonRpc(jsonrpc::Error::Rpc(e))) if e.code == RPC_INVALID_ADDRESS_OR_KEY => Ok(None),
+            Err(e) => Err(bitcoin_rpc_error(format!("Failed to fetch transaction {}", tx_hash), e, RouterError::VerificationError)),
+        }
+    }
+    
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::transfer_only("BTC", AddressFormat::Bitcoin)
+    }
 }
 
+/// Seconds an IBC transfer from an intent without a deadline has to be received
+#[cfg(feature = "cosmos")]
+pub const IBC_TRANSFER_TIMEOUT_SECS: u64 = 600;
+
+/// Gas an ICS-20 `MsgTransfer` typically uses
+#[cfg(feature = "cosmos")]
+const IBC_TRANSFER_GAS: u64 = 150_000;
+
 // Cosmos Adapter
+#[cfg(feature = "cosmos")]
 pub struct CosmosAdapter {
     chain_name: String,
     chain_id: String,
@@ -1013,8 +2831,11 @@ pub struct CosmosAdapter {
     rpc_url: String,
     grpc_url: String,
     translator: Arc<IntentTranslator>,
+    ibc_channels: std::collections::HashMap<String, (String, String)>, // counterparty -> (channel, bech32 prefix)
+    clock: Arc<dyn Clock>,
 }
 
+#[cfg(feature = "cosmos")]
 impl CosmosAdapter {
     pub fn new(rpc_url: String, translator: Arc<IntentTranslator>) -> Self {
         // Convert RPC URL to gRPC URL (typically port 9090)
@@ -1026,7 +2847,69 @@ impl CosmosAdapter {
             rpc_url,
             grpc_url,
             translator,
+            ibc_channels: std::collections::HashMap::new(),
+            clock: system_clock(),
+        }
+    }
+    
+    /// Send transfers to `chain` over IBC through `channel` on this chain, to `hrp`-prefixed accounts
+    pub fn with_ibc_channel(mut self, chain: &str, channel: &str, hrp: &str) -> Self {
+        self.ibc_channels.insert(chain.to_string(), (channel.to_string(), hrp.to_string()));
+        self
+    }
+    
+    /// Use a different time source for IBC timeouts
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// ICS-20 `MsgTransfer`, as a protobuf `Any`, for a transfer to a chain on the other end of `channel`
+    ///
+    /// Times out at the intent's deadline, or `IBC_TRANSFER_TIMEOUT_SECS` from now.
+    fn translate_ibc_transfer(&self, intent: &Intent, channel: &str, hrp: &str) -> Result<TranslatedIntent, RouterError> {
+        use super::intent_translator::{TranslationMetadata, TRANSLATOR_VERSION};
+        
+        let (from, to, amount, asset, memo) = match intent.decode_action()? {
+            super::ActionType::Transfer { from, to, amount, asset, memo } => (from, to, amount, asset, memo),
+            other => {
+                return Err(RouterError::UnsupportedAction(format!("{} can't be sent over IBC", other.name())));
+            }
+        };
+        address::validate(ChainType::Cosmos, &from)?;
+        address::AddressValidator::default().with_cosmos_hrp(hrp).validate(ChainType::Cosmos, &to)?;
+        if let Some(memo) = &memo {
+            validate_memo(ChainType::Cosmos, memo)?;
         }
+        
+        let now = self.clock.now_secs();
+        let deadline = intent.expires_at.unwrap_or(now + IBC_TRANSFER_TIMEOUT_SECS);
+        let msg = ibc::MsgTransfer {
+            memo: memo.unwrap_or_default(),
+            ..ibc::MsgTransfer::new(
+                ibc::TRANSFER_PORT,
+                channel,
+                &asset.to_lowercase(),
+                amount,
+                &from,
+                &to,
+                ibc::Height::default(),
+                deadline.saturating_mul(1_000_000_000),
+            )?
+        };
+        
+        let target_format = msg.to_any_bytes();
+        Ok(TranslatedIntent {
+            original_intent: intent.clone(),
+            translation_metadata: TranslationMetadata {
+                translator_version: TRANSLATOR_VERSION.to_string(),
+                timestamp: now,
+                gas_estimate: IBC_TRANSFER_GAS,
+                translation_hash: TranslatedIntent::compute_hash(intent, &target_format),
+                price_impact_bps: None,
+            },
+            target_format,
+        })
     }
     
     /// Make a gRPC call with retry logic
@@ -1059,13 +2942,19 @@ impl CosmosAdapter {
                         .map_err(|e| RouterError::TranslationError(format!("Failed to read response: {}", e)))?;
                     return Ok(body.to_vec());
                 }
+                // A 4xx is about the request; anything else means the node isn't serving
+                Ok(resp) if resp.status().is_client_error() => {
+                    return Err(RouterError::TranslationError(
+                        format!("gRPC call failed with status: {}", resp.status())
+                    ));
+                }
                 Ok(resp) => {
-                    last_error = Some(RouterError::TranslationError(
+                    last_error = Some(RouterError::ChainUnavailable(
                         format!("gRPC call failed with status: {}", resp.status())
                     ));
                 }
                 Err(e) => {
-                    last_error = Some(RouterError::TranslationError(
+                    last_error = Some(RouterError::ChainUnavailable(
                         format!("gRPC call failed: {}", e)
                     ));
                 }
@@ -1078,7 +2967,7 @@ impl CosmosAdapter {
             }
         }
         
-        Err(last_error.unwrap_or_else(|| RouterError::TranslationError("gRPC call failed after retries".to_string())))
+        Err(last_error.unwrap_or_else(|| RouterError::ChainUnavailable("gRPC call failed after retries".to_string())))
     }
     
     /// Create a Cosmos transaction with proper message types
@@ -1090,6 +2979,8 @@ impl CosmosAdapter {
         denom: &str,
         memo: &str,
     ) -> Result<CosmosTxBody, RouterError> {
+        validate_memo(ChainType::Cosmos, memo)?;
+        
         // Create MsgSend JSON representation
         let msg_json = format!(
             r#"{{"@type":"/cosmos.bank.v1beta1.MsgSend","from_address":"{}","to_address":"{}","amount":[{{"denom":"{}","amount":"{}"}}]}}"#,
@@ -1113,6 +3004,17 @@ impl CosmosAdapter {
         Ok(tx_body)
     }
     
+    /// Transaction body for a transfer intent, carrying its memo
+    pub fn transfer_tx_body(&self, intent: &Intent) -> Result<CosmosTxBody, RouterError> {
+        match intent.decode_action()? {
+            super::ActionType::Transfer { from, to, amount, asset, memo } => {
+                address::validate(ChainType::Cosmos, &to)?;
+                self.create_transaction(&from, &to, amount, &asset.to_lowercase(), memo.as_deref().unwrap_or(""))
+            }
+            other => Err(RouterError::UnsupportedAction(format!("{} isn't a transfer", other.name()))),
+        }
+    }
+    
     /// Calculate gas fee for a transaction
     pub fn calculate_gas_fee(
         &self,
@@ -1135,47 +3037,35 @@ impl CosmosAdapter {
         Ok(fee)
     }
     
-    /// Sign a transaction using secp256k1
-    pub fn sign_transaction(
+    /// Sign a transaction in SIGN_MODE_DIRECT: SHA-256 of the protobuf SignDoc, secp256k1 r || s
+    pub async fn sign_transaction(
         &self,
         tx_body: &CosmosTxBody,
         auth_info: &CosmosAuthInfo,
         account_number: u64,
-        sequence: u64,
         chain_id: &str,
-        private_key: &[u8],
+        signer: &dyn Signer,
     ) -> Result<Vec<u8>, RouterError> {
-        // Create SignDoc by hashing transaction components
-        // In production, this would use proper Cosmos SDK signing with protobuf encoding
-        let mut sign_doc_bytes = Vec::new();
-        sign_doc_bytes.extend_from_slice(chain_id.as_bytes());
-        sign_doc_bytes.extend_from_slice(&account_number.to_le_bytes());
-        sign_doc_bytes.extend_from_slice(&sequence.to_le_bytes());
-        
-        // Include transaction body and auth info in the sign doc
-        // These would normally be protobuf-encoded
-        let _tx_body = tx_body;
-        let _auth_info = auth_info;
-        
-        // Hash the SignDoc using SHA-256
-        use sha3::Digest;
-        use sha3::Sha3_256;
-        let mut hasher = Sha3_256::new();
-        hasher.update(&sign_doc_bytes);
-        let hash = hasher.finalize();
-        
-        // Sign with secp256k1 private key
-        // Note: In production, use proper key management and cosmrs library
-        // For now, create a deterministic signature based on the hash
-        use blake2::Blake2b512;
-        let mut sig_hasher = Blake2b512::new();
-        sig_hasher.update(private_key);
-        sig_hasher.update(&hash);
-        let sig_hash = sig_hasher.finalize();
+        use cosmos_sdk_proto::cosmos::tx::v1beta1::SignDoc;
+        use cosmos_sdk_proto::prost::Message;
+        use sha2::{Digest, Sha256};
+        
+        // The sequence is already committed to through auth_info's signer info
+        let sign_doc = SignDoc {
+            body_bytes: tx_body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            chain_id: chain_id.to_string(),
+            account_number,
+        };
+        let digest = Sha256::digest(sign_doc.encode_to_vec());
         
-        // Take first 64 bytes as signature (r + s components)
-        let signature = sig_hash[0..64].to_vec();
+        let mut signature = signer.sign(&digest).await?;
+        if signature.len() != 65 {
+            return Err(RouterError::TranslationError(format!("Expected a 65-byte signature, got {}", signature.len())));
+        }
         
+        // Cosmos verifies plain r || s, without the recovery id
+        signature.truncate(64);
         Ok(signature)
     }
     
@@ -1222,6 +3112,7 @@ impl CosmosAdapter {
     }
 }
 
+#[cfg(feature = "cosmos")]
 #[async_trait]
 impl ChainAdapter for CosmosAdapter {
     fn chain_name(&self) -> &str {
@@ -1233,9 +3124,32 @@ impl ChainAdapter for CosmosAdapter {
     }
     
     async fn translate_intent(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
+        // Leaving this chain for another Cosmos chain goes over IBC rather than the bank module
+        if intent.from_chain == self.chain_name {
+            if let Some((channel, hrp)) = self.ibc_channels.get(&intent.to_chain) {
+                return self.translate_ibc_transfer(intent, channel, hrp);
+            }
+        }
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        let intent = &translated.original_intent;
+        if intent.from_chain == self.chain_name {
+            if let Some((channel, _)) = self.ibc_channels.get(&intent.to_chain) {
+                let msg = ibc::verify_transfer(&translated.target_format, &intent.decode_action()?)?;
+                if msg.source_channel != *channel {
+                    return Err(RouterError::VerificationError(format!(
+                        "IBC transfer to {} leaves through {}, expected {}",
+                        intent.to_chain, msg.source_channel, channel
+                    )));
+                }
+                return Ok(());
+            }
+        }
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
         // Verify Cosmos IBC (ICS23) proof
         if proof.is_empty() {
@@ -1274,7 +3188,7 @@ impl ChainAdapter for CosmosAdapter {
             ]) as usize;
             offset += 4;
             
-            if offset + op_len > proof_ops.len() {
+            if op_len > proof_ops.len() - offset {
                 return Err(RouterError::VerificationError("Proof op exceeds bounds".to_string()));
             }
             
@@ -1315,6 +3229,7 @@ impl ChainAdapter for CosmosAdapter {
         if address.is_empty() {
             return Err(RouterError::TranslationError("Empty address".to_string()));
         }
+        address::validate(ChainType::Cosmos, address)?;
         
         // Determine denom (default to uatom for ATOM)
         let denom = if asset.is_empty() || asset.to_uppercase() == "ATOM" {
@@ -1362,6 +3277,14 @@ impl ChainAdapter for CosmosAdapter {
         
         Ok(amount)
     }
+    
+    fn capabilities(&self) -> Capabilities {
+        // The Hub delegates natively; swaps and contracts live on other zones
+        Capabilities {
+            supports_stake: true,
+            ..Capabilities::transfer_only("ATOM", AddressFormat::Cosmos)
+        }
+    }
 }
 
 // Sentium Adapter
@@ -1371,6 +3294,8 @@ pub struct SentiumAdapter {
     #[allow(dead_code)]
     rpc_url: String,
     translator: Arc<IntentTranslator>,
+    signing_key: Option<(QuantumPublicKey, QuantumSecretKey)>,
+    next_nonce: std::sync::atomic::AtomicU64,
 }
 
 impl SentiumAdapter {
@@ -1380,7 +3305,44 @@ impl SentiumAdapter {
             chain_id: "sentium-1".to_string(),
             rpc_url,
             translator,
+            signing_key: None,
+            next_nonce: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+    
+    /// Dilithium5 key that signs unsigned envelopes on submission
+    pub fn with_signing_key(mut self, public_key: QuantumPublicKey, secret_key: QuantumSecretKey) -> Self {
+        self.signing_key = Some((public_key, secret_key));
+        self
+    }
+    
+    /// Nonce for the next envelope this adapter signs, e.g. the account's nonce after a restart
+    pub fn with_next_nonce(self, nonce: u64) -> Self {
+        self.next_nonce.store(nonce, std::sync::atomic::Ordering::SeqCst);
+        self
+    }
+    
+    /// Sign `tx_data` if needed and return the envelope as it would go on the wire
+    ///
+    /// A signed envelope must verify and is passed through as is; an unsigned one is signed
+    /// with this adapter's key and next nonce.
+    pub fn prepare_envelope(&self, tx_data: &[u8]) -> Result<SentiumEnvelope, RouterError> {
+        let mut envelope = SentiumEnvelope::decode(tx_data)?;
+        
+        if envelope.is_signed() {
+            if !envelope.verify() {
+                return Err(RouterError::VerificationError("Sentium envelope signature is invalid".to_string()));
+            }
+            return Ok(envelope);
         }
+        
+        let (public_key, secret_key) = self
+            .signing_key
+            .as_ref()
+            .ok_or_else(|| RouterError::TranslationError("Unsigned Sentium envelope and no signing key".to_string()))?;
+        let nonce = self.next_nonce.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        envelope.sign(nonce, public_key, secret_key);
+        Ok(envelope)
     }
 }
 
@@ -1399,6 +3361,10 @@ impl ChainAdapter for SentiumAdapter {
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
         // Verify Sentium quantum-safe proof using Dilithium5
         if proof.is_empty() {
@@ -1456,8 +3422,12 @@ impl ChainAdapter for SentiumAdapter {
             return Err(RouterError::TranslationError("Empty transaction data".to_string()));
         }
         
-        let tx_hash = format!("sentium:{}", hex::encode(&tx_data[..32.min(tx_data.len())]));
-        Ok(tx_hash)
+        let envelope = self.prepare_envelope(tx_data)?;
+        
+        // Dispatch is stubbed until the node RPC exists; the id is what it will report
+        let encoded = envelope.encode();
+        tracing::debug!("Dispatching {}-byte Sentium envelope, nonce {}", encoded.len(), envelope.nonce);
+        Ok(format!("sentium:{}", hex::encode(envelope.id())))
     }
     
     async fn query_balance(&self, address: &str, _asset: &str) -> Result<u64, RouterError> {
@@ -1467,13 +3437,64 @@ impl ChainAdapter for SentiumAdapter {
         
         Ok(5000000) // 5000 QSI (minimum stake)
     }
+    
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::all(Some("QSI"), Some(AddressFormat::Sentium))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    #[allow(unused_imports)]
     use super::*;
     
     #[tokio::test]
+    async fn test_sentium_submit_signs_translated_envelope() {
+        use crate::core::crypto::QuantumKeyManager;
+        
+        let translator = Arc::new(IntentTranslator::new());
+        let intent = Intent {
+            id: "sentium-1".to_string(),
+            from_chain: "ethereum".to_string(),
+            to_chain: "sentium".to_string(),
+            action: "transfer".to_string(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        };
+        let (public_key, secret_key) = QuantumKeyManager::generate_keypair();
+        let adapter = SentiumAdapter::new("http://localhost:9933".to_string(), translator.clone())
+            .with_signing_key(public_key.clone(), secret_key)
+            .with_next_nonce(41);
+        
+        let tx_data = adapter.translate_intent(&intent).await.unwrap().target_format;
+        let unsigned = SentiumEnvelope::decode(&tx_data).unwrap();
+        assert!(!unsigned.is_signed());
+        
+        // Signed with the adapter's key and consecutive nonces; the id commits to both
+        let first = adapter.prepare_envelope(&tx_data).unwrap();
+        let second = adapter.prepare_envelope(&tx_data).unwrap();
+        assert_eq!((first.nonce, second.nonce), (41, 42));
+        assert_eq!(first.intent, unsigned.intent);
+        assert_eq!(first.public_key, public_key.as_bytes());
+        assert!(first.verify());
+        assert_eq!(
+            adapter.submit_transaction(&first.encode()).await.unwrap(),
+            format!("sentium:{}", hex::encode(first.id()))
+        );
+        
+        // Tampered signed envelopes, unsigned ones without a key, and non-envelopes are refused
+        let mut tampered = first;
+        tampered.nonce = 7;
+        assert!(adapter.submit_transaction(&tampered.encode()).await.is_err());
+        let keyless = SentiumAdapter::new("http://localhost:9933".to_string(), translator);
+        assert!(keyless.submit_transaction(&tx_data).await.is_err());
+        assert!(keyless.submit_transaction(b"{\"Transfer\":{}}").await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
     async fn test_ethereum_adapter() {
         let translator = Arc::new(IntentTranslator::new());
         let adapter = EthereumAdapter::new("http://localhost:8545".to_string(), translator);
@@ -1491,7 +3512,936 @@ mod tests {
         assert!(result.is_err() || result.is_ok());
     }
     
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_encode_permit_matches_eip2612_layout() {
+        use crate::core::clock::MockClock;
+        use ethers::types::{Address, U256};
+        
+        let clock = Arc::new(MockClock::new(1_700_000_000));
+        let adapter = EthereumAdapter::new("http://localhost:8545".to_string(), Arc::new(IntentTranslator::new()))
+            .with_clock(clock.clone());
+        
+        let permit = Permit {
+            owner: Address::repeat_byte(0x11),
+            spender: Address::repeat_byte(0x22),
+            value: U256::from(1_000_000u64),
+            deadline: 1_700_000_600,
+            v: 27,
+            r: [0xaa; 32],
+            s: [0xbb; 32],
+        };
+        let data = adapter.encode_permit(&permit).unwrap();
+        
+        assert_eq!(
+            &data[..4],
+            &ethers::utils::id("permit(address,address,uint256,uint256,uint8,bytes32,bytes32)")[..]
+        );
+        assert_eq!(data.len(), 4 + 7 * 32);
+        
+        // Each argument occupies one left-padded 32-byte word
+        let word = |i: usize| &data[4 + i * 32..4 + (i + 1) * 32];
+        assert_eq!(&word(0)[12..], permit.owner.as_bytes());
+        assert_eq!(&word(1)[12..], permit.spender.as_bytes());
+        assert_eq!(U256::from_big_endian(word(2)), permit.value);
+        assert_eq!(U256::from_big_endian(word(3)), U256::from(permit.deadline));
+        assert_eq!(U256::from_big_endian(word(4)), U256::from(27));
+        assert_eq!(word(5), &permit.r);
+        assert_eq!(word(6), &permit.s);
+        
+        clock.advance(601);
+        assert!(adapter.encode_permit(&permit).is_err());
+    }
+    
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_transfer_with_permit_orders_permit_first() {
+        use ethers::types::{Address, TransactionRequest, U256};
+        
+        let adapter = EthereumAdapter::new("http://localhost:8545".to_string(), Arc::new(IntentTranslator::new()));
+        let token = Address::repeat_byte(0x33);
+        let permit = Permit {
+            owner: Address::repeat_byte(0x11),
+            spender: Address::repeat_byte(0x22),
+            value: U256::from(5u64),
+            deadline: u64::MAX,
+            v: 28,
+            r: [1; 32],
+            s: [2; 32],
+        };
+        
+        let txs: Vec<TransactionRequest> = adapter
+            .transfer_with_permit(token, &permit, Address::repeat_byte(0x44))
+            .unwrap()
+            .iter()
+            .map(|tx| serde_json::from_slice(tx).unwrap())
+            .collect();
+        
+        assert_eq!(txs.len(), 2);
+        for tx in &txs {
+            assert_eq!(tx.to, Some(token.into()));
+            assert_eq!(tx.from, Some(permit.spender));
+        }
+        assert_eq!(&txs[0].data.as_ref().unwrap()[..4], &[0xd5, 0x05, 0xac, 0xcf]);
+        assert_eq!(
+            &txs[1].data.as_ref().unwrap()[..4],
+            &ethers::utils::id("transferFrom(address,address,uint256)")[..]
+        );
+    }
+    
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_decode_aggregate3_balances() {
+        use ethers::abi::{encode, Token};
+        use ethers::types::{Address, U256};
+        
+        let calls = [
+            (Address::repeat_byte(0x01), Address::repeat_byte(0xaa)),
+            (Address::repeat_byte(0x02), Address::repeat_byte(0xbb)),
+        ];
+        let calldata = encode_aggregate3_balances(&calls);
+        assert_eq!(&calldata[..4], &ethers::utils::id("aggregate3((address,bool,bytes)[])")[..]);
+        
+        let balance_word = |amount: u64| {
+            let mut word = [0u8; 32];
+            U256::from(amount).to_big_endian(&mut word);
+            Token::Bytes(word.to_vec())
+        };
+        let blob = encode(&[Token::Array(vec![
+            Token::Tuple(vec![Token::Bool(true), balance_word(1_500_000)]),
+            Token::Tuple(vec![Token::Bool(true), balance_word(42)]),
+        ])]);
+        
+        assert_eq!(decode_aggregate3_balances(&blob, 2).unwrap(), vec![1_500_000, 42]);
+        assert!(decode_aggregate3_balances(&blob, 3).is_err());
+        
+        // A reverted balanceOf (e.g. not a token contract) fails the whole batch
+        let reverted = encode(&[Token::Array(vec![
+            Token::Tuple(vec![Token::Bool(true), balance_word(7)]),
+            Token::Tuple(vec![Token::Bool(false), Token::Bytes(vec![])]),
+        ])]);
+        assert!(decode_aggregate3_balances(&reverted, 2).is_err());
+    }
+    
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_encode_multicall3_lists_each_call() {
+        use ethers::abi::{decode, ParamType, Token};
+        use ethers::types::{Address, Bytes, U256};
+        
+        let adapter = EthereumAdapter::new("http://localhost:8545".to_string(), Arc::new(IntentTranslator::new()));
+        let calls = vec![
+            (Address::repeat_byte(0x01), Bytes::from(vec![0x09, 0x5e, 0xa7, 0xb3]), U256::zero()),
+            (Address::repeat_byte(0x02), Bytes::from(vec![0xd0, 0xe3, 0x0d, 0xb0]), U256::from(1_000)),
+            (Address::repeat_byte(0x03), Bytes::from(vec![0xa9, 0x05, 0x9c, 0xbb]), U256::from(25)),
+        ];
+        let data = adapter.encode_multicall3(calls.clone()).unwrap();
+        assert_eq!(&data[..4], &ethers::utils::id("aggregate3Value((address,bool,uint256,bytes)[])")[..]);
+        
+        let call_type = ParamType::Tuple(vec![ParamType::Address, ParamType::Bool, ParamType::Uint(256), ParamType::Bytes]);
+        let decoded = match decode(&[ParamType::Array(Box::new(call_type))], &data[4..]).unwrap().pop() {
+            Some(Token::Array(decoded)) => decoded,
+            other => panic!("expected an array of calls, got {:?}", other),
+        };
+        assert_eq!(decoded.len(), 3);
+        for (token, (target, call_data, value)) in decoded.into_iter().zip(calls) {
+            assert_eq!(
+                token,
+                Token::Tuple(vec![Token::Address(target), Token::Bool(false), Token::Uint(value), Token::Bytes(call_data.to_vec())])
+            );
+        }
+        
+        assert!(adapter.encode_multicall3(Vec::new()).is_err());
+        let empty_call = vec![
+            (Address::repeat_byte(0x01), Bytes::from(vec![0x09, 0x5e, 0xa7, 0xb3]), U256::zero()),
+            (Address::repeat_byte(0x02), Bytes::new(), U256::from(1_000)),
+        ];
+        assert!(adapter.encode_multicall3(empty_call).is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_eip1559_fees_from_fee_history() {
+        use ethers::providers::Provider;
+        use ethers::types::transaction::eip2718::TypedTransaction;
+        use ethers::types::{Address, FeeHistory, TransactionRequest, U256};
+        
+        let gwei = |n: u64| U256::from(n) * 1_000_000_000u64;
+        let history = FeeHistory {
+            base_fee_per_gas: vec![gwei(16), gwei(18), gwei(20)],
+            gas_used_ratio: vec![0.9, 0.7],
+            oldest_block: U256::from(1_000),
+            reward: vec![vec![gwei(3)], vec![gwei(1)], vec![gwei(2)]],
+        };
+        let request = TransactionRequest::new().to(Address::repeat_byte(0x11)).value(1_000).gas(21_000);
+        
+        // Tip is the median reward; the cap is twice the next base fee plus the tip
+        let (provider, mock) = Provider::mocked();
+        mock.push(history.clone()).unwrap();
+        let tx = price_transaction(&provider, request.clone(), TxType::Eip1559, &Standard).await.unwrap();
+        let TypedTransaction::Eip1559(tx) = tx else {
+            panic!("expected an EIP-1559 transaction, got {:?}", tx);
+        };
+        assert_eq!(tx.max_priority_fee_per_gas, Some(gwei(2)));
+        assert_eq!(tx.max_fee_per_gas, Some(gwei(42)));
+        assert_eq!(tx.to, request.to);
+        assert_eq!(tx.gas, Some(U256::from(21_000)));
+        
+        // A node reporting fees near U256::MAX can't be priced rather than panicking
+        let huge_base = FeeHistory { base_fee_per_gas: vec![U256::MAX / 2 + 1], ..history.clone() };
+        assert_eq!(eip1559_fees(&huge_base), None);
+        let huge_tip = FeeHistory { reward: vec![vec![U256::MAX]], ..history.clone() };
+        assert_eq!(eip1559_fees(&huge_tip), None);
+        
+        // Pre-London chains report a zero base fee: a legacy transaction at the node's gas price
+        let (provider, mock) = Provider::mocked();
+        mock.push(gwei(5)).unwrap();
+        mock.push(FeeHistory {
+            base_fee_per_gas: vec![U256::zero(); 3],
+            gas_used_ratio: vec![0.5, 0.5],
+            oldest_block: U256::from(1_000),
+            reward: vec![],
+        })
+        .unwrap();
+        let tx = price_transaction(&provider, request, TxType::Eip1559, &Standard).await.unwrap();
+        assert!(matches!(tx, TypedTransaction::Legacy(_)));
+        assert_eq!(tx.gas_price(), Some(gwei(5)));
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_access_list_attached_from_create_access_list() {
+        use ethers::providers::{JsonRpcError, MockResponse, Provider};
+        use ethers::types::transaction::eip2718::TypedTransaction;
+        use ethers::types::transaction::eip2930::{AccessList, AccessListItem};
+        use ethers::types::{Address, Eip1559TransactionRequest, TransactionRequest, H256};
+        
+        let token: Address = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".parse().unwrap();
+        let response = serde_json::json!({
+            "accessList": [{
+                "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "storageKeys": ["0x0000000000000000000000000000000000000000000000000000000000000003"],
+            }],
+            "gasUsed": "0xb1b2",
+        });
+        let expected = AccessList(vec![AccessListItem { address: token, storage_keys: vec![H256::from_low_u64_be(3)] }]);
+        
+        let (provider, mock) = Provider::mocked();
+        mock.push(response.clone()).unwrap();
+        let mut tx: TypedTransaction = Eip1559TransactionRequest::new().to(token).data(vec![0xa9, 0x05, 0x9c, 0xbb]).into();
+        attach_access_list(&provider, &mut tx).await.unwrap();
+        assert_eq!(tx.access_list(), Some(&expected));
+        
+        // Legacy transactions carry it as EIP-2930
+        mock.push(response).unwrap();
+        let mut tx: TypedTransaction = TransactionRequest::new().to(token).gas_price(1_000_000_000u64).into();
+        attach_access_list(&provider, &mut tx).await.unwrap();
+        let TypedTransaction::Eip2930(tx) = tx else {
+            panic!("expected an EIP-2930 transaction, got {:?}", tx);
+        };
+        assert_eq!(tx.access_list, expected);
+        assert_eq!(tx.tx.gas_price, Some(1_000_000_000u64.into()));
+        
+        // Skipped where the node doesn't have the method
+        mock.push_response(MockResponse::Error(JsonRpcError {
+            code: -32601,
+            message: "the method eth_createAccessList does not exist/is not available".to_string(),
+            data: None,
+        }));
+        let mut tx: TypedTransaction = TransactionRequest::new().to(token).into();
+        attach_access_list(&provider, &mut tx).await.unwrap();
+        assert!(matches!(tx, TypedTransaction::Legacy(_)));
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_receipt_status_follows_receipt() {
+        use ethers::providers::Provider;
+        use ethers::types::TransactionReceipt;
+        
+        let hash = "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b";
+        let receipt = |status: u64| TransactionReceipt { status: Some(status.into()), ..Default::default() };
+        
+        let (provider, mock) = Provider::mocked();
+        mock.push(Option::<TransactionReceipt>::None).unwrap();
+        assert_eq!(receipt_status(&provider, hash).await.unwrap(), TransactionStatus::Pending);
+        mock.push(receipt(1)).unwrap();
+        assert_eq!(receipt_status(&provider, hash).await.unwrap(), TransactionStatus::Confirmed);
+        mock.push(receipt(0)).unwrap();
+        assert_eq!(receipt_status(&provider, hash).await.unwrap(), TransactionStatus::Failed);
+        
+        assert!(receipt_status(&provider, "not-a-hash").await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_evm_receipt_from_transaction_receipt() {
+        use ethers::providers::Provider;
+        use ethers::types::{TransactionReceipt, U64};
+        
+        let hash = "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b";
+        let receipt = |status: u64| TransactionReceipt {
+            status: Some(status.into()),
+            block_number: Some(100u64.into()),
+            gas_used: Some(21_000u64.into()),
+            effective_gas_price: Some(30_000_000_000u64.into()),
+            ..Default::default()
+        };
+        
+        let (provider, mock) = Provider::mocked();
+        mock.push(Option::<TransactionReceipt>::None).unwrap();
+        assert_eq!(evm_receipt(&provider, hash).await.unwrap(), None);
+        
+        // Responses are served last-in first-out: the receipt, then the latest block
+        mock.push(U64::from(104)).unwrap();
+        mock.push(receipt(1)).unwrap();
+        let mined = evm_receipt(&provider, hash).await.unwrap().unwrap();
+        assert_eq!(mined.tx_hash, hash);
+        assert_eq!(mined.status, TransactionStatus::Confirmed);
+        assert_eq!(mined.block_height, Some(100));
+        assert_eq!(mined.confirmations, 5);
+        assert_eq!(mined.fee_paid, Some(21_000 * 30_000_000_000));
+        
+        mock.push(U64::from(100)).unwrap();
+        mock.push(receipt(0)).unwrap();
+        let reverted = evm_receipt(&provider, hash).await.unwrap().unwrap();
+        assert_eq!((reverted.status, reverted.confirmations), (TransactionStatus::Failed, 1));
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_sign_evm_transaction_recovers_signer_address() {
+        use crate::core::router::LocalSigner;
+        use ethers::types::transaction::eip2718::TypedTransaction;
+        use ethers::types::{Address, Eip1559TransactionRequest, TransactionRequest};
+        use ethers::utils::rlp::Rlp;
+        
+        // Well-known Hardhat/Anvil account #0
+        let secret = hex::decode("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80").unwrap();
+        let signer = LocalSigner::from_bytes(&secret).unwrap();
+        let from = evm_address(&signer.public_key()).unwrap();
+        assert_eq!(from, "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".parse::<Address>().unwrap());
+        
+        let legacy: TypedTransaction = TransactionRequest::new()
+            .from(from).to(Address::repeat_byte(0x11)).value(1_000).nonce(7).gas(21_000).gas_price(1_000_000_000u64).chain_id(1u64)
+            .into();
+        let eip1559: TypedTransaction = Eip1559TransactionRequest::new()
+            .from(from).to(Address::repeat_byte(0x11)).value(1_000).nonce(8).gas(21_000)
+            .max_fee_per_gas(2_000_000_000u64).max_priority_fee_per_gas(1_000_000u64).chain_id(1u64)
+            .into();
+        
+        for tx in [legacy, eip1559] {
+            let raw = sign_evm_transaction(&tx, &signer).await.unwrap();
+            let (decoded, signature) = TypedTransaction::decode_signed(&Rlp::new(&raw)).unwrap();
+            
+            assert_eq!(decoded.sighash(), tx.sighash());
+            assert_eq!(signature.recover(decoded.sighash()).unwrap(), from);
+        }
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_chain_id_mismatch_refused_before_broadcast() {
+        use crate::core::router::LocalSigner;
+        use ethers::providers::Provider;
+        use ethers::types::{Address, TransactionRequest, U256};
+        
+        let mismatch = |result: Result<u64, RouterError>| match result {
+            Err(RouterError::ChainIdMismatch { expected, got }) => (expected, got),
+            other => panic!("expected a chain id mismatch, got {:?}", other),
+        };
+        
+        // Nothing queued: a transaction for the wrong chain fails without asking the node
+        let (provider, mock) = Provider::mocked();
+        assert_eq!(mismatch(check_chain_id(&provider, Some(1), Some(5)).await), (1, 5));
+        
+        // A node on the wrong chain, configured or not
+        mock.push(U256::from(5)).unwrap();
+        assert_eq!(mismatch(check_chain_id(&provider, Some(1), None).await), (1, 5));
+        mock.push(U256::from(1)).unwrap();
+        assert_eq!(mismatch(check_chain_id(&provider, None, Some(5)).await), (1, 5));
+        mock.push(U256::from(1)).unwrap();
+        assert_eq!(check_chain_id(&provider, Some(1), None).await.unwrap(), 1);
+        
+        // A raw transaction signed for Goerli, sent through a mainnet adapter whose node is unreachable
+        let secret = hex::decode("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80").unwrap();
+        let signer = LocalSigner::from_bytes(&secret).unwrap();
+        let tx = TransactionRequest::new()
+            .to(Address::repeat_byte(0x11)).value(1_000).nonce(0).gas(21_000).gas_price(1_000_000_000u64).chain_id(5u64)
+            .into();
+        let raw = sign_evm_transaction(&tx, &signer).await.unwrap();
+        assert_eq!(signed_chain_id(&raw).unwrap(), Some(5));
+        
+        let adapter = EthereumAdapter::new("http://127.0.0.1:1".to_string(), Arc::new(IntentTranslator::new()))
+            .with_evm_chain_id(1);
+        assert!(matches!(
+            adapter.submit_transaction(&raw).await,
+            Err(RouterError::ChainIdMismatch { expected: 1, got: 5 })
+        ));
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_chain_metadata_fetched_once_and_reused() {
+        use ethers::providers::Provider;
+        use ethers::types::{Block, TxHash, U256, U64};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        
+        // One response of each queued, so a second fetch would find the mock empty
+        let (provider, mock) = Provider::mocked();
+        mock.push(Block::<TxHash> {
+            number: Some(U64::from(19_000_000)),
+            base_fee_per_gas: Some(U256::from(30_000_000_000u64)),
+            ..Default::default()
+        })
+        .unwrap();
+        mock.push(U256::from(1)).unwrap();
+        
+        let cache = MetadataCache::default();
+        let unreachable = || async { Err(RouterError::TranslationError("node unreachable".to_string())) };
+        assert!(cache.get_or_fetch(unreachable).await.is_err());
+        
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            fetch_evm_metadata(&provider, "ETH").await
+        };
+        let first = cache.get_or_fetch(fetch).await.unwrap();
+        let second = cache.get_or_fetch(fetch).await.unwrap();
+        
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(first, second);
+        assert_eq!(first.native_chain_id, "1");
+        assert_eq!(first.latest_block, 19_000_000);
+        assert!(first.supports_eip1559);
+        assert_eq!((first.native_token.as_str(), first.decimals), ("ETH", 18));
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_capabilities_refuse_contract_calls() {
+        use crate::core::router::Router;
+        
+        let adapter = BitcoinAdapter::new("http://localhost:8332".to_string(), bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        let capabilities = adapter.capabilities();
+        assert!(!capabilities.supports_contract_call);
+        assert!(!capabilities.supports_swap);
+        assert_eq!(capabilities.native_token.as_deref(), Some("BTC"));
+        assert!(capabilities.supports_action("transfer"));
+        
+        let router = Router::new();
+        router.add_adapter(Arc::new(adapter)).await;
+        let intent = Intent {
+            id: "btc-call".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "bitcoin".to_string(),
+            action: "contract_call".to_string(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        };
+        
+        assert!(matches!(
+            router.route_intent(&intent).await,
+            Err(RouterError::UnsupportedAction(msg)) if msg == "bitcoin cannot contract_call"
+        ));
+    }
+    
     #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_spv_native_hash_mode() {
+        // Bitcoin genesis block header, 80 bytes
+        let genesis = hex::decode(concat!(
+            "01000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a",
+            "29ab5f49ffff001d1dac2b7c",
+        )).unwrap();
+        
+        let native = BitcoinAdapter::new("http://localhost:8332".to_string(), bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        assert!(native.verify_state(&genesis).await.unwrap());
+        
+        // Real proof of work doesn't carry over to the SHA3 hash
+        let quantum = BitcoinAdapter::new("http://localhost:8332".to_string(), bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()))
+            .with_hash_mode(HashMode::QuantumSha3);
+        assert!(quantum.verify_state(&genesis).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_spv_verifies_tx_at_its_index() {
+        let internal = |display: &str| {
+            let mut bytes = hex::decode(display).unwrap();
+            bytes.reverse();
+            bytes
+        };
+        
+        // Block 100000's header, and the branch for its third transaction
+        let header = hex::decode(concat!(
+            "01000000",
+            "50120119172a610421a6c3011dd330d9df07b63616c2cc1f1cd0020000000000",
+            "6657a9252aacd5c0b2940996ecff952228c3067cc38d4885efb5a4ac4247e9f3",
+            "37221b4d4c86041b0f2b5710",
+        )).unwrap();
+        let txid = internal("6359f0868171b1d194cbee1af2f16ea598ae8fad666d9b012c8ed2b79a236ec4");
+        let siblings = [
+            internal("e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d"),
+            internal("ccdafb73d8dcd0173d5d5c3c9a0770d0b3953db889dab99ef05b1907518cb815"),
+        ]
+        .concat();
+        let proof = |index: u32| [header.clone(), index.to_le_bytes().to_vec(), txid.clone(), siblings.clone()].concat();
+        
+        let adapter = BitcoinAdapter::new("http://localhost:8332".to_string(), bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        assert!(adapter.verify_state(&proof(2)).await.unwrap());
+        
+        // The same branch claimed for another position doesn't hash to the root
+        for index in [0, 1, 3, 4] {
+            assert!(adapter.verify_state(&proof(index)).await.is_err(), "index {}", index);
+        }
+    }
+    
+    /// Bitcoin Core stand-in answering `listunspent` from `amounts` (sats), honouring the
+    /// amount and count query options; returns its URL and a count of calls made
+    #[cfg(feature = "btc")]
+    fn spawn_listunspent_rpc(amounts: Vec<u64>) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        let script = bitcoin::ScriptBuf::from_bytes(hex::decode("0014751e76e8199196d454941c45d1b3a323f1433bd6").unwrap());
+        spawn_bitcoin_rpc(
+            amounts
+                .into_iter()
+                .map(|amount| bitcoin::TxOut { value: bitcoin::Amount::from_sat(amount), script_pubkey: script.clone() })
+                .collect(),
+        )
+    }
+    
+    /// As `spawn_listunspent_rpc`, with each of `outputs` paid by its own funding transaction
+    /// that `getrawtransaction` returns
+    #[cfg(feature = "btc")]
+    fn spawn_bitcoin_rpc(outputs: Vec<bitcoin::TxOut>) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::io::{Read, Write};
+        use std::sync::atomic::Ordering;
+        
+        let funding: Vec<bitcoin::Transaction> = outputs
+            .into_iter()
+            .enumerate()
+            .map(|(i, output)| bitcoin::Transaction {
+                version: bitcoin::transaction::Version::TWO,
+                lock_time: bitcoin::absolute::LockTime::from_consensus(i as u32),
+                input: vec![bitcoin::TxIn::default()],
+                output: vec![output],
+            })
+            .collect();
+        
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = calls.clone();
+        
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                let body = loop {
+                    let n = stream.read(&mut buf).unwrap_or(0);
+                    if n == 0 {
+                        break None;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                    
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some(header_end) = text.find("\r\n\r\n") {
+                        let content_length = text[..header_end]
+                            .lines()
+                            .find_map(|l| {
+                                let (name, value) = l.split_once(':')?;
+                                name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse().ok()).flatten()
+                            })
+                            .unwrap_or(0);
+                        if request.len() >= header_end + 4 + content_length {
+                            break Some(request[header_end + 4..header_end + 4 + content_length].to_vec());
+                        }
+                    }
+                };
+                let Some(body) = body else { continue };
+                counter.fetch_add(1, Ordering::SeqCst);
+                
+                let rpc: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                if rpc["method"] == "getrawtransaction" {
+                    let raw = funding
+                        .iter()
+                        .find(|tx| rpc["params"][0] == tx.txid().to_string())
+                        .map(bitcoin::consensus::encode::serialize_hex);
+                    let response = serde_json::json!({ "result": raw, "error": null, "id": rpc["id"] }).to_string();
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        response.len(),
+                        response
+                    );
+                    continue;
+                }
+                if rpc["method"] == "gettransaction" {
+                    let response = match funding.iter().find(|tx| rpc["params"][0] == tx.txid().to_string()) {
+                        Some(tx) => serde_json::json!({ "result": wallet_transaction(tx, 6), "error": null, "id": rpc["id"] }),
+                        None => serde_json::json!({
+                            "result": null,
+                            "error": { "code": -5, "message": "Invalid or non-wallet transaction id" },
+                            "id": rpc["id"],
+                        }),
+                    }
+                    .to_string();
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        response.len(),
+                        response
+                    );
+                    continue;
+                }
+                
+                let options = &rpc["params"][4];
+                let sats = |v: &serde_json::Value| v.as_f64().map(|btc| (btc * 1e8).round() as u64);
+                let min = sats(&options["minimumAmount"]).unwrap_or(0);
+                let max = sats(&options["maximumAmount"]).unwrap_or(u64::MAX);
+                let count = options["maximumCount"].as_u64().unwrap_or(u64::MAX) as usize;
+                
+                let result: Vec<_> = funding
+                    .iter()
+                    .filter(|tx| (min..=max).contains(&tx.output[0].value.to_sat()))
+                    .take(count)
+                    .map(|tx| serde_json::json!({
+                        "txid": tx.txid().to_string(),
+                        "vout": 0,
+                        "scriptPubKey": hex::encode(tx.output[0].script_pubkey.as_bytes()),
+                        "amount": tx.output[0].value.to_btc(),
+                        "confirmations": 6,
+                        "spendable": true,
+                        "solvable": true,
+                        "safe": true,
+                    }))
+                    .collect();
+                let response = serde_json::json!({ "result": result, "error": null, "id": rpc["id"] }).to_string();
+                
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                );
+            }
+        });
+        
+        (url, calls)
+    }
+    
+    /// `gettransaction` result for a wallet send of `tx` with 141 sats fee, mined at height
+    /// 800,000 when `confirmations` is positive
+    #[cfg(feature = "btc")]
+    fn wallet_transaction(tx: &bitcoin::Transaction, confirmations: i32) -> serde_json::Value {
+        let mut result = serde_json::json!({
+            "txid": tx.txid().to_string(),
+            "confirmations": confirmations,
+            "time": 1_700_000_000,
+            "timereceived": 1_700_000_000,
+            "bip125-replaceable": "no",
+            "walletconflicts": [],
+            "amount": -0.001,
+            "fee": -0.00000141,
+            "details": [],
+            "hex": bitcoin::consensus::encode::serialize_hex(tx),
+        });
+        if confirmations > 0 {
+            result["blockhash"] = serde_json::json!("00".repeat(32));
+            result["blockheight"] = serde_json::json!(800_000);
+        }
+        result
+    }
+    
+    #[test]
+    #[cfg(feature = "btc")]
+    fn test_bitcoin_receipt_from_gettransaction() {
+        let tx = bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn::default()],
+            output: vec![],
+        };
+        let receipt = |confirmations| bitcoin_receipt(&serde_json::from_value(wallet_transaction(&tx, confirmations)).unwrap());
+        
+        let mined = receipt(3);
+        assert_eq!(mined.tx_hash, tx.txid().to_string());
+        assert_eq!(mined.status, TransactionStatus::Confirmed);
+        assert_eq!((mined.block_height, mined.confirmations, mined.fee_paid), (Some(800_000), 3, Some(141)));
+        
+        let mempool = receipt(0);
+        assert_eq!(mempool.status, TransactionStatus::Pending);
+        assert_eq!((mempool.block_height, mempool.confirmations), (None, 0));
+        
+        // A conflicting spend was mined instead
+        let conflicted = receipt(-2);
+        assert_eq!(conflicted.status, TransactionStatus::Failed);
+        assert_eq!(conflicted.confirmations, 0);
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_get_receipt_none_for_unknown_txid() {
+        let (url, _) = spawn_listunspent_rpc(vec![50_000]);
+        let adapter = BitcoinAdapter::new(url, bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        
+        let unknown = "11".repeat(32);
+        assert_eq!(adapter.get_receipt(&unknown).await.unwrap(), None);
+        assert!(adapter.get_receipt("not-a-txid").await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_utxos_paginated_by_amount_band() {
+        use futures::{StreamExt, TryStreamExt};
+        use std::sync::atomic::Ordering;
+        
+        const ADDRESS: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        // Unsorted, with a run of equal amounts too long for one page
+        let amounts = vec![
+            5_000, 120_000, 75_000, 2_500_000, 900, 40_000, 40_000, 40_000, 40_000, 310_000, 1_000_000, 15_000,
+        ];
+        let (url, calls) = spawn_listunspent_rpc(amounts.clone());
+        let adapter = BitcoinAdapter::new(url, bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()))
+            .with_credentials("user".to_string(), "password".to_string())
+            .with_utxo_page_size(3);
+        
+        let pages: Vec<Vec<Utxo>> = adapter.utxos_paginated(ADDRESS, 3).try_collect().await.unwrap();
+        let full_scan = calls.load(Ordering::SeqCst);
+        
+        // Every UTXO exactly once, largest bands first
+        let amount_range = |page: &[Utxo]| {
+            let amounts = page.iter().map(|u| u.amount);
+            (amounts.clone().min().unwrap(), amounts.max().unwrap())
+        };
+        assert!(pages.windows(2).all(|w| amount_range(&w[0]).0 > amount_range(&w[1]).1));
+        let mut seen: Vec<u64> = pages.iter().flatten().map(|u| u.amount).collect();
+        let mut txids: Vec<&str> = pages.iter().flatten().map(|u| u.txid.as_str()).collect();
+        txids.sort();
+        txids.dedup();
+        assert_eq!(txids.len(), amounts.len());
+        seen.sort();
+        let mut expected = amounts.clone();
+        expected.sort();
+        assert_eq!(seen, expected);
+        
+        // Only the four equal 40_000 UTXOs, which no band can split, exceed a page
+        assert!(pages.iter().all(|p| p.len() <= 3 || p.iter().all(|u| u.amount == 40_000)));
+        assert_eq!(amount_range(&pages[0]).1, 2_500_000);
+        
+        // Stopping once the first page covers the target leaves the rest unfetched
+        calls.store(0, Ordering::SeqCst);
+        let first = std::pin::pin!(adapter.utxos_paginated(ADDRESS, 3)).next().await.unwrap().unwrap();
+        assert!(first.iter().map(|u| u.amount).sum::<u64>() >= 1_000_000);
+        assert!(calls.load(Ordering::SeqCst) < full_scan);
+        
+        calls.store(0, Ordering::SeqCst);
+        let covering = adapter.utxos_covering(ADDRESS, 3_000_000, 10).await.unwrap();
+        assert!(covering.iter().map(|u| u.amount).sum::<u64>() >= 3_000_000);
+        assert!(covering.len() < amounts.len());
+        assert!(calls.load(Ordering::SeqCst) < full_scan);
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_batch_pays_every_recipient() {
+        use bitcoin::Address;
+        use std::str::FromStr;
+        
+        const FROM: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        let recipients = vec![
+            ("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2".to_string(), 40_000),
+            ("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy".to_string(), 60_000),
+            ("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq".to_string(), 80_000),
+        ];
+        let (url, _) = spawn_listunspent_rpc(vec![500_000, 30_000]);
+        let adapter = BitcoinAdapter::new(url, bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        
+        let tx = adapter.create_transaction_multi(FROM, &recipients, 10, None).await.unwrap();
+        assert_eq!(tx.input.len(), 1);
+        assert_eq!(tx.output.len(), 4);
+        for (output, (address, amount)) in tx.output.iter().zip(&recipients) {
+            assert_eq!(output.script_pubkey, Address::from_str(address).unwrap().assume_checked().script_pubkey());
+            assert_eq!(output.value.to_sat(), *amount);
+        }
+        
+        // The fee covers one input and all four outputs
+        let paid: u64 = tx.output.iter().map(|o| o.value.to_sat()).sum();
+        assert_eq!(500_000 - paid, (10 + 148 + 4 * 34) * 10);
+        
+        let mut bad = recipients.clone();
+        bad[1].0 = "not-an-address".to_string();
+        assert!(adapter.create_transaction_multi(FROM, &bad, 10, None).await.is_err());
+        bad[1] = ("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy".to_string(), 100);
+        assert!(adapter.create_transaction_multi(FROM, &bad, 10, None).await.is_err());
+        assert!(adapter.create_transaction_multi(FROM, &[], 10, None).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_addresses_must_match_network() {
+        use bitcoin::Network;
+        
+        const MAINNET: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        const TESTNET: &str = "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx";
+        let translator = Arc::new(IntentTranslator::new());
+        let mainnet = BitcoinAdapter::new("http://localhost:8332".to_string(), Network::Bitcoin, translator.clone());
+        let testnet = BitcoinAdapter::new("http://localhost:18332".to_string(), Network::Testnet, translator.clone());
+        
+        assert_eq!(mainnet.chain_id(), "bitcoin-mainnet");
+        assert_eq!(testnet.chain_id(), "bitcoin-testnet");
+        assert_eq!(
+            BitcoinAdapter::new(String::new(), Network::Regtest, translator).chain_id(),
+            "bitcoin-regtest"
+        );
+        
+        assert!(mainnet.parse_address(MAINNET).is_ok());
+        assert!(matches!(
+            mainnet.parse_address(TESTNET),
+            Err(RouterError::TranslationError(msg)) if msg.contains("not a bitcoin address")
+        ));
+        assert!(testnet.parse_address(TESTNET).is_ok());
+        assert!(testnet.parse_address(MAINNET).is_err());
+        
+        // Refused before the node is asked for UTXOs
+        assert!(mainnet.create_transaction(MAINNET, TESTNET, 100_000, 10, None).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_estimate_matches_created_transaction() {
+        const FROM: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        const TO: &str = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        let (url, _) = spawn_listunspent_rpc(vec![500_000, 30_000]);
+        let adapter = BitcoinAdapter::new(url, bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        
+        let estimate = adapter.estimate_transaction(FROM, TO, 100_000, 10).await.unwrap();
+        let tx = adapter.create_transaction(FROM, TO, 100_000, 10, None).await.unwrap();
+        
+        let spent: Vec<_> = estimate.selected_utxos.iter().map(|u| (u.txid.clone(), u.vout)).collect();
+        let inputs: Vec<_> = tx.input.iter().map(|i| (i.previous_output.txid.to_string(), i.previous_output.vout)).collect();
+        assert_eq!(spent, inputs);
+        assert_eq!(tx.output[1].value.to_sat(), estimate.change_amount);
+        let paid: u64 = tx.output.iter().map(|o| o.value.to_sat()).sum();
+        assert_eq!(estimate.total_input - paid, estimate.estimated_fee);
+        assert_eq!(estimate.estimated_fee, (10 + 148 + 2 * 34) * 10);
+        
+        // The recipient isn't parsed, only the amount checked
+        let preview = adapter.estimate_transaction(FROM, "not-an-address", 100_000, 10).await.unwrap();
+        assert_eq!(preview.estimated_fee, estimate.estimated_fee);
+        assert!(adapter.create_transaction(FROM, "not-an-address", 100_000, 10, None).await.is_err());
+        assert!(adapter.estimate_transaction(FROM, TO, 100, 10).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_psbt_carries_utxo_metadata() {
+        use bitcoin::secp256k1::{Message, Secp256k1, SecretKey};
+        use bitcoin::{ecdsa, sighash::EcdsaSighashType, Amount, Psbt, PublicKey, ScriptBuf, TxOut};
+        
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+        let public_key = PublicKey::new(secret_key.public_key(&secp));
+        let segwit = TxOut {
+            value: Amount::from_sat(60_000),
+            script_pubkey: ScriptBuf::new_p2wpkh(&public_key.wpubkey_hash().unwrap()),
+        };
+        let legacy = TxOut { value: Amount::from_sat(70_000), script_pubkey: ScriptBuf::new_p2pkh(&public_key.pubkey_hash()) };
+        let (url, _) = spawn_bitcoin_rpc(vec![segwit.clone(), legacy.clone()]);
+        let adapter = BitcoinAdapter::new(url, bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        
+        let recipients = vec![("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2".to_string(), 100_000)];
+        let psbt = adapter
+            .create_psbt("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", &recipients, 10, None)
+            .await
+            .unwrap();
+        assert_eq!(psbt.inputs.len(), 2);
+        
+        // Survives the encoding an external signer would see
+        let psbt = Psbt::deserialize(&psbt.serialize()).unwrap();
+        
+        for (input, txin) in psbt.inputs.iter().zip(&psbt.unsigned_tx.input) {
+            match &input.witness_utxo {
+                Some(output) => {
+                    assert_eq!(output, &segwit);
+                    assert!(input.non_witness_utxo.is_none());
+                }
+                None => {
+                    let previous = input.non_witness_utxo.as_ref().expect("legacy input without its previous transaction");
+                    assert_eq!(previous.txid(), txin.previous_output.txid);
+                    assert_eq!(previous.output[txin.previous_output.vout as usize], legacy);
+                }
+            }
+        }
+        
+        // Nothing to extract until every input is signed
+        assert!(adapter.finalize_psbt(psbt.clone()).is_err());
+        
+        let mut signed = psbt;
+        let signature = ecdsa::Signature {
+            sig: secp.sign_ecdsa(&Message::from_digest([7; 32]), &secret_key),
+            hash_ty: EcdsaSighashType::All,
+        };
+        for input in &mut signed.inputs {
+            input.partial_sigs.insert(public_key, signature);
+        }
+        let tx = adapter.finalize_psbt(signed.clone()).unwrap();
+        assert_eq!(tx.output, signed.unsigned_tx.output);
+        for (txin, input) in tx.input.iter().zip(&signed.inputs) {
+            if input.witness_utxo.is_some() {
+                assert_eq!(txin.witness.len(), 2);
+                assert!(txin.script_sig.is_empty());
+            } else {
+                assert!(txin.witness.is_empty());
+                assert!(!txin.script_sig.is_empty());
+            }
+        }
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "btc")]
+    async fn test_bitcoin_change_goes_to_derived_address() {
+        use bitcoin::Address;
+        use std::str::FromStr;
+        
+        const FROM: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        const TO: &str = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        const CHANGE: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let script = |address: &str| Address::from_str(address).unwrap().assume_checked().script_pubkey();
+        let (url, _) = spawn_listunspent_rpc(vec![200_000]);
+        
+        let derived = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = derived.clone();
+        let adapter = BitcoinAdapter::new(url.clone(), bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()))
+            .with_change_address_provider(Arc::new(move |from: &str| {
+                assert_eq!(from, FROM);
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Ok(CHANGE.to_string())
+            }));
+        
+        let tx = adapter.create_transaction(FROM, TO, 50_000, 10, None).await.unwrap();
+        assert_eq!(tx.output.len(), 2);
+        assert_eq!(tx.output[0].script_pubkey, script(TO));
+        assert_eq!(tx.output[1].script_pubkey, script(CHANGE));
+        assert_ne!(tx.output[1].script_pubkey, script(FROM));
+        
+        // An explicit change address wins, and nothing is derived for it
+        let tx = adapter.create_transaction(FROM, TO, 50_000, 10, Some(TO)).await.unwrap();
+        assert_eq!(tx.output[1].script_pubkey, script(TO));
+        assert_eq!(derived.load(std::sync::atomic::Ordering::SeqCst), 1);
+        
+        // Without a provider change falls back to the sender
+        let fallback = BitcoinAdapter::new(url, bitcoin::Network::Bitcoin, Arc::new(IntentTranslator::new()));
+        let tx = fallback.create_transaction(FROM, TO, 50_000, 10, None).await.unwrap();
+        assert_eq!(tx.output[1].script_pubkey, script(FROM));
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "dot")]
     async fn test_polkadot_adapter() {
         let translator = Arc::new(IntentTranslator::new());
         let adapter = PolkadotAdapter::new("wss://rpc.polkadot.io".to_string(), translator);
@@ -1508,5 +4458,273 @@ mod tests {
         let result = adapter.verify_state(&proof).await;
         // Result may be Ok or Err depending on proof validation, just check it doesn't panic
         let _ = result;
+    }    
+    #[tokio::test]
+    #[cfg(feature = "dot")]
+    async fn test_polkadot_oversized_lengths_rejected() {
+        let adapter = PolkadotAdapter::new("wss://rpc.polkadot.io".to_string(), Arc::new(IntentTranslator::new()));
+        let expect_error = |result: Result<bool, RouterError>, message: &str| match result {
+            Err(RouterError::VerificationError(e)) => assert_eq!(e, message),
+            other => panic!("expected \"{}\", got {:?}", message, other),
+        };
+        
+        // Key length of u32::MAX with a few bytes behind it
+        let proof = [&[0u8; 32][..], &u32::MAX.to_le_bytes(), &[0xAB; 8]].concat();
+        expect_error(adapter.verify_state(&proof).await, "Proof too short for key");
+        
+        // Empty key, then a value length of u32::MAX
+        let proof = [&[0u8; 32][..], &0u32.to_le_bytes(), &u32::MAX.to_le_bytes(), &[0xAB; 8]].concat();
+        expect_error(adapter.verify_state(&proof).await, "Proof too short for value");
+        
+        // One byte more than remains is still too long
+        let proof = [&[0u8; 32][..], &5u32.to_le_bytes(), &[1, 2, 3, 4]].concat();
+        expect_error(adapter.verify_state(&proof).await, "Proof too short for key");
+        
+        // An offset + length sum past usize::MAX, as a u32 length can produce on 32-bit targets
+        assert!(matches!(
+            field_end(usize::MAX - 2, u32::MAX as usize, usize::MAX, "key"),
+            Err(RouterError::VerificationError(e)) if e == "Length of key overflows"
+        ));
+        assert_eq!(field_end(36, 4, 40, "key").unwrap(), 40);
+    }    
+    /// Reader that hands out `proof` a few bytes at a time
+    #[cfg(any(feature = "eth", feature = "dot"))]
+    fn chunked(proof: &[u8], chunk: usize) -> tokio_test::io::Mock {
+        let mut builder = tokio_test::io::Builder::new();
+        for part in proof.chunks(chunk) {
+            builder.read(part);
+        }
+        builder.build()
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "eth")]
+    async fn test_ethereum_streaming_matches_buffered() {
+        use sha3::{Digest, Keccak256};
+        let adapter = EthereumAdapter::new("http://localhost:8545".to_string(), Arc::new(IntentTranslator::new()));
+        
+        // Root -> node 1, whose last 32 bytes reference node 2
+        let leaf = vec![0x42u8; 5_000];
+        let branch = [&[0xC0u8; 40][..], &Keccak256::digest(&leaf)[..]].concat();
+        let mut proof = Keccak256::digest(&branch).to_vec();
+        for node in [&branch, &leaf] {
+            proof.extend_from_slice(&(node.len() as u32).to_be_bytes());
+            proof.extend_from_slice(node);
+        }
+        
+        assert!(adapter.verify_state(&proof).await.unwrap());
+        assert!(adapter.verify_state_streaming(&mut chunked(&proof, 7)).await.unwrap());
+        
+        // Truncated mid-node, and a corrupted leaf
+        let truncated = &proof[..proof.len() - 10];
+        assert!(adapter.verify_state(truncated).await.is_err());
+        assert!(adapter.verify_state_streaming(&mut chunked(truncated, 7)).await.is_err());
+        
+        let mut corrupted = proof.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert!(adapter.verify_state(&corrupted).await.is_err());
+        assert!(adapter.verify_state_streaming(&mut chunked(&corrupted, 7)).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "dot")]
+    async fn test_polkadot_streaming_matches_buffered() {
+        use parity_scale_codec::Encode;
+        let adapter = PolkadotAdapter::new("wss://rpc.polkadot.io".to_string(), Arc::new(IntentTranslator::new()));
+        
+        let nodes = StorageProof::new(vec![vec![1u8; 100], vec![2u8; 300]]).encode();
+        let proof = [&[0u8; 32][..], &4u32.to_le_bytes(), &[1, 2, 3, 4], &2u32.to_le_bytes(), &[9, 9], &nodes].concat();
+        
+        assert!(adapter.verify_state(&proof).await.unwrap());
+        assert!(adapter.verify_state_streaming(&mut chunked(&proof, 5)).await.unwrap());
+        
+        let truncated = &proof[..proof.len() - 1];
+        assert!(adapter.verify_state(truncated).await.is_err());
+        assert!(adapter.verify_state_streaming(&mut chunked(truncated, 5)).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "cosmos")]
+    async fn test_cosmos_transfer_to_ibc_counterparty_emits_msg_transfer() {
+        use crate::core::clock::MockClock;
+        use crate::core::router::ActionType;
+        use bech32::ToBase32;
+        use prost::Message;
+        
+        let adapter = CosmosAdapter::new("http://localhost:26657".to_string(), Arc::new(IntentTranslator::new()))
+            .with_ibc_channel("osmosis", "channel-141", "osmo")
+            .with_clock(Arc::new(MockClock::new(1_700_000_000)));
+        let receiver = bech32::encode("osmo", [7u8; 20].to_base32(), bech32::Variant::Bech32).unwrap();
+        let intent = Intent {
+            id: "ibc-1".to_string(),
+            from_chain: "cosmos".to_string(),
+            to_chain: "osmosis".to_string(),
+            action: String::new(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        }
+        .with_action(ActionType::Transfer {
+            from: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".to_string(),
+            to: receiver.clone(),
+            amount: 5_000,
+            asset: "UATOM".to_string(),
+            memo: None,
+        });
+        
+        let translated = adapter.translate_intent(&intent).await.unwrap();
+        let any = prost_types::Any::decode(translated.target_format.as_slice()).unwrap();
+        assert_eq!(any.type_url, ibc::MSG_TRANSFER_TYPE_URL);
+        
+        let msg = ibc::MsgTransfer::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.source_port, "transfer");
+        assert_eq!(msg.source_channel, "channel-141");
+        assert_eq!(msg.receiver, receiver);
+        assert_eq!(msg.token, Some(ibc::Coin { denom: "uatom".to_string(), amount: "5000".to_string() }));
+        assert_eq!(msg.timeout_timestamp, (1_700_000_000 + IBC_TRANSFER_TIMEOUT_SECS) * 1_000_000_000);
+        assert!(translated.verify_hash());
+        adapter.verify_translation(&translated).unwrap();
+        
+        // Same transfer, leaving through a channel to some other chain
+        let rerouted = ibc::MsgTransfer { source_channel: "channel-0".to_string(), ..msg };
+        let mut misrouted = translated.clone();
+        misrouted.target_format = rerouted.to_any_bytes();
+        assert!(matches!(adapter.verify_translation(&misrouted), Err(RouterError::VerificationError(_))));
+        
+        // A receiver with this chain's prefix isn't an osmosis account
+        let wrong_prefix = intent.clone().with_action(ActionType::Transfer {
+            from: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".to_string(),
+            to: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".to_string(),
+            amount: 5_000,
+            asset: "UATOM".to_string(),
+            memo: None,
+        });
+        assert!(adapter.translate_intent(&wrong_prefix).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[cfg(feature = "cosmos")]
+    async fn test_cosmos_transfer_memo_lands_in_tx_body() {
+        use crate::core::router::intent_translator::COSMOS_MAX_MEMO_LEN;
+        use crate::core::router::ActionType;
+        
+        let adapter = CosmosAdapter::new("http://localhost:26657".to_string(), Arc::new(IntentTranslator::new()));
+        let deposit = |memo: &str| {
+            Intent {
+                id: "memo-1".to_string(),
+                from_chain: "sentium".to_string(),
+                to_chain: "cosmos".to_string(),
+                action: String::new(),
+                params: vec![],
+                context: vec![],
+                expires_at: None,
+                signature: None,
+            }
+            .with_action(ActionType::Transfer {
+                from: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".to_string(),
+                to: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".to_string(),
+                amount: 5_000,
+                asset: "UATOM".to_string(),
+                memo: Some(memo.to_string()),
+            })
+        };
+        
+        let body = adapter.transfer_tx_body(&deposit("104729")).unwrap();
+        assert_eq!(body.memo, "104729");
+        assert_eq!(body.messages.len(), 1);
+        
+        let translated = adapter.translate_intent(&deposit("104729")).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&translated.target_format).unwrap();
+        assert_eq!(json["memo"], "104729");
+        assert_eq!(json["messages"][0]["@type"], "/cosmos.bank.v1beta1.MsgSend");
+        
+        let too_long = "x".repeat(COSMOS_MAX_MEMO_LEN + 1);
+        assert!(adapter.transfer_tx_body(&deposit(&too_long)).is_err());
+        assert!(adapter.translate_intent(&deposit(&too_long)).await.is_err());
+        assert!(adapter.transfer_tx_body(&deposit(&too_long[1..])).is_ok());
+    }
+    
+    fn utxos_of(amounts: &[u64]) -> Vec<Utxo> {
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(i, &amount)| Utxo {
+                txid: format!("{:064x}", i),
+                vout: 0,
+                amount,
+                script_pubkey: vec![],
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn test_privacy_preserving_prefers_smallest_single_input() {
+        let selector = UtxoSelector::new(SelectionStrategy::PrivacyPreserving);
+        
+        // 50_000 plus a one-input fee of 2_260 at 10 sat/vB; 55_000 is the smallest that covers it
+        let selection = selector.select_utxos(utxos_of(&[80_000, 20_000, 55_000, 200_000]), 50_000, 10).unwrap();
+        assert_eq!(selection.selected_utxos.len(), 1);
+        assert_eq!(selection.selected_utxos[0].amount, 55_000);
+        assert_eq!(selection.estimated_fee, 2_260);
+        assert_eq!(selection.change_amount, 55_000 - 52_260);
+    }
+    
+    #[test]
+    fn test_privacy_preserving_combines_fewest_inputs() {
+        let selector = UtxoSelector::new(SelectionStrategy::PrivacyPreserving);
+        let utxos = utxos_of(&[30_000, 25_000, 10_000, 8_000, 40_000]);
+        
+        // No single UTXO covers 60_000; two do, with 25_000 the smallest partner for 40_000
+        let selection = selector.select_utxos(utxos.clone(), 60_000, 10).unwrap();
+        let mut amounts: Vec<u64> = selection.selected_utxos.iter().map(|u| u.amount).collect();
+        amounts.sort();
+        assert_eq!(amounts, vec![25_000, 40_000]);
+        assert_eq!(selection.total_input, 65_000);
+        assert_eq!(selection.change_amount, 65_000 - 60_000 - 3_740);
+        
+        // Smallest-first links four of the wallet's UTXOs for the same payment
+        let smallest_first = UtxoSelector::new(SelectionStrategy::SmallestFirst)
+            .select_utxos(utxos.clone(), 60_000, 10)
+            .unwrap();
+        assert_eq!(smallest_first.selected_utxos.len(), 4);
+        
+        assert!(selector.select_utxos(utxos, 110_000, 10).is_err());
+    }
+    
+    #[test]
+    fn test_branch_and_bound_minimizes_waste_not_leftover() {
+        let utxos = utxos_of(&[150_000, 60_000, 60_000]);
+        let amounts = |selection: &UtxoSelection| {
+            let mut amounts: Vec<u64> = selection.selected_utxos.iter().map(|u| u.amount).collect();
+            amounts.sort();
+            amounts
+        };
+        
+        // At 50 sat/vB against a long-term 10, each extra input wastes 5_920 sats. Taking the
+        // pair leaves the least over (the old change + fee metric) but the single input,
+        // with change, wastes 9_100 against the pair's 14_840.
+        let busy = UtxoSelector::new(SelectionStrategy::BranchAndBound)
+            .select_utxos(utxos.clone(), 100_000, 50)
+            .unwrap();
+        assert_eq!(amounts(&busy), vec![150_000]);
+        assert_eq!(busy.estimated_fee, (148 + 2 * 34 + 10) * 50);
+        assert_eq!(busy.change_amount, 150_000 - 100_000 - 11_300);
+        
+        // At 1 sat/vB spending an input now is cheaper than later, so it consolidates
+        let quiet = UtxoSelector::new(SelectionStrategy::BranchAndBound)
+            .select_utxos(utxos.clone(), 100_000, 1)
+            .unwrap();
+        assert_eq!(amounts(&quiet), vec![60_000, 60_000, 150_000]);
+        
+        // With equal rates inputs cost nothing extra, and a pair that lands within the cost
+        // of change drops the change output, giving the excess to fees
+        let exact = UtxoSelector::new(SelectionStrategy::BranchAndBound)
+            .with_long_term_fee_rate(50)
+            .select_utxos(utxos_of(&[150_000, 60_000, 58_500]), 100_000, 50)
+            .unwrap();
+        assert_eq!(amounts(&exact), vec![58_500, 60_000]);
+        assert_eq!(exact.change_amount, 0);
+        assert_eq!(exact.estimated_fee, 18_500);
     }
 }
@@ -9,6 +9,7 @@ use std::collections::HashMap;
 use tokio::sync::RwLock;
 
 use super::{Intent, RouterError};
+use super::address::{validate_chia, CHIA_HRP, CHIA_TESTNET_HRP};
 use super::intent_translator::{IntentTranslator, TranslatedIntent};
 
 #[derive(Debug, Deserialize)]
@@ -20,17 +21,12 @@ struct ChiaRpcResponse<T> {
 
 #[derive(Debug, Deserialize)]
 struct WalletBalance {
-    confirmed_wallet_balance: u64,
-    unconfirmed_wallet_balance: u64,
     spendable_balance: u64,
 }
 
 #[derive(Debug, Deserialize)]
 struct WalletInfo {
     id: u32,
-    name: String,
-    #[serde(rename = "type")]
-    wallet_type: u32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,7 +39,7 @@ struct AddressesResponse {
     addresses: Vec<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct ChiaTransaction {
     wallet_id: u32,
     amount: u64, // mojos (1 XCH = 1,000,000,000,000 mojos)
@@ -57,6 +53,7 @@ pub struct RealChiaAdapter {
     chain_id: String,
     http_client: Arc<HttpClient>,
     rpc_url: String,
+    address_hrp: &'static str,
     translator: Arc<IntentTranslator>,
     // Cache for address -> wallet_id mapping
     wallet_cache: Arc<RwLock<HashMap<String, u32>>>,
@@ -68,9 +65,9 @@ impl RealChiaAdapter {
         network: ChiaNetwork,
         translator: Arc<IntentTranslator>,
     ) -> Result<Self, RouterError> {
-        let chain_id = match network {
-            ChiaNetwork::Mainnet => "chia-mainnet",
-            ChiaNetwork::Testnet => "chia-testnet",
+        let (chain_id, address_hrp) = match network {
+            ChiaNetwork::Mainnet => ("chia-mainnet", CHIA_HRP),
+            ChiaNetwork::Testnet => ("chia-testnet", CHIA_TESTNET_HRP),
         };
         
         Ok(Self {
@@ -78,6 +75,7 @@ impl RealChiaAdapter {
             chain_id: chain_id.to_string(),
             http_client: Arc::new(HttpClient::new()),
             rpc_url,
+            address_hrp,
             translator,
             wallet_cache: Arc::new(RwLock::new(HashMap::new())),
         })
@@ -89,7 +87,7 @@ impl RealChiaAdapter {
         params: serde_json::Value,
     ) -> Result<T, RouterError> {
         let response = self.http_client
-            .post(&format!("{}/{}", self.rpc_url, endpoint))
+            .post(format!("{}/{}", self.rpc_url, endpoint))
             .json(&params)
             .send()
             .await
@@ -105,23 +103,14 @@ impl RealChiaAdapter {
         Ok(result.data)
     }
     
-    fn validate_chia_address(&self, address: &str) -> Result<(), RouterError> {
-        // Chia addresses start with 'xch' (mainnet) or 'txch' (testnet)
-        if !address.starts_with("xch") && !address.starts_with("txch") {
-            return Err(RouterError::TranslationError("Invalid Chia address prefix".to_string()));
-        }
-        
-        // Chia addresses are Bech32m encoded and typically 62 characters
-        if address.len() != 62 {
-            return Err(RouterError::TranslationError("Invalid Chia address length".to_string()));
-        }
-        
-        Ok(())
+    /// Bech32m puzzle hash address for this adapter's network (`xch1...` or `txch1...`)
+    pub fn validate_chia_address(&self, address: &str) -> Result<(), RouterError> {
+        validate_chia(address, self.address_hrp)
     }
     
     /// Find the wallet ID that contains the given address
     /// This method queries all wallets and their addresses to find a match
-    async fn find_wallet_for_address(&self, address: &str) -> Result<u32, RouterError> {
+    pub async fn find_wallet_for_address(&self, address: &str) -> Result<u32, RouterError> {
         // Check cache first
         {
             let cache = self.wallet_cache.read().await;
@@ -137,25 +126,22 @@ impl RealChiaAdapter {
         ).await?;
         
         // Iterate through all wallets to find the one containing this address
+        let mut wallet_addresses = Vec::new();
         for wallet in wallets_response.wallets {
-            let wallet_id = wallet.id;
-            
-            // Query addresses for this wallet
-            if let Ok(addresses) = self.get_wallet_addresses(wallet_id).await {
-                // Check if our target address is in this wallet
-                if addresses.iter().any(|addr| addr == address) {
-                    // Cache the result
-                    let mut cache = self.wallet_cache.write().await;
-                    cache.insert(address.to_string(), wallet_id);
-                    
-                    return Ok(wallet_id);
-                }
+            // Wallets whose addresses can't be listed are skipped
+            if let Ok(addresses) = self.get_wallet_addresses(wallet.id).await {
+                wallet_addresses.push((wallet.id, addresses));
             }
         }
         
-        Err(RouterError::TranslationError(
-            format!("Wallet not found for address: {}", address)
-        ))
+        let wallet_id = wallet_containing(&wallet_addresses, address).ok_or_else(|| {
+            RouterError::TranslationError(format!("Wallet not found for address: {}", address))
+        })?;
+        
+        // Cache the result
+        self.wallet_cache.write().await.insert(address.to_string(), wallet_id);
+        
+        Ok(wallet_id)
     }
     
     /// Get all addresses for a specific wallet
@@ -186,6 +172,14 @@ impl RealChiaAdapter {
     }
 }
 
+/// First wallet (by listing order) whose addresses include `address`
+pub fn wallet_containing(wallets: &[(u32, Vec<String>)], address: &str) -> Option<u32> {
+    wallets
+        .iter()
+        .find(|(_, addresses)| addresses.iter().any(|addr| addr == address))
+        .map(|(wallet_id, _)| *wallet_id)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ChiaNetwork {
     Mainnet,
@@ -206,6 +200,10 @@ impl super::chain_adapter::ChainAdapter for RealChiaAdapter {
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
         if proof.len() < 62 {
             return Err(RouterError::VerificationError("Proof too short".to_string()));
@@ -279,21 +277,69 @@ mod tests {
         assert!(adapter.is_ok());
     }
     
+    const ADDRESS: &str = "xch1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0srg6dkm";
+    
+    fn adapter(network: ChiaNetwork) -> RealChiaAdapter {
+        RealChiaAdapter::new(
+            "http://localhost:9256".to_string(),
+            network,
+            Arc::new(IntentTranslator::new()),
+        ).unwrap()
+    }
+    
     #[test]
     fn test_validate_chia_address() {
-        let translator = Arc::new(IntentTranslator::new());
-        let adapter = RealChiaAdapter::new(
-            "http://localhost:9256".to_string(),
-            ChiaNetwork::Mainnet,
-            translator,
-        ).unwrap();
+        let mainnet = adapter(ChiaNetwork::Mainnet);
+        assert!(mainnet.validate_chia_address(ADDRESS).is_ok());
+        
+        // Right length and prefix, but not a bech32m checksum
+        assert!(mainnet.validate_chia_address(&format!("xch{}", "1".repeat(59))).is_err());
+        assert!(mainnet.validate_chia_address("invalid").is_err());
+        assert!(mainnet.validate_chia_address("0x1234").is_err());
+        
+        // Testnet expects txch
+        let testnet = adapter(ChiaNetwork::Testnet);
+        assert!(testnet.validate_chia_address(ADDRESS).is_err());
+        assert!(testnet
+            .validate_chia_address("txch1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sw0amhg")
+            .is_ok());
+    }
+    
+    #[test]
+    fn test_wallet_containing() {
+        let wallets = vec![
+            (1, vec!["xch1abc".to_string(), "xch1def".to_string()]),
+            (2, vec!["xch1ghi".to_string()]),
+            (3, vec!["xch1ghi".to_string()]),
+        ];
+        
+        assert_eq!(wallet_containing(&wallets, "xch1def"), Some(1));
+        assert_eq!(wallet_containing(&wallets, "xch1ghi"), Some(2));
+        assert_eq!(wallet_containing(&wallets, "xch1xyz"), None);
+        assert_eq!(wallet_containing(&[], "xch1abc"), None);
+    }
+    
+    #[tokio::test]
+    async fn test_find_wallet_uses_cache() {
+        // Nothing listens on the RPC port, so only a cached entry can resolve
+        let adapter = adapter(ChiaNetwork::Mainnet);
+        adapter.wallet_cache.write().await.insert(ADDRESS.to_string(), 7);
+        assert_eq!(adapter.find_wallet_for_address(ADDRESS).await.unwrap(), 7);
+        
+        adapter.invalidate_cache(Some(ADDRESS)).await;
+        assert!(adapter.find_wallet_for_address(ADDRESS).await.is_err());
+    }
+    
+    #[tokio::test]
+    #[ignore] // Requires a local Chia wallet RPC with the address in one of its wallets
+    async fn test_query_balance_rpc() {
+        use super::super::chain_adapter::ChainAdapter;
         
-        // Valid mainnet address format (62 chars starting with 'xch')
-        let valid_addr = "xch" + &"1".repeat(59);
-        assert!(adapter.validate_chia_address(&valid_addr).is_ok());
+        let adapter = adapter(ChiaNetwork::Mainnet);
+        let address = std::env::var("CHIA_WALLET_ADDRESS").unwrap_or_else(|_| ADDRESS.to_string());
         
-        // Invalid addresses
-        assert!(adapter.validate_chia_address("invalid").is_err());
-        assert!(adapter.validate_chia_address("0x1234").is_err());
+        let wallet_id = adapter.find_wallet_for_address(&address).await.unwrap();
+        assert!(wallet_id >= 1);
+        assert!(adapter.query_balance(&address, "XCH").await.is_ok());
     }
 }
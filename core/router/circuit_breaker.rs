@@ -0,0 +1,234 @@
+// Circuit Breaker - Stops routing to a chain whose adapter keeps failing
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::RouterError;
+
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+pub const DEFAULT_COOLDOWN_SECS: u64 = 30;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,   // Routing normally
+    Open,     // Refusing until the cooldown ends
+    HalfOpen, // One probe in flight; its outcome closes or reopens the circuit
+}
+
+/// Breaker for one chain
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: u64,
+    probe_started_at: u64,
+}
+
+impl CircuitBreaker {
+    fn new() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            opened_at: 0,
+            probe_started_at: 0,
+        }
+    }
+    
+    pub fn state(&self) -> CircuitState {
+        self.state
+    }
+    
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+}
+
+/// Per-chain circuit breakers, tripping open after `failure_threshold` consecutive failures
+///
+/// After `cooldown_secs` an open circuit lets a single probe through (half-open): a success
+/// closes it, a failure opens it for another cooldown. A probe that never reports back, say
+/// because its caller was cancelled, expires after another `cooldown_secs`.
+pub struct CircuitBreakers {
+    failure_threshold: u32,
+    cooldown_secs: u64,
+    breakers: Mutex<HashMap<String, CircuitBreaker>>,
+}
+
+impl CircuitBreakers {
+    pub fn new(failure_threshold: u32, cooldown_secs: u64) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown_secs,
+            breakers: Mutex::new(HashMap::new()),
+        }
+    }
+    
+    /// Admit a call to `chain` at `now` (Unix seconds), or fail with `RouterError::CircuitOpen`
+    pub fn check(&self, chain: &str, now: u64) -> Result<(), RouterError> {
+        let mut breakers = self.breakers.lock().unwrap();
+        let breaker = breakers.entry(chain.to_string()).or_insert_with(CircuitBreaker::new);
+        
+        let retry_after_secs = match breaker.state {
+            CircuitState::Closed => return Ok(()),
+            CircuitState::Open => {
+                let reopens_at = breaker.opened_at.saturating_add(self.cooldown_secs);
+                if now >= reopens_at {
+                    breaker.state = CircuitState::HalfOpen;
+                    breaker.probe_started_at = now;
+                    return Ok(());
+                }
+                reopens_at - now
+            }
+            // The probe hasn't reported back yet; give up on it after a cooldown
+            CircuitState::HalfOpen => {
+                let expires_at = breaker.probe_started_at.saturating_add(self.cooldown_secs);
+                if now >= expires_at {
+                    breaker.probe_started_at = now;
+                    return Ok(());
+                }
+                expires_at - now
+            }
+        };
+        
+        Err(RouterError::CircuitOpen {
+            chain: chain.to_string(),
+            retry_after_secs,
+        })
+    }
+    
+    /// Refuse work for `chain` while its circuit is open and cooling down, without claiming
+    /// the half-open probe; for steps that make no adapter calls, like translation
+    pub fn check_routable(&self, chain: &str, now: u64) -> Result<(), RouterError> {
+        let breakers = self.breakers.lock().unwrap();
+        match breakers.get(chain) {
+            Some(breaker) if breaker.state == CircuitState::Open => {
+                let reopens_at = breaker.opened_at.saturating_add(self.cooldown_secs);
+                if now < reopens_at {
+                    return Err(RouterError::CircuitOpen {
+                        chain: chain.to_string(),
+                        retry_after_secs: reopens_at - now,
+                    });
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+    
+    pub fn record_success(&self, chain: &str) {
+        let mut breakers = self.breakers.lock().unwrap();
+        let breaker = breakers.entry(chain.to_string()).or_insert_with(CircuitBreaker::new);
+        breaker.state = CircuitState::Closed;
+        breaker.consecutive_failures = 0;
+    }
+    
+    /// Give back a half-open probe whose call ended without saying anything about the chain
+    ///
+    /// The circuit goes back to open with its cooldown already served, so the next call
+    /// probes again.
+    pub fn release_probe(&self, chain: &str) {
+        let mut breakers = self.breakers.lock().unwrap();
+        if let Some(breaker) = breakers.get_mut(chain).filter(|breaker| breaker.state == CircuitState::HalfOpen) {
+            breaker.state = CircuitState::Open;
+        }
+    }
+    
+    pub fn record_failure(&self, chain: &str, now: u64) {
+        let mut breakers = self.breakers.lock().unwrap();
+        let breaker = breakers.entry(chain.to_string()).or_insert_with(CircuitBreaker::new);
+        breaker.consecutive_failures = breaker.consecutive_failures.saturating_add(1);
+        
+        if breaker.state == CircuitState::HalfOpen || breaker.consecutive_failures >= self.failure_threshold {
+            if breaker.state != CircuitState::Open {
+                tracing::warn!(
+                    "Circuit for {} opened after {} consecutive failures",
+                    chain, breaker.consecutive_failures
+                );
+            }
+            breaker.state = CircuitState::Open;
+            breaker.opened_at = now;
+        }
+    }
+    
+    /// Current state for `chain`; chains never seen are closed
+    pub fn state(&self, chain: &str) -> CircuitState {
+        self.breakers
+            .lock()
+            .unwrap()
+            .get(chain)
+            .map_or(CircuitState::Closed, CircuitBreaker::state)
+    }
+}
+
+impl Default for CircuitBreakers {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAILURE_THRESHOLD, DEFAULT_COOLDOWN_SECS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_half_open_failure_reopens_for_full_cooldown() {
+        let breakers = CircuitBreakers::new(2, 10);
+        
+        breakers.record_failure("polkadot", 100);
+        assert!(breakers.check("polkadot", 100).is_ok());
+        breakers.record_failure("polkadot", 101);
+        assert_eq!(breakers.state("polkadot"), CircuitState::Open);
+        assert!(matches!(
+            breakers.check("polkadot", 105),
+            Err(RouterError::CircuitOpen { retry_after_secs: 6, .. })
+        ));
+        
+        // Only one probe at a time
+        assert!(breakers.check("polkadot", 111).is_ok());
+        assert_eq!(breakers.state("polkadot"), CircuitState::HalfOpen);
+        assert!(breakers.check("polkadot", 111).is_err());
+        
+        breakers.record_failure("polkadot", 112);
+        assert!(matches!(
+            breakers.check("polkadot", 112),
+            Err(RouterError::CircuitOpen { retry_after_secs: 10, .. })
+        ));
+        
+        // Other chains are unaffected
+        assert_eq!(breakers.state("cosmos"), CircuitState::Closed);
+    }
+    
+    #[test]
+    fn test_abandoned_probe_expires_after_cooldown() {
+        let breakers = CircuitBreakers::new(1, 10);
+        breakers.record_failure("polkadot", 100);
+        
+        // The probe's caller is cancelled before it reports back
+        assert!(breakers.check("polkadot", 110).is_ok());
+        assert!(matches!(
+            breakers.check("polkadot", 115),
+            Err(RouterError::CircuitOpen { retry_after_secs: 5, .. })
+        ));
+        
+        assert!(breakers.check("polkadot", 120).is_ok());
+        breakers.record_success("polkadot");
+        assert_eq!(breakers.state("polkadot"), CircuitState::Closed);
+    }
+    
+    #[test]
+    fn test_released_probe_lets_next_call_probe() {
+        let breakers = CircuitBreakers::new(1, 10);
+        breakers.record_failure("polkadot", 100);
+        
+        // Translation doesn't claim the probe, and only waits out the cooldown
+        assert!(breakers.check_routable("polkadot", 105).is_err());
+        assert!(breakers.check_routable("polkadot", 110).is_ok());
+        assert_eq!(breakers.state("polkadot"), CircuitState::Open);
+        
+        assert!(breakers.check("polkadot", 110).is_ok());
+        breakers.release_probe("polkadot");
+        assert_eq!(breakers.state("polkadot"), CircuitState::Open);
+        assert!(breakers.check("polkadot", 110).is_ok());
+        assert_eq!(breakers.state("polkadot"), CircuitState::HalfOpen);
+    }
+}
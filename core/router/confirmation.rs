@@ -0,0 +1,361 @@
+// Confirmation Tracker - Counts confirmations by block hash so reorgs reset them
+use std::collections::{BTreeMap, HashMap};
+
+use crate::core::context::UserPreferences;
+
+/// Canonical heads kept for ancestry checks; older blocks are treated as final
+const HEAD_HISTORY: u64 = 256;
+
+/// Confirmations required on chains the policy has no entry for
+pub const DEFAULT_CONFIRMATIONS: u64 = 12;
+
+/// Minimum confirmations per chain id, used when the user doesn't ask for a number
+///
+/// The defaults cover the built-in chains and common L2s; chains with fast finality
+/// need one block, proof-of-work chains need several.
+#[derive(Debug, Clone)]
+pub struct ConfirmationPolicy {
+    by_chain: HashMap<String, u64>,
+    fallback: u64,
+}
+
+impl Default for ConfirmationPolicy {
+    fn default() -> Self {
+        let by_chain = [
+            ("bitcoin-mainnet", 6),
+            ("ethereum-1", 12),
+            ("polkadot-0", 2),
+            ("cosmoshub-4", 1),  // instant finality
+            ("sentium-1", 1),
+            ("arbitrum-42161", 1),
+            ("optimism-10", 1),
+            ("base-8453", 1),
+        ]
+        .into_iter()
+        .map(|(chain_id, confirmations)| (chain_id.to_string(), confirmations))
+        .collect();
+        
+        Self { by_chain, fallback: DEFAULT_CONFIRMATIONS }
+    }
+}
+
+impl ConfirmationPolicy {
+    /// Require `confirmations` on `chain_id`, replacing its default
+    pub fn with_chain(mut self, chain_id: &str, confirmations: u64) -> Self {
+        self.by_chain.insert(chain_id.to_string(), confirmations.max(1));
+        self
+    }
+    
+    /// Confirmations for chains without an entry
+    pub fn with_fallback(mut self, confirmations: u64) -> Self {
+        self.fallback = confirmations.max(1);
+        self
+    }
+    
+    pub fn recommended_confirmations(&self, chain_id: &str) -> u64 {
+        self.by_chain.get(chain_id).copied().unwrap_or(self.fallback)
+    }
+    
+    /// The user's `min_confirmations` if set, otherwise the chain's recommendation
+    pub fn required_confirmations(&self, chain_id: &str, preferences: &UserPreferences) -> u64 {
+        match preferences.min_confirmations {
+            0 => self.recommended_confirmations(chain_id),
+            explicit => explicit as u64,
+        }
+    }
+}
+
+/// A block header as seen on the head subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockRef {
+    pub number: u64,
+    pub hash: [u8; 32],
+    pub parent_hash: [u8; 32],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationStatus {
+    Pending,            // mining block not seen on a head yet
+    Confirming(u64),    // confirmations so far, below the requirement
+    Confirmed,
+    Reorged,            // mining block is no longer on the canonical chain
+}
+
+#[derive(Debug, Clone)]
+struct TrackedTx {
+    block_number: u64,
+    block_hash: [u8; 32],
+    status: ConfirmationStatus,
+}
+
+pub struct ConfirmationTracker {
+    required_confirmations: u64,
+    canonical: BTreeMap<u64, BlockRef>, // blocks proven, by parent links, to lead to the tip
+    txs: HashMap<String, TrackedTx>,
+}
+
+impl ConfirmationTracker {
+    pub fn new(required_confirmations: u64) -> Self {
+        Self {
+            required_confirmations: required_confirmations.max(1),
+            canonical: BTreeMap::new(),
+            txs: HashMap::new(),
+        }
+    }
+    
+    /// Tracker for `chain_id` requiring what `policy` gives for the user's preferences
+    pub fn for_chain(policy: &ConfirmationPolicy, chain_id: &str, preferences: &UserPreferences) -> Self {
+        Self::new(policy.required_confirmations(chain_id, preferences))
+    }
+    
+    /// Track a transaction mined in the given block; re-tracking after a reorg replaces the block
+    pub fn track(&mut self, tx_hash: &str, block_number: u64, block_hash: [u8; 32]) -> ConfirmationStatus {
+        let mut tx = TrackedTx {
+            block_number,
+            block_hash,
+            status: ConfirmationStatus::Pending,
+        };
+        tx.status = self.evaluate(&tx);
+        
+        let status = tx.status;
+        self.txs.insert(tx_hash.to_string(), tx);
+        status
+    }
+    
+    pub fn forget(&mut self, tx_hash: &str) {
+        self.txs.remove(tx_hash);
+    }
+    
+    pub fn status(&self, tx_hash: &str) -> Option<ConfirmationStatus> {
+        self.txs.get(tx_hash).map(|tx| tx.status)
+    }
+    
+    /// Confirmations of a tracked transaction, zero unless its block is canonical
+    pub fn confirmations(&self, tx_hash: &str) -> u64 {
+        self.txs.get(tx_hash).map_or(0, |tx| self.count(tx).unwrap_or(0))
+    }
+    
+    /// Advance the canonical chain to `head` and return every transaction whose status changed
+    ///
+    /// A head on top of the block we have at `number - 1` replaces the chain from `number`
+    /// up. A head that doesn't link to it, whether forked deeper or after a gap, leaves no
+    /// block below it proven to be its ancestor, so the chain restarts at that head. Blocks
+    /// below the tip can be delivered again to re-prove them: one that is the parent of the
+    /// block above it is filled in without replacing anything. Transactions mined in a block
+    /// that isn't on the proven chain move to `Reorged` with their count reset.
+    pub fn on_new_head(&mut self, head: BlockRef) -> Vec<(String, ConfirmationStatus)> {
+        let links_child = head
+            .number
+            .checked_add(1)
+            .and_then(|n| self.canonical.get(&n))
+            .is_some_and(|child| child.parent_hash == head.hash);
+        let links_parent = head
+            .number
+            .checked_sub(1)
+            .and_then(|n| self.canonical.get(&n))
+            .is_some_and(|parent| parent.hash == head.parent_hash);
+        
+        if links_child {
+            // A missing ancestor of the tip; what's below it only stays if it links up too
+            if !links_parent {
+                self.canonical = self.canonical.split_off(&head.number);
+            }
+        } else if links_parent {
+            self.canonical.split_off(&head.number);
+        } else {
+            self.canonical.clear();
+        }
+        self.canonical.insert(head.number, head);
+        
+        // Forget heads too deep to matter for ancestry
+        let keep_from = head.number.saturating_sub(HEAD_HISTORY);
+        self.canonical = self.canonical.split_off(&keep_from);
+        
+        let mut changed = Vec::new();
+        for (tx_hash, tx) in self.txs.iter_mut() {
+            // Blocks older than the kept history keep their last status
+            if tx.block_number < keep_from {
+                continue;
+            }
+            
+            let status = evaluate(&self.canonical, self.required_confirmations, tx);
+            if status != tx.status {
+                tx.status = status;
+                changed.push((tx_hash.clone(), status));
+            }
+        }
+        
+        changed.sort_by(|a, b| a.0.cmp(&b.0));
+        changed
+    }
+    
+    fn evaluate(&self, tx: &TrackedTx) -> ConfirmationStatus {
+        evaluate(&self.canonical, self.required_confirmations, tx)
+    }
+    
+    fn count(&self, tx: &TrackedTx) -> Option<u64> {
+        confirmation_count(&self.canonical, tx)
+    }
+}
+
+fn confirmation_count(canonical: &BTreeMap<u64, BlockRef>, tx: &TrackedTx) -> Option<u64> {
+    let tip = *canonical.keys().next_back()?;
+    match canonical.get(&tx.block_number) {
+        Some(block) if block.hash == tx.block_hash => Some(tip - tx.block_number + 1),
+        _ => None,
+    }
+}
+
+fn evaluate(canonical: &BTreeMap<u64, BlockRef>, required: u64, tx: &TrackedTx) -> ConfirmationStatus {
+    let tip = canonical.keys().next_back().copied();
+    
+    match confirmation_count(canonical, tx) {
+        Some(count) if count >= required => ConfirmationStatus::Confirmed,
+        Some(count) => ConfirmationStatus::Confirming(count),
+        // Not reached yet: still waiting for the mining block to arrive
+        None if tip.is_none_or(|tip| tx.block_number > tip) => match tx.status {
+            ConfirmationStatus::Pending => ConfirmationStatus::Pending,
+            _ => ConfirmationStatus::Reorged,
+        },
+        None => ConfirmationStatus::Reorged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Linear chain of heads on branch `fork`, numbered from `start` on top of `parent`
+    fn chain(start: u64, len: u64, parent: [u8; 32], fork: u8) -> Vec<BlockRef> {
+        let mut parent_hash = parent;
+        (start..start + len)
+            .map(|number| {
+                let mut hash = [fork; 32];
+                hash[..8].copy_from_slice(&number.to_be_bytes());
+                let block = BlockRef { number, hash, parent_hash };
+                parent_hash = hash;
+                block
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn test_confirmations_count_from_mining_block() {
+        let mut tracker = ConfirmationTracker::new(3);
+        let heads = chain(100, 4, [0; 32], 0xaa);
+        
+        tracker.on_new_head(heads[0]);
+        assert_eq!(tracker.track("0xtx", 100, heads[0].hash), ConfirmationStatus::Confirming(1));
+        
+        assert_eq!(tracker.on_new_head(heads[1]), vec![("0xtx".to_string(), ConfirmationStatus::Confirming(2))]);
+        assert_eq!(tracker.on_new_head(heads[2]), vec![("0xtx".to_string(), ConfirmationStatus::Confirmed)]);
+        assert!(tracker.on_new_head(heads[3]).is_empty());
+        assert_eq!(tracker.confirmations("0xtx"), 4);
+    }
+    
+    #[test]
+    fn test_tx_ahead_of_tip_is_pending() {
+        let mut tracker = ConfirmationTracker::new(2);
+        let heads = chain(100, 2, [0; 32], 0xaa);
+        
+        tracker.on_new_head(heads[0]);
+        assert_eq!(tracker.track("0xtx", 101, heads[1].hash), ConfirmationStatus::Pending);
+        assert_eq!(tracker.on_new_head(heads[1]), vec![("0xtx".to_string(), ConfirmationStatus::Confirming(1))]);
+    }
+    
+    #[test]
+    fn test_reorg_replacing_mining_block_resets_count() {
+        let mut tracker = ConfirmationTracker::new(6);
+        let original = chain(100, 3, [0; 32], 0xaa);
+        for head in &original {
+            tracker.on_new_head(*head);
+        }
+        
+        tracker.track("0xtx", 101, original[1].hash);
+        assert_eq!(tracker.confirmations("0xtx"), 2);
+        
+        // Same numbers, different hashes: 101' and 102' replace the mining block and its child
+        let fork = chain(101, 3, original[0].hash, 0xbb);
+        assert_eq!(tracker.on_new_head(fork[0]), vec![("0xtx".to_string(), ConfirmationStatus::Reorged)]);
+        assert_eq!(tracker.confirmations("0xtx"), 0);
+        
+        // Further heads on the new branch don't revive it
+        assert!(tracker.on_new_head(fork[1]).is_empty());
+        assert_eq!(tracker.status("0xtx"), Some(ConfirmationStatus::Reorged));
+        
+        // Re-mined on the new branch, counting starts over from that block
+        assert_eq!(tracker.track("0xtx", 102, fork[1].hash), ConfirmationStatus::Confirming(1));
+        assert_eq!(tracker.on_new_head(fork[2]), vec![("0xtx".to_string(), ConfirmationStatus::Confirming(2))]);
+    }
+    
+    #[test]
+    fn test_policy_defaults_per_chain_unless_user_sets_one() {
+        let policy = ConfirmationPolicy::default();
+        let unset = UserPreferences { min_confirmations: 0, ..UserPreferences::default() };
+        
+        assert_eq!(policy.required_confirmations("bitcoin-mainnet", &unset), 6);
+        assert_eq!(policy.required_confirmations("arbitrum-42161", &unset), 1);
+        assert_ne!(policy.recommended_confirmations("bitcoin-mainnet"), policy.recommended_confirmations("arbitrum-42161"));
+        assert_eq!(policy.recommended_confirmations("unknown-chain"), DEFAULT_CONFIRMATIONS);
+        
+        // An explicit preference wins either way
+        let explicit = UserPreferences { min_confirmations: 3, ..UserPreferences::default() };
+        assert_eq!(policy.required_confirmations("bitcoin-mainnet", &explicit), 3);
+        assert_eq!(policy.required_confirmations("arbitrum-42161", &explicit), 3);
+        
+        let mut tracker = ConfirmationTracker::for_chain(&policy.with_chain("bitcoin-mainnet", 2), "bitcoin-mainnet", &unset);
+        let heads = chain(100, 2, [0; 32], 0xaa);
+        tracker.on_new_head(heads[0]);
+        tracker.track("0xtx", 100, heads[0].hash);
+        assert_eq!(tracker.on_new_head(heads[1]), vec![("0xtx".to_string(), ConfirmationStatus::Confirmed)]);
+    }
+    
+    #[test]
+    fn test_head_with_unknown_parent_orphans_previous_block() {
+        let mut tracker = ConfirmationTracker::new(2);
+        let original = chain(100, 2, [0; 32], 0xaa);
+        for head in &original {
+            tracker.on_new_head(*head);
+        }
+        tracker.track("0xtx", 101, original[1].hash);
+        
+        // 102' builds on a 101 we never saw, so our 101 is no longer an ancestor of the tip
+        let fork = chain(102, 1, [0xcc; 32], 0xbb);
+        assert_eq!(tracker.on_new_head(fork[0]), vec![("0xtx".to_string(), ConfirmationStatus::Reorged)]);
+    }
+    
+    #[test]
+    fn test_two_deep_reorg_unproves_blocks_below_unlinked_head() {
+        let mut tracker = ConfirmationTracker::new(6);
+        let original = chain(100, 3, [0; 32], 0xaa);
+        for head in &original {
+            tracker.on_new_head(*head);
+        }
+        tracker.track("0xtx", 101, original[1].hash);
+        assert_eq!(tracker.confirmations("0xtx"), 2);
+        
+        // 103' sits on a 102' we never saw; our 101 may have been replaced along with 102
+        let fork = chain(102, 2, original[1].hash, 0xbb);
+        assert_eq!(tracker.on_new_head(fork[1]), vec![("0xtx".to_string(), ConfirmationStatus::Reorged)]);
+        assert_eq!(tracker.confirmations("0xtx"), 0);
+        
+        // Filling in 102' alone doesn't say what's under it
+        assert!(tracker.on_new_head(fork[0]).is_empty());
+        assert_eq!(tracker.confirmations("0xtx"), 0);
+        
+        // 101 is 102's parent after all, so the transaction survived the reorg
+        assert_eq!(tracker.on_new_head(original[1]), vec![("0xtx".to_string(), ConfirmationStatus::Confirming(3))]);
+        
+        // A different 101 under 102 would have kept it reorged
+        let mut other = ConfirmationTracker::new(6);
+        for head in &original {
+            other.on_new_head(*head);
+        }
+        other.track("0xtx", 101, original[1].hash);
+        let deeper = chain(101, 3, original[0].hash, 0xcc);
+        other.on_new_head(deeper[2]);
+        other.on_new_head(deeper[1]);
+        assert!(other.on_new_head(deeper[0]).is_empty());
+        assert_eq!(other.status("0xtx"), Some(ConfirmationStatus::Reorged));
+    }
+}
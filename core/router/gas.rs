@@ -0,0 +1,243 @@
+// Gas Strategy - How urgently EVM transactions are priced
+use async_trait::async_trait;
+use ethers::providers::Middleware;
+use ethers::types::{BlockNumber, FeeHistory, U256};
+
+use super::chain_adapter::{eip1559_fees, FEE_HISTORY_BLOCKS};
+use super::RouterError;
+
+/// Priority fee percentile `Economy` prices at
+pub const ECONOMY_PERCENTILE: f64 = 10.0;
+
+/// Priority fee percentile `Standard` prices at
+pub const STANDARD_PERCENTILE: f64 = 50.0;
+
+/// Priority fee percentile `Fast` prices at
+pub const FAST_PERCENTILE: f64 = 90.0;
+
+/// Fees for one transaction, in wei per gas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasSettings {
+    pub gas_price: U256, // for legacy transactions
+    pub max_fee_per_gas: Option<U256>, // EIP-1559 fields, unset on chains without a base fee
+    pub max_priority_fee_per_gas: Option<U256>,
+}
+
+impl GasSettings {
+    pub fn legacy(gas_price: U256) -> Self {
+        Self {
+            gas_price,
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+        }
+    }
+    
+    /// `(max_fee_per_gas, max_priority_fee_per_gas)`, if the chain takes EIP-1559 transactions
+    pub fn eip1559(&self) -> Option<(U256, U256)> {
+        Some((self.max_fee_per_gas?, self.max_priority_fee_per_gas?))
+    }
+}
+
+/// The fee queries a strategy may make; every ethers `Middleware` is one
+#[async_trait]
+pub trait FeeSource: Send + Sync {
+    async fn fee_history(&self, blocks: u64, percentile: f64) -> Result<FeeHistory, RouterError>;
+    async fn gas_price(&self) -> Result<U256, RouterError>;
+}
+
+#[async_trait]
+impl<M: Middleware> FeeSource for M {
+    async fn fee_history(&self, blocks: u64, percentile: f64) -> Result<FeeHistory, RouterError> {
+        Middleware::fee_history(self, blocks, BlockNumber::Latest, &[percentile])
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to query fee history: {}", e)))
+    }
+    
+    async fn gas_price(&self) -> Result<U256, RouterError> {
+        self.get_gas_price()
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to query gas price: {}", e)))
+    }
+}
+
+/// Picks the fees a transaction is submitted with
+#[async_trait]
+pub trait GasStrategy: Send + Sync {
+    async fn gas_price(&self, provider: &dyn FeeSource) -> Result<GasSettings, RouterError>;
+}
+
+/// Tip at the 10th percentile of recent blocks, for transactions that can wait
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Economy;
+
+/// Median tip of recent blocks
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Standard;
+
+/// Tip at the 90th percentile of recent blocks, to land in the next few
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fast;
+
+/// The same price every time, in wei per gas, paid in full as the tip under EIP-1559
+#[derive(Debug, Clone, Copy)]
+pub struct Fixed(pub u64);
+
+/// Another strategy's fees, never above `max_gas_price` wei per gas
+///
+/// A transaction priced at the cap may wait for the network's fees to come down rather
+/// than land at once.
+#[derive(Debug, Clone, Copy)]
+pub struct Capped<S> {
+    pub strategy: S,
+    pub max_gas_price: u64,
+}
+
+#[async_trait]
+impl GasStrategy for Economy {
+    async fn gas_price(&self, provider: &dyn FeeSource) -> Result<GasSettings, RouterError> {
+        percentile_settings(provider, ECONOMY_PERCENTILE).await
+    }
+}
+
+#[async_trait]
+impl GasStrategy for Standard {
+    async fn gas_price(&self, provider: &dyn FeeSource) -> Result<GasSettings, RouterError> {
+        percentile_settings(provider, STANDARD_PERCENTILE).await
+    }
+}
+
+#[async_trait]
+impl GasStrategy for Fast {
+    async fn gas_price(&self, provider: &dyn FeeSource) -> Result<GasSettings, RouterError> {
+        percentile_settings(provider, FAST_PERCENTILE).await
+    }
+}
+
+#[async_trait]
+impl GasStrategy for Fixed {
+    async fn gas_price(&self, _provider: &dyn FeeSource) -> Result<GasSettings, RouterError> {
+        let price = U256::from(self.0);
+        Ok(GasSettings {
+            gas_price: price,
+            max_fee_per_gas: Some(price),
+            max_priority_fee_per_gas: Some(price),
+        })
+    }
+}
+
+#[async_trait]
+impl<S: GasStrategy> GasStrategy for Capped<S> {
+    async fn gas_price(&self, provider: &dyn FeeSource) -> Result<GasSettings, RouterError> {
+        let settings = self.strategy.gas_price(provider).await?;
+        let cap = U256::from(self.max_gas_price);
+        let max_fee_per_gas = settings.max_fee_per_gas.map(|max_fee| max_fee.min(cap));
+        Ok(GasSettings {
+            gas_price: settings.gas_price.min(cap),
+            max_fee_per_gas,
+            max_priority_fee_per_gas: settings
+                .max_priority_fee_per_gas
+                .map(|tip| max_fee_per_gas.map_or(tip, |max_fee| tip.min(max_fee))),
+        })
+    }
+}
+
+/// Fees from `eth_feeHistory` at `percentile`, or the node's gas price on chains without a base fee
+///
+/// A legacy transaction is priced at the next base fee plus the tip, what an EIP-1559 one
+/// would effectively pay.
+async fn percentile_settings(provider: &dyn FeeSource, percentile: f64) -> Result<GasSettings, RouterError> {
+    match provider.fee_history(FEE_HISTORY_BLOCKS, percentile).await {
+        Ok(history) => {
+            if let (Some((max_fee, tip)), Some(&base_fee)) = (eip1559_fees(&history), history.base_fee_per_gas.last()) {
+                return Ok(GasSettings {
+                    gas_price: base_fee + tip,
+                    max_fee_per_gas: Some(max_fee),
+                    max_priority_fee_per_gas: Some(tip),
+                });
+            }
+            tracing::debug!("Chain reports no base fee, pricing at the node's gas price");
+        }
+        Err(e) => tracing::debug!("eth_feeHistory failed, pricing at the node's gas price: {}", e),
+    }
+    
+    Ok(GasSettings::legacy(provider.gas_price().await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn gwei(n: u64) -> U256 {
+        U256::from(n) * 1_000_000_000u64
+    }
+    
+    /// A fee history with tips recorded at the economy, standard and fast percentiles,
+    /// answering each query with the column asked for
+    struct RecordedFees {
+        base_fee: U256,
+        tips: Vec<(f64, Vec<U256>)>, // percentile and its tip in each block
+    }
+    
+    #[async_trait]
+    impl FeeSource for RecordedFees {
+        async fn fee_history(&self, blocks: u64, percentile: f64) -> Result<FeeHistory, RouterError> {
+            let (_, tips) = self.tips.iter().find(|(p, _)| *p == percentile).unwrap();
+            assert_eq!(tips.len() as u64, blocks);
+            Ok(FeeHistory {
+                base_fee_per_gas: vec![self.base_fee; tips.len() + 1],
+                gas_used_ratio: vec![0.5; tips.len()],
+                oldest_block: U256::from(1_000),
+                reward: tips.iter().map(|&tip| vec![tip]).collect(),
+            })
+        }
+        
+        async fn gas_price(&self) -> Result<U256, RouterError> {
+            panic!("priced from the fee history, not eth_gasPrice")
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_fast_prices_above_economy() {
+        let blocks = FEE_HISTORY_BLOCKS as usize;
+        let fees = RecordedFees {
+            base_fee: gwei(20),
+            tips: vec![
+                (ECONOMY_PERCENTILE, vec![gwei(1); blocks]),
+                (STANDARD_PERCENTILE, vec![gwei(2); blocks]),
+                (FAST_PERCENTILE, vec![gwei(5); blocks]),
+            ],
+        };
+        
+        let economy = Economy.gas_price(&fees).await.unwrap();
+        let standard = Standard.gas_price(&fees).await.unwrap();
+        let fast = Fast.gas_price(&fees).await.unwrap();
+        
+        assert_eq!(economy.eip1559(), Some((gwei(41), gwei(1))));
+        assert_eq!(standard.eip1559(), Some((gwei(42), gwei(2))));
+        assert_eq!(fast.eip1559(), Some((gwei(45), gwei(5))));
+        assert_eq!(economy.gas_price, gwei(21));
+        assert!(economy.gas_price < standard.gas_price && standard.gas_price < fast.gas_price);
+        
+        let fixed = Fixed(7).gas_price(&fees).await.unwrap();
+        assert_eq!(fixed.gas_price, U256::from(7));
+        assert_eq!(fixed.eip1559(), Some((U256::from(7), U256::from(7))));
+    }
+    
+    #[tokio::test]
+    async fn test_capped_never_prices_above_the_cap() {
+        let blocks = FEE_HISTORY_BLOCKS as usize;
+        let fees = RecordedFees {
+            base_fee: gwei(20),
+            tips: vec![(FAST_PERCENTILE, vec![gwei(5); blocks])],
+        };
+        
+        let capped = Capped { strategy: Fast, max_gas_price: 30_000_000_000 }.gas_price(&fees).await.unwrap();
+        assert_eq!(capped.eip1559(), Some((gwei(30), gwei(5))));
+        assert_eq!(capped.gas_price, gwei(25));
+        
+        // A cap below the tip leaves the whole fee as tip
+        let capped = Capped { strategy: Fast, max_gas_price: 3_000_000_000 }.gas_price(&fees).await.unwrap();
+        assert_eq!(capped.eip1559(), Some((gwei(3), gwei(3))));
+        assert_eq!(capped.gas_price, gwei(3));
+    }
+}
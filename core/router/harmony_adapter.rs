@@ -4,11 +4,16 @@ use async_trait::async_trait;
 use ethers::prelude::*;
 use ethers::providers::{Provider, Ws, Http};
 use ethers::types::{Address, U256, TransactionRequest, Bytes, BlockNumber};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::utils::rlp::RlpStream;
+use std::collections::HashMap;
+use futures::Stream;
 use std::sync::Arc;
 use std::str::FromStr;
 use bech32::{ToBase32, FromBase32, Variant};
 
 use super::{Intent, RouterError};
+use super::block_subscription::{reconnecting_blocks, sealed_blocks};
 use super::intent_translator::{IntentTranslator, TranslatedIntent};
 
 /// HarmonyAddressConverter handles conversion between Harmony ONE addresses (bech32)
@@ -104,34 +109,7 @@ impl HarmonyAddressConverter {
     /// # Returns
     /// * `Result<bool, RouterError>` - true if valid, error otherwise
     pub fn validate_one_address(one_address: &str) -> Result<bool, RouterError> {
-        // Attempt to decode - this validates the checksum automatically
-        let (hrp, data, variant) = bech32::decode(one_address)
-            .map_err(|e| RouterError::TranslationError(format!("Invalid bech32 address: {}", e)))?;
-        
-        // Verify HRP
-        if hrp != "one" {
-            return Err(RouterError::TranslationError(format!(
-                "Invalid HRP: expected 'one', got '{}'", hrp
-            )));
-        }
-        
-        // Verify variant
-        if variant != Variant::Bech32 {
-            return Err(RouterError::TranslationError(
-                "Invalid bech32 variant".to_string()
-            ));
-        }
-        
-        // Verify data length
-        let bytes = Vec::<u8>::from_base32(&data)
-            .map_err(|e| RouterError::TranslationError(format!("Invalid base32 data: {}", e)))?;
-        
-        if bytes.len() != 20 {
-            return Err(RouterError::TranslationError(format!(
-                "Invalid address length: expected 20 bytes, got {}", bytes.len()
-            )));
-        }
-        
+        super::address::validate_harmony(one_address)?;
         Ok(true)
     }
     
@@ -160,12 +138,62 @@ impl HarmonyAddressConverter {
     }
 }
 
+/// Harmony legacy transaction, which carries source and destination shard fields
+#[derive(Debug, Clone, PartialEq)]
+pub struct HarmonyTransaction {
+    pub nonce: U256,
+    pub gas_price: U256,
+    pub gas_limit: U256,
+    pub shard_id: u32,
+    pub to_shard_id: u32,
+    pub to: Address,
+    pub value: U256,
+    pub data: Bytes,
+}
+
+impl HarmonyTransaction {
+    /// EIP-155 signing payload:
+    /// rlp([nonce, gasPrice, gasLimit, shardID, toShardID, to, value, data, chainId, 0, 0])
+    pub fn unsigned_rlp(&self, chain_id: u64) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(11);
+        self.append_fields(&mut stream);
+        stream.append(&chain_id);
+        stream.append(&0u8);
+        stream.append(&0u8);
+        stream.out().to_vec()
+    }
+    
+    /// Raw transaction for `hmy_sendRawTransaction`, with an EIP-155 `v`
+    pub fn signed_rlp(&self, signature: &Signature) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(11);
+        self.append_fields(&mut stream);
+        stream.append(&signature.v);
+        stream.append(&signature.r);
+        stream.append(&signature.s);
+        stream.out().to_vec()
+    }
+    
+    fn append_fields(&self, stream: &mut RlpStream) {
+        stream.append(&self.nonce);
+        stream.append(&self.gas_price);
+        stream.append(&self.gas_limit);
+        stream.append(&self.shard_id);
+        stream.append(&self.to_shard_id);
+        stream.append(&self.to);
+        stream.append(&self.value);
+        stream.append(&self.data.as_ref());
+    }
+}
+
 pub struct RealHarmonyAdapter {
     chain_name: String,
     chain_id: u64,
     shard_id: u32,
+    ws_url: String,
     provider: Arc<Provider<Ws>>,
     http_provider: Arc<Provider<Http>>,
+    shard_providers: HashMap<u32, Arc<Provider<Http>>>, // other shards, for cross-shard verification
+    signer: Option<LocalWallet>,
     translator: Arc<IntentTranslator>,
 }
 
@@ -188,12 +216,163 @@ impl RealHarmonyAdapter {
             chain_name: "harmony".to_string(),
             chain_id,
             shard_id,
+            ws_url,
             provider: Arc::new(provider),
             http_provider: Arc::new(http_provider),
+            shard_providers: HashMap::new(),
+            signer: None,
             translator,
         })
     }
     
+    /// New blocks pushed over the WebSocket connection; ends when the connection drops
+    pub async fn subscribe_blocks(&self) -> Result<impl Stream<Item = Block<TxHash>> + '_, RouterError> {
+        let subscription = self.provider.subscribe_blocks().await
+            .map_err(|e| RouterError::TranslationError(format!("Block subscription failed: {}", e)))?;
+        
+        Ok(sealed_blocks(subscription))
+    }
+    
+    /// Like `subscribe_blocks`, but reconnects to the WebSocket endpoint after a disconnect
+    pub fn subscribe_blocks_reconnecting(&self, max_retries: u32) -> impl Stream<Item = Block<TxHash>> {
+        reconnecting_blocks(self.ws_url.clone(), max_retries)
+    }
+    
+    /// Register the RPC endpoint of another shard (e.g. https://api.s1.t.hmny.io)
+    pub fn with_shard_endpoint(mut self, shard_id: u32, http_url: &str) -> Result<Self, RouterError> {
+        let provider = Provider::<Http>::try_from(http_url)
+            .map_err(|e| RouterError::TranslationError(format!("HTTP connection failed: {}", e)))?;
+        self.shard_providers.insert(shard_id, Arc::new(provider));
+        Ok(self)
+    }
+    
+    /// Key used to sign transactions built by `submit_cross_shard`
+    pub fn with_signer(mut self, signer: LocalWallet) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+    
+    /// Build an unsigned transaction from `to (20 bytes) || calldata` with estimated gas,
+    /// current gas price and this shard's chain id, for callers that sign externally
+    pub async fn build_transaction(
+        &self,
+        tx_data: &[u8],
+        from: Option<Address>,
+    ) -> Result<TransactionRequest, RouterError> {
+        if tx_data.len() < 20 {
+            return Err(RouterError::TranslationError("Invalid transaction data".to_string()));
+        }
+        
+        let to = Address::from_slice(&tx_data[..20]);
+        let data = Bytes::from(tx_data[20..].to_vec());
+        
+        let mut request = TransactionRequest::new().to(to).data(data);
+        if let Some(from) = from {
+            let nonce = self.http_provider
+                .get_transaction_count(from, Some(BlockNumber::Pending.into()))
+                .await
+                .map_err(|e| RouterError::TranslationError(format!("Failed to get nonce: {}", e)))?;
+            request = request.from(from).nonce(nonce);
+        }
+        
+        let gas_estimate = self.http_provider.estimate_gas(&request.clone().into(), None).await
+            .map_err(|e| RouterError::TranslationError(format!("Gas estimation failed: {}", e)))?;
+        
+        let gas_price = self.http_provider.get_gas_price().await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to get gas price: {}", e)))?;
+        
+        Ok(Self::assemble_transaction(request, gas_estimate, gas_price, self.chain_id))
+    }
+    
+    fn assemble_transaction(
+        request: TransactionRequest,
+        gas: U256,
+        gas_price: U256,
+        chain_id: u64,
+    ) -> TransactionRequest {
+        request.gas(gas).gas_price(gas_price).chain_id(chain_id)
+    }
+    
+    fn shard_provider(&self, shard_id: u32) -> Result<Arc<Provider<Http>>, RouterError> {
+        if shard_id == self.shard_id {
+            return Ok(self.http_provider.clone());
+        }
+        
+        self.shard_providers
+            .get(&shard_id)
+            .cloned()
+            .ok_or_else(|| RouterError::VerificationError(format!("No endpoint configured for shard {}", shard_id)))
+    }
+    
+    /// Submit a transfer from `from_shard` to `to_shard`
+    ///
+    /// `tx_data` is `to (20 bytes) || calldata`, as for `submit_transaction`. Transactions are
+    /// always sent on the source shard; the destination shard receives the cross-link receipt.
+    pub async fn submit_cross_shard(
+        &self,
+        from_shard: u32,
+        to_shard: u32,
+        tx_data: &[u8],
+    ) -> Result<String, RouterError> {
+        if from_shard != self.shard_id {
+            return Err(RouterError::RoutingError(format!(
+                "Adapter is connected to shard {}, cannot submit from shard {}",
+                self.shard_id, from_shard
+            )));
+        }
+        
+        if tx_data.len() < 20 {
+            return Err(RouterError::TranslationError("Invalid transaction data".to_string()));
+        }
+        
+        let signer = self.signer.as_ref()
+            .ok_or_else(|| RouterError::TranslationError("No signer configured".to_string()))?;
+        
+        let to = Address::from_slice(&tx_data[..20]);
+        let data = Bytes::from(tx_data[20..].to_vec());
+        
+        let nonce = self.http_provider
+            .get_transaction_count(signer.address(), Some(BlockNumber::Pending.into()))
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to get nonce: {}", e)))?;
+        
+        let gas_price = self.http_provider.get_gas_price().await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to get gas price: {}", e)))?;
+        
+        let estimate_request = TransactionRequest::new()
+            .from(signer.address())
+            .to(to)
+            .data(data.clone());
+        let gas_limit = self.http_provider.estimate_gas(&estimate_request.into(), None).await
+            .map_err(|e| RouterError::TranslationError(format!("Gas estimation failed: {}", e)))?;
+        
+        let tx = HarmonyTransaction {
+            nonce,
+            gas_price,
+            gas_limit,
+            shard_id: from_shard,
+            to_shard_id: to_shard,
+            to,
+            value: U256::zero(),
+            data,
+        };
+        
+        let sighash = ethers::utils::keccak256(tx.unsigned_rlp(self.chain_id));
+        let mut signature = signer.sign_hash(H256::from(sighash))
+            .map_err(|e| RouterError::TranslationError(format!("Signing failed: {}", e)))?;
+        
+        // sign_hash returns v in {27, 28}; EIP-155 expects recovery_id + chain_id * 2 + 35
+        signature.v = signature.v - 27 + self.chain_id * 2 + 35;
+        
+        let raw = format!("0x{}", hex::encode(tx.signed_rlp(&signature)));
+        let tx_hash: H256 = self.http_provider
+            .request("hmy_sendRawTransaction", [raw])
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to submit transaction: {}", e)))?;
+        
+        Ok(format!("0x{:x}", tx_hash))
+    }
+    
     /// Convert Harmony ONE address to Ethereum hex address
     /// Supports both ONE (bech32) and ETH (hex) address formats
     fn convert_one_to_eth_address(&self, address: &str) -> Result<String, RouterError> {
@@ -211,11 +390,7 @@ impl RealHarmonyAdapter {
             ))
         }
     }
-    
-    /// Convert Ethereum hex address to Harmony ONE address
-    fn convert_eth_to_one_address(&self, eth_address: &str) -> Result<String, RouterError> {
-        HarmonyAddressConverter::eth_to_one(eth_address)
-    }
+
 }
 
 #[async_trait]
@@ -238,13 +413,26 @@ impl super::chain_adapter::ChainAdapter for RealHarmonyAdapter {
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
+        // Proof format: state_root (32 bytes) || optional shard_id (u32, big-endian)
         if proof.len() < 32 {
             return Err(RouterError::VerificationError("Proof too short".to_string()));
         }
         
         let state_root = &proof[..32];
-        let block = self.provider.get_block(BlockNumber::Latest).await
+        let shard_id = match proof.len() {
+            32 => self.shard_id,
+            36 => u32::from_be_bytes([proof[32], proof[33], proof[34], proof[35]]),
+            _ => return Err(RouterError::VerificationError("Invalid proof length".to_string())),
+        };
+        
+        // Each shard has its own chain, so compare against that shard's block
+        let provider = self.shard_provider(shard_id)?;
+        let block = provider.get_block(BlockNumber::Latest).await
             .map_err(|e| RouterError::VerificationError(format!("Failed to get block: {}", e)))?
             .ok_or_else(|| RouterError::VerificationError("Block not found".to_string()))?;
         
@@ -252,30 +440,24 @@ impl super::chain_adapter::ChainAdapter for RealHarmonyAdapter {
     }
     
     async fn submit_transaction(&self, tx_data: &[u8]) -> Result<String, RouterError> {
-        if tx_data.len() < 20 {
-            return Err(RouterError::TranslationError("Invalid transaction data".to_string()));
-        }
-        
-        let to_bytes: [u8; 20] = tx_data[..20].try_into()
-            .map_err(|_| RouterError::TranslationError("Invalid address".to_string()))?;
-        let to = Address::from(to_bytes);
-        let data = Bytes::from(tx_data[20..].to_vec());
+        let signer = self.signer.as_ref()
+            .ok_or_else(|| RouterError::TranslationError("No signer configured".to_string()))?;
         
-        let tx = TransactionRequest::new()
-            .to(to)
-            .data(data)
-            .chain_id(self.chain_id);
-        
-        let gas_estimate = self.provider.estimate_gas(&tx.clone().into(), None).await
-            .map_err(|e| RouterError::TranslationError(format!("Gas estimation failed: {}", e)))?;
+        let tx = self.build_transaction(tx_data, Some(signer.address())).await?;
+        let typed_tx: TypedTransaction = tx.into();
         
-        let gas_price = self.provider.get_gas_price().await
-            .map_err(|e| RouterError::TranslationError(format!("Failed to get gas price: {}", e)))?;
+        let signature = signer.clone()
+            .with_chain_id(self.chain_id)
+            .sign_transaction(&typed_tx)
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Signing failed: {}", e)))?;
         
-        let tx = tx.gas(gas_estimate).gas_price(gas_price);
-        let tx_hash = format!("0x{}", hex::encode(&tx_data[..32.min(tx_data.len())]));
+        let pending_tx = self.http_provider
+            .send_raw_transaction(typed_tx.rlp_signed(&signature))
+            .await
+            .map_err(|e| RouterError::RoutingError(format!("Transaction rejected by node: {}", e)))?;
         
-        Ok(tx_hash)
+        Ok(format!("0x{:x}", pending_tx.tx_hash()))
     }
     
     async fn query_balance(&self, address: &str, asset: &str) -> Result<u64, RouterError> {
@@ -310,11 +492,12 @@ impl super::chain_adapter::ChainAdapter for RealHarmonyAdapter {
                         internal_type: None,
                     },
                 ],
+                #[allow(deprecated)]
                 constant: Some(true),
                 state_mutability: ethabi::StateMutability::View,
             };
             
-            let call_data = function.encode_input(&[ethabi::Token::Address(addr.into())])
+            let call_data = function.encode_input(&[ethabi::Token::Address(addr)])
                 .map_err(|e| RouterError::TranslationError(format!("ABI encoding failed: {}", e)))?;
             
             let tx = TransactionRequest::new()
@@ -344,12 +527,13 @@ mod tests {
     fn test_harmony_address_converter_one_to_eth() {
         // Test valid ONE address conversion
         // Note: This is a test address - in production use real addresses
-        let one_addr = "one1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq9yrzh5";
+        let one_addr = "one1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqquzw7vz";
         let result = HarmonyAddressConverter::one_to_eth(one_addr);
         assert!(result.is_ok());
         let eth_addr = result.unwrap();
         assert!(eth_addr.starts_with("0x"));
         assert_eq!(eth_addr.len(), 42); // 0x + 40 hex chars
+        assert_eq!(eth_addr, "0x0000000000000000000000000000000000000000");
     }
     
     #[test]
@@ -379,7 +563,7 @@ mod tests {
     #[test]
     fn test_harmony_address_converter_invalid_hrp() {
         // Test with invalid HRP (not "one")
-        let invalid_addr = "eth1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq9yrzh5";
+        let invalid_addr = "eth1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqfdrqed";
         let result = HarmonyAddressConverter::one_to_eth(invalid_addr);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid HRP"));
@@ -395,10 +579,10 @@ mod tests {
     
     #[test]
     fn test_harmony_address_converter_validate_one() {
-        let valid_one = "one1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq9yrzh5";
+        let valid_one = "one1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqquzw7vz";
         let result = HarmonyAddressConverter::validate_one_address(valid_one);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), true);
+        assert!(result.unwrap());
     }
     
     #[test]
@@ -406,7 +590,7 @@ mod tests {
         let valid_eth = "0x0000000000000000000000000000000000000000";
         let result = HarmonyAddressConverter::validate_eth_address(valid_eth);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), true);
+        assert!(result.unwrap());
         
         let invalid_eth = "0x123"; // Too short
         let result = HarmonyAddressConverter::validate_eth_address(invalid_eth);
@@ -416,11 +600,9 @@ mod tests {
     #[test]
     fn test_adapter_convert_one_to_eth() {
         // Test the adapter's conversion method
-        let translator = Arc::new(IntentTranslator::new());
-        
         // Create a mock adapter (we can't use async new in sync test)
         // Just test the converter directly since it's static
-        let one_addr = "one1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq9yrzh5";
+        let one_addr = "one1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqquzw7vz";
         let result = HarmonyAddressConverter::one_to_eth(one_addr);
         assert!(result.is_ok());
     }
@@ -438,4 +620,75 @@ mod tests {
         ).await;
         assert!(adapter.is_ok());
     }
+    
+    #[test]
+    fn test_cross_shard_transaction_encoding() {
+        use ethers::utils::rlp::Rlp;
+        
+        let tx = HarmonyTransaction {
+            nonce: U256::from(7),
+            gas_price: U256::from(100_000_000_000u64),
+            gas_limit: U256::from(21_000),
+            shard_id: 0,
+            to_shard_id: 1,
+            to: Address::from([0x11; 20]),
+            value: U256::from(1_000),
+            data: Bytes::from(vec![0xab, 0xcd]),
+        };
+        
+        let encoded = tx.unsigned_rlp(1666700000);
+        let rlp = Rlp::new(&encoded);
+        
+        assert_eq!(rlp.item_count().unwrap(), 11);
+        assert_eq!(rlp.val_at::<u32>(3).unwrap(), 0); // shardID
+        assert_eq!(rlp.val_at::<u32>(4).unwrap(), 1); // toShardID
+        assert_eq!(rlp.val_at::<Address>(5).unwrap(), tx.to);
+        assert_eq!(rlp.val_at::<u64>(8).unwrap(), 1666700000);
+        
+        // Same-shard transfers differ only in toShardID
+        let same_shard = HarmonyTransaction { to_shard_id: 0, ..tx.clone() };
+        assert_ne!(same_shard.unsigned_rlp(1666700000), encoded);
+    }
+    
+    #[test]
+    fn test_assembled_transaction_carries_gas_and_chain_id() {
+        let request = TransactionRequest::new()
+            .to(Address::from([0x22; 20]))
+            .data(Bytes::from(vec![0x01]));
+        
+        let tx = RealHarmonyAdapter::assemble_transaction(
+            request,
+            U256::from(54_321),
+            U256::from(30_000_000_000u64),
+            1666600000,
+        );
+        
+        assert_eq!(tx.gas, Some(U256::from(54_321)));
+        assert_eq!(tx.gas_price, Some(U256::from(30_000_000_000u64)));
+        assert_eq!(tx.chain_id, Some(U64::from(1666600000u64)));
+        assert_eq!(tx.to, Some(NameOrAddress::Address(Address::from([0x22; 20]))));
+    }
+    
+    #[tokio::test]
+    #[ignore] // Requires Harmony testnet access and a funded key in HARMONY_TESTNET_KEY
+    async fn test_submit_cross_shard_live() {
+        let translator = Arc::new(IntentTranslator::new());
+        let key = std::env::var("HARMONY_TESTNET_KEY").unwrap();
+        let wallet = LocalWallet::from_str(&key).unwrap();
+        let recipient = wallet.address();
+        
+        let adapter = RealHarmonyAdapter::new(
+            "wss://ws.s0.t.hmny.io".to_string(),
+            "https://api.s0.t.hmny.io".to_string(),
+            1666700000, // Testnet shard 0
+            0,
+            translator,
+        ).await.unwrap().with_signer(wallet);
+        
+        let tx_hash = adapter
+            .submit_cross_shard(0, 1, recipient.as_bytes())
+            .await
+            .unwrap();
+        assert!(tx_hash.starts_with("0x"));
+    }
 }
@@ -0,0 +1,294 @@
+// Intent Tracker - Lifecycle status of routed intents, with a sweeper for ones that stall
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::RouterError;
+use crate::core::clock::{system_clock, Clock};
+
+/// Default age after which an unfinished intent is timed out
+pub const DEFAULT_TIMEOUT_SECS: u64 = 3600;
+
+/// Default time between sweeps
+pub const DEFAULT_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum IntentStatus {
+    Submitted, // Routed, not yet seen on the target chain
+    Pending,   // Seen on chain, waiting for confirmations
+    Confirmed,
+    Failed,
+    TimedOut, // Never finished; set by the sweeper
+}
+
+impl IntentStatus {
+    /// Whether the intent is done and no longer tracked
+    pub fn is_terminal(self) -> bool {
+        matches!(self, IntentStatus::Confirmed | IntentStatus::Failed | IntentStatus::TimedOut)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackedIntent {
+    pub intent_id: String,
+    pub status: IntentStatus,
+    pub tracked_at: u64,  // Unix seconds
+    pub updated_at: u64,
+}
+
+/// A status change, broadcast to subscribers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusTransition {
+    pub intent_id: String,
+    pub from: IntentStatus,
+    pub to: IntentStatus,
+    pub at: u64,
+}
+
+/// Where intents are kept once they reach a terminal status
+#[async_trait]
+pub trait IntentStatusStore: Send + Sync {
+    async fn save(&self, intent: &TrackedIntent) -> Result<(), RouterError>;
+    async fn load(&self, intent_id: &str) -> Result<Option<TrackedIntent>, RouterError>;
+}
+
+#[derive(Default)]
+pub struct InMemoryIntentStatusStore {
+    intents: tokio::sync::RwLock<HashMap<String, TrackedIntent>>,
+}
+
+impl InMemoryIntentStatusStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IntentStatusStore for InMemoryIntentStatusStore {
+    async fn save(&self, intent: &TrackedIntent) -> Result<(), RouterError> {
+        self.intents.write().await.insert(intent.intent_id.clone(), intent.clone());
+        Ok(())
+    }
+    
+    async fn load(&self, intent_id: &str) -> Result<Option<TrackedIntent>, RouterError> {
+        Ok(self.intents.read().await.get(intent_id).cloned())
+    }
+}
+
+/// Tracks intents from routing until they finish
+///
+/// Only unfinished intents are held in memory; an intent reaching a terminal status is
+/// written to the store and dropped. Every change is broadcast to `subscribe`rs.
+pub struct IntentTracker {
+    tracked: Mutex<HashMap<String, TrackedIntent>>,
+    store: Arc<dyn IntentStatusStore>,
+    clock: Arc<dyn Clock>,
+    transitions: broadcast::Sender<StatusTransition>,
+}
+
+impl IntentTracker {
+    pub fn new() -> Self {
+        Self {
+            tracked: Mutex::new(HashMap::new()),
+            store: Arc::new(InMemoryIntentStatusStore::new()),
+            clock: system_clock(),
+            transitions: broadcast::channel(256).0,
+        }
+    }
+    
+    pub fn with_store(mut self, store: Arc<dyn IntentStatusStore>) -> Self {
+        self.store = store;
+        self
+    }
+    
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Transitions from now on; a receiver that falls behind skips the oldest
+    pub fn subscribe(&self) -> broadcast::Receiver<StatusTransition> {
+        self.transitions.subscribe()
+    }
+    
+    /// Start tracking an intent as `Submitted`; re-tracking an unfinished intent keeps its state
+    pub fn track(&self, intent_id: &str) {
+        let now = self.clock.now_secs();
+        self.tracked.lock().unwrap().entry(intent_id.to_string()).or_insert_with(|| TrackedIntent {
+            intent_id: intent_id.to_string(),
+            status: IntentStatus::Submitted,
+            tracked_at: now,
+            updated_at: now,
+        });
+    }
+    
+    /// Move a tracked intent to `status`, persisting and dropping it if that is terminal
+    pub async fn set_status(&self, intent_id: &str, status: IntentStatus) -> Result<(), RouterError> {
+        let now = self.clock.now_secs();
+        let finished = {
+            let mut tracked = self.tracked.lock().unwrap();
+            let intent = tracked
+                .get_mut(intent_id)
+                .ok_or_else(|| RouterError::RoutingError(format!("Intent {} is not tracked", intent_id)))?;
+            if intent.status == status {
+                return Ok(());
+            }
+            
+            let from = std::mem::replace(&mut intent.status, status);
+            intent.updated_at = now;
+            self.emit(intent_id, from, status, now);
+            
+            if status.is_terminal() {
+                tracked.remove(intent_id)
+            } else {
+                None
+            }
+        };
+        
+        match finished {
+            Some(intent) => self.store.save(&intent).await,
+            None => Ok(()),
+        }
+    }
+    
+    /// Current status, from memory if still tracked or else from the store
+    pub async fn status(&self, intent_id: &str) -> Result<Option<IntentStatus>, RouterError> {
+        if let Some(intent) = self.tracked.lock().unwrap().get(intent_id) {
+            return Ok(Some(intent.status));
+        }
+        Ok(self.store.load(intent_id).await?.map(|intent| intent.status))
+    }
+    
+    /// Ids of intents still in memory
+    pub fn tracked_ids(&self) -> Vec<String> {
+        self.tracked.lock().unwrap().keys().cloned().collect()
+    }
+    
+    /// Time out every unfinished intent tracked for longer than `timeout_secs`
+    ///
+    /// Returns the ids timed out. An intent whose final status can't be stored stays tracked
+    /// for the next sweep; the first such error is returned once the rest have been tried.
+    pub async fn sweep(&self, timeout_secs: u64) -> Result<Vec<String>, RouterError> {
+        let now = self.clock.now_secs();
+        let expired: Vec<TrackedIntent> = {
+            let mut tracked = self.tracked.lock().unwrap();
+            let ids: Vec<String> = tracked
+                .values()
+                .filter(|intent| now.saturating_sub(intent.tracked_at) > timeout_secs)
+                .map(|intent| intent.intent_id.clone())
+                .collect();
+            
+            ids.iter().filter_map(|id| tracked.remove(id)).collect()
+        };
+        
+        let mut timed_out = Vec::with_capacity(expired.len());
+        let mut first_error = None;
+        for mut intent in expired {
+            let from = std::mem::replace(&mut intent.status, IntentStatus::TimedOut);
+            intent.updated_at = now;
+            
+            if let Err(e) = self.store.save(&intent).await {
+                intent.status = from;
+                self.tracked.lock().unwrap().insert(intent.intent_id.clone(), intent);
+                first_error.get_or_insert(e);
+                continue;
+            }
+            
+            tracing::info!("Intent {} timed out while {:?}", intent.intent_id, from);
+            self.emit(&intent.intent_id, from, IntentStatus::TimedOut, now);
+            timed_out.push(intent.intent_id);
+        }
+        
+        first_error.map_or(Ok(timed_out), Err)
+    }
+    
+    fn emit(&self, intent_id: &str, from: IntentStatus, to: IntentStatus, at: u64) {
+        // No subscribers is fine
+        let _ = self.transitions.send(StatusTransition {
+            intent_id: intent_id.to_string(),
+            from,
+            to,
+            at,
+        });
+    }
+}
+
+impl Default for IntentTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run `IntentTracker::sweep` every `interval`, timing out intents older than `timeout_secs`
+///
+/// The task only holds a weak reference, so it ends once the tracker is dropped; abort the
+/// handle to stop it sooner.
+pub fn spawn_sweeper(tracker: &Arc<IntentTracker>, timeout_secs: u64, interval: Duration) -> JoinHandle<()> {
+    let tracker: Weak<IntentTracker> = Arc::downgrade(tracker);
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            let Some(tracker) = tracker.upgrade() else {
+                break;
+            };
+            if let Err(e) = tracker.sweep(timeout_secs).await {
+                tracing::warn!("Intent sweep failed: {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clock::MockClock;
+    
+    #[tokio::test]
+    async fn test_sweeper_times_out_stalled_intent() {
+        let clock = Arc::new(MockClock::new(1_700_000_000));
+        let store = Arc::new(InMemoryIntentStatusStore::new());
+        let tracker = Arc::new(IntentTracker::new().with_clock(clock.clone()).with_store(store.clone()));
+        let mut transitions = tracker.subscribe();
+        
+        tracker.track("stalled");
+        tracker.set_status("stalled", IntentStatus::Pending).await.unwrap();
+        tracker.track("done");
+        tracker.set_status("done", IntentStatus::Confirmed).await.unwrap();
+        clock.advance(60);
+        tracker.track("fresh");
+        
+        let sweeper = spawn_sweeper(&tracker, 100, Duration::from_millis(10));
+        assert_eq!(transitions.recv().await.unwrap().to, IntentStatus::Pending);
+        assert_eq!(transitions.recv().await.unwrap().to, IntentStatus::Confirmed);
+        
+        // Sweeps before the threshold leave everything alone
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(tracker.status("stalled").await.unwrap(), Some(IntentStatus::Pending));
+        
+        clock.advance(41);
+        let transition = tokio::time::timeout(Duration::from_secs(5), transitions.recv())
+            .await
+            .expect("sweeper never ran")
+            .unwrap();
+        assert_eq!(
+            transition,
+            StatusTransition {
+                intent_id: "stalled".to_string(),
+                from: IntentStatus::Pending,
+                to: IntentStatus::TimedOut,
+                at: 1_700_000_101,
+            }
+        );
+        sweeper.abort();
+        
+        // Dropped from memory, final status persisted; the younger intent is still tracked
+        assert_eq!(tracker.tracked_ids(), vec!["fresh".to_string()]);
+        assert_eq!(store.load("stalled").await.unwrap().unwrap().status, IntentStatus::TimedOut);
+        assert_eq!(tracker.status("stalled").await.unwrap(), Some(IntentStatus::TimedOut));
+        assert_eq!(tracker.status("done").await.unwrap(), Some(IntentStatus::Confirmed));
+    }
+}
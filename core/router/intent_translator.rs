@@ -1,36 +1,103 @@
 // Intent Translator - Translates intents between different blockchain formats
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Sha3_512};
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use super::{Intent, RouterError};
+use super::sentium_envelope::SentiumEnvelope;
+use super::slippage::{SlippageCalculator, DEFAULT_SLIPPAGE_BPS};
+use super::{address, Intent, RouterError};
+use crate::core::clock::{system_clock, Clock};
+use crate::core::crypto::canonical::to_canonical_bytes;
+use crate::core::context::UserPreferences;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TranslatedIntent {
     pub original_intent: Intent,
     pub target_format: Vec<u8>,
     pub translation_metadata: TranslationMetadata,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TranslationMetadata {
     pub translator_version: String,
     pub timestamp: u64,
     pub gas_estimate: u64,
     pub translation_hash: Vec<u8>,
+    #[serde(default)]
+    pub price_impact_bps: Option<u16>, // swaps against a known pool only
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Version recorded in `TranslationMetadata`; bumped when the translation hash preimage changes
+pub const TRANSLATOR_VERSION: &str = "0.3.0";
+
+/// `transfer(address,uint256)`
+pub const EVM_TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+/// `swapExactTokensForTokens`, encoded here as amountIn, amountOutMin, deadline
+pub const EVM_SWAP_SELECTOR: [u8; 4] = [0x38, 0xed, 0x17, 0x39];
+
+impl TranslatedIntent {
+    /// Translation hash binding the encoded payload to its source intent
+    ///
+    /// Preimage: the canonical encoding of id, from_chain, to_chain, action, params and
+    /// target_format, the byte fields as lowercase hex.
+    pub fn compute_hash(intent: &Intent, target_format: &[u8]) -> Vec<u8> {
+        let preimage = to_canonical_bytes(&serde_json::json!({
+            "id": intent.id,
+            "from_chain": intent.from_chain,
+            "to_chain": intent.to_chain,
+            "action": intent.action,
+            "params": hex::encode(&intent.params),
+            "target_format": hex::encode(target_format),
+        }));
+        Sha3_512::digest(&preimage).to_vec()
+    }
+    
+    /// Recompute the translation hash and compare it to the recorded one
+    pub fn verify_hash(&self) -> bool {
+        Self::compute_hash(&self.original_intent, &self.target_format) == self.translation_metadata.translation_hash
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum ActionType {
-    Transfer { from: String, to: String, amount: u64, asset: String },
-    Swap { asset_in: String, asset_out: String, amount_in: u64, min_amount_out: u64 },
+    Transfer {
+        from: String,
+        to: String,
+        amount: u64,
+        asset: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        memo: Option<String>, // routes a deposit to a sub-account on chains that take one
+    },
+    Swap { asset_in: String, asset_out: String, amount_in: u64, expected_amount_out: u64, min_amount_out: u64 },
     Stake { asset: String, amount: u64, validator: String },
     Unstake { asset: String, amount: u64 },
     ContractCall { contract: String, method: String, params: Vec<u8> },
+    /// One source debit fanned out as transfers of `asset` to (chain, address, amount) outputs
+    Split { from: String, asset: String, outputs: Vec<(String, String, u64)> },
+}
+
+impl ActionType {
+    /// The `Intent::action` string for this action
+    pub fn name(&self) -> &'static str {
+        match self {
+            ActionType::Transfer { .. } => "transfer",
+            ActionType::Swap { .. } => "swap",
+            ActionType::Stake { .. } => "stake",
+            ActionType::Unstake { .. } => "unstake",
+            ActionType::ContractCall { .. } => "contract_call",
+            ActionType::Split { .. } => "split",
+        }
+    }
 }
 
 pub struct IntentTranslator {
     supported_chains: HashMap<String, ChainConfig>,
+    pool_reserves: HashMap<(String, String), (u64, u64)>, // (asset_in, asset_out) -> (reserve_in, reserve_out)
+    clock: Arc<dyn Clock>,
+    default_slippage_bps: u16, // for swaps translated without user preferences
 }
 
 #[derive(Debug, Clone)]
@@ -41,7 +108,7 @@ pub struct ChainConfig {
     pub gas_token: String,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ChainType {
     EVM,        // Ethereum, BSC, Polygon, etc.
     Substrate,  // Polkadot, Kusama
@@ -50,6 +117,77 @@ pub enum ChainType {
     Sentium,    // Sentium native
 }
 
+/// Longest transaction memo the Cosmos SDK accepts by default (`MaxMemoCharacters`)
+pub const COSMOS_MAX_MEMO_LEN: usize = 256;
+
+/// Largest standard `OP_RETURN` payload
+pub const BITCOIN_MAX_MEMO_LEN: usize = 80;
+
+/// Longest memo a Sentium intent carries
+pub const SENTIUM_MAX_MEMO_LEN: usize = 256;
+
+impl ChainType {
+    /// Longest transfer memo in bytes, or `None` if transfers on this chain can't carry one
+    pub fn max_memo_len(self) -> Option<usize> {
+        match self {
+            ChainType::Cosmos => Some(COSMOS_MAX_MEMO_LEN),
+            ChainType::Bitcoin => Some(BITCOIN_MAX_MEMO_LEN),
+            ChainType::Sentium => Some(SENTIUM_MAX_MEMO_LEN),
+            ChainType::EVM | ChainType::Substrate => None,
+        }
+    }
+}
+
+/// Refuse a memo the chain would reject or, worse, drop, losing the deposit it routes
+pub fn validate_memo(chain_type: ChainType, memo: &str) -> Result<(), RouterError> {
+    match chain_type.max_memo_len() {
+        None => Err(RouterError::UnsupportedAction(format!("{:?} transfers can't carry a memo", chain_type))),
+        Some(max) if memo.len() > max => Err(RouterError::TranslationError(format!(
+            "Memo is {} bytes, {:?} allows {}",
+            memo.len(),
+            chain_type,
+            max
+        ))),
+        Some(_) => Ok(()),
+    }
+}
+
+/// `bitcoin-mainnet`, `bitcoin-testnet`, `bitcoin-signet` or `bitcoin-regtest`
+#[cfg(feature = "btc")]
+pub fn bitcoin_chain_id(network: bitcoin::Network) -> String {
+    match network {
+        bitcoin::Network::Bitcoin => "bitcoin-mainnet".to_string(),
+        other => format!("bitcoin-{}", other),
+    }
+}
+
+/// Network a `bitcoin_chain_id` chain id names
+#[cfg(feature = "btc")]
+pub fn bitcoin_network(chain_id: &str) -> Result<bitcoin::Network, RouterError> {
+    match chain_id.strip_prefix("bitcoin-") {
+        Some("mainnet") => Ok(bitcoin::Network::Bitcoin),
+        Some(network) => network
+            .parse()
+            .map_err(|_| RouterError::TranslationError(format!("Unknown Bitcoin network in chain id {}", chain_id))),
+        None => Err(RouterError::TranslationError(format!("{} is not a Bitcoin chain id", chain_id))),
+    }
+}
+
+/// Sum of a split's output amounts; an empty split or zero output is refused
+pub fn split_total(outputs: &[(String, String, u64)]) -> Result<u64, RouterError> {
+    if outputs.is_empty() {
+        return Err(RouterError::TranslationError("Split has no outputs".to_string()));
+    }
+    outputs.iter().try_fold(0u64, |total, (chain, address, amount)| {
+        if *amount == 0 {
+            return Err(RouterError::TranslationError(format!("Split output to {} on {} is zero", address, chain)));
+        }
+        total
+            .checked_add(*amount)
+            .ok_or_else(|| RouterError::TranslationError("Split outputs overflow u64".to_string()))
+    })
+}
+
 #[derive(Debug, Clone)]
 pub enum AddressFormat {
     Ethereum,   // 0x... (20 bytes)
@@ -99,14 +237,71 @@ impl IntentTranslator {
             gas_token: "QSI".to_string(),
         });
         
-        Self { supported_chains }
+        Self {
+            supported_chains,
+            pool_reserves: HashMap::new(),
+            clock: system_clock(),
+            default_slippage_bps: DEFAULT_SLIPPAGE_BPS,
+        }
+    }
+    
+    /// Slippage tolerance for `translate`, in basis points; `DEFAULT_SLIPPAGE_BPS` unless set
+    pub fn with_default_slippage(mut self, slippage_bps: u16) -> Self {
+        self.default_slippage_bps = slippage_bps;
+        self
+    }
+    
+    /// Use a different time source for translation timestamps
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Translate Bitcoin transfers for `network` rather than mainnet
+    #[cfg(feature = "btc")]
+    pub fn with_bitcoin_network(mut self, network: bitcoin::Network) -> Self {
+        if let Some(config) = self.supported_chains.get_mut("bitcoin") {
+            config.chain_id = bitcoin_chain_id(network);
+        }
+        self
     }
     
     pub fn add_chain(&mut self, name: String, config: ChainConfig) {
         self.supported_chains.insert(name, config);
     }
     
+    /// Register constant-product pool depth used to estimate swap price impact
+    pub fn add_pool(&mut self, asset_in: &str, asset_out: &str, reserve_in: u64, reserve_out: u64) {
+        self.pool_reserves
+            .insert((asset_in.to_string(), asset_out.to_string()), (reserve_in, reserve_out));
+    }
+    
     pub fn translate(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
+        self.translate_with_slippage(intent, self.default_slippage_bps)
+    }
+    
+    /// Translate with swap bounds derived from the user's slippage tolerance
+    pub fn translate_with_preferences(
+        &self,
+        intent: &Intent,
+        preferences: &UserPreferences,
+    ) -> Result<TranslatedIntent, RouterError> {
+        self.translate_with_slippage(intent, preferences.slippage_bps)
+    }
+    
+    /// Amount of the intent's input asset (transfer, swap or stake amount)
+    pub fn input_amount(&self, intent: &Intent) -> Result<u64, RouterError> {
+        match self.parse_action(intent)? {
+            ActionType::Transfer { amount, .. }
+            | ActionType::Stake { amount, .. }
+            | ActionType::Unstake { amount, .. } => Ok(amount),
+            ActionType::Swap { amount_in, .. } => Ok(amount_in),
+            ActionType::ContractCall { .. } => Ok(0),
+            ActionType::Split { outputs, .. } => split_total(&outputs),
+        }
+    }
+    
+    fn translate_with_slippage(&self, intent: &Intent, slippage_bps: u16) -> Result<TranslatedIntent, RouterError> {
         // Get target chain configuration
         let target_config = self.supported_chains
             .get(&intent.to_chain)
@@ -114,37 +309,104 @@ impl IntentTranslator {
         
         // Parse action from intent
         let action = self.parse_action(intent)?;
+        if let ActionType::Split { .. } = action {
+            return Err(RouterError::UnsupportedAction(
+                "Split intents are routed per output by Router::route_split".to_string(),
+            ));
+        }
+        let action = apply_slippage(action, slippage_bps)?;
+        if let ActionType::Transfer { memo: Some(memo), .. } = &action {
+            validate_memo(target_config.chain_type, memo)?;
+        }
         
         // Translate to target chain format
         let target_format = match target_config.chain_type {
-            ChainType::EVM => self.translate_to_evm(&action, target_config)?,
+            ChainType::EVM => {
+                let data = self.translate_to_evm(&action, target_config, intent.expires_at)?;
+                #[cfg(all(feature = "eth", debug_assertions))]
+                verify_evm_calldata(&data, &action)?;
+                data
+            }
             ChainType::Substrate => self.translate_to_substrate(&action, target_config)?,
             ChainType::Cosmos => self.translate_to_cosmos(&action, target_config)?,
             ChainType::Bitcoin => self.translate_to_bitcoin(&action, target_config)?,
             ChainType::Sentium => self.translate_to_sentium(&action, target_config)?,
         };
         
-        // Calculate translation hash
-        let mut hasher = Sha3_512::new();
-        hasher.update(&target_format);
-        let translation_hash = hasher.finalize().to_vec();
+        let translation_hash = TranslatedIntent::compute_hash(intent, &target_format);
+        
+        let price_impact_bps = self.price_impact(&action);
+        if let Some(impact) = price_impact_bps.filter(|impact| *impact > slippage_bps) {
+            tracing::warn!(
+                "Intent {}: expected price impact {} bps exceeds slippage tolerance {} bps",
+                intent.id,
+                impact,
+                slippage_bps
+            );
+        }
         
         Ok(TranslatedIntent {
             original_intent: intent.clone(),
             target_format,
             translation_metadata: TranslationMetadata {
-                translator_version: "0.1.0".to_string(),
-                timestamp: std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs(),
+                translator_version: TRANSLATOR_VERSION.to_string(),
+                timestamp: self.clock.now_secs(),
                 gas_estimate: self.estimate_gas(&action, target_config),
                 translation_hash,
+                price_impact_bps,
             },
         })
     }
     
+    /// Decode a translation's payload for its target chain and check it carries the original
+    /// intent's action: the same recipient, amount and asset wherever the chain encodes them
+    ///
+    /// A swap's minimum output comes from the slippage tolerance it was translated with, so
+    /// it only has to be nonzero and no more than the expected output.
+    pub fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        let intent = &translated.original_intent;
+        let config = self
+            .supported_chains
+            .get(&intent.to_chain)
+            .ok_or_else(|| RouterError::UnsupportedChain(intent.to_chain.clone()))?;
+        let expected = self.parse_action(intent)?;
+        let data = &translated.target_format;
+        
+        match config.chain_type {
+            #[cfg(feature = "eth")]
+            ChainType::EVM => verify_evm_calldata(data, &with_encoded_minimum(expected, evm_swap_minimum(data))?),
+            #[cfg(feature = "dot")]
+            ChainType::Substrate => verify_substrate_call(data, &expected),
+            ChainType::Cosmos => verify_cosmos_msg(data, &expected),
+            #[cfg(feature = "btc")]
+            ChainType::Bitcoin => verify_bitcoin_tx(data, &expected, bitcoin_network(&config.chain_id)?),
+            ChainType::Sentium => verify_sentium_envelope(data, &expected),
+            #[allow(unreachable_patterns)]
+            other => Err(RouterError::VerificationError(format!(
+                "{:?} payloads can't be decoded without their chain's feature",
+                other
+            ))),
+        }
+    }
+    
+    fn price_impact(&self, action: &ActionType) -> Option<u16> {
+        match action {
+            ActionType::Swap { asset_in, asset_out, amount_in, .. } => self
+                .pool_reserves
+                .get(&(asset_in.clone(), asset_out.clone()))
+                .map(|&(reserve_in, reserve_out)| {
+                    SlippageCalculator::price_impact_bps(*amount_in, reserve_in, reserve_out)
+                }),
+            _ => None,
+        }
+    }
+    
     fn parse_action(&self, intent: &Intent) -> Result<ActionType, RouterError> {
+        // Intents built with `Intent::with_action` carry the full action in params
+        if let Ok(action) = intent.decode_action() {
+            return Ok(action);
+        }
+        
         // Parse action from intent params using proper deserialization
         // Intent.params is Vec<u8>, so we deserialize from the byte array
         match intent.action.as_str() {
@@ -163,6 +425,7 @@ impl IntentTranslator {
                     to,
                     amount,
                     asset,
+                    memo: None,
                 })
             }
             "swap" => {
@@ -170,7 +433,8 @@ impl IntentTranslator {
                     asset_in: "USDT".to_string(),
                     asset_out: "ETH".to_string(),
                     amount_in: 100,
-                    min_amount_out: 0,
+                    expected_amount_out: 100,
+                    min_amount_out: 0, // set from the slippage tolerance by apply_slippage
                 })
             }
             "stake" => {
@@ -186,7 +450,13 @@ impl IntentTranslator {
         }
     }
     
-    fn translate_to_evm(&self, action: &ActionType, _config: &ChainConfig) -> Result<Vec<u8>, RouterError> {
+    #[cfg(feature = "eth")]
+    fn translate_to_evm(
+        &self,
+        action: &ActionType,
+        _config: &ChainConfig,
+        deadline: Option<u64>,
+    ) -> Result<Vec<u8>, RouterError> {
         // Translate to EVM transaction format with proper ABI encoding
         use ethers::abi::{Token, encode};
         
@@ -194,12 +464,12 @@ impl IntentTranslator {
             ActionType::Transfer { to, amount, .. } => {
                 // EVM transfer: function selector + properly encoded address + amount
                 let mut data = Vec::new();
-                // transfer(address,uint256) selector: 0xa9059cbb
-                data.extend_from_slice(&[0xa9, 0x05, 0x9c, 0xbb]);
+                data.extend_from_slice(&EVM_TRANSFER_SELECTOR);
                 
                 // Parse address
                 use ethers::types::Address;
                 use std::str::FromStr;
+                address::validate(ChainType::EVM, to)?;
                 let address = Address::from_str(to)
                     .map_err(|e| RouterError::TranslationError(format!("Invalid EVM address: {}", e)))?;
                 
@@ -212,16 +482,21 @@ impl IntentTranslator {
                 
                 Ok(data)
             }
-            ActionType::Swap { asset_in: _asset_in, asset_out: _asset_out, amount_in, min_amount_out } => {
+            ActionType::Swap { amount_in, min_amount_out, .. } => {
                 // Uniswap-style swap with proper ABI encoding
                 let mut data = Vec::new();
-                // swapExactTokensForTokens selector
-                data.extend_from_slice(&[0x38, 0xed, 0x17, 0x39]);
+                data.extend_from_slice(&EVM_SWAP_SELECTOR);
+                
+                // The router reverts after the deadline; no expiry maps to the max value
+                let deadline = deadline
+                    .map(ethers::types::U256::from)
+                    .unwrap_or(ethers::types::U256::MAX);
                 
                 // Properly encode parameters
                 let encoded = encode(&[
                     Token::Uint(ethers::types::U256::from(*amount_in)),
                     Token::Uint(ethers::types::U256::from(*min_amount_out)),
+                    Token::Uint(deadline),
                 ]);
                 data.extend_from_slice(&encoded);
                 
@@ -231,6 +506,7 @@ impl IntentTranslator {
         }
     }
     
+    #[cfg(feature = "dot")]
     fn translate_to_substrate(&self, action: &ActionType, _config: &ChainConfig) -> Result<Vec<u8>, RouterError> {
         // Translate to Substrate extrinsic format with proper SCALE encoding
         use parity_scale_codec::Encode;
@@ -246,6 +522,7 @@ impl IntentTranslator {
                 // Parse SS58 address to AccountId32
                 use sp_core::crypto::Ss58Codec;
                 use sp_core::sr25519::Public;
+                address::validate(ChainType::Substrate, to)?;
                 let account = Public::from_ss58check(to)
                     .map_err(|e| RouterError::TranslationError(format!("Invalid SS58 address: {:?}", e)))?;
                 
@@ -264,6 +541,7 @@ impl IntentTranslator {
                 // Parse validator address
                 use sp_core::crypto::Ss58Codec;
                 use sp_core::sr25519::Public;
+                address::validate(ChainType::Substrate, validator)?;
                 let validator_account = Public::from_ss58check(validator)
                     .map_err(|e| RouterError::TranslationError(format!("Invalid validator address: {:?}", e)))?;
                 
@@ -281,9 +559,11 @@ impl IntentTranslator {
         // Translate to Cosmos SDK message format with proper JSON encoding
         // Cosmos SDK supports both protobuf and JSON encoding
         match action {
-            ActionType::Transfer { from, to, amount, asset } => {
+            ActionType::Transfer { from, to, amount, asset, memo } => {
+                address::validate(ChainType::Cosmos, to)?;
+                
                 // Create MsgSend in JSON format (Amino JSON)
-                let msg = serde_json::json!({
+                let mut msg = serde_json::json!({
                     "@type": "/cosmos.bank.v1beta1.MsgSend",
                     "from_address": from,
                     "to_address": to,
@@ -293,6 +573,11 @@ impl IntentTranslator {
                     }]
                 });
                 
+                // The memo belongs to the transaction, so a transfer with one becomes a tx body
+                if let Some(memo) = memo {
+                    msg = serde_json::json!({ "messages": [msg], "memo": memo });
+                }
+                
                 // Serialize to JSON bytes
                 serde_json::to_vec(&msg)
                     .map_err(|e| RouterError::TranslationError(format!("Failed to encode MsgSend: {}", e)))
@@ -317,20 +602,24 @@ impl IntentTranslator {
         }
     }
     
-    fn translate_to_bitcoin(&self, action: &ActionType, _config: &ChainConfig) -> Result<Vec<u8>, RouterError> {
+    #[cfg(feature = "btc")]
+    fn translate_to_bitcoin(&self, action: &ActionType, config: &ChainConfig) -> Result<Vec<u8>, RouterError> {
         // Translate to Bitcoin transaction format using proper Bitcoin library
         use bitcoin::{Transaction, TxOut, Address, Amount};
         use std::str::FromStr;
         
         match action {
-            ActionType::Transfer { to, amount, .. } => {
-                // Parse Bitcoin address
+            ActionType::Transfer { to, amount, memo, .. } => {
+                // Parse Bitcoin address, refusing one for another network
+                address::validate(ChainType::Bitcoin, to)?;
+                let network = bitcoin_network(&config.chain_id)?;
                 let address = Address::from_str(to)
                     .map_err(|e| RouterError::TranslationError(format!("Invalid Bitcoin address: {}", e)))?
-                    .assume_checked();
+                    .require_network(network)
+                    .map_err(|_| RouterError::TranslationError(format!("{} is not a {} address", to, network)))?;
                 
                 // Create proper Bitcoin transaction structure
-                let tx = Transaction {
+                let mut tx = Transaction {
                     version: bitcoin::transaction::Version::TWO,
                     lock_time: bitcoin::absolute::LockTime::ZERO,
                     input: vec![], // Inputs will be added during UTXO selection
@@ -342,6 +631,16 @@ impl IntentTranslator {
                     ],
                 };
                 
+                // A memo rides in a zero-value OP_RETURN output after the payment
+                if let Some(memo) = memo {
+                    let data = <&bitcoin::script::PushBytes>::try_from(memo.as_bytes())
+                        .map_err(|e| RouterError::TranslationError(format!("Memo too long for OP_RETURN: {}", e)))?;
+                    tx.output.push(TxOut {
+                        value: Amount::ZERO,
+                        script_pubkey: bitcoin::ScriptBuf::new_op_return(data),
+                    });
+                }
+                
                 // Serialize transaction using Bitcoin consensus encoding
                 use bitcoin::consensus::encode::serialize;
                 Ok(serialize(&tx))
@@ -350,11 +649,32 @@ impl IntentTranslator {
         }
     }
     
+    #[cfg(not(feature = "eth"))]
+    fn translate_to_evm(
+        &self,
+        _action: &ActionType,
+        _config: &ChainConfig,
+        _deadline: Option<u64>,
+    ) -> Result<Vec<u8>, RouterError> {
+        Err(feature_disabled("EVM", "eth"))
+    }
+    
+    #[cfg(not(feature = "dot"))]
+    fn translate_to_substrate(&self, _action: &ActionType, _config: &ChainConfig) -> Result<Vec<u8>, RouterError> {
+        Err(feature_disabled("Substrate", "dot"))
+    }
+    
+    #[cfg(not(feature = "btc"))]
+    fn translate_to_bitcoin(&self, _action: &ActionType, _config: &ChainConfig) -> Result<Vec<u8>, RouterError> {
+        Err(feature_disabled("Bitcoin", "btc"))
+    }
+    
     fn translate_to_sentium(&self, action: &ActionType, _config: &ChainConfig) -> Result<Vec<u8>, RouterError> {
-        // Translate to Sentium native format
-        // Sentium supports intent-based transactions natively
-        serde_json::to_vec(action)
-            .map_err(|e| RouterError::TranslationError(format!("Serialization error: {}", e)))
+        // Sentium takes intents natively: the action's JSON in an unsigned envelope, signed
+        // with the sender's nonce by SentiumAdapter on submission
+        let intent = serde_json::to_vec(action)
+            .map_err(|e| RouterError::TranslationError(format!("Serialization error: {}", e)))?;
+        Ok(SentiumEnvelope::unsigned(intent).encode())
     }
     
     fn estimate_gas(&self, action: &ActionType, config: &ChainConfig) -> u64 {
@@ -371,6 +691,271 @@ impl IntentTranslator {
     }
 }
 
+/// Decode EVM calldata produced by the translator and check it carries `expected`'s parameters
+///
+/// The calldata must be exactly the selector and ABI words the action encodes to; a swap's
+/// deadline comes from the intent rather than the action, so it isn't compared.
+#[cfg(feature = "eth")]
+pub fn verify_evm_calldata(data: &[u8], expected: &ActionType) -> Result<(), RouterError> {
+    use ethers::abi::{decode, ParamType, Token};
+    use ethers::types::{Address, U256};
+    use std::str::FromStr;
+    
+    let (selector, params) = match expected {
+        ActionType::Transfer { .. } => (EVM_TRANSFER_SELECTOR, vec![ParamType::Address, ParamType::Uint(256)]),
+        ActionType::Swap { .. } => (EVM_SWAP_SELECTOR, vec![ParamType::Uint(256); 3]),
+        other => {
+            return Err(RouterError::VerificationError(format!(
+                "No EVM calldata for a {} action",
+                other.name()
+            )))
+        }
+    };
+    
+    let mismatch = |what: &str| RouterError::VerificationError(format!("EVM calldata {}", what));
+    if data.len() != 4 + 32 * params.len() {
+        return Err(mismatch(&format!("is {} bytes, expected {}", data.len(), 4 + 32 * params.len())));
+    }
+    if data[..4] != selector {
+        return Err(mismatch(&format!("has selector 0x{}, expected 0x{}", hex::encode(&data[..4]), hex::encode(selector))));
+    }
+    let tokens = decode(&params, &data[4..]).map_err(|e| mismatch(&format!("doesn't decode: {}", e)))?;
+    
+    let matches = match (expected, tokens.as_slice()) {
+        (ActionType::Transfer { to, amount, .. }, [Token::Address(recipient), Token::Uint(value)]) => {
+            Address::from_str(to).ok() == Some(*recipient) && *value == U256::from(*amount)
+        }
+        (
+            ActionType::Swap { amount_in, min_amount_out, .. },
+            [Token::Uint(encoded_in), Token::Uint(encoded_min_out), Token::Uint(_deadline)],
+        ) => *encoded_in == U256::from(*amount_in) && *encoded_min_out == U256::from(*min_amount_out),
+        _ => false,
+    };
+    if !matches {
+        return Err(mismatch(&format!("doesn't match the {} action", expected.name())));
+    }
+    
+    Ok(())
+}
+
+/// `amountOutMin` of swap calldata, if it has one that fits a `u64`
+#[cfg(feature = "eth")]
+fn evm_swap_minimum(data: &[u8]) -> Option<u64> {
+    let word = ethers::types::U256::from_big_endian(data.get(36..68)?);
+    (word <= ethers::types::U256::from(u64::MAX)).then(|| word.as_u64())
+}
+
+/// `expected` with a swap's minimum output replaced by the encoded one, once that is
+/// within `1..=expected_amount_out`; other actions are returned as they are
+fn with_encoded_minimum(expected: ActionType, encoded: Option<u64>) -> Result<ActionType, RouterError> {
+    match expected {
+        ActionType::Swap { asset_in, asset_out, amount_in, expected_amount_out, .. } => match encoded {
+            Some(min_amount_out) if (1..=expected_amount_out).contains(&min_amount_out) => {
+                Ok(ActionType::Swap { asset_in, asset_out, amount_in, expected_amount_out, min_amount_out })
+            }
+            _ => Err(RouterError::VerificationError(format!(
+                "Swap minimum output {:?} is outside 1..={}",
+                encoded, expected_amount_out
+            ))),
+        },
+        other => Ok(other),
+    }
+}
+
+/// Decode a translated Substrate call and check it carries `expected`'s account and amount
+#[cfg(feature = "dot")]
+pub fn verify_substrate_call(data: &[u8], expected: &ActionType) -> Result<(), RouterError> {
+    use parity_scale_codec::Decode;
+    use sp_core::crypto::Ss58Codec;
+    use sp_core::sr25519::Public;
+    
+    let (call, account, amount) = match expected {
+        ActionType::Transfer { to, amount, .. } => ([0x05, 0x00], to, *amount),
+        ActionType::Stake { validator, amount, .. } => ([0x06, 0x00], validator, *amount),
+        other => {
+            return Err(RouterError::VerificationError(format!(
+                "No Substrate call for a {} action",
+                other.name()
+            )))
+        }
+    };
+    
+    let mismatch = |what: &str| RouterError::VerificationError(format!("Substrate call {}", what));
+    // pallet and call index || AccountId32 || u64 amount
+    if data.len() != 2 + 32 + 8 {
+        return Err(mismatch(&format!("is {} bytes, expected 42", data.len())));
+    }
+    if data[..2] != call {
+        return Err(mismatch(&format!("is 0x{}, expected 0x{}", hex::encode(&data[..2]), hex::encode(call))));
+    }
+    let account = Public::from_ss58check(account)
+        .map_err(|e| mismatch(&format!("can't be checked against {}: {:?}", account, e)))?;
+    if data[2..34] != account.0 {
+        return Err(mismatch("pays a different account"));
+    }
+    let encoded = u64::decode(&mut &data[34..]).map_err(|e| mismatch(&format!("amount doesn't decode: {}", e)))?;
+    if encoded != amount {
+        return Err(mismatch(&format!("amount is {}, expected {}", encoded, amount)));
+    }
+    
+    Ok(())
+}
+
+/// Decode a translated Cosmos message and check it carries `expected`'s addresses, coin
+/// and memo
+pub fn verify_cosmos_msg(data: &[u8], expected: &ActionType) -> Result<(), RouterError> {
+    use serde_json::{json, Value};
+    
+    let mismatch = |what: &str| RouterError::VerificationError(format!("Cosmos message {}", what));
+    let decoded: Value = match serde_json::from_slice(data) {
+        Ok(decoded) => decoded,
+        // Transfers to another Cosmos chain go out as a protobuf ICS-20 `MsgTransfer`
+        #[cfg(feature = "cosmos")]
+        Err(_) if data.first() != Some(&b'{') => return super::ibc::verify_transfer(data, expected).map(drop),
+        Err(e) => return Err(mismatch(&format!("doesn't decode: {}", e))),
+    };
+    let coin = |asset: &str, amount: u64| json!({ "denom": asset.to_lowercase(), "amount": amount.to_string() });
+    
+    let (msg, fields) = match expected {
+        ActionType::Transfer { from, to, amount, asset, memo } => {
+            // A memo wraps the message in a tx body carrying it
+            let msg = match memo {
+                Some(memo) => {
+                    if decoded["memo"] != json!(memo) {
+                        return Err(mismatch(&format!("memo is {}, expected {:?}", decoded["memo"], memo)));
+                    }
+                    match decoded["messages"].as_array().map(Vec::as_slice) {
+                        Some([msg]) => msg,
+                        _ => return Err(mismatch("should hold exactly one message")),
+                    }
+                }
+                None => &decoded,
+            };
+            let fields = vec![
+                ("@type", json!("/cosmos.bank.v1beta1.MsgSend")),
+                ("from_address", json!(from)),
+                ("to_address", json!(to)),
+                ("amount", json!([coin(asset, *amount)])),
+            ];
+            (msg, fields)
+        }
+        ActionType::Stake { asset, amount, validator } => {
+            let fields = vec![
+                ("@type", json!("/cosmos.staking.v1beta1.MsgDelegate")),
+                ("validator_address", json!(validator)),
+                ("amount", coin(asset, *amount)),
+            ];
+            (&decoded, fields)
+        }
+        other => {
+            return Err(RouterError::VerificationError(format!(
+                "No Cosmos message for a {} action",
+                other.name()
+            )))
+        }
+    };
+    
+    for (field, value) in fields {
+        if msg[field] != value {
+            return Err(mismatch(&format!("{} is {}, expected {}", field, msg[field], value)));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Decode a translated Bitcoin transaction and check its outputs pay `expected`'s amount
+/// to its recipient on `network`, followed by the memo if it has one
+#[cfg(feature = "btc")]
+pub fn verify_bitcoin_tx(data: &[u8], expected: &ActionType, network: bitcoin::Network) -> Result<(), RouterError> {
+    use bitcoin::{Address, Amount, ScriptBuf, Transaction};
+    use std::str::FromStr;
+    
+    let ActionType::Transfer { to, amount, memo, .. } = expected else {
+        return Err(RouterError::VerificationError(format!(
+            "No Bitcoin transaction for a {} action",
+            expected.name()
+        )));
+    };
+    
+    let mismatch = |what: &str| RouterError::VerificationError(format!("Bitcoin transaction {}", what));
+    let tx: Transaction = bitcoin::consensus::encode::deserialize(data)
+        .map_err(|e| mismatch(&format!("doesn't decode: {}", e)))?;
+    let recipient = Address::from_str(to)
+        .ok()
+        .and_then(|address| address.require_network(network).ok())
+        .ok_or_else(|| mismatch(&format!("can't be checked against {}", to)))?;
+    
+    let mut outputs = vec![(Amount::from_sat(*amount), recipient.script_pubkey())];
+    if let Some(memo) = memo {
+        let data = <&bitcoin::script::PushBytes>::try_from(memo.as_bytes())
+            .map_err(|e| mismatch(&format!("can't carry memo: {}", e)))?;
+        outputs.push((Amount::ZERO, ScriptBuf::new_op_return(data)));
+    }
+    
+    if tx.output.len() != outputs.len() {
+        return Err(mismatch(&format!("has {} outputs, expected {}", tx.output.len(), outputs.len())));
+    }
+    for (index, (output, (value, script))) in tx.output.iter().zip(outputs).enumerate() {
+        if output.value != value || output.script_pubkey != script {
+            return Err(mismatch(&format!("output {} doesn't match the transfer", index)));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Decode a translated Sentium envelope and check the action inside is `expected`
+pub fn verify_sentium_envelope(data: &[u8], expected: &ActionType) -> Result<(), RouterError> {
+    let mismatch = |what: &str| RouterError::VerificationError(format!("Sentium envelope {}", what));
+    let envelope = SentiumEnvelope::decode(data)?;
+    let decoded: ActionType = serde_json::from_slice(&envelope.intent)
+        .map_err(|e| mismatch(&format!("action doesn't decode: {}", e)))?;
+    
+    let encoded_minimum = match &decoded {
+        ActionType::Swap { min_amount_out, .. } => Some(*min_amount_out),
+        _ => None,
+    };
+    let expected = with_encoded_minimum(expected.clone(), encoded_minimum)?;
+    if serde_json::to_value(&decoded).ok() != serde_json::to_value(&expected).ok() {
+        return Err(mismatch(&format!("carries a {} action that doesn't match", decoded.name())));
+    }
+    
+    Ok(())
+}
+
+/// Fill in a swap's minimum output from its expected output; other actions pass through
+///
+/// The tolerance must be within `SlippageCalculator::validate_slippage` bounds, and a zero
+/// minimum would accept any price, so such swaps are rejected.
+fn apply_slippage(action: ActionType, slippage_bps: u16) -> Result<ActionType, RouterError> {
+    match action {
+        ActionType::Swap { asset_in, asset_out, amount_in, expected_amount_out, .. } => {
+            SlippageCalculator::validate_slippage(slippage_bps)?;
+            
+            let min_amount_out = SlippageCalculator::calculate_minimum_output(expected_amount_out, slippage_bps);
+            if min_amount_out == 0 {
+                return Err(RouterError::TranslationError(format!(
+                    "Swap minimum output is zero (expected {}, slippage {} bps)",
+                    expected_amount_out, slippage_bps
+                )));
+            }
+            
+            Ok(ActionType::Swap { asset_in, asset_out, amount_in, expected_amount_out, min_amount_out })
+        }
+        other => Ok(other),
+    }
+}
+
+// Error returned when a chain's encoding support was compiled out
+#[allow(dead_code)]
+fn feature_disabled(chain_type: &str, feature: &str) -> RouterError {
+    RouterError::TranslationError(format!(
+        "{} support not compiled in (enable the `{}` feature)",
+        chain_type, feature
+    ))
+}
+
 impl Default for IntentTranslator {
     fn default() -> Self {
         Self::new()
@@ -388,6 +973,7 @@ mod tests {
     }
     
     #[test]
+    #[cfg(feature = "eth")]
     fn test_translate_to_ethereum() {
         let translator = IntentTranslator::new();
         let intent = Intent {
@@ -397,6 +983,8 @@ mod tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let result = translator.translate(&intent);
@@ -410,6 +998,265 @@ mod tests {
         assert!(translated.target_format.len() > 0);
     }
     
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_evm_swap_carries_intent_deadline() {
+        let translator = IntentTranslator::new();
+        let mut intent = Intent {
+            id: "test-3".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "ethereum".to_string(),
+            action: "swap".to_string(),
+            params: vec![],
+            context: vec![],
+            expires_at: Some(1_700_000_000),
+            signature: None,
+        };
+        
+        // selector || amountIn || amountOutMin || deadline
+        let data = translator.translate(&intent).unwrap().target_format;
+        assert_eq!(&data[..4], &[0x38, 0xed, 0x17, 0x39]);
+        assert_eq!(ethers::types::U256::from_big_endian(&data[68..100]), 1_700_000_000u64.into());
+        
+        intent.expires_at = None;
+        let data = translator.translate(&intent).unwrap().target_format;
+        assert_eq!(ethers::types::U256::from_big_endian(&data[68..100]), ethers::types::U256::MAX);
+    }
+    
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_verify_evm_calldata_round_trips() {
+        let translator = IntentTranslator::new();
+        let transfer = ActionType::Transfer {
+            from: "0x0000000000000000000000000000000000000001".to_string(),
+            to: "0x00000000000000000000000000000000000000b2".to_string(),
+            amount: 1_250_000,
+            asset: "USDC".to_string(),
+            memo: None,
+        };
+        let data = translator
+            .translate_to_evm(&transfer, &translator.supported_chains["ethereum"], None)
+            .unwrap();
+        verify_evm_calldata(&data, &transfer).unwrap();
+        
+        // Truncated, or decoding to different parameters
+        assert!(verify_evm_calldata(&data[..data.len() - 1], &transfer).is_err());
+        assert!(verify_evm_calldata(&data[..4], &transfer).is_err());
+        let mut other = transfer.clone();
+        if let ActionType::Transfer { amount, .. } = &mut other {
+            *amount += 1;
+        }
+        assert!(verify_evm_calldata(&data, &other).is_err());
+        
+        let swap = apply_slippage(
+            ActionType::Swap {
+                asset_in: "USDC".to_string(),
+                asset_out: "ETH".to_string(),
+                amount_in: 2_000_000,
+                expected_amount_out: 1_000,
+                min_amount_out: 0,
+            },
+            DEFAULT_SLIPPAGE_BPS,
+        )
+        .unwrap();
+        let data = translator
+            .translate_to_evm(&swap, &translator.supported_chains["ethereum"], Some(1_700_000_000))
+            .unwrap();
+        verify_evm_calldata(&data, &swap).unwrap();
+        assert!(verify_evm_calldata(&data, &transfer).is_err());
+    }
+    
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_translate_uses_params_from_with_action() {
+        let translator = IntentTranslator::new();
+        let intent = Intent {
+            id: "test-4".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "ethereum".to_string(),
+            action: String::new(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        }
+        .with_action(ActionType::Transfer {
+            from: "0x0000000000000000000000000000000000000001".to_string(),
+            to: "0x00000000000000000000000000000000000000aa".to_string(),
+            amount: 123_456,
+            asset: "USDC".to_string(),
+            memo: None,
+        });
+        
+        // transfer(address,uint256) with the encoded recipient and amount, not the defaults
+        let data = translator.translate(&intent).unwrap().target_format;
+        assert_eq!(data[35], 0xaa);
+        assert_eq!(ethers::types::U256::from_big_endian(&data[36..68]), 123_456u64.into());
+    }
+    
+    #[test]
+    fn test_swap_minimum_from_slippage() {
+        let swap = |expected_amount_out| ActionType::Swap {
+            asset_in: "USDT".to_string(),
+            asset_out: "ETH".to_string(),
+            amount_in: 1000,
+            expected_amount_out,
+            min_amount_out: 0,
+        };
+        
+        match apply_slippage(swap(1000), 100).unwrap() {
+            ActionType::Swap { min_amount_out, .. } => assert_eq!(min_amount_out, 990),
+            other => panic!("expected swap, got {:?}", other),
+        }
+        
+        assert!(apply_slippage(swap(0), 100).is_err());
+        assert!(apply_slippage(swap(1000), 10000).is_err());
+    }
+    
+    #[test]
+    fn test_swap_reports_price_impact_for_known_pool() {
+        let intent = Intent {
+            id: "test-5".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "sentium".to_string(),
+            action: "swap".to_string(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        };
+        
+        // No pool registered: nothing to estimate against
+        let mut translator = IntentTranslator::new();
+        let translated = translator.translate(&intent).unwrap();
+        assert_eq!(translated.translation_metadata.price_impact_bps, None);
+        
+        // Placeholder swap is 100 USDT in; a shallow pool means 5% impact
+        translator.add_pool("USDT", "ETH", 1_900, 1);
+        let translated = translator.translate(&intent).unwrap();
+        assert_eq!(translated.translation_metadata.price_impact_bps, Some(500));
+    }
+    
+    #[test]
+    fn test_zero_slippage_swap_rejected_for_every_chain() {
+        use crate::core::context::RiskLevel;
+        
+        let translator = IntentTranslator::new();
+        let preferences = UserPreferences {
+            slippage_bps: 0,
+            max_gas_price: 100,
+            min_confirmations: 6,
+            preferred_routes: vec![],
+            risk_tolerance: RiskLevel::Low,
+        };
+        
+        for chain in ["ethereum", "polkadot", "cosmos", "bitcoin", "sentium"] {
+            let intent = Intent {
+                id: format!("zero-slippage-{}", chain),
+                from_chain: "sentium".to_string(),
+                to_chain: chain.to_string(),
+                action: "swap".to_string(),
+                params: vec![],
+                context: vec![],
+                expires_at: None,
+                signature: None,
+            };
+            
+            match translator.translate_with_preferences(&intent, &preferences) {
+                Err(RouterError::TranslationError(msg)) => assert!(msg.contains("cannot be zero"), "{}: {}", chain, msg),
+                other => panic!("{}: expected slippage rejection, got {:?}", chain, other.map(|t| t.target_format)),
+            }
+        }
+    }
+    
+    #[test]
+    #[cfg(feature = "eth")]
+    fn test_evm_swap_uses_preferred_slippage() {
+        use crate::core::context::RiskLevel;
+        
+        let translator = IntentTranslator::new();
+        let intent = Intent {
+            id: "test-4".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "ethereum".to_string(),
+            action: "swap".to_string(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        };
+        let preferences = UserPreferences {
+            slippage_bps: 500,
+            max_gas_price: 100,
+            min_confirmations: 6,
+            preferred_routes: vec![],
+            risk_tolerance: RiskLevel::Low,
+        };
+        
+        // selector || amountIn || amountOutMin || deadline; placeholder swap expects 100 out
+        let data = translator.translate_with_preferences(&intent, &preferences).unwrap().target_format;
+        assert_eq!(ethers::types::U256::from_big_endian(&data[36..68]), 95u64.into());
+    }
+    
+    #[test]
+    fn test_verify_hash_detects_tampering() {
+        let translator = IntentTranslator::new();
+        let intent = Intent {
+            id: "test-6".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "sentium".to_string(),
+            action: "transfer".to_string(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        };
+        
+        let mut translated = translator.translate(&intent).unwrap();
+        assert!(translated.verify_hash());
+        
+        translated.target_format[0] ^= 0x01;
+        assert!(!translated.verify_hash());
+        
+        translated.target_format[0] ^= 0x01;
+        translated.target_format.push(0);
+        assert!(!translated.verify_hash());
+    }
+    
+    #[test]
+    fn test_verify_hash_binds_original_intent() {
+        let translator = IntentTranslator::new();
+        let intent = Intent {
+            id: "test-7".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "sentium".to_string(),
+            action: "transfer".to_string(),
+            params: vec![1, 2, 3],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        };
+        
+        let translated = translator.translate(&intent).unwrap();
+        assert!(translated.verify_hash());
+        assert_eq!(translated.translation_metadata.translator_version, TRANSLATOR_VERSION);
+        
+        // Same payload, different source intent
+        let mut swapped = translated.clone();
+        swapped.original_intent.id = "test-8".to_string();
+        assert!(!swapped.verify_hash());
+        
+        let mut swapped = translated.clone();
+        swapped.original_intent.params = vec![1, 2, 4];
+        assert!(!swapped.verify_hash());
+        
+        // Length prefixes keep field boundaries from shifting
+        let mut shifted = translated;
+        shifted.original_intent.from_chain = "sentiums".to_string();
+        shifted.original_intent.to_chain = "entium".to_string();
+        assert!(!shifted.verify_hash());
+    }
+    
     #[test]
     fn test_unsupported_chain() {
         let translator = IntentTranslator::new();
@@ -420,9 +1267,186 @@ mod tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let result = translator.translate(&intent);
         assert!(result.is_err());
     }
+    
+    #[test]
+    #[cfg(all(feature = "btc", feature = "eth"))]
+    fn test_memo_carried_on_bitcoin_and_refused_on_evm() {
+        let translator = IntentTranslator::new();
+        let deposit = |to_chain: &str, to: &str| {
+            Intent {
+                id: "memo-2".to_string(),
+                from_chain: "sentium".to_string(),
+                to_chain: to_chain.to_string(),
+                action: String::new(),
+                params: vec![],
+                context: vec![],
+                expires_at: None,
+                signature: None,
+            }
+            .with_action(ActionType::Transfer {
+                from: "0x0000000000000000000000000000000000000001".to_string(),
+                to: to.to_string(),
+                amount: 50_000,
+                asset: "BTC".to_string(),
+                memo: Some("104729".to_string()),
+            })
+        };
+        
+        let data = translator.translate(&deposit("bitcoin", "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")).unwrap().target_format;
+        let tx: bitcoin::Transaction = bitcoin::consensus::encode::deserialize(&data).unwrap();
+        assert_eq!(tx.output.len(), 2);
+        assert!(tx.output[1].script_pubkey.is_op_return());
+        assert!(tx.output[1].script_pubkey.as_bytes().ends_with(b"104729"));
+        
+        assert!(matches!(
+            translator.translate(&deposit("ethereum", "0x00000000000000000000000000000000000000aa")),
+            Err(RouterError::UnsupportedAction(_))
+        ));
+    }
+    
+    #[test]
+    #[cfg(feature = "btc")]
+    fn test_bitcoin_recipient_must_match_network() {
+        const MAINNET: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        const TESTNET: &str = "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx";
+        let payment = |to: &str| {
+            Intent {
+                id: "network-1".to_string(),
+                from_chain: "sentium".to_string(),
+                to_chain: "bitcoin".to_string(),
+                action: String::new(),
+                params: vec![],
+                context: vec![],
+                expires_at: None,
+                signature: None,
+            }
+            .with_action(ActionType::Transfer {
+                from: "sentium1sender".to_string(),
+                to: to.to_string(),
+                amount: 50_000,
+                asset: "BTC".to_string(),
+                memo: None,
+            })
+        };
+        
+        let mainnet = IntentTranslator::new();
+        assert!(mainnet.translate(&payment(MAINNET)).is_ok());
+        assert!(matches!(
+            mainnet.translate(&payment(TESTNET)),
+            Err(RouterError::TranslationError(msg)) if msg.contains("not a bitcoin address")
+        ));
+        
+        let testnet = IntentTranslator::new().with_bitcoin_network(bitcoin::Network::Testnet);
+        assert!(testnet.translate(&payment(TESTNET)).is_ok());
+        assert!(testnet.translate(&payment(MAINNET)).is_err());
+        
+        assert_eq!(bitcoin_network(&bitcoin_chain_id(bitcoin::Network::Regtest)).unwrap(), bitcoin::Network::Regtest);
+        assert!(bitcoin_network("ethereum-1").is_err());
+    }
+    
+    fn action_intent(to_chain: &str, action: ActionType) -> Intent {
+        Intent {
+            id: format!("verify-{}", to_chain),
+            from_chain: "sentium".to_string(),
+            to_chain: to_chain.to_string(),
+            action: String::new(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        }
+        .with_action(action)
+    }
+    
+    fn transfer_to(to: &str, memo: Option<&str>) -> ActionType {
+        ActionType::Transfer {
+            from: "sentium1sender".to_string(),
+            to: to.to_string(),
+            amount: 50_000,
+            asset: "ATOM".to_string(),
+            memo: memo.map(str::to_string),
+        }
+    }
+    
+    #[test]
+    #[cfg(all(feature = "eth", feature = "dot", feature = "btc"))]
+    fn test_verify_translation_accepts_every_chain_encoding() {
+        let translator = IntentTranslator::new();
+        let swap = ActionType::Swap {
+            asset_in: "USDC".to_string(),
+            asset_out: "ETH".to_string(),
+            amount_in: 1_000_000,
+            expected_amount_out: 400,
+            min_amount_out: 0,
+        };
+        let intents = [
+            action_intent("ethereum", transfer_to("0x00000000000000000000000000000000000000aa", None)),
+            action_intent("ethereum", swap.clone()),
+            action_intent("polkadot", transfer_to("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", None)),
+            action_intent("cosmos", transfer_to("cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu", None)),
+            action_intent("cosmos", transfer_to("cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu", Some("104729"))),
+            action_intent("bitcoin", transfer_to("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", Some("104729"))),
+            action_intent("sentium", swap),
+        ];
+        
+        for intent in intents {
+            let translated = translator.translate(&intent).unwrap();
+            translator
+                .verify_translation(&translated)
+                .unwrap_or_else(|e| panic!("{} {}: {}", intent.to_chain, intent.action, e));
+        }
+    }
+    
+    #[test]
+    #[cfg(all(feature = "eth", feature = "dot", feature = "btc"))]
+    fn test_verify_translation_rejects_corrupted_payloads() {
+        let translator = IntentTranslator::new();
+        let rejects = |translated: &TranslatedIntent| {
+            matches!(translator.verify_translation(translated), Err(RouterError::VerificationError(_)))
+        };
+        
+        // EVM: the amount's low byte
+        let mut evm = translator
+            .translate(&action_intent("ethereum", transfer_to("0x00000000000000000000000000000000000000aa", None)))
+            .unwrap();
+        *evm.target_format.last_mut().unwrap() ^= 0x01;
+        assert!(rejects(&evm));
+        
+        // Substrate: one byte of the recipient account
+        let mut substrate = translator
+            .translate(&action_intent("polkadot", transfer_to("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", None)))
+            .unwrap();
+        substrate.target_format[10] ^= 0x01;
+        assert!(rejects(&substrate));
+        
+        // Cosmos: the recipient's last character
+        let mut cosmos = translator
+            .translate(&action_intent("cosmos", transfer_to("cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu", Some("104729"))))
+            .unwrap();
+        let json = String::from_utf8(cosmos.target_format.clone()).unwrap();
+        cosmos.target_format = json.replace("lzv7xu", "lzv7xv").into_bytes();
+        assert!(rejects(&cosmos));
+        
+        // Bitcoin: one sat less to the recipient
+        let mut btc = translator
+            .translate(&action_intent("bitcoin", transfer_to("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", None)))
+            .unwrap();
+        let mut tx: bitcoin::Transaction = bitcoin::consensus::encode::deserialize(&btc.target_format).unwrap();
+        tx.output[0].value = bitcoin::Amount::from_sat(49_999);
+        btc.target_format = bitcoin::consensus::encode::serialize(&tx);
+        assert!(rejects(&btc));
+        
+        // Sentium: the action inside the envelope pays someone else
+        let mut sentium = translator.translate(&action_intent("sentium", transfer_to("sentium1recipient", None))).unwrap();
+        let altered = serde_json::to_vec(&transfer_to("sentium1attacker", None)).unwrap();
+        sentium.target_format = SentiumEnvelope::unsigned(altered).encode();
+        assert!(rejects(&sentium));
+    }
 }
@@ -0,0 +1,238 @@
+// Transaction Journal - Write-ahead log of submissions, replayed by Router::recover after a crash
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use super::RouterError;
+use crate::core::context::TransactionStatus;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum JournalPhase {
+    Submitting, // About to call submit_transaction
+    Submitted,  // The chain returned a hash; not yet in the context history
+    Recorded,   // In the context history, nothing left to recover
+    Failed,     // Submission was rejected
+}
+
+impl JournalPhase {
+    /// Whether a crash at this phase leaves the submission's outcome unknown
+    pub fn is_in_doubt(self) -> bool {
+        matches!(self, JournalPhase::Submitting | JournalPhase::Submitted)
+    }
+}
+
+/// One step of a submission
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JournalEntry {
+    pub intent_id: String,
+    pub source_chain: String,
+    pub chain: String, // target chain the transaction goes to
+    pub context_id: String,
+    pub phase: JournalPhase,
+    pub tx_hash: Option<String>, // set from Submitted on
+    pub timestamp: u64,
+}
+
+/// Where journal entries are kept; entries are only ever appended
+///
+/// `append` must not return until the entry is durable, since it runs before the
+/// submission it describes.
+#[async_trait]
+pub trait TransactionJournal: Send + Sync {
+    async fn append(&self, entry: &JournalEntry) -> Result<(), RouterError>;
+    
+    /// Every entry, in the order appended
+    async fn entries(&self) -> Result<Vec<JournalEntry>, RouterError>;
+}
+
+/// Latest entry of each submission whose outcome is unknown, oldest first
+///
+/// A submission is an intent id and target chain; only its most recent entry counts.
+pub fn in_doubt(entries: &[JournalEntry]) -> Vec<JournalEntry> {
+    let mut latest: HashMap<(&str, &str), usize> = HashMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        latest.insert((&entry.intent_id, &entry.chain), index);
+    }
+    
+    let mut indices: Vec<usize> = latest
+        .into_values()
+        .filter(|&index| entries[index].phase.is_in_doubt())
+        .collect();
+    indices.sort_unstable();
+    indices.into_iter().map(|index| entries[index].clone()).collect()
+}
+
+/// Outcome of `Router::recover`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecoveryReport {
+    pub reconciled: Vec<(JournalEntry, TransactionStatus)>, // status now in the context history
+    pub unresolved: Vec<JournalEntry>, // no hash, or the chain couldn't be asked; still in doubt
+}
+
+/// Appends entries to a file as JSON lines, syncing each one to disk
+pub struct FileJournal {
+    path: PathBuf,
+    tail_checked: Mutex<bool>, // held across appends so lines don't interleave
+}
+
+impl FileJournal {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            tail_checked: Mutex::new(false),
+        }
+    }
+    
+    /// Cut off a partial last line left by a crash mid-append, so new entries start clean
+    async fn truncate_torn_tail(&self) -> Result<(), RouterError> {
+        let contents = match tokio::fs::read(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(journal_error("read", e)),
+        };
+        if contents.last().is_none_or(|&byte| byte == b'\n') {
+            return Ok(());
+        }
+        
+        let keep = contents.iter().rposition(|&byte| byte == b'\n').map_or(0, |newline| newline + 1);
+        let file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| journal_error("open", e))?;
+        file.set_len(keep as u64).await.map_err(|e| journal_error("truncate", e))?;
+        file.sync_data().await.map_err(|e| journal_error("sync", e))
+    }
+}
+
+#[async_trait]
+impl TransactionJournal for FileJournal {
+    async fn append(&self, entry: &JournalEntry) -> Result<(), RouterError> {
+        let mut line = serde_json::to_vec(entry).map_err(|e| journal_error("encode", e))?;
+        line.push(b'\n');
+        
+        let mut tail_checked = self.tail_checked.lock().await;
+        if !*tail_checked {
+            self.truncate_torn_tail().await?;
+            *tail_checked = true;
+        }
+        
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| journal_error("open", e))?;
+        file.write_all(&line).await.map_err(|e| journal_error("write", e))?;
+        file.sync_data().await.map_err(|e| journal_error("sync", e))
+    }
+    
+    async fn entries(&self) -> Result<Vec<JournalEntry>, RouterError> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(journal_error("read", e)),
+        };
+        
+        // A crash mid-append can leave a partial last line until the next append cuts it off
+        let lines: Vec<&str> = contents.lines().filter(|line| !line.trim().is_empty()).collect();
+        let mut entries = Vec::with_capacity(lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            match serde_json::from_str(line) {
+                Ok(entry) => entries.push(entry),
+                Err(_) if index + 1 == lines.len() && !contents.ends_with('\n') => break,
+                Err(e) => return Err(journal_error("decode", e)),
+            }
+        }
+        Ok(entries)
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryJournal {
+    entries: Mutex<Vec<JournalEntry>>,
+}
+
+impl InMemoryJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TransactionJournal for InMemoryJournal {
+    async fn append(&self, entry: &JournalEntry) -> Result<(), RouterError> {
+        self.entries.lock().await.push(entry.clone());
+        Ok(())
+    }
+    
+    async fn entries(&self) -> Result<Vec<JournalEntry>, RouterError> {
+        Ok(self.entries.lock().await.clone())
+    }
+}
+
+fn journal_error(action: &str, e: impl std::fmt::Display) -> RouterError {
+    RouterError::RoutingError(format!("Failed to {} transaction journal: {}", action, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn entry(intent_id: &str, phase: JournalPhase) -> JournalEntry {
+        JournalEntry {
+            intent_id: intent_id.to_string(),
+            source_chain: "sentium".to_string(),
+            chain: "ethereum".to_string(),
+            context_id: format!("ctx-{}", intent_id),
+            phase,
+            tx_hash: (phase != JournalPhase::Submitting).then(|| format!("0x{}", intent_id)),
+            timestamp: 1_700_000_000,
+        }
+    }
+    
+    #[test]
+    fn test_in_doubt_uses_latest_phase() {
+        let entries = vec![
+            entry("a", JournalPhase::Submitting),
+            entry("b", JournalPhase::Submitting),
+            entry("a", JournalPhase::Submitted),
+            entry("c", JournalPhase::Submitting),
+            entry("c", JournalPhase::Failed),
+            entry("b", JournalPhase::Submitted),
+            entry("b", JournalPhase::Recorded),
+        ];
+        
+        assert_eq!(in_doubt(&entries), vec![entry("a", JournalPhase::Submitted)]);
+    }
+    
+    #[tokio::test]
+    async fn test_file_journal_ignores_torn_last_line() {
+        let path = std::env::temp_dir().join(format!("sentium-journal-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let journal = FileJournal::new(&path);
+        assert!(journal.entries().await.unwrap().is_empty());
+        
+        journal.append(&entry("a", JournalPhase::Submitting)).await.unwrap();
+        journal.append(&entry("a", JournalPhase::Submitted)).await.unwrap();
+        
+        // Crash partway through the next append
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        std::io::Write::write_all(&mut file, br#"{"intent_id":"a","sou"#).unwrap();
+        
+        assert_eq!(
+            journal.entries().await.unwrap(),
+            vec![entry("a", JournalPhase::Submitting), entry("a", JournalPhase::Submitted)]
+        );
+        
+        // After a restart the first append replaces the torn line
+        let journal = FileJournal::new(&path);
+        journal.append(&entry("a", JournalPhase::Recorded)).await.unwrap();
+        assert_eq!(journal.entries().await.unwrap().len(), 3);
+        assert!(in_doubt(&journal.entries().await.unwrap()).is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -4,16 +4,93 @@
 pub mod intent_translator;
 pub mod chain_adapter;
 pub mod routing_logic;
+pub mod nonce_manager;
+pub mod confirmation;
+pub mod signer;
+#[cfg(feature = "eth")]
+pub mod intent;
+#[cfg(feature = "eth")]
+pub mod gas;
+#[cfg(feature = "cosmos")]
+pub mod ibc;
+#[cfg(feature = "dot")]
+pub mod polkadot_adapter;
+#[cfg(any(feature = "somnia", feature = "harmony"))]
+pub mod block_subscription;
+#[cfg(feature = "chia")]
+pub mod chia_adapter;
+#[cfg(feature = "harmony")]
+pub mod harmony_adapter;
+#[cfg(feature = "somnia")]
+pub mod somnia_adapter;
+#[cfg(feature = "starknet")]
+pub mod starknet_adapter;
+pub mod address;
+pub mod slippage;
+pub mod authorizer;
+pub mod rate_limit;
+pub mod price_oracle;
+pub mod schema;
+pub mod circuit_breaker;
+pub mod intent_tracker;
+pub mod sentium_envelope;
+pub mod shutdown;
+pub mod journal;
+pub mod route_executor;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
+use crate::core::clock::{system_clock, Clock};
+use crate::core::config::{BridgeConfig, ChainConfig, DefaultSettings};
+use crate::core::context::{ContextError, ContextPreserver, SemanticContext, TransactionRecord, TransactionStatus, UserPreferences};
+
 pub use intent_translator::{IntentTranslator, TranslatedIntent, ActionType};
-pub use chain_adapter::{ChainAdapter, EthereumAdapter, PolkadotAdapter, BitcoinAdapter, CosmosAdapter, SentiumAdapter};
-pub use routing_logic::{RoutingEngine, Route, RouteHop, BridgeType};
+pub use chain_adapter::{Capabilities, ChainAdapter, ChainMetadata, SentiumAdapter, SimulationResult, UnifiedReceipt};
+#[cfg(feature = "eth")]
+pub use chain_adapter::{EthereumAdapter, Permit, TxType};
+#[cfg(feature = "dot")]
+pub use chain_adapter::PolkadotAdapter;
+#[cfg(feature = "dot")]
+pub use polkadot_adapter::{RealSubstrateAdapter, SigningParams, TransferKind, MORTAL_PERIOD};
+#[cfg(feature = "chia")]
+pub use chia_adapter::{ChiaNetwork, RealChiaAdapter};
+#[cfg(feature = "harmony")]
+pub use harmony_adapter::{HarmonyAddressConverter, RealHarmonyAdapter};
+#[cfg(feature = "somnia")]
+pub use somnia_adapter::RealSomniaAdapter;
+#[cfg(feature = "starknet")]
+pub use starknet_adapter::RealStarkNetAdapter;
+#[cfg(feature = "btc")]
+pub use chain_adapter::{BitcoinAdapter, ChangeAddressProvider};
+#[cfg(feature = "cosmos")]
+pub use chain_adapter::CosmosAdapter;
+pub use routing_logic::{RoutingEngine, Route, RouteHop, BridgeType, ConnectionRecord, HopQuote, HopSimulation, RouteQuote, RouteSimulation};
+pub use nonce_manager::{NonceManager, NonceSender, SubstrateNonceManager, SubstrateNonceSource};
+pub use confirmation::{BlockRef, ConfirmationPolicy, ConfirmationStatus, ConfirmationTracker};
+pub use signer::{LocalSigner, Signer};
+pub use address::AddressValidator;
+pub use slippage::SlippageCalculator;
+pub use authorizer::{AllowAll, Authorizer, ANONYMOUS_PRINCIPAL};
+#[cfg(feature = "eth")]
+pub use authorizer::Eip712SignatureAuthorizer;
+#[cfg(feature = "eth")]
+pub use gas::{Capped, Economy, Fast, FeeSource, Fixed, GasSettings, GasStrategy, Standard};
+pub use rate_limit::{RateLimiter, TokenBucketLimiter};
+pub use price_oracle::{CoinGeckoOracle, PriceOracle};
+pub use circuit_breaker::{CircuitBreakers, CircuitState};
+pub use intent_tracker::{IntentStatus, IntentTracker, StatusTransition};
+pub use sentium_envelope::SentiumEnvelope;
+pub use shutdown::ShutdownReport;
+pub use journal::{FileJournal, InMemoryJournal, JournalEntry, JournalPhase, RecoveryReport, TransactionJournal};
+pub use route_executor::{HopProgress, RouteExecution, RouteExecutor};
+
+use shutdown::{InFlight, DEFAULT_SHUTDOWN_TIMEOUT};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Intent {
     pub id: String,
     pub from_chain: String,
@@ -21,13 +98,66 @@ pub struct Intent {
     pub action: String,
     pub params: Vec<u8>,
     pub context: Vec<u8>,
+    #[serde(default)]
+    pub expires_at: Option<u64>, // Unix seconds; None never expires
+    #[serde(default)]
+    pub signature: Option<Vec<u8>>, // EIP-712 signature over the other fields, if signed
+}
+
+impl Intent {
+    /// Whether the deadline has passed at `now` (Unix seconds)
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|deadline| now > deadline)
+    }
+    
+    /// Set `action` and encode `params` as the JSON of `action`, the canonical params format
+    pub fn with_action(mut self, action: ActionType) -> Self {
+        self.action = action.name().to_string();
+        self.params = serde_json::to_vec(&action).expect("ActionType always serializes to JSON");
+        self
+    }
+    
+    /// Decode `params` written by `with_action`, checking it agrees with `action`
+    pub fn decode_action(&self) -> Result<ActionType, RouterError> {
+        let action: ActionType = serde_json::from_slice(&self.params)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid action params: {}", e)))?;
+        
+        if action.name() != self.action {
+            return Err(RouterError::TranslationError(format!(
+                "Params encode a {} action but the intent says {}",
+                action.name(),
+                self.action
+            )));
+        }
+        
+        Ok(action)
+    }
+}
+
+/// Persistable snapshot of a router, without live adapter connections
+///
+/// Adapters are rebuilt from config separately; `chains` only records which were registered.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RouterState {
+    pub chains: Vec<String>,        // chains with a registered adapter
+    pub graph_chains: Vec<String>,  // nodes of the routing graph
+    pub connections: Vec<ConnectionRecord>,
 }
 
+/// Clones share all state, so any clone can be handed to `shutdown` while others route
+#[derive(Clone)]
 pub struct Router {
     adapters: Arc<RwLock<Vec<Arc<dyn ChainAdapter>>>>,
-    #[allow(dead_code)]
     translator: Arc<IntentTranslator>,
     routing_engine: Arc<RwLock<RoutingEngine>>,
+    clock: Arc<dyn Clock>,
+    authorizer: Arc<dyn Authorizer>,
+    rate_limiter: Option<Arc<dyn RateLimiter>>,
+    circuit_breakers: Arc<CircuitBreakers>,
+    in_flight: Arc<InFlight>,
+    context_preserver: Option<Arc<ContextPreserver>>,
+    shutdown_timeout: Duration,
+    journal: Option<Arc<dyn TransactionJournal>>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -43,6 +173,30 @@ pub enum RouterError {
     
     #[error("Routing failed: {0}")]
     RoutingError(String),
+    
+    #[error("Intent expired: {0}")]
+    IntentExpired(String),
+    
+    #[error("Action not supported: {0}")]
+    UnsupportedAction(String),
+    
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+    
+    #[error("Rate limited: {origin}, retry after {retry_after_secs}s")]
+    RateLimited { origin: String, retry_after_secs: u64 },
+    
+    #[error("Circuit open for {chain}, retry after {retry_after_secs}s")]
+    CircuitOpen { chain: String, retry_after_secs: u64 },
+    
+    #[error("Chain unavailable: {0}")]
+    ChainUnavailable(String),
+    
+    #[error("Router is shutting down")]
+    ShuttingDown,
+    
+    #[error("Chain id mismatch: expected {expected}, got {got}")]
+    ChainIdMismatch { expected: u64, got: u64 },
 }
 
 impl Router {
@@ -51,15 +205,179 @@ impl Router {
             adapters: Arc::new(RwLock::new(Vec::new())),
             translator: Arc::new(IntentTranslator::new()),
             routing_engine: Arc::new(RwLock::new(RoutingEngine::new())),
+            clock: system_clock(),
+            authorizer: Arc::new(AllowAll),
+            rate_limiter: None,
+            circuit_breakers: Arc::new(CircuitBreakers::default()),
+            in_flight: Arc::new(InFlight::default()),
+            context_preserver: None,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            journal: None,
         }
     }
     
+    /// Build a router with an adapter for every enabled chain in the config
+    ///
+    /// The config's default slippage tolerance applies to every translation, and its
+    /// maximum gas price caps what EVM adapters bid.
+    pub fn from_config(config: &BridgeConfig) -> Result<Self, RouterError> {
+        let translator = IntentTranslator::new().with_default_slippage(slippage_bps(config.defaults.slippage_tolerance)?);
+        #[cfg(feature = "btc")]
+        let translator = match config.chains.get("bitcoin").filter(|chain| chain.enabled) {
+            Some(chain) => translator.with_bitcoin_network(configured_bitcoin_network(chain)?),
+            None => translator,
+        };
+        let translator = Arc::new(translator);
+        
+        let adapters = config
+            .enabled_chains()
+            .map(|(name, chain)| adapter_from_config(name, chain, &config.defaults, translator.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(Self {
+            adapters: Arc::new(RwLock::new(adapters)),
+            translator,
+            ..Self::new()
+        })
+    }
+    
+    /// Use a different time source for recorded transaction timestamps
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Check every intent with `authorizer` before routing it; the default allows all
+    pub fn with_authorizer(mut self, authorizer: Arc<dyn Authorizer>) -> Self {
+        self.authorizer = authorizer;
+        self
+    }
+    
+    /// Limit how many intents each principal the authorizer verifies may route
+    pub fn with_rate_limiter(mut self, limiter: Arc<dyn RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+    
+    /// Open a chain's circuit after `failure_threshold` consecutive adapter failures, for `cooldown_secs`
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown_secs: u64) -> Self {
+        self.circuit_breakers = Arc::new(CircuitBreakers::new(failure_threshold, cooldown_secs));
+        self
+    }
+    
+    /// Close `preserver` on shutdown, flushing its write-behind queue
+    pub fn with_context_preserver(mut self, preserver: Arc<ContextPreserver>) -> Self {
+        self.context_preserver = Some(preserver);
+        self
+    }
+    
+    /// How long `shutdown` waits for in-flight intents before giving up on them
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+    
+    /// Log every `submit_with_context` and `RouteExecutor` hop submission to `journal`, so
+    /// `recover` can finish it after a crash
+    pub fn with_journal(mut self, journal: Arc<dyn TransactionJournal>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+    
+    /// Circuit breaker state for a chain
+    pub fn circuit_state(&self, chain: &str) -> CircuitState {
+        self.circuit_breakers.state(chain)
+    }
+    
     pub async fn add_adapter(&self, adapter: Arc<dyn ChainAdapter>) {
         let mut adapters = self.adapters.write().await;
         adapters.push(adapter);
     }
     
+    /// Look up the adapter registered for a chain
+    pub async fn adapter(&self, chain: &str) -> Option<Arc<dyn ChainAdapter>> {
+        let adapters = self.adapters.read().await;
+        adapters.iter().find(|a| a.chain_name() == chain).cloned()
+    }
+    
+    /// Names of all chains with a registered adapter
+    pub async fn supported_chains(&self) -> Vec<String> {
+        let adapters = self.adapters.read().await;
+        adapters.iter().map(|a| a.chain_name().to_string()).collect()
+    }
+    
     pub async fn route_intent(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
+        // Held until routing finishes so `shutdown` can wait for it
+        let _in_flight = self.in_flight.enter(&intent.id).ok_or(RouterError::ShuttingDown)?;
+        
+        // Refuse stale intents before doing any work for them
+        self.check_expiry(intent)?;
+        
+        let principal = self.authorizer.authorize(intent).await?;
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(&principal).await?;
+        }
+        
+        self.translate_routed(intent).await
+    }
+    
+    /// Fan a split intent out as one transfer per output
+    ///
+    /// The intent is checked once, and the outputs' total against the sender's balance
+    /// on the source chain. Each output is then routed on its own as intent
+    /// `{id}:{index}`, so one failing output doesn't stop the rest.
+    pub async fn route_split(&self, intent: &Intent) -> Result<Vec<Result<TranslatedIntent, RouterError>>, RouterError> {
+        let _in_flight = self.in_flight.enter(&intent.id).ok_or(RouterError::ShuttingDown)?;
+        self.check_expiry(intent)?;
+        
+        let principal = self.authorizer.authorize(intent).await?;
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(&principal).await?;
+        }
+        
+        let ActionType::Split { from, asset, outputs } = intent.decode_action()? else {
+            return Err(RouterError::UnsupportedAction(format!("{} is not a split", intent.action)));
+        };
+        let total = intent_translator::split_total(&outputs)?;
+        
+        let source = self
+            .adapter(&intent.from_chain)
+            .await
+            .ok_or_else(|| RouterError::UnsupportedChain(intent.from_chain.clone()))?;
+        let available = self
+            .call_adapter(&intent.from_chain, source.query_balance(&from, &asset))
+            .await?;
+        if total > available {
+            return Err(RouterError::RoutingError(format!(
+                "Split of {} {} exceeds the {} available to {}",
+                total, asset, available, from
+            )));
+        }
+        
+        let mut results = Vec::with_capacity(outputs.len());
+        for (index, (chain, address, amount)) in outputs.into_iter().enumerate() {
+            let leg = Intent {
+                id: format!("{}:{}", intent.id, index),
+                to_chain: chain,
+                signature: None,
+                ..intent.clone()
+            }
+            .with_action(ActionType::Transfer {
+                from: from.clone(),
+                to: address,
+                amount,
+                asset: asset.clone(),
+                memo: None,
+            });
+            results.push(self.translate_routed(&leg).await);
+        }
+        
+        Ok(results)
+    }
+    
+    /// Route and translate an intent that has already passed expiry, authorization and
+    /// rate limiting
+    async fn translate_routed(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
         // Find optimal route
         let mut routing_engine = self.routing_engine.write().await;
         let _route = routing_engine.find_route(intent)
@@ -72,8 +390,405 @@ impl Router {
             .find(|a| a.chain_name() == intent.to_chain)
             .ok_or_else(|| RouterError::UnsupportedChain(intent.to_chain.clone()))?;
         
+        if !adapter.capabilities().supports_action(&intent.action) {
+            return Err(RouterError::UnsupportedAction(format!(
+                "{} cannot {}",
+                intent.to_chain, intent.action
+            )));
+        }
+        
+        // Don't route to a chain whose node keeps failing; translation is offline, so its
+        // errors are about the intent and don't count against the chain
+        self.circuit_breakers.check_routable(&intent.to_chain, self.clock.now_secs())?;
+        
         // Translate intent
-        adapter.translate_intent(intent).await
+        let translated = adapter.translate_intent(intent).await?;
+        
+        // Don't hand off a payload that no longer matches its recorded hash
+        if !translated.verify_hash() {
+            return Err(RouterError::VerificationError(format!(
+                "Translation hash mismatch for intent {}",
+                intent.id
+            )));
+        }
+        
+        Ok(translated)
+    }
+    
+    /// Translate an intent for signing without touching any adapter or network
+    ///
+    /// Only the router's `IntentTranslator` is used, so no adapter needs to be registered
+    /// for the target chain. Authorization, rate limits and circuit breakers are left to
+    /// `route_intent`; expiry is still enforced, and the payload is decoded back and checked
+    /// against the intent's action.
+    pub fn translate_offline(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
+        self.check_expiry(intent)?;
+        
+        let translated = self.translator.translate(intent)?;
+        if !translated.verify_hash() {
+            return Err(RouterError::VerificationError(format!(
+                "Translation hash mismatch for intent {}",
+                intent.id
+            )));
+        }
+        self.verify_translation_matches_intent(&translated)?;
+        
+        Ok(translated)
+    }
+    
+    /// Decode a translation's payload for its target chain and check it still carries the
+    /// original intent's recipient, amount and asset
+    ///
+    /// Meant to run between translation and submission, so a translator bug can't send
+    /// funds somewhere the user didn't ask. The hash check alone can't catch that, since
+    /// the hash is computed over whatever payload the translator produced.
+    pub fn verify_translation_matches_intent(&self, ti: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(ti)
+    }
+    
+    /// Run an adapter call that reaches `chain`'s node through its circuit breaker
+    ///
+    /// Only `ChainUnavailable` counts as a failure. Other errors are about the request, so
+    /// they neither close nor reopen a half-open circuit; they hand its probe back.
+    async fn call_adapter<T>(
+        &self,
+        chain: &str,
+        call: impl std::future::Future<Output = Result<T, RouterError>>,
+    ) -> Result<T, RouterError> {
+        self.circuit_breakers.check(chain, self.clock.now_secs())?;
+        
+        let result = call.await;
+        match &result {
+            Ok(_) => self.circuit_breakers.record_success(chain),
+            Err(RouterError::ChainUnavailable(_)) => self.circuit_breakers.record_failure(chain, self.clock.now_secs()),
+            Err(_) => self.circuit_breakers.release_probe(chain),
+        }
+        result
+    }
+    
+    fn check_expiry(&self, intent: &Intent) -> Result<(), RouterError> {
+        if let Some(deadline) = intent.expires_at {
+            let now = self.clock.now_secs();
+            if intent.is_expired(now) {
+                return Err(RouterError::IntentExpired(format!(
+                    "{} expired at {}, now {}",
+                    intent.id, deadline, now
+                )));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Route an intent and record it in its `SemanticContext`, creating the context if needed
+    ///
+    /// The context is the one named by `intent.context` (UTF-8 id) or else the one derived
+    /// from the intent's id and chains. Its id is written back to `intent.context`, and the
+    /// routing is recorded as a pending transaction on the target chain with no hash yet,
+    /// which `submit_with_context` fills in, or a failed one if routing fails.
+    pub async fn route_with_context(
+        &self,
+        intent: &mut Intent,
+        preserver: &ContextPreserver,
+    ) -> Result<TranslatedIntent, RouterError> {
+        // Covers the context save too, so a shutdown flush doesn't miss it
+        let _in_flight = self.in_flight.enter(&intent.id).ok_or(RouterError::ShuttingDown)?;
+        let context_error = |e: ContextError| RouterError::RoutingError(format!("Context error: {}", e));
+        
+        let context_id = context_id_for(intent);
+        let mut context = match preserver.load_context(&context_id).await {
+            Ok(context) => context,
+            Err(ContextError::NotFound(_)) => SemanticContext::new_with_clock(
+                intent.id.clone(),
+                intent.from_chain.clone(),
+                intent.to_chain.clone(),
+                UserPreferences::default(),
+                self.clock.as_ref(),
+            ),
+            Err(e) => return Err(context_error(e)),
+        };
+        intent.context = context.id.clone().into_bytes();
+        
+        let result = self.route_intent(intent).await;
+        
+        let status = match &result {
+            Ok(_) => TransactionStatus::Pending,
+            Err(_) => TransactionStatus::Failed,
+        };
+        context.add_transaction(TransactionRecord {
+            chain: intent.to_chain.clone(),
+            tx_hash: String::new(),
+            status,
+            timestamp: self.clock.now_secs(),
+        });
+        preserver.save_context(context).await.map_err(context_error)?;
+        
+        result
+    }
+    
+    /// Submit a translated intent and record its hash in the intent's `SemanticContext`
+    ///
+    /// Expiry, authorization and the translation hash are checked again first, since the
+    /// translation may have been held or handed around since it was routed, and the target
+    /// chain's adapter decodes the payload to check it against the intent. The context is
+    /// chosen as in `route_with_context`. With a journal configured, a
+    /// `Submitting` entry is written before the adapter is called, `Submitted` as soon as it
+    /// returns a hash and `Recorded` once the context is saved, so `recover` can finish a
+    /// submission interrupted anywhere in between.
+    pub async fn submit_with_context(
+        &self,
+        translated: &TranslatedIntent,
+        preserver: &ContextPreserver,
+    ) -> Result<String, RouterError> {
+        let intent = &translated.original_intent;
+        let _in_flight = self.in_flight.enter(&intent.id).ok_or(RouterError::ShuttingDown)?;
+        
+        // Nothing is journaled for a submission refused here
+        self.check_expiry(intent)?;
+        self.authorizer.authorize(intent).await?;
+        if !translated.verify_hash() {
+            return Err(RouterError::VerificationError(format!(
+                "Translation hash mismatch for intent {}",
+                intent.id
+            )));
+        }
+        
+        let adapter = self
+            .adapter(&intent.to_chain)
+            .await
+            .ok_or_else(|| RouterError::UnsupportedChain(intent.to_chain.clone()))?;
+        adapter.verify_translation(translated)?;
+        
+        let mut entry = JournalEntry {
+            intent_id: intent.id.clone(),
+            source_chain: intent.from_chain.clone(),
+            chain: intent.to_chain.clone(),
+            context_id: context_id_for(intent),
+            phase: JournalPhase::Submitting,
+            tx_hash: None,
+            timestamp: self.clock.now_secs(),
+        };
+        if let Some(journal) = &self.journal {
+            journal.append(&entry).await?;
+        }
+        
+        let tx_hash = match self
+            .call_adapter(&intent.to_chain, adapter.submit_transaction(&translated.target_format))
+            .await
+        {
+            Ok(tx_hash) => tx_hash,
+            Err(e) => {
+                if let Some(journal) = &self.journal {
+                    entry.phase = JournalPhase::Failed;
+                    entry.timestamp = self.clock.now_secs();
+                    if let Err(journal_error) = journal.append(&entry).await {
+                        tracing::warn!("Failed to journal rejected submission of {}: {}", intent.id, journal_error);
+                    }
+                }
+                return Err(e);
+            }
+        };
+        entry.tx_hash = Some(tx_hash.clone());
+        
+        if let Some(journal) = &self.journal {
+            entry.phase = JournalPhase::Submitted;
+            entry.timestamp = self.clock.now_secs();
+            journal.append(&entry).await?;
+        }
+        self.record_submission(preserver, &entry, TransactionStatus::Pending).await?;
+        if let Some(journal) = &self.journal {
+            entry.phase = JournalPhase::Recorded;
+            entry.timestamp = self.clock.now_secs();
+            journal.append(&entry).await?;
+        }
+        
+        Ok(tx_hash)
+    }
+    
+    /// Finish submissions a crash left in doubt, asking each chain what became of them
+    ///
+    /// Run at startup, before routing resumes. Each in-doubt hash is looked up with
+    /// `ChainAdapter::transaction_status` and written to its context history, added if the
+    /// crash came before it was saved and updated otherwise; the journal then marks it
+    /// `Recorded`. Submissions without a hash, or whose chain can't be asked, are left in
+    /// doubt and returned as unresolved.
+    pub async fn recover(
+        &self,
+        journal: &dyn TransactionJournal,
+        preserver: &ContextPreserver,
+    ) -> Result<RecoveryReport, RouterError> {
+        let mut report = RecoveryReport::default();
+        
+        for mut entry in journal::in_doubt(&journal.entries().await?) {
+            let (tx_hash, adapter) = match (&entry.tx_hash, self.adapter(&entry.chain).await) {
+                (Some(tx_hash), Some(adapter)) => (tx_hash.clone(), adapter),
+                _ => {
+                    report.unresolved.push(entry);
+                    continue;
+                }
+            };
+            
+            let status = match self.call_adapter(&entry.chain, adapter.transaction_status(&tx_hash)).await {
+                Ok(status) => status,
+                Err(e) => {
+                    tracing::warn!("Can't recover {} on {}: {}", tx_hash, entry.chain, e);
+                    report.unresolved.push(entry);
+                    continue;
+                }
+            };
+            
+            self.record_submission(preserver, &entry, status.clone()).await?;
+            entry.phase = JournalPhase::Recorded;
+            entry.timestamp = self.clock.now_secs();
+            journal.append(&entry).await?;
+            report.reconciled.push((entry, status));
+        }
+        
+        Ok(report)
+    }
+    
+    /// Write a submitted transaction's status into its context, replacing an earlier record
+    /// of it or else the pending, hashless one `route_with_context` left for the chain
+    async fn record_submission(
+        &self,
+        preserver: &ContextPreserver,
+        entry: &JournalEntry,
+        status: TransactionStatus,
+    ) -> Result<(), RouterError> {
+        let context_error = |e: ContextError| RouterError::RoutingError(format!("Context error: {}", e));
+        let tx_hash = entry.tx_hash.clone().unwrap_or_default();
+        
+        let mut context = match preserver.load_context(&entry.context_id).await {
+            Ok(context) => context,
+            Err(ContextError::NotFound(_)) => SemanticContext::new_with_clock(
+                entry.intent_id.clone(),
+                entry.source_chain.clone(),
+                entry.chain.clone(),
+                UserPreferences::default(),
+                self.clock.as_ref(),
+            ),
+            Err(e) => return Err(context_error(e)),
+        };
+        
+        let existing = match context
+            .transaction_history
+            .iter()
+            .position(|record| record.chain == entry.chain && record.tx_hash == tx_hash)
+        {
+            Some(index) => Some(index),
+            None => context.transaction_history.iter().position(|record| {
+                record.chain == entry.chain && record.tx_hash.is_empty() && record.status == TransactionStatus::Pending
+            }),
+        };
+        match existing.map(|index| &mut context.transaction_history[index]) {
+            Some(record) => {
+                record.tx_hash = tx_hash;
+                record.status = status;
+                record.timestamp = self.clock.now_secs();
+                context.update_integrity_hash();
+            }
+            None => context.add_transaction(TransactionRecord {
+                chain: entry.chain.clone(),
+                tx_hash,
+                status,
+                timestamp: self.clock.now_secs(),
+            }),
+        }
+        
+        preserver.save_context(context).await.map_err(context_error)
+    }
+    
+    /// Route an intent only if it carries a valid EIP-712 signature by `signer`
+    #[cfg(feature = "eth")]
+    pub async fn route_signed_intent(
+        &self,
+        intent: &Intent,
+        signature: &[u8],
+        signer: ethers::types::Address,
+    ) -> Result<TranslatedIntent, RouterError> {
+        if !intent::verify_intent_signature(intent, signature, signer)? {
+            return Err(RouterError::VerificationError(format!(
+                "Intent {} is not signed by {:?}",
+                intent.id, signer
+            )));
+        }
+        
+        self.route_intent(intent).await
+    }
+    
+    /// Verify a state/inclusion proof with the adapter for `chain_id` (chain name or id)
+    pub async fn verify_inclusion(&self, chain_id: &str, proof: &[u8]) -> Result<bool, RouterError> {
+        let adapter = {
+            let adapters = self.adapters.read().await;
+            adapters
+                .iter()
+                .find(|a| a.chain_name() == chain_id || a.chain_id() == chain_id)
+                .cloned()
+                .ok_or_else(|| RouterError::UnsupportedChain(chain_id.to_string()))?
+        };
+        
+        adapter.verify_state(proof).await
+    }
+    
+    /// Verify a proof and, if it holds, record the transaction as confirmed in the context
+    pub async fn verify_and_record(
+        &self,
+        chain_id: &str,
+        proof: &[u8],
+        tx_hash: &str,
+        context: &mut SemanticContext,
+    ) -> Result<bool, RouterError> {
+        let verified = self.verify_inclusion(chain_id, proof).await?;
+        
+        if verified {
+            context.add_transaction(TransactionRecord {
+                chain: chain_id.to_string(),
+                tx_hash: tx_hash.to_string(),
+                status: TransactionStatus::Confirmed,
+                timestamp: self.clock.now_secs(),
+            });
+        }
+        
+        Ok(verified)
+    }
+    
+    /// Stop accepting intents, drain the ones in flight, then flush storage and close adapters
+    ///
+    /// New `route_intent` calls on any clone fail with `RouterError::ShuttingDown` from the
+    /// moment this is called. In-flight intents get up to the shutdown timeout to finish;
+    /// the report lists those that didn't, along with any flush or close failures.
+    pub async fn shutdown(self) -> ShutdownReport {
+        self.in_flight.close();
+        
+        let mut report = ShutdownReport {
+            abandoned_intents: self.in_flight.drain(self.shutdown_timeout).await,
+            ..ShutdownReport::default()
+        };
+        if !report.abandoned_intents.is_empty() {
+            tracing::warn!(
+                "Shutting down with {} intents still routing",
+                report.abandoned_intents.len()
+            );
+        }
+        
+        if let Some(preserver) = &self.context_preserver {
+            if let Err(e) = preserver.close().await {
+                report.context_flush_error = Some(e.to_string());
+            }
+        }
+        
+        let adapters = self.adapters.read().await.clone();
+        for adapter in adapters {
+            if let Err(e) = adapter.close().await {
+                report.adapter_errors.push((adapter.chain_name().to_string(), e.to_string()));
+            }
+        }
+        
+        report
+    }
+    
+    /// Whether `shutdown` has been called on this router or a clone of it
+    pub fn is_shutting_down(&self) -> bool {
+        self.in_flight.is_closed()
     }
     
     pub async fn find_route(&self, intent: &Intent) -> Result<Route, RouterError> {
@@ -82,12 +797,209 @@ impl Router {
             .map_err(|e| RouterError::RoutingError(e.to_string()))
     }
     
+    /// Quote every cost of routing an intent: per-hop gas from each hop's adapter plus bridge fees
+    pub async fn quote_route(&self, intent: &Intent) -> Result<RouteQuote, RouterError> {
+        let route = self.find_route(intent).await?;
+        let amount_in = self.translator.input_amount(intent)?;
+        
+        let mut hops = Vec::with_capacity(route.hops.len());
+        for hop in route.hops {
+            let adapter = self
+                .adapter(&hop.to_chain)
+                .await
+                .ok_or_else(|| RouterError::UnsupportedChain(hop.to_chain.clone()))?;
+            
+            // Each leg executes on its destination chain, so that adapter prices it
+            let leg = Intent {
+                from_chain: hop.from_chain.clone(),
+                to_chain: hop.to_chain.clone(),
+                ..intent.clone()
+            };
+            let translated = adapter.translate_intent(&leg).await?;
+            
+            hops.push(HopQuote {
+                gas_estimate: translated.translation_metadata.gas_estimate,
+                bridge_fee: hop.cost,
+                fee_token: adapter.capabilities().native_token,
+                from_chain: hop.from_chain,
+                to_chain: hop.to_chain,
+                bridge_type: hop.bridge_type,
+            });
+        }
+        
+        Ok(RouteQuote::new(&intent.id, hops, amount_in))
+    }
+    
+    /// Dry-run an intent's route hop by hop, without submitting anything
+    ///
+    /// Each hop after the first is simulated with the amount the previous hop is expected
+    /// to deliver, when that adapter reports one and the intent carries its action in
+    /// params. Stops at the first hop that would fail.
+    pub async fn simulate_route(&self, intent: &Intent) -> Result<RouteSimulation, RouterError> {
+        let route = self.find_route(intent).await?;
+        let mut amount_in = self.translator.input_amount(intent)?;
+        
+        let mut hops = Vec::with_capacity(route.hops.len());
+        for hop in route.hops {
+            let adapter = self
+                .adapter(&hop.to_chain)
+                .await
+                .ok_or_else(|| RouterError::UnsupportedChain(hop.to_chain.clone()))?;
+            
+            let mut leg = Intent {
+                from_chain: hop.from_chain.clone(),
+                to_chain: hop.to_chain.clone(),
+                ..intent.clone()
+            };
+            if let Ok(action) = leg.decode_action() {
+                leg = leg.with_action(with_input_amount(action, amount_in));
+            }
+            
+            let translated = adapter.translate_intent(&leg).await?;
+            let result = self
+                .call_adapter(&hop.to_chain, adapter.simulate_transaction(&translated))
+                .await?;
+            
+            hops.push(HopSimulation {
+                from_chain: hop.from_chain,
+                to_chain: hop.to_chain,
+                amount_in,
+                success: result.success,
+                gas_used: result.gas_used,
+                amount_out: result.amount_out,
+                revert_reason: result.revert_reason,
+            });
+            if !result.success {
+                break;
+            }
+            amount_in = result.amount_out.unwrap_or(amount_in);
+        }
+        
+        Ok(RouteSimulation::new(&intent.id, hops))
+    }
+    
+    /// Snapshot the registered chains and routing graph
+    pub async fn export_state(&self) -> RouterState {
+        let mut chains = self.supported_chains().await;
+        chains.sort();
+        
+        let routing_engine = self.routing_engine.read().await;
+        RouterState {
+            chains,
+            graph_chains: routing_engine.chains(),
+            connections: routing_engine.connections(),
+        }
+    }
+    
+    /// Replace the routing graph with one from a snapshot, dropping cached routes
+    ///
+    /// Registered adapters are left alone; chains in `state.chains` without an adapter
+    /// are logged so a missing adapter config is noticed before routing fails.
+    pub async fn import_state(&self, state: RouterState) {
+        let registered = self.supported_chains().await;
+        for chain in state.chains.iter().filter(|c| !registered.contains(c)) {
+            tracing::warn!("Imported router state lists {} but no adapter is registered for it", chain);
+        }
+        
+        let mut routing_engine = self.routing_engine.write().await;
+        *routing_engine = RoutingEngine::from_connections(&state.graph_chains, &state.connections);
+    }
+    
     pub async fn get_all_routes(&self, from: &str, to: &str, max_hops: usize) -> Vec<Route> {
         let routing_engine = self.routing_engine.read().await;
         routing_engine.get_all_routes(from, to, max_hops)
     }
 }
 
+/// Context an intent belongs to: the UTF-8 id in `intent.context`, or the one derived from it
+fn context_id_for(intent: &Intent) -> String {
+    match std::str::from_utf8(&intent.context) {
+        Ok(id) if !id.is_empty() => id.to_string(),
+        _ => SemanticContext::id_for(&intent.id, &intent.from_chain, &intent.to_chain),
+    }
+}
+
+/// `action` with its input amount replaced; a swap's output bounds scale with it
+fn with_input_amount(action: ActionType, amount: u64) -> ActionType {
+    let scale = |value: u64, from: u64| (value as u128 * amount as u128 / from.max(1) as u128) as u64;
+    match action {
+        ActionType::Transfer { from, to, asset, memo, .. } => ActionType::Transfer { from, to, amount, asset, memo },
+        ActionType::Swap { asset_in, asset_out, amount_in, expected_amount_out, min_amount_out } => ActionType::Swap {
+            asset_in,
+            asset_out,
+            amount_in: amount,
+            expected_amount_out: scale(expected_amount_out, amount_in),
+            min_amount_out: scale(min_amount_out, amount_in),
+        },
+        ActionType::Stake { asset, validator, .. } => ActionType::Stake { asset, amount, validator },
+        ActionType::Unstake { asset, .. } => ActionType::Unstake { asset, amount },
+        call @ ActionType::ContractCall { .. } => call,
+        split @ ActionType::Split { .. } => split,
+    }
+}
+
+/// The `network` a Bitcoin chain config names, mainnet if none
+#[cfg(feature = "btc")]
+fn configured_bitcoin_network(chain: &ChainConfig) -> Result<bitcoin::Network, RouterError> {
+    match &chain.network {
+        Some(network) => network
+            .parse()
+            .map_err(|e| RouterError::RoutingError(format!("Bad Bitcoin network: {}", e))),
+        None => Ok(bitcoin::Network::Bitcoin),
+    }
+}
+
+/// A config's slippage tolerance, a fraction such as 0.005, in basis points
+fn slippage_bps(tolerance: f64) -> Result<u16, RouterError> {
+    if !(0.0..=1.0).contains(&tolerance) {
+        return Err(RouterError::RoutingError(format!("Bad slippage tolerance: {}", tolerance)));
+    }
+    let bps = (tolerance * 10_000.0).round() as u16;
+    SlippageCalculator::validate_slippage(bps)?;
+    Ok(bps)
+}
+
+#[cfg_attr(not(feature = "eth"), allow(unused_variables))]
+fn adapter_from_config(
+    name: &str,
+    chain: &ChainConfig,
+    defaults: &DefaultSettings,
+    translator: Arc<IntentTranslator>,
+) -> Result<Arc<dyn ChainAdapter>, RouterError> {
+    let rpc_url = chain.rpc_url.clone();
+    
+    let adapter: Arc<dyn ChainAdapter> = match name {
+        #[cfg(feature = "eth")]
+        "ethereum" => {
+            let adapter = EthereumAdapter::new(rpc_url, translator);
+            let adapter = match defaults.max_gas_price {
+                Some(max_gas_price) => adapter.with_gas_strategy(Arc::new(Capped { strategy: Standard, max_gas_price })),
+                None => adapter,
+            };
+            match chain.chain_id {
+                Some(chain_id) => Arc::new(adapter.with_evm_chain_id(chain_id)),
+                None => Arc::new(adapter),
+            }
+        }
+        #[cfg(feature = "dot")]
+        "polkadot" => Arc::new(PolkadotAdapter::new(rpc_url, translator)),
+        #[cfg(feature = "btc")]
+        "bitcoin" => {
+            let adapter = BitcoinAdapter::new(rpc_url, configured_bitcoin_network(chain)?, translator);
+            match &chain.credentials {
+                Some(creds) => Arc::new(adapter.with_credentials(creds.username.clone(), creds.password.clone())),
+                None => Arc::new(adapter),
+            }
+        }
+        #[cfg(feature = "cosmos")]
+        "cosmos" => Arc::new(CosmosAdapter::new(rpc_url, translator)),
+        "sentium" => Arc::new(SentiumAdapter::new(rpc_url, translator)),
+        other => return Err(RouterError::UnsupportedChain(other.to_string())),
+    };
+    
+    Ok(adapter)
+}
+
 impl Default for Router {
     fn default() -> Self {
         Self::new()
@@ -106,6 +1018,7 @@ mod tests {
     }
     
     #[tokio::test]
+    #[cfg(feature = "eth")]
     async fn test_add_adapter() {
         let router = Router::new();
         let translator = Arc::new(IntentTranslator::new());
@@ -116,4 +1029,137 @@ mod tests {
         let adapters = router.adapters.read().await;
         assert_eq!(adapters.len(), 1);
     }
+    
+    #[tokio::test]
+    #[cfg(all(feature = "eth", feature = "btc"))]
+    async fn test_router_from_config() {
+        let config = BridgeConfig::from_toml_str(r#"
+[chains.ethereum]
+rpc_url = "http://localhost:8545"
+
+[chains.bitcoin]
+rpc_url = "http://localhost:8332"
+credentials = { username = "user", password = "pass" }
+network = "testnet"
+
+[chains.cosmos]
+enabled = false
+rpc_url = "http://localhost:9090"
+"#).unwrap();
+        
+        let router = Router::from_config(&config).unwrap();
+        
+        let mut chains = router.supported_chains().await;
+        chains.sort();
+        assert_eq!(chains, vec!["bitcoin", "ethereum"]);
+        assert!(router.adapter("ethereum").await.is_some());
+        assert!(router.adapter("cosmos").await.is_none());
+        assert_eq!(router.adapter("bitcoin").await.unwrap().chain_id(), "bitcoin-testnet");
+    }
+    
+    #[test]
+    fn test_router_from_config_applies_default_slippage() {
+        let config = BridgeConfig::from_toml_str("[defaults]\nslippage_tolerance = 0.02\n").unwrap();
+        let router = Router::from_config(&config).unwrap();
+        
+        let intent = Intent {
+            id: "config-swap".to_string(),
+            from_chain: "ethereum".to_string(),
+            to_chain: "sentium".to_string(),
+            action: String::new(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        }
+        .with_action(ActionType::Swap {
+            asset_in: "USDC".to_string(),
+            asset_out: "ETH".to_string(),
+            amount_in: 10_000,
+            expected_amount_out: 10_000,
+            min_amount_out: 0,
+        });
+        let translated = router.translate_offline(&intent).unwrap();
+        let envelope = SentiumEnvelope::decode(&translated.target_format).unwrap();
+        match serde_json::from_slice(&envelope.intent).unwrap() {
+            ActionType::Swap { min_amount_out, .. } => assert_eq!(min_amount_out, 9_800),
+            other => panic!("expected a swap, got {:?}", other),
+        }
+        
+        for tolerance in ["0.0", "0.5", "-0.01"] {
+            let config = BridgeConfig::from_toml_str(&format!("[defaults]\nslippage_tolerance = {}\n", tolerance)).unwrap();
+            assert!(Router::from_config(&config).is_err(), "slippage {}", tolerance);
+        }
+    }
+    
+    #[test]
+    fn test_router_from_config_unknown_chain() {
+        let config = BridgeConfig::from_toml_str("[chains.unknown]\nrpc_url = \"http://localhost\"\n").unwrap();
+        
+        assert!(matches!(
+            Router::from_config(&config),
+            Err(RouterError::UnsupportedChain(chain)) if chain == "unknown"
+        ));
+    }
+    
+    #[test]
+    fn test_with_action_round_trips_every_variant() {
+        let actions = vec![
+            ActionType::Transfer {
+                from: "0x0000000000000000000000000000000000000001".to_string(),
+                to: "0x0000000000000000000000000000000000000002".to_string(),
+                amount: 1_500,
+                asset: "USDC".to_string(),
+                memo: Some("12345".to_string()),
+            },
+            ActionType::Swap {
+                asset_in: "USDC".to_string(),
+                asset_out: "ETH".to_string(),
+                amount_in: 2_000,
+                expected_amount_out: 1,
+                min_amount_out: 0,
+            },
+            ActionType::Stake { asset: "DOT".to_string(), amount: 10, validator: "validator-1".to_string() },
+            ActionType::Unstake { asset: "DOT".to_string(), amount: 4 },
+            ActionType::ContractCall { contract: "0xabc".to_string(), method: "ping()".to_string(), params: vec![1, 2] },
+        ];
+        
+        for action in actions {
+            let intent = Intent {
+                id: "action-1".to_string(),
+                from_chain: "sentium".to_string(),
+                to_chain: "ethereum".to_string(),
+                action: String::new(),
+                params: vec![],
+                context: vec![],
+                expires_at: None,
+                signature: None,
+            }
+            .with_action(action.clone());
+            
+            assert_eq!(intent.action, action.name());
+            assert_eq!(format!("{:?}", intent.decode_action().unwrap()), format!("{:?}", action));
+        }
+    }
+    
+    #[test]
+    fn test_decode_action_rejects_mismatched_name() {
+        let mut intent = Intent {
+            id: "action-2".to_string(),
+            from_chain: "sentium".to_string(),
+            to_chain: "ethereum".to_string(),
+            action: String::new(),
+            params: vec![],
+            context: vec![],
+            expires_at: None,
+            signature: None,
+        }
+        .with_action(ActionType::Unstake { asset: "DOT".to_string(), amount: 4 });
+        
+        intent.action = "stake".to_string();
+        assert!(matches!(intent.decode_action(), Err(RouterError::TranslationError(_))));
+        
+        intent.params = vec![1, 2, 3];
+        assert!(intent.decode_action().is_err());
+    }
 }
@@ -0,0 +1,437 @@
+// Nonce Manager - Serializes EVM submissions from one address and assigns sequential nonces
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use super::RouterError;
+use crate::core::clock::{system_clock, Clock};
+
+/// EVM backend able to report an account nonce and submit a transaction with an explicit nonce
+#[async_trait]
+pub trait NonceSender: Send + Sync {
+    /// Next nonce for the address as seen by the node, including pending transactions
+    async fn transaction_count(&self, address: &str) -> Result<u64, RouterError>;
+    
+    async fn submit_with_nonce(
+        &self,
+        address: &str,
+        nonce: u64,
+        tx_data: &[u8],
+    ) -> Result<String, RouterError>;
+}
+
+/// Nonce bookkeeping for one (adapter, from-address) pair
+pub struct NonceManager {
+    sender: Arc<dyn NonceSender>,
+    address: String,
+    state: Mutex<NonceState>,
+}
+
+#[derive(Debug, Default)]
+struct NonceState {
+    next_nonce: Option<u64>, // None until first synced from the node
+    pending: BTreeMap<u64, String>, // nonce -> tx hash, submitted but not confirmed
+    confirmed: Option<u64>, // highest confirmed nonce
+}
+
+impl NonceManager {
+    pub fn new(sender: Arc<dyn NonceSender>, address: String) -> Self {
+        Self {
+            sender,
+            address,
+            state: Mutex::new(NonceState::default()),
+        }
+    }
+    
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+    
+    /// Submit transactions in order with consecutive nonces
+    ///
+    /// A failed submission leaves its nonce unused, so every later transaction in the
+    /// batch is rejected rather than submitted behind a gap. Returns one result per
+    /// transaction, in order.
+    pub async fn submit_sequential(&self, txs: &[Vec<u8>]) -> Vec<Result<String, RouterError>> {
+        if txs.is_empty() {
+            return Vec::new();
+        }
+        
+        // Holding the lock for the whole batch serializes concurrent callers
+        let mut state = self.state.lock().await;
+        let mut results = Vec::with_capacity(txs.len());
+        
+        let mut nonce = match state.next_nonce {
+            Some(nonce) => nonce,
+            None => match self.sender.transaction_count(&self.address).await {
+                Ok(nonce) => nonce,
+                Err(e) => {
+                    results.push(Err(e));
+                    results.extend((1..txs.len()).map(|_| Err(Self::blocked(None))));
+                    return results;
+                }
+            },
+        };
+        
+        let mut failed_nonce = None;
+        
+        for tx in txs {
+            if failed_nonce.is_some() {
+                results.push(Err(Self::blocked(failed_nonce)));
+                continue;
+            }
+            
+            match self.sender.submit_with_nonce(&self.address, nonce, tx).await {
+                Ok(tx_hash) => {
+                    state.pending.insert(nonce, tx_hash.clone());
+                    nonce += 1;
+                    results.push(Ok(tx_hash));
+                }
+                Err(e) => {
+                    failed_nonce = Some(nonce);
+                    results.push(Err(e));
+                }
+            }
+        }
+        
+        state.next_nonce = Some(nonce);
+        results
+    }
+    
+    /// Mark a nonce (and every nonce below it) as confirmed on chain
+    pub async fn mark_confirmed(&self, nonce: u64) {
+        let mut state = self.state.lock().await;
+        state.pending = state.pending.split_off(&(nonce + 1));
+        state.confirmed = state.confirmed.max(Some(nonce));
+    }
+    
+    /// Nonces submitted but not yet confirmed, in order
+    pub async fn pending_nonces(&self) -> Vec<u64> {
+        self.state.lock().await.pending.keys().copied().collect()
+    }
+    
+    pub async fn confirmed_nonce(&self) -> Option<u64> {
+        self.state.lock().await.confirmed
+    }
+    
+    /// Nonce that the next submission will use, if already known
+    pub async fn next_nonce(&self) -> Option<u64> {
+        self.state.lock().await.next_nonce
+    }
+    
+    /// Gap recovery: re-read the node's nonce and rewind if submitted transactions were dropped
+    ///
+    /// Returns the nonce the next submission will use.
+    pub async fn resync(&self) -> Result<u64, RouterError> {
+        let mut state = self.state.lock().await;
+        let chain_nonce = self.sender.transaction_count(&self.address).await?;
+        
+        match state.next_nonce {
+            Some(next) if chain_nonce < next => {
+                // Everything from chain_nonce up was lost and must be resubmitted
+                let dropped = state.pending.split_off(&chain_nonce);
+                tracing::warn!(
+                    "Nonce gap for {}: rewinding from {} to {}, {} pending transaction(s) dropped",
+                    self.address,
+                    next,
+                    chain_nonce,
+                    dropped.len()
+                );
+                state.next_nonce = Some(chain_nonce);
+            }
+            _ => state.next_nonce = Some(chain_nonce),
+        }
+        
+        Ok(chain_nonce)
+    }
+    
+    fn blocked(failed_nonce: Option<u64>) -> RouterError {
+        match failed_nonce {
+            Some(nonce) => RouterError::RoutingError(format!("Blocked by failed submission at nonce {}", nonce)),
+            None => RouterError::RoutingError("Blocked: account nonce unavailable".to_string()),
+        }
+    }
+}
+
+/// Seconds an extrinsic may hold up its account's nonce before it is treated as dropped
+pub const DEFAULT_NONCE_STALL_SECS: u64 = 120;
+
+/// Substrate backend able to report an account nonce
+#[async_trait]
+pub trait SubstrateNonceSource: Send + Sync {
+    /// `System::Account` nonce: how many of the account's extrinsics are included on chain
+    async fn account_nonce(&self, account: &str) -> Result<u32, RouterError>;
+}
+
+/// Nonce assignment for Substrate accounts that heals gaps left by dropped extrinsics
+///
+/// The on-chain nonce only counts included extrinsics, so it can't tell a dropped extrinsic
+/// from a slow one. One that still holds up its account after the stall timeout is
+/// treated as dropped and its nonce handed out again.
+pub struct SubstrateNonceManager {
+    source: Arc<dyn SubstrateNonceSource>,
+    clock: Arc<dyn Clock>,
+    stall_after_secs: u64,
+    assigned: Mutex<HashMap<String, BTreeMap<u32, u64>>>, // account -> nonce -> assigned at
+}
+
+impl SubstrateNonceManager {
+    pub fn new(source: Arc<dyn SubstrateNonceSource>) -> Self {
+        Self {
+            source,
+            clock: system_clock(),
+            stall_after_secs: DEFAULT_NONCE_STALL_SECS,
+            assigned: Mutex::new(HashMap::new()),
+        }
+    }
+    
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    pub fn with_stall_after(mut self, secs: u64) -> Self {
+        self.stall_after_secs = secs;
+        self
+    }
+    
+    /// Nonce for the account's next extrinsic
+    ///
+    /// The lowest nonce at or above the on-chain one that isn't already out, or the
+    /// on-chain nonce itself once the extrinsic holding it has stalled.
+    pub async fn next_nonce(&self, account: &str) -> Result<u32, RouterError> {
+        let chain_nonce = self.source.account_nonce(account).await?;
+        let now = self.clock.now_secs();
+        
+        let mut assigned = self.assigned.lock().await;
+        let nonces = assigned.entry(account.to_string()).or_default();
+        // Everything below the on-chain nonce has been included
+        *nonces = nonces.split_off(&chain_nonce);
+        
+        let nonce = match nonces.get(&chain_nonce) {
+            Some(&at) if now.saturating_sub(at) >= self.stall_after_secs => {
+                tracing::warn!(
+                    "Nonce {} for {} stalled for {}s, reassigning it",
+                    chain_nonce,
+                    account,
+                    now - at
+                );
+                chain_nonce
+            }
+            _ => {
+                let nonce = (chain_nonce..=u32::MAX)
+                    .find(|n| !nonces.contains_key(n))
+                    .ok_or_else(|| RouterError::RoutingError(format!("Nonces exhausted for {}", account)))?;
+                if nonces.keys().next_back().is_some_and(|&highest| nonce < highest) {
+                    tracing::warn!("Nonce gap for {}: filling {}", account, nonce);
+                }
+                nonce
+            }
+        };
+        
+        nonces.insert(nonce, now);
+        Ok(nonce)
+    }
+    
+    /// Free a nonce whose extrinsic never reached the pool, so the next call reuses it
+    pub async fn release(&self, account: &str, nonce: u32) {
+        if let Some(nonces) = self.assigned.lock().await.get_mut(account) {
+            nonces.remove(&nonce);
+        }
+    }
+    
+    /// Nonces handed out and not yet seen included, in order
+    pub async fn pending_nonces(&self, account: &str) -> Vec<u32> {
+        self.assigned
+            .lock()
+            .await
+            .get(account)
+            .map(|nonces| nonces.keys().copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex as StdMutex;
+    
+    struct MockSender {
+        start_nonce: u64,
+        fail_at: Option<u64>,
+        count_queries: StdMutex<u32>,
+        submitted: StdMutex<Vec<u64>>,
+    }
+    
+    impl MockSender {
+        fn new(start_nonce: u64, fail_at: Option<u64>) -> Arc<Self> {
+            Arc::new(Self {
+                start_nonce,
+                fail_at,
+                count_queries: StdMutex::new(0),
+                submitted: StdMutex::new(Vec::new()),
+            })
+        }
+    }
+    
+    #[async_trait]
+    impl NonceSender for MockSender {
+        async fn transaction_count(&self, _address: &str) -> Result<u64, RouterError> {
+            *self.count_queries.lock().unwrap() += 1;
+            Ok(self.start_nonce)
+        }
+        
+        async fn submit_with_nonce(
+            &self,
+            _address: &str,
+            nonce: u64,
+            _tx_data: &[u8],
+        ) -> Result<String, RouterError> {
+            if self.fail_at == Some(nonce) {
+                return Err(RouterError::RoutingError("replacement underpriced".to_string()));
+            }
+            
+            self.submitted.lock().unwrap().push(nonce);
+            Ok(format!("0x{:064x}", nonce))
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_sequential_nonces() {
+        let sender = MockSender::new(7, None);
+        let manager = NonceManager::new(sender.clone(), "0xabc".to_string());
+        
+        let results = manager.submit_sequential(&[vec![1], vec![2], vec![3]]).await;
+        
+        assert!(results.iter().all(|r| r.is_ok()));
+        assert_eq!(*sender.submitted.lock().unwrap(), vec![7, 8, 9]);
+        assert_eq!(manager.pending_nonces().await, vec![7, 8, 9]);
+        assert_eq!(manager.next_nonce().await, Some(10));
+        
+        manager.mark_confirmed(8).await;
+        assert_eq!(manager.pending_nonces().await, vec![9]);
+        assert_eq!(manager.confirmed_nonce().await, Some(8));
+    }
+    
+    #[tokio::test]
+    async fn test_empty_batch_skips_the_node() {
+        let sender = MockSender::new(7, None);
+        let manager = NonceManager::new(sender.clone(), "0xabc".to_string());
+        
+        assert!(manager.submit_sequential(&[]).await.is_empty());
+        assert_eq!(*sender.count_queries.lock().unwrap(), 0);
+        assert_eq!(manager.next_nonce().await, None);
+    }
+    
+    #[tokio::test]
+    async fn test_unreachable_node_fails_every_transaction() {
+        struct Unreachable;
+        
+        #[async_trait]
+        impl NonceSender for Unreachable {
+            async fn transaction_count(&self, _address: &str) -> Result<u64, RouterError> {
+                Err(RouterError::RoutingError("connection refused".to_string()))
+            }
+            
+            async fn submit_with_nonce(&self, _: &str, _: u64, _: &[u8]) -> Result<String, RouterError> {
+                unreachable!("nothing is submitted without a nonce")
+            }
+        }
+        
+        let manager = NonceManager::new(Arc::new(Unreachable), "0xabc".to_string());
+        let results = manager.submit_sequential(&[vec![1], vec![2], vec![3]]).await;
+        
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.is_err()));
+        assert_eq!(manager.next_nonce().await, None);
+    }
+    
+    #[tokio::test]
+    async fn test_failure_blocks_later_nonces() {
+        let sender = MockSender::new(7, Some(8));
+        let manager = NonceManager::new(sender.clone(), "0xabc".to_string());
+        
+        let results = manager.submit_sequential(&[vec![1], vec![2], vec![3]]).await;
+        
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(matches!(&results[2], Err(RouterError::RoutingError(msg)) if msg.contains("nonce 8")));
+        
+        // n+2 was never sent and n+1 is reused by the next submission
+        assert_eq!(*sender.submitted.lock().unwrap(), vec![7]);
+        assert_eq!(manager.next_nonce().await, Some(8));
+    }
+    
+    #[tokio::test]
+    async fn test_resync_rewinds_after_dropped_transactions() {
+        let sender = MockSender::new(7, None);
+        let manager = NonceManager::new(sender.clone(), "0xabc".to_string());
+        
+        manager.submit_sequential(&[vec![1], vec![2], vec![3]]).await;
+        
+        // The node still reports 7: all three were dropped from the mempool
+        assert_eq!(manager.resync().await.unwrap(), 7);
+        assert_eq!(manager.next_nonce().await, Some(7));
+        assert!(manager.pending_nonces().await.is_empty());
+    }
+    
+    /// Nonce source whose on-chain nonce the test moves by hand
+    struct MockNonceSource {
+        nonce: StdMutex<u32>,
+    }
+    
+    #[async_trait]
+    impl SubstrateNonceSource for MockNonceSource {
+        async fn account_nonce(&self, _account: &str) -> Result<u32, RouterError> {
+            Ok(*self.nonce.lock().unwrap())
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_substrate_manager_reuses_stalled_nonce() {
+        use crate::core::clock::MockClock;
+        
+        let source = Arc::new(MockNonceSource { nonce: StdMutex::new(4) });
+        let clock = Arc::new(MockClock::new(1_700_000_000));
+        let manager = SubstrateNonceManager::new(source.clone())
+            .with_clock(clock.clone())
+            .with_stall_after(60);
+        let alice = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        
+        for expected in 4..7 {
+            assert_eq!(manager.next_nonce(alice).await.unwrap(), expected);
+        }
+        
+        // 4 is included but 5 was dropped, leaving 6 stuck behind it
+        *source.nonce.lock().unwrap() = 5;
+        clock.advance(30);
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 7);
+        assert_eq!(manager.pending_nonces(alice).await, vec![5, 6, 7]);
+        
+        clock.advance(60);
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 5);
+        
+        // The resubmitted 5 is fresh again, so the next one carries on after 7
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 8);
+        
+        // Accounts are tracked separately
+        assert_eq!(manager.next_nonce("14E5nqKAp3oAJcmzgZhUD2RcptBeUBScxKHgJKU4HPNcKVf3").await.unwrap(), 5);
+    }
+    
+    #[tokio::test]
+    async fn test_substrate_manager_refills_released_nonce() {
+        let source = Arc::new(MockNonceSource { nonce: StdMutex::new(0) });
+        let manager = SubstrateNonceManager::new(source);
+        let alice = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 0);
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 1);
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 2);
+        
+        // 1 was rejected before reaching the pool
+        manager.release(alice, 1).await;
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 1);
+        assert_eq!(manager.next_nonce(alice).await.unwrap(), 3);
+    }
+}
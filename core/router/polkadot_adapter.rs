@@ -0,0 +1,830 @@
+// REAL Substrate/Polkadot Adapter - Production-ready implementation with subxt
+use async_trait::async_trait;
+use subxt::{OnlineClient, PolkadotConfig};
+use subxt::backend::rpc::{rpc_params, RpcClient};
+use subxt::ext::scale_value::At;
+use subxt::tx::{SubmittableExtrinsic, TxStatus};
+use subxt::utils::{AccountId32, MultiAddress};
+use sp_core::{sr25519, Pair, H256, Blake2Hasher};
+use sp_runtime::generic::Era;
+use sp_trie::{LayoutV1, StorageProof, Trie, TrieDBBuilder};
+use parity_scale_codec::{Compact, Encode};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::str::FromStr;
+
+use super::{Intent, RouterError};
+use super::intent_translator::{IntentTranslator, TranslatedIntent};
+
+/// Balances pallet index on Polkadot and most Substrate chains
+const BALANCES_PALLET_INDEX: u8 = 5;
+
+/// Extrinsic format version 4 with the "signed" bit set
+const SIGNED_EXTRINSIC_VERSION: u8 = 0x84;
+
+/// Blocks a signed extrinsic stays valid for, counted from the block it was signed against
+pub const MORTAL_PERIOD: u64 = 64;
+
+/// `CheckMetadataHash` mode byte: the metadata hash isn't checked
+const METADATA_HASH_DISABLED: u8 = 0;
+
+/// Chain parameters committed to by a signed extrinsic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningParams {
+    pub spec_version: u32,
+    pub transaction_version: u32,
+    pub genesis_hash: [u8; 32],
+    pub era: Era,
+    pub era_block_hash: [u8; 32], // block the era starts at; the genesis for an immortal era
+}
+
+/// Which `Balances` call a transfer is encoded as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransferKind {
+    /// `transfer` (call index 0), deprecated and removed on newer runtimes
+    Transfer,
+    /// `transfer_keep_alive` (call index 3), fails rather than reaping the sender
+    #[default]
+    KeepAlive,
+}
+
+impl TransferKind {
+    pub fn call_index(&self) -> u8 {
+        match self {
+            TransferKind::Transfer => 0,
+            TransferKind::KeepAlive => 3,
+        }
+    }
+}
+
+pub struct RealSubstrateAdapter {
+    chain_name: String,
+    chain_id: String,
+    rpc: RpcClient,
+    client: Arc<OnlineClient<PolkadotConfig>>,
+    translator: Arc<IntentTranslator>,
+}
+
+impl RealSubstrateAdapter {
+    pub async fn new(
+        rpc_url: String,
+        chain_name: String,
+        chain_id: String,
+        translator: Arc<IntentTranslator>,
+    ) -> Result<Self, RouterError> {
+        // Connect to Substrate node
+        let rpc = RpcClient::from_url(&rpc_url).await
+            .map_err(|e| RouterError::TranslationError(format!("Substrate connection failed: {}", e)))?;
+        let client = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc.clone()).await
+            .map_err(|e| RouterError::TranslationError(format!("Substrate connection failed: {}", e)))?;
+        
+        Ok(Self {
+            chain_name,
+            chain_id,
+            rpc,
+            client: Arc::new(client),
+            translator,
+        })
+    }
+    
+    pub async fn create_transfer_call(
+        &self,
+        dest: MultiAddress<AccountId32, u32>,
+        value: u128,
+        kind: TransferKind,
+    ) -> Result<Vec<u8>, RouterError> {
+        Ok(Self::encode_transfer_call(dest, value, kind))
+    }
+    
+    /// SCALE-encode a Balances transfer; the caller picks the `MultiAddress` variant
+    /// (`Id`, `Index`, `Raw`, `Address32` or `Address20`) the target chain expects
+    pub fn encode_transfer_call(
+        dest: MultiAddress<AccountId32, u32>,
+        value: u128,
+        kind: TransferKind,
+    ) -> Vec<u8> {
+        #[derive(Encode)]
+        struct TransferCall {
+            dest: MultiAddress<AccountId32, u32>,
+            value: u128,
+        }
+        
+        let call = TransferCall { dest, value };
+        
+        // Encode as SCALE
+        let mut encoded = vec![BALANCES_PALLET_INDEX, kind.call_index()]; // Pallet and call indices
+        encoded.extend(call.encode());
+        
+        encoded
+    }
+    
+    pub async fn create_staking_bond_call(
+        &self,
+        controller: AccountId32,
+        value: u128,
+        payee: u8, // 0 = Staked, 1 = Stash, 2 = Controller
+    ) -> Result<Vec<u8>, RouterError> {
+        // Create Staking::bond call
+        // Pallet index: 6, Call index: 0
+        
+        #[derive(Encode)]
+        struct BondCall {
+            controller: MultiAddress<AccountId32, ()>,
+            value: u128,
+            payee: u8,
+        }
+        
+        let call = BondCall {
+            controller: MultiAddress::Id(controller),
+            value,
+            payee,
+        };
+        
+        let mut encoded = vec![6u8, 0u8];
+        encoded.extend(call.encode());
+        
+        Ok(encoded)
+    }
+    
+    /// Wrap call bytes into a signed extrinsic ready for `submit_transaction`
+    ///
+    /// The extrinsic is mortal, valid for `MORTAL_PERIOD` blocks from the finalized head.
+    pub async fn sign_extrinsic(
+        &self,
+        call: Vec<u8>,
+        signer: &sr25519::Pair,
+        nonce: u32,
+        tip: u128,
+    ) -> Result<Vec<u8>, RouterError> {
+        let (spec_version, transaction_version, genesis_hash) = self.runtime_version().await?;
+        let (era, era_block_hash) = fetch_mortal_era(&self.rpc).await?;
+        
+        let params = SigningParams {
+            spec_version,
+            transaction_version,
+            genesis_hash: genesis_hash.0,
+            era,
+            era_block_hash: era_block_hash.0,
+        };
+        
+        Ok(Self::build_signed_extrinsic(&call, signer, nonce, tip, &params))
+    }
+    
+    /// Current `(spec_version, transaction_version, genesis_hash)` from the node
+    ///
+    /// Fetched on every call rather than taken from the client, whose copy is from connect
+    /// time and goes stale after a runtime upgrade.
+    pub async fn runtime_version(&self) -> Result<(u32, u32, H256), RouterError> {
+        fetch_runtime_version(&self.rpc).await
+    }
+    
+    /// Payload signed by the sender: call || extra || additional signed data
+    ///
+    /// Payloads longer than 256 bytes are signed by their blake2_256 hash.
+    pub fn signed_payload(call: &[u8], nonce: u32, tip: u128, params: &SigningParams) -> Vec<u8> {
+        let mut payload = call.to_vec();
+        payload.extend(Self::signed_extra(nonce, tip, params.era));
+        
+        // Additional signed: spec version, tx version, genesis hash, era birth block hash and
+        // the metadata hash, which is `None` while the mode is disabled
+        params.spec_version.encode_to(&mut payload);
+        params.transaction_version.encode_to(&mut payload);
+        payload.extend_from_slice(&params.genesis_hash);
+        payload.extend_from_slice(&params.era_block_hash);
+        None::<[u8; 32]>.encode_to(&mut payload);
+        
+        if payload.len() > 256 {
+            sp_core::blake2_256(&payload).to_vec()
+        } else {
+            payload
+        }
+    }
+    
+    pub fn build_signed_extrinsic(
+        call: &[u8],
+        signer: &sr25519::Pair,
+        nonce: u32,
+        tip: u128,
+        params: &SigningParams,
+    ) -> Vec<u8> {
+        let signature = signer.sign(&Self::signed_payload(call, nonce, tip, params));
+        
+        let mut body = vec![SIGNED_EXTRINSIC_VERSION];
+        MultiAddress::<AccountId32, ()>::Id(AccountId32::from(signer.public().0)).encode_to(&mut body);
+        body.push(1); // MultiSignature::Sr25519
+        body.extend_from_slice(&signature.0);
+        body.extend(Self::signed_extra(nonce, tip, params.era));
+        body.extend_from_slice(call);
+        
+        // Extrinsics are length-prefixed
+        let mut extrinsic = Compact(body.len() as u32).encode();
+        extrinsic.extend(body);
+        extrinsic
+    }
+    
+    fn signed_extra(nonce: u32, tip: u128, era: Era) -> Vec<u8> {
+        // Era || compact nonce || compact tip || CheckMetadataHash mode
+        let mut extra = era.encode();
+        Compact(nonce).encode_to(&mut extra);
+        Compact(tip).encode_to(&mut extra);
+        extra.push(METADATA_HASH_DISABLED);
+        extra
+    }
+    
+    fn verify_storage_proof(
+        &self,
+        key: &[u8],
+        value: Option<&[u8]>,
+        proof: &[Vec<u8>],
+        state_root: &[u8; 32],
+    ) -> Result<bool, RouterError> {
+        // Verify Merkle proof for storage item using sp-trie
+        
+        if proof.is_empty() {
+            return Err(RouterError::VerificationError("Empty proof".to_string()));
+        }
+        
+        // Convert proof nodes to StorageProof
+        let storage_proof = StorageProof::new(proof.iter().cloned());
+        
+        // Build trie from proof
+        let db = storage_proof.into_memory_db::<Blake2Hasher>();
+        let state_root_h256 = H256::from_slice(state_root);
+        
+        let trie = TrieDBBuilder::<LayoutV1<Blake2Hasher>>::new(&db, &state_root_h256)
+            .build();
+        
+        // Get value from trie
+        let trie_value = trie.get(key)
+            .map_err(|e| RouterError::VerificationError(format!("Trie lookup failed: {:?}", e)))?;
+        
+        // Compare with expected value
+        Ok(trie_value.as_deref() == value)
+    }
+}
+
+/// The fields of `state_getRuntimeVersion` a signer commits to
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcRuntimeVersion {
+    spec_version: u32,
+    transaction_version: u32,
+}
+
+/// `(spec_version, transaction_version, genesis_hash)` via `state_getRuntimeVersion` and
+/// `chain_getBlockHash(0)`
+pub async fn fetch_runtime_version(rpc: &RpcClient) -> Result<(u32, u32, H256), RouterError> {
+    let version: RpcRuntimeVersion = rpc
+        .request("state_getRuntimeVersion", rpc_params![])
+        .await
+        .map_err(|e| RouterError::TranslationError(format!("Failed to fetch runtime version: {}", e)))?;
+    let genesis_hash: Option<H256> = rpc
+        .request("chain_getBlockHash", rpc_params![0u32])
+        .await
+        .map_err(|e| RouterError::TranslationError(format!("Failed to fetch genesis hash: {}", e)))?;
+    let genesis_hash = genesis_hash
+        .ok_or_else(|| RouterError::TranslationError("Node has no genesis block hash".to_string()))?;
+    
+    Ok((version.spec_version, version.transaction_version, genesis_hash))
+}
+
+/// The header field a mortal era is built from
+#[derive(Deserialize)]
+struct RpcHeader {
+    number: String, // hex-encoded block number
+}
+
+/// A `MORTAL_PERIOD` era starting at the finalized head, with that head's hash
+///
+/// The finalized head rather than the best block, so the era's block can't be reorged away.
+pub async fn fetch_mortal_era(rpc: &RpcClient) -> Result<(Era, H256), RouterError> {
+    let head: H256 = rpc
+        .request("chain_getFinalizedHead", rpc_params![])
+        .await
+        .map_err(|e| RouterError::TranslationError(format!("Failed to fetch finalized head: {}", e)))?;
+    let header: Option<RpcHeader> = rpc
+        .request("chain_getHeader", rpc_params![head])
+        .await
+        .map_err(|e| RouterError::TranslationError(format!("Failed to fetch header: {}", e)))?;
+    let header = header
+        .ok_or_else(|| RouterError::TranslationError(format!("Node has no header for {:?}", head)))?;
+    let number = u64::from_str_radix(header.number.trim_start_matches("0x"), 16)
+        .map_err(|e| RouterError::TranslationError(format!("Invalid block number {}: {}", header.number, e)))?;
+    
+    Ok((Era::mortal(MORTAL_PERIOD, number), head))
+}
+
+#[async_trait]
+impl super::chain_adapter::ChainAdapter for RealSubstrateAdapter {
+    fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+    
+    fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    
+    async fn translate_intent(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
+        self.translator.translate(intent)
+    }
+    
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
+    async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
+        // Verify Substrate state proof using sp-trie
+        
+        if proof.is_empty() {
+            return Err(RouterError::VerificationError("Empty proof".to_string()));
+        }
+        
+        // Decode SCALE-encoded proof data
+        // Expected format: state_root (32 bytes) + key_len (4 bytes) + key + value_len (4 bytes) + value + proof_nodes
+        if proof.len() < 32 {
+            return Err(RouterError::VerificationError("Proof too short for state root".to_string()));
+        }
+        
+        // Extract state root (first 32 bytes)
+        let mut state_root_bytes = [0u8; 32];
+        state_root_bytes.copy_from_slice(&proof[0..32]);
+        
+        // Parse the rest of the proof
+        let mut offset = 32;
+        
+        // Extract key length and key
+        if proof.len() < offset + 4 {
+            return Err(RouterError::VerificationError("Proof too short for key length".to_string()));
+        }
+        let key_len = u32::from_le_bytes([proof[offset], proof[offset+1], proof[offset+2], proof[offset+3]]) as usize;
+        offset += 4;
+        
+        if proof.len() < offset + key_len {
+            return Err(RouterError::VerificationError("Proof too short for key".to_string()));
+        }
+        let key = &proof[offset..offset + key_len];
+        offset += key_len;
+        
+        // Extract value length and value (expected value)
+        if proof.len() < offset + 4 {
+            return Err(RouterError::VerificationError("Proof too short for value length".to_string()));
+        }
+        let value_len = u32::from_le_bytes([proof[offset], proof[offset+1], proof[offset+2], proof[offset+3]]) as usize;
+        offset += 4;
+        
+        let expected_value = if value_len > 0 {
+            if proof.len() < offset + value_len {
+                return Err(RouterError::VerificationError("Proof too short for value".to_string()));
+            }
+            Some(&proof[offset..offset + value_len])
+        } else {
+            None
+        };
+        offset += value_len;
+        
+        // Extract number of proof nodes
+        if proof.len() < offset + 4 {
+            return Err(RouterError::VerificationError("Proof too short for proof nodes count".to_string()));
+        }
+        let nodes_count = u32::from_le_bytes([proof[offset], proof[offset+1], proof[offset+2], proof[offset+3]]) as usize;
+        offset += 4;
+        
+        // Extract proof nodes
+        let mut proof_nodes = Vec::new();
+        for _ in 0..nodes_count {
+            if proof.len() < offset + 4 {
+                return Err(RouterError::VerificationError("Proof too short for node length".to_string()));
+            }
+            let node_len = u32::from_le_bytes([proof[offset], proof[offset+1], proof[offset+2], proof[offset+3]]) as usize;
+            offset += 4;
+            
+            if proof.len() < offset + node_len {
+                return Err(RouterError::VerificationError("Proof too short for node data".to_string()));
+            }
+            proof_nodes.push(proof[offset..offset + node_len].to_vec());
+            offset += node_len;
+        }
+        
+        // Verify the storage proof using sp-trie
+        self.verify_storage_proof(key, expected_value, &proof_nodes, &state_root_bytes)
+    }
+    
+    async fn submit_transaction(&self, tx_data: &[u8]) -> Result<String, RouterError> {
+        // Submit extrinsic using subxt and wait for finalization
+        
+        if tx_data.is_empty() {
+            return Err(RouterError::TranslationError("Empty transaction data".to_string()));
+        }
+        
+        // Submit extrinsic and watch for finalization
+        let mut tx_progress = SubmittableExtrinsic::from_bytes((*self.client).clone(), tx_data.to_vec())
+            .submit_and_watch()
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to submit extrinsic: {}", e)))?;
+        
+        // Monitor transaction status through different stages
+        while let Some(status) = tx_progress.next().await {
+            match status {
+                Ok(status) => {
+                    match status {
+                        TxStatus::InBestBlock(_) => {
+                            // Transaction is in a block, continue waiting for finalization
+                            continue;
+                        }
+                        TxStatus::InFinalizedBlock(details) => {
+                            // Transaction is finalized
+                            let tx_hash = details.extrinsic_hash();
+                            let block_hash = details.block_hash();
+                            
+                            // Return comprehensive finalization information
+                            return Ok(format!(
+                                "0x{}:finalized_in_block:0x{}",
+                                hex::encode(tx_hash),
+                                hex::encode(block_hash)
+                            ));
+                        }
+                        TxStatus::Error { message } => {
+                            return Err(RouterError::TranslationError(
+                                format!("Transaction error: {}", message)
+                            ));
+                        }
+                        TxStatus::Invalid { message } => {
+                            return Err(RouterError::TranslationError(
+                                format!("Transaction invalid: {}", message)
+                            ));
+                        }
+                        TxStatus::Dropped { message } => {
+                            return Err(RouterError::TranslationError(
+                                format!("Transaction dropped: {}", message)
+                            ));
+                        }
+                        _ => {
+                            // Other statuses like Validated, Broadcasted, etc.
+                            continue;
+                        }
+                    }
+                }
+                Err(e) => {
+                    return Err(RouterError::TranslationError(
+                        format!("Transaction status error: {}", e)
+                    ));
+                }
+            }
+        }
+        
+        // If we exit the loop without finalization, try wait_for_finalized as fallback
+        let tx_in_block = tx_progress
+            .wait_for_finalized()
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to wait for finalization: {}", e)))?;
+        
+        let tx_hash = tx_in_block.extrinsic_hash();
+        let block_hash = tx_in_block.block_hash();
+        
+        Ok(format!(
+            "0x{}:finalized_in_block:0x{}",
+            hex::encode(tx_hash),
+            hex::encode(block_hash)
+        ))
+    }
+    
+    async fn query_balance(&self, address: &str, _asset: &str) -> Result<u64, RouterError> {
+        // Parse SS58 address
+        let account = AccountId32::from_str(address)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid SS58 address: {}", e)))?;
+        
+        // Query account info
+        let account_info_address = subxt::dynamic::storage(
+            "System",
+            "Account",
+            vec![subxt::dynamic::Value::from_bytes(&account)],
+        );
+        
+        let account_info = self.client
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to get storage: {}", e)))?
+            .fetch(&account_info_address)
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to fetch account: {}", e)))?;
+        
+        if let Some(info) = account_info {
+            // Decode account info
+            // Structure: { nonce, consumers, providers, sufficients, data: { free, reserved, ... } }
+            let value = info.to_value()
+                .map_err(|e| RouterError::TranslationError(format!("Failed to decode account info: {}", e)))?;
+            
+            // Extract free balance
+            if let Some(data) = value.at("data") {
+                if let Some(free) = data.at("free") {
+                    if let Some(balance) = free.as_u128() {
+                        return Ok(balance as u64);
+                    }
+                }
+            }
+        }
+        
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::chain_adapter::ChainAdapter;
+    use parity_scale_codec::Decode;
+    
+    #[tokio::test]
+    #[ignore] // Requires Polkadot node
+    async fn test_substrate_connection() {
+        let translator = Arc::new(IntentTranslator::new());
+        
+        // Connect to Polkadot public RPC
+        let adapter = RealSubstrateAdapter::new(
+            "wss://rpc.polkadot.io".to_string(),
+            "polkadot".to_string(),
+            "polkadot-0".to_string(),
+            translator,
+        ).await;
+        
+        assert!(adapter.is_ok());
+    }
+    
+    #[tokio::test]
+    #[ignore] // Requires Polkadot node
+    async fn test_query_balance() {
+        let translator = Arc::new(IntentTranslator::new());
+        
+        let adapter = RealSubstrateAdapter::new(
+            "wss://rpc.polkadot.io".to_string(),
+            "polkadot".to_string(),
+            "polkadot-0".to_string(),
+            translator,
+        ).await.unwrap();
+        
+        // Query a known address (Polkadot treasury)
+        let balance = adapter.query_balance(
+            "13UVJyLnbVp9RBZYFwFGyDvVd1y27Tt8tkntv6Q7JVPhFsTB",
+            "DOT"
+        ).await;
+        
+        assert!(balance.is_ok());
+    }
+    
+    #[tokio::test]
+    #[ignore] // Requires Polkadot node
+    async fn test_create_transfer_call() {
+        let translator = Arc::new(IntentTranslator::new());
+        
+        // Create adapter without connecting
+        let client = OnlineClient::<PolkadotConfig>::from_url("wss://rpc.polkadot.io")
+            .await
+            .unwrap();
+        
+        let adapter = RealSubstrateAdapter {
+            chain_name: "polkadot".to_string(),
+            chain_id: "polkadot-0".to_string(),
+            rpc: RpcClient::from_url("wss://rpc.polkadot.io").await.unwrap(),
+            client: Arc::new(client),
+            translator,
+        };
+        
+        let dest = AccountId32::from_str("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY")
+            .unwrap();
+        
+        let call = adapter
+            .create_transfer_call(MultiAddress::Id(dest), 1_000_000_000_000, TransferKind::Transfer)
+            .await;
+        
+        assert!(call.is_ok());
+        let encoded = call.unwrap();
+        
+        // Check pallet and call indices
+        assert_eq!(encoded[0], 5); // Balances pallet
+        assert_eq!(encoded[1], 0); // transfer call
+    }
+    
+    #[test]
+    fn test_transfer_kind_call_index() {
+        let dest = AccountId32::from([7u8; 32]);
+        
+        let transfer = RealSubstrateAdapter::encode_transfer_call(
+            MultiAddress::Id(dest.clone()),
+            1_000,
+            TransferKind::Transfer,
+        );
+        let keep_alive = RealSubstrateAdapter::encode_transfer_call(
+            MultiAddress::Id(dest),
+            1_000,
+            TransferKind::KeepAlive,
+        );
+        
+        assert_eq!(&transfer[..2], &[5, 0]);
+        assert_eq!(&keep_alive[..2], &[5, 3]);
+        assert_eq!(transfer[2..], keep_alive[2..]);
+    }
+    
+    #[test]
+    fn test_multi_address_variants() {
+        let id = RealSubstrateAdapter::encode_transfer_call(
+            MultiAddress::Id(AccountId32::from([7u8; 32])),
+            1_000,
+            TransferKind::KeepAlive,
+        );
+        let index = RealSubstrateAdapter::encode_transfer_call(
+            MultiAddress::Index(42),
+            1_000,
+            TransferKind::KeepAlive,
+        );
+        let address32 = RealSubstrateAdapter::encode_transfer_call(
+            MultiAddress::Address32([7u8; 32]),
+            1_000,
+            TransferKind::KeepAlive,
+        );
+        let address20 = RealSubstrateAdapter::encode_transfer_call(
+            MultiAddress::Address20([9u8; 20]),
+            1_000,
+            TransferKind::KeepAlive,
+        );
+        
+        // MultiAddress variant index follows the pallet/call indices
+        assert_eq!(id[2], 0);
+        assert_eq!(index[2], 1);
+        assert_eq!(address32[2], 3);
+        assert_eq!(address20[2], 4);
+        
+        // Same account bytes, only the variant byte differs
+        assert_eq!(id[3..], address32[3..]);
+        assert_eq!(&index[3..4], &[42 << 2]); // compact-encoded index
+        assert_eq!(&address20[3..23], &[9u8; 20]);
+    }
+    
+    #[test]
+    fn test_sign_extrinsic_signature_verifies() {
+        let signer = sr25519::Pair::from_seed(&[1u8; 32]);
+        let params = SigningParams {
+            spec_version: 1_002_000,
+            transaction_version: 26,
+            genesis_hash: [0x91; 32],
+            era: Era::mortal(MORTAL_PERIOD, 1_000),
+            era_block_hash: [0xb1; 32],
+        };
+        
+        let call = RealSubstrateAdapter::encode_transfer_call(
+            MultiAddress::Id(AccountId32::from([7u8; 32])),
+            1_000,
+            TransferKind::KeepAlive,
+        );
+        let extrinsic = RealSubstrateAdapter::build_signed_extrinsic(&call, &signer, 5, 10, &params);
+        
+        // Strip the compact length prefix and check it covers the rest
+        let mut input = &extrinsic[..];
+        let len = Compact::<u32>::decode(&mut input).unwrap().0 as usize;
+        assert_eq!(len, input.len());
+        
+        assert_eq!(input[0], 0x84);
+        assert_eq!(input[1], 0); // MultiAddress::Id
+        assert_eq!(&input[2..34], &signer.public().0);
+        assert_eq!(input[34], 1); // MultiSignature::Sr25519
+        
+        let mut signature = [0u8; 64];
+        signature.copy_from_slice(&input[35..99]);
+        let signature = sr25519::Signature::from_raw(signature);
+        
+        // Extra (64-block era at phase 40, nonce 5, tip 10, metadata hash disabled) then the call
+        assert_eq!(&input[99..104], &[0x85, 0x02, 5 << 2, 10 << 2, 0]);
+        assert_eq!(&input[104..], &call[..]);
+        
+        let payload = RealSubstrateAdapter::signed_payload(&call, 5, 10, &params);
+        assert!(sr25519::Pair::verify(&signature, &payload, &signer.public()));
+        
+        // Additional signed ends with the era's block hash and a `None` metadata hash
+        let mut additional = 1_002_000u32.encode();
+        additional.extend(26u32.encode());
+        additional.extend([0x91; 32]);
+        additional.extend([0xb1; 32]);
+        additional.push(0);
+        assert!(payload.ends_with(&additional));
+        
+        // Different chain parameters or era block must not verify
+        for other in [
+            SigningParams { spec_version: 1_003_000, ..params },
+            SigningParams { era_block_hash: [0xb2; 32], ..params },
+            SigningParams { era: Era::Immortal, ..params },
+        ] {
+            let other_payload = RealSubstrateAdapter::signed_payload(&call, 5, 10, &other);
+            assert!(!sr25519::Pair::verify(&signature, &other_payload, &signer.public()));
+        }
+    }
+    
+    /// Answers each method with canned JSON, recording the params it was called with
+    struct MockRpc {
+        responses: Vec<(&'static str, &'static str)>,
+        calls: std::sync::Mutex<Vec<(String, Option<String>)>>,
+    }
+    
+    impl subxt::backend::rpc::RpcClientT for MockRpc {
+        fn request_raw<'a>(
+            &'a self,
+            method: &'a str,
+            params: Option<Box<subxt::backend::rpc::RawValue>>,
+        ) -> subxt::backend::rpc::RawRpcFuture<'a, Box<subxt::backend::rpc::RawValue>> {
+            self.calls.lock().unwrap().push((method.to_string(), params.map(|p| p.get().to_string())));
+            let response = self.responses.iter().find(|(m, _)| *m == method).map(|(_, r)| *r);
+            Box::pin(async move {
+                let response = response
+                    .ok_or_else(|| subxt::error::RpcError::RequestRejected(method.to_string()))?;
+                Ok(subxt::backend::rpc::RawValue::from_string(response.to_string()).unwrap())
+            })
+        }
+        
+        fn subscribe_raw<'a>(
+            &'a self,
+            sub: &'a str,
+            _params: Option<Box<subxt::backend::rpc::RawValue>>,
+            _unsub: &'a str,
+        ) -> subxt::backend::rpc::RawRpcFuture<'a, subxt::backend::rpc::RawRpcSubscription> {
+            Box::pin(async move { Err(subxt::error::RpcError::RequestRejected(sub.to_string())) })
+        }
+    }
+    
+    const POLKADOT_GENESIS: &str = "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3";
+    
+    #[tokio::test]
+    async fn test_fetch_runtime_version_parses_rpc_responses() {
+        // Trimmed from a real Polkadot node response
+        let runtime_version = r#"{"specName":"polkadot","implName":"parity-polkadot","authoringVersion":0,"specVersion":1002000,"implVersion":0,"apis":[["0xdf6acb689907609b",5]],"transactionVersion":26,"stateVersion":0}"#;
+        let mock = Arc::new(MockRpc {
+            responses: vec![
+                ("state_getRuntimeVersion", runtime_version),
+                ("chain_getBlockHash", r#""0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3""#),
+            ],
+            calls: Default::default(),
+        });
+        
+        let (spec_version, transaction_version, genesis_hash) =
+            fetch_runtime_version(&RpcClient::new(mock.clone())).await.unwrap();
+        
+        assert_eq!(spec_version, 1_002_000);
+        assert_eq!(transaction_version, 26);
+        assert_eq!(genesis_hash, H256::from_str(POLKADOT_GENESIS).unwrap());
+        
+        // The genesis hash is block 0's, not the best block's
+        let calls = mock.calls.lock().unwrap();
+        assert_eq!(calls[1], ("chain_getBlockHash".to_string(), Some("[0]".to_string())));
+    }
+    
+    #[tokio::test]
+    async fn test_fetch_runtime_version_without_genesis_hash() {
+        let mock = MockRpc {
+            responses: vec![
+                ("state_getRuntimeVersion", r#"{"specVersion":1,"transactionVersion":1}"#),
+                ("chain_getBlockHash", "null"),
+            ],
+            calls: Default::default(),
+        };
+        
+        assert!(fetch_runtime_version(&RpcClient::new(mock)).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_fetch_mortal_era_starts_at_finalized_head() {
+        let head = "0xb1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1";
+        let mock = Arc::new(MockRpc {
+            responses: vec![
+                ("chain_getFinalizedHead", r#""0xb1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1""#),
+                ("chain_getHeader", r#"{"number":"0x3e8","digest":{"logs":[]}}"#),
+            ],
+            calls: Default::default(),
+        });
+        
+        let (era, hash) = fetch_mortal_era(&RpcClient::new(mock.clone())).await.unwrap();
+        assert_eq!(era, Era::mortal(MORTAL_PERIOD, 1_000));
+        assert_eq!(era.birth(1_000), 1_000);
+        assert_eq!(era.death(1_000), 1_000 + MORTAL_PERIOD);
+        assert_eq!(hash, H256::from_str(head).unwrap());
+        
+        // The header is looked up by the finalized head's hash
+        let calls = mock.calls.lock().unwrap();
+        assert_eq!(calls[1], ("chain_getHeader".to_string(), Some(format!("[\"{}\"]", head))));
+    }
+    
+    #[tokio::test]
+    #[ignore] // Requires Polkadot node
+    async fn test_runtime_version_live() {
+        let translator = Arc::new(IntentTranslator::new());
+        
+        let adapter = RealSubstrateAdapter::new(
+            "wss://rpc.polkadot.io".to_string(),
+            "polkadot".to_string(),
+            "polkadot-0".to_string(),
+            translator,
+        ).await.unwrap();
+        
+        let (spec_version, transaction_version, genesis_hash) = adapter.runtime_version().await.unwrap();
+        
+        assert!(spec_version > 0 && transaction_version > 0);
+        assert_eq!(genesis_hash, H256::from_str(POLKADOT_GENESIS).unwrap());
+    }
+}
@@ -0,0 +1,145 @@
+// Price Oracle - USD prices of native tokens so fees on different chains can be compared
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::RouterError;
+use crate::core::clock::{system_clock, Clock};
+
+/// How long a fetched price is reused before asking the API again
+pub const DEFAULT_PRICE_TTL_SECS: u64 = 60;
+
+/// USD price of one whole token, by symbol (e.g. "ETH")
+#[async_trait]
+pub trait PriceOracle: Send + Sync {
+    async fn price_usd(&self, token: &str) -> Result<f64, RouterError>;
+    
+    /// Decimals of the token's base unit (wei, satoshi, planck) that fees are counted in
+    fn decimals(&self, token: &str) -> u32 {
+        token_decimals(token)
+    }
+}
+
+/// Base-unit decimals of the native tokens the bridge knows about; 18 otherwise
+pub fn token_decimals(token: &str) -> u32 {
+    match token.to_uppercase().as_str() {
+        "BTC" | "BCH" | "LTC" | "ZEC" => 8,
+        "DOT" => 10,
+        "ATOM" | "TRX" | "XTZ" => 6,
+        "SOL" => 9,
+        _ => 18,
+    }
+}
+
+/// CoinGecko `simple/price` lookups, cached per token for a short TTL
+pub struct CoinGeckoOracle {
+    base_url: String,
+    client: reqwest::Client,
+    ttl_secs: u64,
+    clock: Arc<dyn Clock>,
+    cache: Mutex<HashMap<String, (f64, u64)>>, // token -> (price, fetched at)
+}
+
+impl CoinGeckoOracle {
+    pub fn new() -> Self {
+        Self {
+            base_url: "https://api.coingecko.com/api/v3".to_string(),
+            client: reqwest::Client::new(),
+            ttl_secs: DEFAULT_PRICE_TTL_SECS,
+            clock: system_clock(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+    
+    /// Use a different API root, e.g. the Pro API or a local mirror
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url;
+        self
+    }
+    
+    pub fn with_ttl(mut self, ttl_secs: u64) -> Self {
+        self.ttl_secs = ttl_secs;
+        self
+    }
+    
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// CoinGecko's id for a token symbol
+    fn coin_id(token: &str) -> Result<&'static str, RouterError> {
+        Ok(match token.to_uppercase().as_str() {
+            "ETH" => "ethereum",
+            "BTC" => "bitcoin",
+            "DOT" => "polkadot",
+            "ATOM" => "cosmos",
+            "SOL" => "solana",
+            "USDC" => "usd-coin",
+            "USDT" => "tether",
+            other => return Err(RouterError::RoutingError(format!("No price source for {}", other))),
+        })
+    }
+}
+
+#[async_trait]
+impl PriceOracle for CoinGeckoOracle {
+    async fn price_usd(&self, token: &str) -> Result<f64, RouterError> {
+        let now = self.clock.now_secs();
+        if let Some((price, fetched_at)) = self.cache.lock().unwrap().get(token) {
+            if now.saturating_sub(*fetched_at) < self.ttl_secs {
+                return Ok(*price);
+            }
+        }
+        
+        let id = Self::coin_id(token)?;
+        let url = format!("{}/simple/price?ids={}&vs_currencies=usd", self.base_url, id);
+        let response: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| RouterError::RoutingError(format!("Price request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| RouterError::RoutingError(format!("Invalid price response: {}", e)))?;
+        
+        let price = response[id]["usd"]
+            .as_f64()
+            .ok_or_else(|| RouterError::RoutingError(format!("No USD price for {}", token)))?;
+        
+        self.cache.lock().unwrap().insert(token.to_string(), (price, now));
+        Ok(price)
+    }
+}
+
+impl Default for CoinGeckoOracle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clock::MockClock;
+    
+    #[tokio::test]
+    async fn test_cached_price_is_reused_until_ttl() {
+        let clock = Arc::new(MockClock::new(1_000));
+        // Nothing listens here, so any request fails
+        let oracle = CoinGeckoOracle::new()
+            .with_base_url("http://127.0.0.1:9".to_string())
+            .with_ttl(30)
+            .with_clock(clock.clone());
+        oracle.cache.lock().unwrap().insert("ETH".to_string(), (2_500.0, 1_000));
+        
+        clock.advance(29);
+        assert_eq!(oracle.price_usd("ETH").await.unwrap(), 2_500.0);
+        
+        clock.advance(1);
+        assert!(oracle.price_usd("ETH").await.is_err());
+        assert!(oracle.price_usd("DOGE").await.is_err());
+    }
+}
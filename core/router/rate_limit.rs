@@ -0,0 +1,110 @@
+// Rate Limiting - Per-origin token buckets so one client can't flood the router
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::RouterError;
+use crate::core::clock::{system_clock, Clock};
+
+/// Admits or refuses one intent per call for an origin
+///
+/// Implementations may keep their counters elsewhere (e.g. Redis) to share limits
+/// between router instances.
+#[async_trait]
+pub trait RateLimiter: Send + Sync {
+    /// Take one unit of `origin`'s allowance, or fail with `RouterError::RateLimited`
+    async fn acquire(&self, origin: &str) -> Result<(), RouterError>;
+}
+
+/// In-memory token buckets: `max_intents` per `window_secs`, refilled continuously
+pub struct TokenBucketLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    clock: Arc<dyn Clock>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: u64,
+}
+
+impl TokenBucketLimiter {
+    pub fn new(max_intents: u32, window_secs: u64) -> Self {
+        let capacity = max_intents.max(1) as f64;
+        Self {
+            capacity,
+            refill_per_sec: capacity / window_secs.max(1) as f64,
+            clock: system_clock(),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+    
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl RateLimiter for TokenBucketLimiter {
+    async fn acquire(&self, origin: &str) -> Result<(), RouterError> {
+        let now = self.clock.now_secs();
+        let mut buckets = self.buckets.lock().unwrap();
+        
+        // New origins start with a full bucket
+        let bucket = buckets.entry(origin.to_string()).or_insert(Bucket {
+            tokens: self.capacity,
+            updated_at: now,
+        });
+        
+        let elapsed = now.saturating_sub(bucket.updated_at) as f64;
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.updated_at = now;
+        
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+        
+        let retry_after_secs = ((1.0 - bucket.tokens) / self.refill_per_sec).ceil().max(1.0) as u64;
+        Err(RouterError::RateLimited {
+            origin: origin.to_string(),
+            retry_after_secs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clock::MockClock;
+    
+    #[tokio::test]
+    async fn test_bucket_refills_over_window() {
+        let clock = Arc::new(MockClock::new(1_000));
+        let limiter = TokenBucketLimiter::new(2, 10).with_clock(clock.clone());
+        
+        limiter.acquire("a").await.unwrap();
+        limiter.acquire("a").await.unwrap();
+        assert!(matches!(
+            limiter.acquire("a").await,
+            Err(RouterError::RateLimited { retry_after_secs: 5, .. })
+        ));
+        
+        // Other origins have their own bucket
+        limiter.acquire("b").await.unwrap();
+        
+        // One token back every 5 seconds
+        clock.advance(5);
+        limiter.acquire("a").await.unwrap();
+        assert!(limiter.acquire("a").await.is_err());
+        
+        // Idle time never fills past capacity
+        clock.advance(1_000);
+        limiter.acquire("a").await.unwrap();
+        limiter.acquire("a").await.unwrap();
+        assert!(limiter.acquire("a").await.is_err());
+    }
+}
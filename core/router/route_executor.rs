@@ -0,0 +1,314 @@
+// Route Executor - Runs a multi-hop route one hop at a time, resumable after a failure
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::{context_id_for, Intent, JournalEntry, JournalPhase, Router, RouterError};
+use crate::core::context::{ContextError, ContextPreserver, SemanticContext, TransactionRecord, TransactionStatus, UserPreferences};
+
+/// Context metadata key holding the route's `HopProgress` list as JSON
+pub const ROUTE_PROGRESS_KEY: &str = "route_progress";
+
+/// How far one hop of an executing route has got
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HopProgress {
+    pub from_chain: String,
+    pub to_chain: String,
+    pub tx_hash: Option<String>, // set once submitted
+    #[serde(default)]
+    pub submitting: bool, // saved before the hop is sent, cleared once its outcome is known
+    pub confirmed: bool,
+}
+
+/// Progress of a route after an `execute` call
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteExecution {
+    pub intent_id: String,
+    pub hops: Vec<HopProgress>,
+    pub complete: bool, // every hop confirmed
+}
+
+/// Executes routes hop by hop, keeping progress in the intent's `SemanticContext`
+///
+/// Each hop is submitted only once the one before it is confirmed, and its tx hash is
+/// saved before its confirmation is checked. Calling `execute` again for the same intent
+/// picks up where the last call stopped: confirmed hops are skipped and a submitted hop
+/// is checked rather than sent again.
+///
+/// Hops go through the same checks as `Router::submit_with_context`: the target chain's
+/// circuit breaker, the translation hash and the adapter's payload check, and with a
+/// journal configured each submission is journaled. A hop interrupted mid-submission is
+/// only resumed once the journal says whether it was sent; without that it's left for an
+/// operator rather than risk sending it twice.
+pub struct RouteExecutor {
+    router: Router,
+    preserver: Arc<ContextPreserver>,
+}
+
+impl RouteExecutor {
+    pub fn new(router: Router, preserver: Arc<ContextPreserver>) -> Self {
+        Self { router, preserver }
+    }
+    
+    /// Run, or resume, an intent's route
+    ///
+    /// Returns once every hop is confirmed, or early with `complete` unset while a hop is
+    /// still pending on chain. A hop that fails is left to be sent again on the next call;
+    /// hops before it stay done. Expiry and rate limits only apply before the first hop
+    /// is sent, since a route stopped midway must still be finishable.
+    pub async fn execute(&self, intent: &Intent) -> Result<RouteExecution, RouterError> {
+        let _in_flight = self.router.in_flight.enter(&intent.id).ok_or(RouterError::ShuttingDown)?;
+        let principal = self.router.authorizer.authorize(intent).await?;
+        
+        let mut context = self.load_context(intent).await?;
+        let mut hops = saved_progress(&context)?;
+        
+        if hops.is_empty() {
+            self.router.check_expiry(intent)?;
+            if let Some(limiter) = &self.router.rate_limiter {
+                limiter.acquire(&principal).await?;
+            }
+            
+            // Fixed on the first call, so a resume follows the route funds are already on
+            hops = self
+                .router
+                .find_route(intent)
+                .await?
+                .hops
+                .into_iter()
+                .map(|hop| HopProgress {
+                    from_chain: hop.from_chain,
+                    to_chain: hop.to_chain,
+                    tx_hash: None,
+                    submitting: false,
+                    confirmed: false,
+                })
+                .collect();
+            self.save_progress(&mut context, &hops).await?;
+        }
+        
+        for index in 0..hops.len() {
+            if hops[index].confirmed {
+                continue;
+            }
+            let hop = hops[index].clone();
+            let adapter = self
+                .router
+                .adapter(&hop.to_chain)
+                .await
+                .ok_or_else(|| RouterError::UnsupportedChain(hop.to_chain.clone()))?;
+            
+            let tx_hash = match hop.tx_hash.clone() {
+                Some(tx_hash) => tx_hash,
+                None if hop.submitting => match self.journaled_outcome(intent, &hop).await? {
+                    // Sent before the crash; its status is checked below like any other
+                    Some(Some(tx_hash)) => {
+                        hops[index].tx_hash = Some(tx_hash.clone());
+                        hops[index].submitting = false;
+                        record_status(&mut context, &hop.to_chain, &tx_hash, TransactionStatus::Pending, self.router.clock.now_secs());
+                        self.save_progress(&mut context, &hops).await?;
+                        tx_hash
+                    }
+                    Some(None) => self.submit_hop(intent, &mut context, &mut hops, index).await?,
+                    None => {
+                        return Err(RouterError::RoutingError(format!(
+                            "Hop {} ({} -> {}) of {} may already have been sent; resolve it before resuming",
+                            index, hop.from_chain, hop.to_chain, intent.id
+                        )))
+                    }
+                },
+                None => self.submit_hop(intent, &mut context, &mut hops, index).await?,
+            };
+            
+            let status = self
+                .router
+                .call_adapter(&hop.to_chain, adapter.transaction_status(&tx_hash))
+                .await?;
+            record_status(&mut context, &hop.to_chain, &tx_hash, status.clone(), self.router.clock.now_secs());
+            match status {
+                TransactionStatus::Confirmed => {
+                    hops[index].confirmed = true;
+                    self.save_progress(&mut context, &hops).await?;
+                }
+                TransactionStatus::Pending => {
+                    self.save_progress(&mut context, &hops).await?;
+                    return Ok(RouteExecution { intent_id: intent.id.clone(), hops, complete: false });
+                }
+                TransactionStatus::Failed => {
+                    // Nothing moved, so the hop is sent afresh next time
+                    hops[index].tx_hash = None;
+                    self.save_progress(&mut context, &hops).await?;
+                    return Err(RouterError::RoutingError(format!(
+                        "Hop {} ({} -> {}) of {} failed on chain in {}",
+                        index, hop.from_chain, hop.to_chain, intent.id, tx_hash
+                    )));
+                }
+            }
+        }
+        
+        Ok(RouteExecution { intent_id: intent.id.clone(), hops, complete: true })
+    }
+    
+    /// Translate, check and send hop `index`, saving its hash before returning it
+    async fn submit_hop(
+        &self,
+        intent: &Intent,
+        context: &mut SemanticContext,
+        hops: &mut [HopProgress],
+        index: usize,
+    ) -> Result<String, RouterError> {
+        let hop = hops[index].clone();
+        let adapter = self
+            .router
+            .adapter(&hop.to_chain)
+            .await
+            .ok_or_else(|| RouterError::UnsupportedChain(hop.to_chain.clone()))?;
+        let leg = Intent {
+            from_chain: hop.from_chain.clone(),
+            to_chain: hop.to_chain.clone(),
+            ..intent.clone()
+        };
+        
+        self.router.circuit_breakers.check_routable(&hop.to_chain, self.router.clock.now_secs())?;
+        let translated = adapter.translate_intent(&leg).await?;
+        if !translated.verify_hash() {
+            return Err(RouterError::VerificationError(format!(
+                "Translation hash mismatch for hop {} of {}",
+                index, intent.id
+            )));
+        }
+        adapter.verify_translation(&translated)?;
+        
+        let mut entry = JournalEntry {
+            intent_id: intent.id.clone(),
+            source_chain: hop.from_chain.clone(),
+            chain: hop.to_chain.clone(),
+            context_id: context.id.clone(),
+            phase: JournalPhase::Submitting,
+            tx_hash: None,
+            timestamp: self.router.clock.now_secs(),
+        };
+        self.journal(&mut entry, JournalPhase::Submitting).await?;
+        // Marked before sending, so a crash from here on leaves the hop in doubt, not unsent
+        hops[index].submitting = true;
+        self.save_progress(context, hops).await?;
+        
+        let tx_hash = match self
+            .router
+            .call_adapter(&hop.to_chain, adapter.submit_transaction(&translated.target_format))
+            .await
+        {
+            Ok(tx_hash) => tx_hash,
+            Err(e) => {
+                if let Err(journal_error) = self.journal(&mut entry, JournalPhase::Failed).await {
+                    tracing::warn!("Failed to journal rejected hop {} of {}: {}", index, intent.id, journal_error);
+                }
+                hops[index].submitting = false;
+                self.save_progress(context, hops).await?;
+                return Err(e);
+            }
+        };
+        entry.tx_hash = Some(tx_hash.clone());
+        self.journal(&mut entry, JournalPhase::Submitted).await?;
+        
+        hops[index].tx_hash = Some(tx_hash.clone());
+        hops[index].submitting = false;
+        record_status(context, &hop.to_chain, &tx_hash, TransactionStatus::Pending, self.router.clock.now_secs());
+        self.save_progress(context, hops).await?;
+        self.journal(&mut entry, JournalPhase::Recorded).await?;
+        
+        Ok(tx_hash)
+    }
+    
+    /// What the journal says became of a hop left submitting: `Some(Some(hash))` if it was
+    /// sent, `Some(None)` if it was rejected, `None` if nobody knows
+    async fn journaled_outcome(&self, intent: &Intent, hop: &HopProgress) -> Result<Option<Option<String>>, RouterError> {
+        let Some(journal) = &self.router.journal else {
+            return Ok(None);
+        };
+        let latest = journal
+            .entries()
+            .await?
+            .into_iter()
+            .rev()
+            .find(|entry| entry.intent_id == intent.id && entry.chain == hop.to_chain);
+        
+        Ok(match latest {
+            Some(JournalEntry { phase: JournalPhase::Failed, .. }) => Some(None),
+            Some(JournalEntry { tx_hash: Some(tx_hash), .. }) => Some(Some(tx_hash)),
+            _ => None,
+        })
+    }
+    
+    /// Append `entry` at `phase` to the router's journal, if it has one
+    async fn journal(&self, entry: &mut JournalEntry, phase: JournalPhase) -> Result<(), RouterError> {
+        if let Some(journal) = &self.router.journal {
+            entry.phase = phase;
+            entry.timestamp = self.router.clock.now_secs();
+            journal.append(entry).await?;
+        }
+        Ok(())
+    }
+    
+    /// Saved progress of an intent's route, empty if it was never executed
+    pub async fn progress(&self, intent: &Intent) -> Result<Vec<HopProgress>, RouterError> {
+        match self.preserver.load_context(&context_id_for(intent)).await {
+            Ok(context) => saved_progress(&context),
+            Err(ContextError::NotFound(_)) => Ok(Vec::new()),
+            Err(e) => Err(context_error(e)),
+        }
+    }
+    
+    async fn load_context(&self, intent: &Intent) -> Result<SemanticContext, RouterError> {
+        match self.preserver.load_context(&context_id_for(intent)).await {
+            Ok(context) => Ok(context),
+            Err(ContextError::NotFound(_)) => Ok(SemanticContext::new_with_clock(
+                intent.id.clone(),
+                intent.from_chain.clone(),
+                intent.to_chain.clone(),
+                UserPreferences::default(),
+                self.router.clock.as_ref(),
+            )),
+            Err(e) => Err(context_error(e)),
+        }
+    }
+    
+    async fn save_progress(&self, context: &mut SemanticContext, hops: &[HopProgress]) -> Result<(), RouterError> {
+        let progress = serde_json::to_string(hops).expect("HopProgress always serializes to JSON");
+        context.add_metadata(ROUTE_PROGRESS_KEY.to_string(), progress);
+        self.preserver.save_context(context.clone()).await.map_err(context_error)
+    }
+}
+
+fn saved_progress(context: &SemanticContext) -> Result<Vec<HopProgress>, RouterError> {
+    match context.metadata.get(ROUTE_PROGRESS_KEY) {
+        Some(progress) => serde_json::from_str(progress).map_err(|e| {
+            RouterError::RoutingError(format!("Corrupt route progress for {}: {}", context.intent_id, e))
+        }),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Set the status of a hop's transaction in the context history, adding it if new
+fn record_status(context: &mut SemanticContext, chain: &str, tx_hash: &str, status: TransactionStatus, now: u64) {
+    let existing = context
+        .transaction_history
+        .iter_mut()
+        .find(|record| record.chain == chain && record.tx_hash == tx_hash);
+    match existing {
+        Some(record) => {
+            record.status = status;
+            record.timestamp = now;
+            context.update_integrity_hash();
+        }
+        None => context.add_transaction(TransactionRecord {
+            chain: chain.to_string(),
+            tx_hash: tx_hash.to_string(),
+            status,
+            timestamp: now,
+        }),
+    }
+}
+
+fn context_error(e: ContextError) -> RouterError {
+    RouterError::RoutingError(format!("Context error: {}", e))
+}
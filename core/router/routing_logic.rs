@@ -1,7 +1,8 @@
 // Routing Logic - Handles intent routing decisions and path finding
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
+use super::price_oracle::PriceOracle;
 use super::{Intent, RouterError};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +24,109 @@ pub struct RouteHop {
     pub time_ms: u64,
 }
 
+/// Cost breakdown for one hop of a quoted route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HopQuote {
+    pub from_chain: String,
+    pub to_chain: String,
+    pub bridge_type: BridgeType,
+    pub gas_estimate: u64, // in the destination chain's gas unit
+    pub bridge_fee: u64,   // routing graph cost of the connection
+    #[serde(default)]
+    pub fee_token: Option<String>, // native token the hop's fees are paid in, if its adapter says
+}
+
+/// All costs of executing an intent along a route
+///
+/// Gas units differ per chain (gas, weight, vbytes), so there is no common denomination;
+/// `gas_by_chain` keeps them apart and `total_fee` is only meaningful for comparing routes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteQuote {
+    pub intent_id: String,
+    pub hops: Vec<HopQuote>,
+    pub gas_by_chain: BTreeMap<String, u64>,
+    pub total_gas: u64,
+    pub total_bridge_fees: u64,
+    pub total_fee: u64,
+    pub amount_in: u64,
+    pub expected_amount_out: u64, // amount_in less bridge fees, which are taken from the bridged asset
+}
+
+impl RouteQuote {
+    pub fn new(intent_id: &str, hops: Vec<HopQuote>, amount_in: u64) -> Self {
+        let mut gas_by_chain = BTreeMap::new();
+        for hop in &hops {
+            *gas_by_chain.entry(hop.to_chain.clone()).or_insert(0u64) += hop.gas_estimate;
+        }
+        
+        let total_gas: u64 = hops.iter().map(|h| h.gas_estimate).sum();
+        let total_bridge_fees: u64 = hops.iter().map(|h| h.bridge_fee).sum();
+        
+        Self {
+            intent_id: intent_id.to_string(),
+            hops,
+            gas_by_chain,
+            total_gas,
+            total_bridge_fees,
+            total_fee: total_gas + total_bridge_fees,
+            amount_in,
+            expected_amount_out: amount_in.saturating_sub(total_bridge_fees),
+        }
+    }
+    
+    /// Total fee in USD, pricing each hop's gas and bridge fee in that hop's `fee_token`
+    ///
+    /// Fees are taken as base units of the token (wei, satoshi, planck), scaled by the
+    /// oracle's decimals for it.
+    pub async fn total_usd(&self, oracle: &dyn PriceOracle) -> Result<f64, RouterError> {
+        let mut total = 0.0;
+        for hop in &self.hops {
+            let token = hop.fee_token.as_deref().ok_or_else(|| {
+                RouterError::RoutingError(format!("No fee token known for hop to {}", hop.to_chain))
+            })?;
+            
+            let amount = (hop.gas_estimate + hop.bridge_fee) as f64 / 10f64.powi(oracle.decimals(token) as i32);
+            total += amount * oracle.price_usd(token).await?;
+        }
+        Ok(total)
+    }
+}
+
+/// Simulated outcome of one hop of a route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HopSimulation {
+    pub from_chain: String,
+    pub to_chain: String,
+    pub amount_in: u64, // the previous hop's simulated output, or the intent's amount for the first
+    pub success: bool,
+    pub gas_used: u64, // in the destination chain's gas unit
+    pub amount_out: Option<u64>,
+    pub revert_reason: Option<String>,
+}
+
+/// Hop-by-hop dry run of an intent's route
+///
+/// Simulation stops at the first failing hop, since later hops would have nothing to
+/// work with; `hops` can be shorter than the route.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteSimulation {
+    pub intent_id: String,
+    pub hops: Vec<HopSimulation>,
+    pub total_gas: u64,
+    pub will_succeed: bool,
+}
+
+impl RouteSimulation {
+    pub fn new(intent_id: &str, hops: Vec<HopSimulation>) -> Self {
+        Self {
+            intent_id: intent_id.to_string(),
+            total_gas: hops.iter().map(|h| h.gas_used).sum(),
+            will_succeed: !hops.is_empty() && hops.iter().all(|h| h.success),
+            hops,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BridgeType {
     Native,      // Direct bridge
@@ -31,6 +135,17 @@ pub enum BridgeType {
     Relay,       // Relayer-based
 }
 
+/// One directed edge of the routing graph, in a form that can be persisted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConnectionRecord {
+    pub from: String,
+    pub to: String,
+    pub bridge_type: BridgeType,
+    pub cost: u64,
+    pub latency_ms: u64,
+    pub reliability: f64,
+}
+
 pub struct RoutingEngine {
     chain_graph: ChainGraph,
     route_cache: HashMap<(String, String), Route>,
@@ -66,6 +181,23 @@ impl RoutingEngine {
         engine
     }
     
+    /// Engine over exactly the given chains and connections, without the default graph
+    pub fn from_connections(chains: &[String], connections: &[ConnectionRecord]) -> Self {
+        let mut engine = Self {
+            chain_graph: ChainGraph {
+                nodes: chains.iter().cloned().collect(),
+                edges: HashMap::new(),
+            },
+            route_cache: HashMap::new(),
+        };
+        
+        for conn in connections {
+            engine.add_connection(&conn.from, &conn.to, conn.bridge_type.clone(), conn.cost, conn.latency_ms, conn.reliability);
+        }
+        
+        engine
+    }
+    
     fn initialize_default_connections(&mut self) {
         // Add chains
         let chains = vec!["ethereum", "polkadot", "bitcoin", "cosmos", "sentium"];
@@ -121,6 +253,36 @@ impl RoutingEngine {
             .push(connection);
     }
     
+    /// All chains in the graph, sorted
+    pub fn chains(&self) -> Vec<String> {
+        let mut chains: Vec<String> = self.chain_graph.nodes.iter().cloned().collect();
+        chains.sort();
+        chains
+    }
+    
+    /// Every edge, grouped by source chain in sorted order
+    ///
+    /// Edges from one chain keep their insertion order, which path finding depends on,
+    /// so an engine rebuilt with `from_connections` resolves the same routes.
+    pub fn connections(&self) -> Vec<ConnectionRecord> {
+        let mut sources: Vec<&String> = self.chain_graph.edges.keys().collect();
+        sources.sort();
+        
+        sources
+            .into_iter()
+            .flat_map(|from| {
+                self.chain_graph.edges[from].iter().map(move |conn| ConnectionRecord {
+                    from: from.clone(),
+                    to: conn.target.clone(),
+                    bridge_type: conn.bridge_type.clone(),
+                    cost: conn.cost,
+                    latency_ms: conn.latency_ms,
+                    reliability: conn.reliability,
+                })
+            })
+            .collect()
+    }
+    
     pub fn find_route(&mut self, intent: &Intent) -> Result<Route, RouterError> {
         // Check cache first
         let cache_key = (intent.from_chain.clone(), intent.to_chain.clone());
@@ -357,6 +519,8 @@ mod tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let route = engine.find_route(&intent);
@@ -378,6 +542,8 @@ mod tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let route = engine.find_route(&intent);
@@ -0,0 +1,44 @@
+// Schema - JSON Schema for the intent format, for integrators in other languages
+use schemars::gen::SchemaSettings;
+use schemars::schema::RootSchema;
+
+use super::{ActionType, Intent, TranslatedIntent};
+use crate::core::context::UserPreferences;
+
+/// JSON Schema (draft-07) of `Intent`
+///
+/// `ActionType`, `UserPreferences` and `TranslatedIntent` are included under `definitions`:
+/// `params` carries a JSON-encoded `ActionType` (see `Intent::with_action`), and the others
+/// appear in context and routing responses.
+pub fn intent_schema() -> RootSchema {
+    let mut generator = SchemaSettings::draft07().into_generator();
+    generator.subschema_for::<ActionType>();
+    generator.subschema_for::<UserPreferences>();
+    generator.subschema_for::<TranslatedIntent>();
+    generator.into_root_schema_for::<Intent>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_schema_lists_every_action_variant() {
+        let schema = serde_json::to_value(intent_schema()).unwrap();
+        assert_eq!(schema["title"], "Intent");
+        assert!(schema["properties"]["action"].is_object());
+        
+        // Externally tagged: each variant is an object keyed by its name
+        let variants: Vec<&str> = schema["definitions"]["ActionType"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["required"][0].as_str().unwrap())
+            .collect();
+        assert_eq!(variants, ["Transfer", "Swap", "Stake", "Unstake", "ContractCall", "Split"]);
+        
+        for name in ["UserPreferences", "RiskLevel", "TranslatedIntent", "TranslationMetadata"] {
+            assert!(schema["definitions"][name].is_object(), "{} missing", name);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+// Sentium Envelope - Native intent submission format for the Sentium chain
+use sha3::{Digest, Sha3_256};
+
+use super::RouterError;
+use crate::core::crypto::{QuantumKeyManager, QuantumPublicKey, QuantumSecretKey};
+
+/// First bytes of every encoded envelope
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"SNTM";
+
+/// Layout version written after the magic
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// Signed intent as submitted to Sentium
+///
+/// Encoding, integers big-endian:
+/// `magic (4) | version (1) | nonce (8) | intent_len (4) | intent | public_key_len (4) |
+/// public_key | signature_len (4) | signature`. The Dilithium5 signature covers everything
+/// up to the end of the intent, so the nonce can't be replayed with another intent.
+/// Unsigned envelopes, as produced by translation, have nonce 0 and empty key and signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentiumEnvelope {
+    pub nonce: u64,
+    pub intent: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl SentiumEnvelope {
+    pub fn unsigned(intent: Vec<u8>) -> Self {
+        Self {
+            nonce: 0,
+            intent,
+            public_key: Vec::new(),
+            signature: Vec::new(),
+        }
+    }
+    
+    pub fn is_signed(&self) -> bool {
+        !self.signature.is_empty()
+    }
+    
+    /// Bytes the signature covers: the header and intent
+    pub fn signing_message(&self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(17 + self.intent.len());
+        message.extend_from_slice(&ENVELOPE_MAGIC);
+        message.push(ENVELOPE_VERSION);
+        message.extend_from_slice(&self.nonce.to_be_bytes());
+        message.extend_from_slice(&(self.intent.len() as u32).to_be_bytes());
+        message.extend_from_slice(&self.intent);
+        message
+    }
+    
+    /// Set the nonce and sign with a Dilithium5 key
+    pub fn sign(&mut self, nonce: u64, public_key: &QuantumPublicKey, secret_key: &QuantumSecretKey) {
+        self.nonce = nonce;
+        self.public_key = public_key.as_bytes().to_vec();
+        self.signature = QuantumKeyManager::sign(secret_key, &self.signing_message());
+    }
+    
+    /// Whether the envelope carries a valid signature by its public key
+    pub fn verify(&self) -> bool {
+        match QuantumPublicKey::from_bytes(&self.public_key) {
+            Ok(public_key) => QuantumKeyManager::verify(&public_key, &self.signing_message(), &self.signature),
+            Err(_) => false,
+        }
+    }
+    
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.signing_message();
+        for field in [&self.public_key, &self.signature] {
+            bytes.extend_from_slice(&(field.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(field);
+        }
+        bytes
+    }
+    
+    pub fn decode(bytes: &[u8]) -> Result<Self, RouterError> {
+        let mut reader = Reader { bytes };
+        if reader.take(4)? != ENVELOPE_MAGIC {
+            return Err(malformed("bad magic"));
+        }
+        let version = reader.take(1)?[0];
+        if version != ENVELOPE_VERSION {
+            return Err(malformed(&format!("unsupported version {}", version)));
+        }
+        
+        let nonce = u64::from_be_bytes(reader.take(8)?.try_into().unwrap());
+        let intent = reader.take_prefixed()?;
+        let public_key = reader.take_prefixed()?;
+        let signature = reader.take_prefixed()?;
+        if !reader.bytes.is_empty() {
+            return Err(malformed(&format!("{} trailing bytes", reader.bytes.len())));
+        }
+        
+        Ok(Self { nonce, intent, public_key, signature })
+    }
+    
+    /// Identifier the chain reports for a submitted envelope: SHA3-256 of its encoding
+    pub fn id(&self) -> [u8; 32] {
+        Sha3_256::digest(self.encode()).into()
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], RouterError> {
+        if self.bytes.len() < n {
+            return Err(malformed("truncated"));
+        }
+        let (head, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(head)
+    }
+    
+    fn take_prefixed(&mut self) -> Result<Vec<u8>, RouterError> {
+        let len = u32::from_be_bytes(self.take(4)?.try_into().unwrap()) as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+}
+
+fn malformed(reason: &str) -> RouterError {
+    RouterError::TranslationError(format!("Malformed Sentium envelope: {}", reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_envelope_layout_round_trips() {
+        let envelope = SentiumEnvelope {
+            nonce: 0x0102,
+            intent: b"{}".to_vec(),
+            public_key: vec![0xaa; 3],
+            signature: vec![0xbb; 2],
+        };
+        
+        let bytes = envelope.encode();
+        assert_eq!(
+            hex::encode(&bytes),
+            concat!(
+                "534e544d", "01", "0000000000000102",
+                "00000002", "7b7d",
+                "00000003", "aaaaaa",
+                "00000002", "bbbb",
+            )
+        );
+        assert_eq!(SentiumEnvelope::decode(&bytes).unwrap(), envelope);
+        
+        assert!(SentiumEnvelope::decode(&bytes[..bytes.len() - 1]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(SentiumEnvelope::decode(&trailing).is_err());
+        let mut other_version = bytes;
+        other_version[4] = 2;
+        assert!(SentiumEnvelope::decode(&other_version).is_err());
+    }
+    
+    #[test]
+    fn test_signature_covers_nonce_and_intent() {
+        let (public_key, secret_key) = QuantumKeyManager::generate_keypair();
+        let mut envelope = SentiumEnvelope::unsigned(b"{\"Transfer\":{}}".to_vec());
+        assert!(!envelope.is_signed());
+        assert!(!envelope.verify());
+        
+        envelope.sign(7, &public_key, &secret_key);
+        assert_eq!(envelope.public_key, public_key.as_bytes());
+        let envelope = SentiumEnvelope::decode(&envelope.encode()).unwrap();
+        assert!(envelope.is_signed());
+        assert!(envelope.verify());
+        
+        let mut replayed = envelope.clone();
+        replayed.nonce = 8;
+        assert!(!replayed.verify());
+        let mut altered = envelope;
+        altered.intent[0] ^= 1;
+        assert!(!altered.verify());
+    }
+}
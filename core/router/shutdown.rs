@@ -0,0 +1,113 @@
+// Shutdown - Tracks in-flight routing so the router can drain before it stops
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// How long `Router::shutdown` waits for in-flight intents unless configured otherwise
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// What `Router::shutdown` couldn't finish cleanly
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    pub abandoned_intents: Vec<String>, // ids of intents still routing when the timeout passed
+    pub context_flush_error: Option<String>,
+    pub adapter_errors: Vec<(String, String)>, // chain name and close error
+}
+
+impl ShutdownReport {
+    /// Whether everything drained, flushed and closed
+    pub fn is_clean(&self) -> bool {
+        self.abandoned_intents.is_empty() && self.context_flush_error.is_none() && self.adapter_errors.is_empty()
+    }
+}
+
+/// Intents currently being routed, shared by every clone of a router
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    closed: AtomicBool,
+    next_key: AtomicU64,
+    intents: Mutex<HashMap<u64, String>>,
+    drained: Notify,
+}
+
+impl InFlight {
+    /// Register an intent as routing until the guard drops; `None` once closed
+    pub(crate) fn enter(self: &Arc<Self>, intent_id: &str) -> Option<InFlightGuard> {
+        let key = self.next_key.fetch_add(1, Ordering::Relaxed);
+        self.intents.lock().unwrap().insert(key, intent_id.to_string());
+        let guard = InFlightGuard { in_flight: self.clone(), key };
+        
+        // Checked after registering, so `drain` either sees this intent or it never starts
+        if self.closed.load(Ordering::SeqCst) {
+            return None;
+        }
+        Some(guard)
+    }
+    
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+    
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+    
+    /// Wait until nothing is in flight or `timeout` passes, returning the ids left over
+    pub(crate) async fn drain(&self, timeout: Duration) -> Vec<String> {
+        let wait = async {
+            loop {
+                // Created before the check so a guard dropping in between still wakes us
+                let drained = self.drained.notified();
+                if self.intents.lock().unwrap().is_empty() {
+                    return;
+                }
+                drained.await;
+            }
+        };
+        let _ = tokio::time::timeout(timeout, wait).await;
+        
+        let mut remaining: Vec<String> = self.intents.lock().unwrap().values().cloned().collect();
+        remaining.sort();
+        remaining
+    }
+}
+
+pub(crate) struct InFlightGuard {
+    in_flight: Arc<InFlight>,
+    key: u64,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let mut intents = self.in_flight.intents.lock().unwrap();
+        intents.remove(&self.key);
+        if intents.is_empty() {
+            self.in_flight.drained.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_closed_tracker_refuses_and_drains() {
+        let in_flight = Arc::new(InFlight::default());
+        let first = in_flight.enter("a").unwrap();
+        let second = in_flight.enter("b").unwrap();
+        
+        in_flight.close();
+        assert!(in_flight.enter("c").is_none());
+        drop(first);
+        assert_eq!(in_flight.drain(Duration::from_millis(10)).await, vec!["b".to_string()]);
+        
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(second);
+        });
+        assert!(in_flight.drain(Duration::from_secs(5)).await.is_empty());
+    }
+}
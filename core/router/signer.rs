@@ -0,0 +1,123 @@
+// Signer - Transaction signing behind a trait so keys can live outside the process
+use async_trait::async_trait;
+use k256::ecdsa::SigningKey;
+use zeroize::Zeroizing;
+
+use super::RouterError;
+
+/// Signs 32-byte digests with a secp256k1 key
+///
+/// EVM, Cosmos and Substrate ECDSA accounts all sign a digest of the transaction with
+/// secp256k1, so one signer serves every submission path; each adapter hashes its own
+/// payload first. Implementations may keep the key remotely (KMS, HSM).
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// Recoverable signature over a 32-byte digest: r (32) || s (32) || recovery id (1)
+    async fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, RouterError>;
+    
+    /// SEC1 compressed public key (33 bytes)
+    fn public_key(&self) -> Vec<u8>;
+}
+
+/// Signer over an in-memory secp256k1 key, zeroized when dropped
+pub struct LocalSigner {
+    secret: Zeroizing<[u8; 32]>,
+    public_key: Vec<u8>,
+}
+
+impl LocalSigner {
+    pub fn from_bytes(secret: &[u8]) -> Result<Self, RouterError> {
+        // from_slice would left-pad shorter slices; a truncated key should fail instead
+        if secret.len() != 32 {
+            return Err(RouterError::TranslationError(format!(
+                "Expected a 32-byte secp256k1 secret key, got {} bytes",
+                secret.len()
+            )));
+        }
+        
+        let key = SigningKey::from_slice(secret)
+            .map_err(|_| RouterError::TranslationError("Invalid secp256k1 secret key".to_string()))?;
+        
+        let mut bytes = Zeroizing::new([0u8; 32]);
+        bytes.copy_from_slice(&key.to_bytes());
+        
+        Ok(Self {
+            secret: bytes,
+            public_key: key.verifying_key().to_sec1_bytes().to_vec(),
+        })
+    }
+}
+
+#[async_trait]
+impl Signer for LocalSigner {
+    async fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, RouterError> {
+        if msg.len() != 32 {
+            return Err(RouterError::TranslationError(format!(
+                "Expected a 32-byte digest to sign, got {} bytes",
+                msg.len()
+            )));
+        }
+        
+        // Rebuilt per call so the only long-lived copy of the key is the zeroized one
+        let key = SigningKey::from_slice(self.secret.as_ref())
+            .map_err(|_| RouterError::TranslationError("Invalid secp256k1 secret key".to_string()))?;
+        let (signature, recovery_id) = key
+            .sign_prehash_recoverable(msg)
+            .map_err(|e| RouterError::TranslationError(format!("Signing failed: {}", e)))?;
+        
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        Ok(bytes)
+    }
+    
+    fn public_key(&self) -> Vec<u8> {
+        self.public_key.clone()
+    }
+}
+
+impl std::fmt::Debug for LocalSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print secret key material
+        f.debug_struct("LocalSigner")
+            .field("public_key", &hex::encode(&self.public_key))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+    use k256::ecdsa::signature::hazmat::PrehashVerifier;
+    use sha2::{Digest, Sha256};
+    
+    #[tokio::test]
+    async fn test_local_signer_signature_verifies_and_recovers() {
+        let signer = LocalSigner::from_bytes(&[0x42; 32]).unwrap();
+        let digest = Sha256::digest(b"sentium bridge transfer");
+        
+        let sig = signer.sign(&digest).await.unwrap();
+        assert_eq!(sig.len(), 65);
+        
+        let public_key = VerifyingKey::from_sec1_bytes(&signer.public_key()).unwrap();
+        let signature = Signature::from_slice(&sig[..64]).unwrap();
+        assert!(public_key.verify_prehash(&digest, &signature).is_ok());
+        
+        let recovery_id = RecoveryId::from_byte(sig[64]).unwrap();
+        let recovered = VerifyingKey::recover_from_prehash(&digest, &signature, recovery_id).unwrap();
+        assert_eq!(recovered, public_key);
+        
+        // A different digest doesn't verify
+        assert!(public_key.verify_prehash(&Sha256::digest(b"other"), &signature).is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_local_signer_rejects_bad_input() {
+        assert!(LocalSigner::from_bytes(&[0u8; 32]).is_err());
+        assert!(LocalSigner::from_bytes(&[1u8; 31]).is_err());
+        
+        let signer = LocalSigner::from_bytes(&[0x42; 32]).unwrap();
+        assert!(signer.sign(b"not a digest").await.is_err());
+        assert!(!format!("{:?}", signer).contains("4242424242424242"));
+    }
+}
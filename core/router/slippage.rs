@@ -0,0 +1,144 @@
+// Slippage - Minimum-output / maximum-input bounds for DEX swaps, shared by all adapters
+use super::RouterError;
+
+/// Slippage applied when the caller supplies no preferences (0.5%)
+pub const DEFAULT_SLIPPAGE_BPS: u16 = 50;
+
+/// Slippage calculator for DEX operations
+pub struct SlippageCalculator;
+
+impl SlippageCalculator {
+    /// Calculate minimum output amount based on expected output and slippage tolerance
+    /// 
+    /// # Arguments
+    /// * `expected_output` - Expected output amount from the swap
+    /// * `slippage_bps` - Slippage tolerance in basis points (e.g., 50 = 0.5%)
+    /// 
+    /// # Returns
+    /// Minimum acceptable output amount
+    pub fn calculate_minimum_output(expected_output: u64, slippage_bps: u16) -> u64 {
+        // Calculate slippage amount: expected_output * slippage_bps / 10000
+        let slippage_amount = (expected_output as u128)
+            .saturating_mul(slippage_bps as u128)
+            .saturating_div(10000)
+            as u64;
+        
+        // Minimum output = expected output - slippage amount
+        expected_output.saturating_sub(slippage_amount)
+    }
+    
+    /// Calculate maximum input amount based on expected input and slippage tolerance
+    /// 
+    /// # Arguments
+    /// * `expected_input` - Expected input amount for the swap
+    /// * `slippage_bps` - Slippage tolerance in basis points (e.g., 50 = 0.5%)
+    /// 
+    /// # Returns
+    /// Maximum acceptable input amount
+    pub fn calculate_maximum_input(expected_input: u64, slippage_bps: u16) -> u64 {
+        // Calculate slippage amount: expected_input * slippage_bps / 10000
+        let slippage_amount = (expected_input as u128)
+            .saturating_mul(slippage_bps as u128)
+            .saturating_div(10000)
+            as u64;
+        
+        // Maximum input = expected input + slippage amount
+        expected_input.saturating_add(slippage_amount)
+    }
+    
+    /// Expected price impact of a constant-product (x * y = k) swap, ignoring fees
+    ///
+    /// Impact is the shortfall of the executed price against the spot price, which reduces to
+    /// `amount_in / (reserve_in + amount_in)`. An empty pool has total impact.
+    pub fn price_impact_bps(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u16 {
+        if reserve_in == 0 || reserve_out == 0 {
+            return 10000;
+        }
+        
+        let impact = (amount_in as u128) * 10000 / (reserve_in as u128 + amount_in as u128);
+        impact as u16
+    }
+    
+    /// Convert a fractional tolerance (0.01 = 1%) to basis points
+    pub fn bps_from_tolerance(tolerance: f64) -> u16 {
+        (tolerance * 10000.0).round().clamp(0.0, u16::MAX as f64) as u16
+    }
+    
+    /// Validate slippage tolerance is within acceptable range
+    /// 
+    /// # Arguments
+    /// * `slippage_bps` - Slippage tolerance in basis points
+    /// 
+    /// # Returns
+    /// Ok if valid, Err if out of range
+    pub fn validate_slippage(slippage_bps: u16) -> Result<(), RouterError> {
+        // Typical range: 0.1% (10 bps) to 5% (500 bps)
+        if slippage_bps == 0 {
+            return Err(RouterError::TranslationError(
+                "Slippage tolerance cannot be zero".to_string()
+            ));
+        }
+        
+        if slippage_bps > 1000 {
+            return Err(RouterError::TranslationError(
+                format!("Slippage tolerance too high: {}% (max 10%)", slippage_bps as f64 / 100.0)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_one_percent_of_thousand() {
+        let bps = SlippageCalculator::bps_from_tolerance(0.01);
+        assert_eq!(bps, 100);
+        assert_eq!(SlippageCalculator::calculate_minimum_output(1000, bps), 990);
+        assert_eq!(SlippageCalculator::calculate_maximum_input(1000, bps), 1010);
+    }
+    
+    #[test]
+    fn test_bounds_match_bps_formula() {
+        // (expected, bps, min_out, max_in): expected * bps / 10000, truncated
+        let cases: &[(u64, u16, u64, u64)] = &[
+            (1000, 50, 995, 1005),
+            (999, 50, 995, 1003),
+            (1, 50, 1, 1),
+            (0, 100, 0, 0),
+            (1_000_000, 1000, 900_000, 1_100_000),
+            (u64::MAX, 100, u64::MAX - u64::MAX / 100, u64::MAX),
+        ];
+        
+        for &(expected, bps, min_out, max_in) in cases {
+            assert_eq!(SlippageCalculator::calculate_minimum_output(expected, bps), min_out, "{} @ {}", expected, bps);
+            assert_eq!(SlippageCalculator::calculate_maximum_input(expected, bps), max_in, "{} @ {}", expected, bps);
+        }
+    }
+    
+    #[test]
+    fn test_price_impact_grows_with_trade_size() {
+        let (reserve_in, reserve_out) = (1_000_000, 2_000_000);
+        
+        let small = SlippageCalculator::price_impact_bps(1_000, reserve_in, reserve_out);
+        let large = SlippageCalculator::price_impact_bps(100_000, reserve_in, reserve_out);
+        
+        assert_eq!(small, 9);
+        assert_eq!(large, 909);
+        assert!(small < DEFAULT_SLIPPAGE_BPS && large > DEFAULT_SLIPPAGE_BPS);
+        
+        assert_eq!(SlippageCalculator::price_impact_bps(0, reserve_in, reserve_out), 0);
+        assert_eq!(SlippageCalculator::price_impact_bps(1_000, 0, reserve_out), 10000);
+    }
+    
+    #[test]
+    fn test_validate_slippage_bounds() {
+        assert!(SlippageCalculator::validate_slippage(0).is_err());
+        assert!(SlippageCalculator::validate_slippage(DEFAULT_SLIPPAGE_BPS).is_ok());
+        assert!(SlippageCalculator::validate_slippage(1000).is_ok());
+        assert!(SlippageCalculator::validate_slippage(1001).is_err());
+    }
+}
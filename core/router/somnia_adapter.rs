@@ -4,15 +4,18 @@ use async_trait::async_trait;
 use ethers::prelude::*;
 use ethers::providers::{Provider, Ws, Http};
 use ethers::types::{Address, U256, TransactionRequest, Bytes, BlockNumber};
+use futures::Stream;
 use std::sync::Arc;
 use std::str::FromStr;
 
 use super::{Intent, RouterError};
+use super::block_subscription::{reconnecting_blocks, sealed_blocks};
 use super::intent_translator::{IntentTranslator, TranslatedIntent};
 
 pub struct RealSomniaAdapter {
     chain_name: String,
     chain_id: u64,
+    ws_url: String,
     provider: Arc<Provider<Ws>>,
     http_provider: Arc<Provider<Http>>,
     translator: Arc<IntentTranslator>,
@@ -37,13 +40,27 @@ impl RealSomniaAdapter {
         Ok(Self {
             chain_name: "somnia".to_string(),
             chain_id,
+            ws_url,
             provider: Arc::new(provider),
             http_provider: Arc::new(http_provider),
             translator,
         })
     }
     
-    async fn encode_somnia_transfer(&self, to: Address, amount: U256) -> Result<Bytes, RouterError> {
+    /// New blocks pushed over the WebSocket connection; ends when the connection drops
+    pub async fn subscribe_blocks(&self) -> Result<impl Stream<Item = Block<TxHash>> + '_, RouterError> {
+        let subscription = self.provider.subscribe_blocks().await
+            .map_err(|e| RouterError::TranslationError(format!("Block subscription failed: {}", e)))?;
+        
+        Ok(sealed_blocks(subscription))
+    }
+    
+    /// Like `subscribe_blocks`, but reconnects to the WebSocket endpoint after a disconnect
+    pub fn subscribe_blocks_reconnecting(&self, max_retries: u32) -> impl Stream<Item = Block<TxHash>> {
+        reconnecting_blocks(self.ws_url.clone(), max_retries)
+    }
+    
+    pub fn encode_somnia_transfer(to: Address, amount: U256) -> Result<Bytes, RouterError> {
         // Somnia native token transfer (EVM-compatible)
         let function = ethabi::Function {
             name: "transfer".to_string(),
@@ -66,20 +83,20 @@ impl RealSomniaAdapter {
                     internal_type: None,
                 },
             ],
+            #[allow(deprecated)]
             constant: Some(false),
             state_mutability: ethabi::StateMutability::NonPayable,
         };
         
         let encoded = function.encode_input(&[
-            ethabi::Token::Address(to.into()),
-            ethabi::Token::Uint(amount.into()),
+            ethabi::Token::Address(to),
+            ethabi::Token::Uint(amount),
         ]).map_err(|e| RouterError::TranslationError(format!("ABI encoding failed: {}", e)))?;
         
         Ok(Bytes::from(encoded))
     }
     
-    async fn encode_somnia_dex_swap(
-        &self,
+    pub fn encode_somnia_dex_swap(
         token_in: Address,
         token_out: Address,
         amount_in: U256,
@@ -124,29 +141,28 @@ impl RealSomniaAdapter {
                     internal_type: None,
                 },
             ],
+            #[allow(deprecated)]
             constant: Some(false),
             state_mutability: ethabi::StateMutability::NonPayable,
         };
         
         let path = vec![
-            ethabi::Token::Address(token_in.into()),
-            ethabi::Token::Address(token_out.into()),
+            ethabi::Token::Address(token_in),
+            ethabi::Token::Address(token_out),
         ];
         
         let encoded = function.encode_input(&[
-            ethabi::Token::Uint(amount_in.into()),
-            ethabi::Token::Uint(amount_out_min.into()),
+            ethabi::Token::Uint(amount_in),
+            ethabi::Token::Uint(amount_out_min),
             ethabi::Token::Array(path),
-            ethabi::Token::Address(recipient.into()),
-            ethabi::Token::Uint(deadline.into()),
+            ethabi::Token::Address(recipient),
+            ethabi::Token::Uint(deadline),
         ]).map_err(|e| RouterError::TranslationError(format!("ABI encoding failed: {}", e)))?;
         
         Ok(Bytes::from(encoded))
     }
     
-    async fn encode_somnia_nft_transfer(
-        &self,
-        nft_contract: Address,
+    pub fn encode_somnia_nft_transfer(
         from: Address,
         to: Address,
         token_id: U256,
@@ -172,14 +188,15 @@ impl RealSomniaAdapter {
                 },
             ],
             outputs: vec![],
+            #[allow(deprecated)]
             constant: Some(false),
             state_mutability: ethabi::StateMutability::NonPayable,
         };
         
         let encoded = function.encode_input(&[
-            ethabi::Token::Address(from.into()),
-            ethabi::Token::Address(to.into()),
-            ethabi::Token::Uint(token_id.into()),
+            ethabi::Token::Address(from),
+            ethabi::Token::Address(to),
+            ethabi::Token::Uint(token_id),
         ]).map_err(|e| RouterError::TranslationError(format!("ABI encoding failed: {}", e)))?;
         
         Ok(Bytes::from(encoded))
@@ -200,6 +217,10 @@ impl super::chain_adapter::ChainAdapter for RealSomniaAdapter {
         self.translator.translate(intent)
     }
     
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        self.translator.verify_translation(translated)
+    }
+    
     async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
         // Verify Somnia state proof (EVM Merkle Patricia Trie)
         if proof.len() < 32 {
@@ -248,7 +269,7 @@ impl super::chain_adapter::ChainAdapter for RealSomniaAdapter {
         let gas_price = self.provider.get_gas_price().await
             .map_err(|e| RouterError::TranslationError(format!("Failed to get gas price: {}", e)))?;
         
-        let tx = tx.gas_price(gas_price);
+        let _tx = tx.gas_price(gas_price);
         
         // Return transaction hash (in production, would actually send)
         let tx_hash = format!("0x{}", hex::encode(&tx_data[..32.min(tx_data.len())]));
@@ -289,12 +310,13 @@ impl super::chain_adapter::ChainAdapter for RealSomniaAdapter {
                         internal_type: None,
                     },
                 ],
+                #[allow(deprecated)]
                 constant: Some(true),
                 state_mutability: ethabi::StateMutability::View,
             };
             
             let call_data = function.encode_input(&[
-                ethabi::Token::Address(addr.into()),
+                ethabi::Token::Address(addr),
             ]).map_err(|e| RouterError::TranslationError(format!("ABI encoding failed: {}", e)))?;
             
             // Make call
@@ -321,6 +343,7 @@ impl super::chain_adapter::ChainAdapter for RealSomniaAdapter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::chain_adapter::ChainAdapter;
     
     #[tokio::test]
     #[ignore] // Requires Somnia node
@@ -338,6 +361,24 @@ mod tests {
         assert!(adapter.is_ok());
     }
     
+    #[tokio::test]
+    #[ignore] // Requires a Somnia WebSocket endpoint in SOMNIA_WS_URL
+    async fn test_subscribe_blocks_live() {
+        use futures::StreamExt;
+        
+        let ws_url = std::env::var("SOMNIA_WS_URL").unwrap();
+        let adapter = RealSomniaAdapter::new(
+            ws_url.clone(),
+            ws_url.replace("wss://", "https://"),
+            50311,
+            Arc::new(IntentTranslator::new()),
+        ).await.unwrap();
+        
+        let blocks: Vec<_> = adapter.subscribe_blocks().await.unwrap().take(2).collect().await;
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].parent_hash, blocks[0].hash.unwrap());
+    }
+    
     #[tokio::test]
     #[ignore]
     async fn test_query_somnia_balance() {
@@ -361,72 +402,36 @@ mod tests {
     
     #[test]
     fn test_encode_somnia_transfer() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        rt.block_on(async {
-            let translator = Arc::new(IntentTranslator::new());
-            
-            let ws = Ws::connect("ws://localhost:8545").await.unwrap();
-            let provider = Provider::new(ws);
-            
-            let http_provider = Provider::<Http>::try_from("http://localhost:8545").unwrap();
-            
-            let adapter = RealSomniaAdapter {
-                chain_name: "somnia".to_string(),
-                chain_id: 50311,
-                provider: Arc::new(provider),
-                http_provider: Arc::new(http_provider),
-                translator,
-            };
-            
-            let to = Address::from_str("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").unwrap();
-            let amount = U256::from(1000000000000000000u64); // 1 STT
-            
-            let encoded = adapter.encode_somnia_transfer(to, amount).await;
-            assert!(encoded.is_ok());
-            
-            let data = encoded.unwrap();
-            // Check function selector (first 4 bytes)
-            assert_eq!(&data[..4], &[0xa9, 0x05, 0x9c, 0xbb]); // transfer(address,uint256)
-        });
+        let to = Address::from_str("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").unwrap();
+        let amount = U256::from(1000000000000000000u64); // 1 STT
+        
+        let encoded = RealSomniaAdapter::encode_somnia_transfer(to, amount);
+        assert!(encoded.is_ok());
+        
+        let data = encoded.unwrap();
+        // Check function selector (first 4 bytes)
+        assert_eq!(&data[..4], &[0xa9, 0x05, 0x9c, 0xbb]); // transfer(address,uint256)
     }
     
     #[test]
     fn test_encode_somnia_dex_swap() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        rt.block_on(async {
-            let translator = Arc::new(IntentTranslator::new());
-            
-            let ws = Ws::connect("ws://localhost:8545").await.unwrap();
-            let provider = Provider::new(ws);
-            
-            let http_provider = Provider::<Http>::try_from("http://localhost:8545").unwrap();
-            
-            let adapter = RealSomniaAdapter {
-                chain_name: "somnia".to_string(),
-                chain_id: 50311,
-                provider: Arc::new(provider),
-                http_provider: Arc::new(http_provider),
-                translator,
-            };
-            
-            let token_in = Address::from_str("0x0000000000000000000000000000000000000001").unwrap();
-            let token_out = Address::from_str("0x0000000000000000000000000000000000000002").unwrap();
-            let recipient = Address::from_str("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").unwrap();
-            
-            let encoded = adapter.encode_somnia_dex_swap(
-                token_in,
-                token_out,
-                U256::from(1000000),
-                U256::from(900000),
-                recipient,
-                U256::from(1700000000),
-            ).await;
-            
-            assert!(encoded.is_ok());
-            
-            let data = encoded.unwrap();
-            // Check function selector
-            assert_eq!(&data[..4], &[0x38, 0xed, 0x17, 0x39]); // swapExactTokensForTokens
-        });
+        let token_in = Address::from_str("0x0000000000000000000000000000000000000001").unwrap();
+        let token_out = Address::from_str("0x0000000000000000000000000000000000000002").unwrap();
+        let recipient = Address::from_str("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").unwrap();
+        
+        let encoded = RealSomniaAdapter::encode_somnia_dex_swap(
+            token_in,
+            token_out,
+            U256::from(1000000),
+            U256::from(900000),
+            recipient,
+            U256::from(1700000000),
+        );
+        
+        assert!(encoded.is_ok());
+        
+        let data = encoded.unwrap();
+        // Check function selector
+        assert_eq!(&data[..4], &[0x38, 0xed, 0x17, 0x39]); // swapExactTokensForTokens
     }
 }
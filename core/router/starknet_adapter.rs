@@ -0,0 +1,576 @@
+// REAL StarkNet Adapter - Production-ready implementation
+// StarkNet is a validity rollup; accounts are Cairo contracts and all values are field elements
+use async_trait::async_trait;
+use reqwest::Client as HttpClient;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+use std::sync::Arc;
+
+use super::{Intent, RouterError};
+use super::intent_translator::{IntentTranslator, TranslatedIntent, TranslationMetadata, TRANSLATOR_VERSION};
+
+/// StarkNet field prime: 2^251 + 17 * 2^192 + 1 (big-endian)
+const FIELD_PRIME: [u8; 32] = [
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// ETH and STRK fee token contracts (same address on mainnet and Sepolia)
+pub const ETH_TOKEN: &str = "0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7";
+pub const STRK_TOKEN: &str = "0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d";
+
+/// Field element (felt252), stored big-endian and always below the field prime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Felt([u8; 32]);
+
+impl Felt {
+    pub const ZERO: Felt = Felt([0u8; 32]);
+    
+    pub fn from_bytes_be(bytes: [u8; 32]) -> Result<Self, RouterError> {
+        // Big-endian byte order makes lexicographic comparison numeric
+        if bytes >= FIELD_PRIME {
+            return Err(RouterError::TranslationError(format!(
+                "Felt out of range: 0x{}",
+                hex::encode(bytes)
+            )));
+        }
+        Ok(Self(bytes))
+    }
+    
+    /// Parse a `0x`-prefixed hex felt of at most 64 digits
+    pub fn from_hex(s: &str) -> Result<Self, RouterError> {
+        let digits = s
+            .strip_prefix("0x")
+            .ok_or_else(|| RouterError::TranslationError(format!("Felt must be 0x-prefixed: {}", s)))?;
+        
+        if digits.is_empty() || digits.len() > 64 {
+            return Err(RouterError::TranslationError(format!("Invalid felt length: {}", s)));
+        }
+        
+        let padded = format!("{:0>64}", digits);
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(&padded, &mut bytes)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid felt {}: {}", s, e)))?;
+        
+        Self::from_bytes_be(bytes)
+    }
+    
+    pub fn from_u128(value: u128) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&value.to_be_bytes());
+        Self(bytes)
+    }
+    
+    pub fn from_u64(value: u64) -> Self {
+        Self::from_u128(value as u128)
+    }
+    
+    /// Value as u128, if it fits
+    pub fn to_u128(self) -> Option<u128> {
+        if self.0[..16].iter().any(|b| *b != 0) {
+            return None;
+        }
+        Some(u128::from_be_bytes(self.0[16..].try_into().unwrap()))
+    }
+    
+    pub fn to_bytes_be(self) -> [u8; 32] {
+        self.0
+    }
+    
+    /// Canonical RPC form: `0x` followed by hex without leading zeros
+    pub fn to_hex(self) -> String {
+        let encoded = hex::encode(self.0);
+        let trimmed = encoded.trim_start_matches('0');
+        if trimmed.is_empty() {
+            "0x0".to_string()
+        } else {
+            format!("0x{}", trimmed)
+        }
+    }
+}
+
+impl Serialize for Felt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+impl<'de> Deserialize<'de> for Felt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Felt::from_hex(&s).map_err(|e| serde::de::Error::custom(format!("{:?}", e)))
+    }
+}
+
+/// StarkNet addresses are felts; reject anything outside the field
+pub fn validate_starknet_address(address: &str) -> Result<Felt, RouterError> {
+    Felt::from_hex(address)
+}
+
+/// Entry point selector: keccak256 of the function name truncated to 250 bits
+pub fn selector_from_name(name: &str) -> Felt {
+    let mut bytes: [u8; 32] = Keccak256::digest(name.as_bytes()).into();
+    bytes[0] &= 0x03;
+    Felt(bytes)
+}
+
+/// Cairo u256 as two felts: (low 128 bits, high 128 bits)
+pub fn u256_to_felts(value: [u8; 32]) -> [Felt; 2] {
+    let mut low = [0u8; 32];
+    let mut high = [0u8; 32];
+    low[16..].copy_from_slice(&value[16..]);
+    high[16..].copy_from_slice(&value[..16]);
+    [Felt(low), Felt(high)]
+}
+
+/// Reassemble a Cairo u256 from its (low, high) felts
+pub fn felts_to_u256(low: &Felt, high: &Felt) -> Result<[u8; 32], RouterError> {
+    let (low, high) = match (low.to_u128(), high.to_u128()) {
+        (Some(low), Some(high)) => (low, high),
+        _ => return Err(RouterError::TranslationError("u256 limb exceeds 128 bits".to_string())),
+    };
+    
+    let mut value = [0u8; 32];
+    value[..16].copy_from_slice(&high.to_be_bytes());
+    value[16..].copy_from_slice(&low.to_be_bytes());
+    Ok(value)
+}
+
+/// A single Cairo contract call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarkNetCall {
+    pub contract_address: Felt,
+    pub entry_point: String,
+    pub calldata: Vec<Felt>,
+}
+
+/// Account `__execute__` calldata for a multicall (Cairo 1 account encoding)
+///
+/// `[n_calls, (to, selector, calldata_len, ...calldata) * n_calls]`
+pub fn execute_calldata(calls: &[StarkNetCall]) -> Vec<Felt> {
+    let mut calldata = vec![Felt::from_u64(calls.len() as u64)];
+    
+    for call in calls {
+        calldata.push(call.contract_address);
+        calldata.push(selector_from_name(&call.entry_point));
+        calldata.push(Felt::from_u64(call.calldata.len() as u64));
+        calldata.extend_from_slice(&call.calldata);
+    }
+    
+    calldata
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceBound {
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+
+impl Serialize for ResourceBound {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // RPC encodes both fields as hex quantities
+        json!({
+            "max_amount": format!("{:#x}", self.max_amount),
+            "max_price_per_unit": format!("{:#x}", self.max_price_per_unit),
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResourceBound {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            max_amount: String,
+            max_price_per_unit: String,
+        }
+        
+        let raw = Raw::deserialize(deserializer)?;
+        let parse = |s: &str| u128::from_str_radix(s.trim_start_matches("0x"), 16).map_err(serde::de::Error::custom);
+        
+        Ok(Self {
+            max_amount: parse(&raw.max_amount)? as u64,
+            max_price_per_unit: parse(&raw.max_price_per_unit)?,
+        })
+    }
+}
+
+/// Fee limits for a v3 transaction, paid in STRK
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceBounds {
+    pub l1_gas: ResourceBound,
+    pub l2_gas: ResourceBound,
+}
+
+impl Default for ResourceBounds {
+    fn default() -> Self {
+        Self {
+            l1_gas: ResourceBound { max_amount: 10_000, max_price_per_unit: 100_000_000_000_000 },
+            l2_gas: ResourceBound { max_amount: 0, max_price_per_unit: 0 },
+        }
+    }
+}
+
+/// Broadcasted INVOKE transaction, version 3
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvokeTransactionV3 {
+    #[serde(rename = "type")]
+    pub tx_type: String,
+    pub version: String,
+    pub sender_address: Felt,
+    pub calldata: Vec<Felt>,
+    pub signature: Vec<Felt>,
+    pub nonce: Felt,
+    pub resource_bounds: ResourceBounds,
+    pub tip: String,
+    pub paymaster_data: Vec<Felt>,
+    pub account_deployment_data: Vec<Felt>,
+    pub nonce_data_availability_mode: String,
+    pub fee_data_availability_mode: String,
+}
+
+impl InvokeTransactionV3 {
+    /// Unsigned transaction; the account contract's signer fills `signature` before submission
+    pub fn new(sender_address: Felt, calls: &[StarkNetCall], nonce: Felt, resource_bounds: ResourceBounds) -> Self {
+        Self {
+            tx_type: "INVOKE".to_string(),
+            version: "0x3".to_string(),
+            sender_address,
+            calldata: execute_calldata(calls),
+            signature: Vec::new(),
+            nonce,
+            resource_bounds,
+            tip: "0x0".to_string(),
+            paymaster_data: Vec::new(),
+            account_deployment_data: Vec::new(),
+            nonce_data_availability_mode: "L1".to_string(),
+            fee_data_availability_mode: "L1".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct StarkNetRpcResponse<T> {
+    result: Option<T>,
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AddInvokeResult {
+    transaction_hash: Felt,
+}
+
+pub struct RealStarkNetAdapter {
+    chain_name: String,
+    chain_id: String,
+    http_client: Arc<HttpClient>,
+    rpc_url: String,
+    account_address: Felt,
+    resource_bounds: ResourceBounds,
+    translator: Arc<IntentTranslator>,
+}
+
+impl RealStarkNetAdapter {
+    /// `account_address` is the sender account contract for translated intents
+    pub fn new(
+        rpc_url: String,
+        network: StarkNetNetwork,
+        account_address: &str,
+        translator: Arc<IntentTranslator>,
+    ) -> Result<Self, RouterError> {
+        let chain_id = match network {
+            StarkNetNetwork::Mainnet => "SN_MAIN",
+            StarkNetNetwork::Sepolia => "SN_SEPOLIA",
+        };
+        
+        Ok(Self {
+            chain_name: "starknet".to_string(),
+            chain_id: chain_id.to_string(),
+            http_client: Arc::new(HttpClient::new()),
+            rpc_url,
+            account_address: validate_starknet_address(account_address)?,
+            resource_bounds: ResourceBounds::default(),
+            translator,
+        })
+    }
+    
+    pub fn with_resource_bounds(mut self, resource_bounds: ResourceBounds) -> Self {
+        self.resource_bounds = resource_bounds;
+        self
+    }
+    
+    async fn rpc_call<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, RouterError> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        
+        let response = self.http_client
+            .post(&self.rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| RouterError::TranslationError(format!("HTTP request failed: {}", e)))?;
+        
+        let result: StarkNetRpcResponse<T> = response.json().await
+            .map_err(|e| RouterError::TranslationError(format!("Failed to parse response: {}", e)))?;
+        
+        result.result.ok_or_else(|| {
+            RouterError::TranslationError(format!("RPC error: {:?}", result.error))
+        })
+    }
+    
+    /// Read-only contract call at the latest block
+    pub async fn call(&self, call: &StarkNetCall) -> Result<Vec<Felt>, RouterError> {
+        self.rpc_call("starknet_call", json!({
+            "request": {
+                "contract_address": call.contract_address,
+                "entry_point_selector": selector_from_name(&call.entry_point),
+                "calldata": call.calldata,
+            },
+            "block_id": "latest",
+        })).await
+    }
+    
+    async fn get_nonce(&self) -> Result<Felt, RouterError> {
+        self.rpc_call("starknet_getNonce", json!({
+            "block_id": "pending",
+            "contract_address": self.account_address,
+        })).await
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum StarkNetNetwork {
+    Mainnet,
+    Sepolia,
+}
+
+#[async_trait]
+impl super::chain_adapter::ChainAdapter for RealStarkNetAdapter {
+    fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+    
+    fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    
+    async fn translate_intent(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
+        // Contract-call intents carry the Cairo calls directly; everything else goes through the translator
+        if intent.action != "contract_call" {
+            return self.translator.translate(intent);
+        }
+        
+        let calls: Vec<StarkNetCall> = serde_json::from_slice(&intent.params)
+            .map_err(|e| RouterError::TranslationError(format!("Invalid StarkNet calls: {}", e)))?;
+        
+        let nonce = self.get_nonce().await?;
+        let tx = InvokeTransactionV3::new(self.account_address, &calls, nonce, self.resource_bounds);
+        
+        let target_format = serde_json::to_vec(&tx)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to encode transaction: {}", e)))?;
+        
+        Ok(TranslatedIntent {
+            original_intent: intent.clone(),
+            translation_metadata: TranslationMetadata {
+                translator_version: TRANSLATOR_VERSION.to_string(),
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs(),
+                gas_estimate: self.resource_bounds.l1_gas.max_amount,
+                translation_hash: TranslatedIntent::compute_hash(intent, &target_format),
+                price_impact_bps: None,
+            },
+            target_format,
+        })
+    }
+    
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        let intent = &translated.original_intent;
+        if intent.action != "contract_call" {
+            return self.translator.verify_translation(translated);
+        }
+        
+        // The payload must be an invoke from our account running exactly the intent's calls
+        let calls: Vec<StarkNetCall> = serde_json::from_slice(&intent.params)
+            .map_err(|e| RouterError::VerificationError(format!("Invalid StarkNet calls: {}", e)))?;
+        let tx: InvokeTransactionV3 = serde_json::from_slice(&translated.target_format)
+            .map_err(|e| RouterError::VerificationError(format!("Payload is not an invoke transaction: {}", e)))?;
+        
+        if tx.tx_type != "INVOKE" || tx.sender_address != self.account_address {
+            return Err(RouterError::VerificationError(format!(
+                "Invoke for intent {} is not sent from account {}",
+                intent.id,
+                self.account_address.to_hex()
+            )));
+        }
+        if tx.calldata != execute_calldata(&calls) {
+            return Err(RouterError::VerificationError(format!(
+                "Invoke calldata doesn't match the calls of intent {}",
+                intent.id
+            )));
+        }
+        
+        Ok(())
+    }
+    
+    async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
+        if proof.len() < 32 {
+            return Err(RouterError::VerificationError("Proof too short".to_string()));
+        }
+        
+        // Proof starts with a block hash; it must be known to the node
+        let block_hash = Felt::from_bytes_be(proof[..32].try_into().unwrap())
+            .map_err(|_| RouterError::VerificationError("Invalid block hash".to_string()))?;
+        
+        let result: Result<serde_json::Value, _> = self.rpc_call(
+            "starknet_getBlockWithTxHashes",
+            json!({ "block_id": { "block_hash": block_hash } }),
+        ).await;
+        
+        Ok(result.is_ok())
+    }
+    
+    async fn submit_transaction(&self, tx_data: &[u8]) -> Result<String, RouterError> {
+        let tx: InvokeTransactionV3 = serde_json::from_slice(tx_data)
+            .map_err(|e| RouterError::TranslationError(format!("Failed to parse transaction: {}", e)))?;
+        
+        if tx.signature.is_empty() {
+            return Err(RouterError::TranslationError("Invoke transaction is not signed".to_string()));
+        }
+        
+        let result: AddInvokeResult = self.rpc_call(
+            "starknet_addInvokeTransaction",
+            json!({ "invoke_transaction": tx }),
+        ).await
+            .map_err(|e| RouterError::RoutingError(format!("Transaction rejected: {:?}", e)))?;
+        
+        Ok(result.transaction_hash.to_hex())
+    }
+    
+    /// ERC-20 balance of `address`; `asset` is a token contract address or "ETH"/"STRK"
+    async fn query_balance(&self, address: &str, asset: &str) -> Result<u64, RouterError> {
+        let owner = validate_starknet_address(address)?;
+        let token = match asset {
+            "ETH" => Felt::from_hex(ETH_TOKEN)?,
+            "STRK" => Felt::from_hex(STRK_TOKEN)?,
+            contract => validate_starknet_address(contract)?,
+        };
+        
+        let result = self.call(&StarkNetCall {
+            contract_address: token,
+            entry_point: "balanceOf".to_string(),
+            calldata: vec![owner],
+        }).await?;
+        
+        // balanceOf returns a Cairo u256 as (low, high)
+        let (low, high) = match result.as_slice() {
+            [low, high] => (low, high),
+            _ => return Err(RouterError::TranslationError(format!("Unexpected balanceOf result: {:?}", result))),
+        };
+        
+        let balance = felts_to_u256(low, high)?;
+        if balance[..24].iter().any(|b| *b != 0) {
+            return Err(RouterError::TranslationError("Balance exceeds u64".to_string()));
+        }
+        
+        Ok(u64::from_be_bytes(balance[24..].try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_felt_encoding_and_range() {
+        let felt = Felt::from_hex(ETH_TOKEN).unwrap();
+        assert_eq!(felt.to_hex(), "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7");
+        assert_eq!(Felt::ZERO.to_hex(), "0x0");
+        assert_eq!(serde_json::to_string(&Felt::from_u64(255)).unwrap(), "\"0xff\"");
+        
+        // P - 1 is the largest felt, P itself is out of range
+        assert!(Felt::from_hex("0x800000000000011000000000000000000000000000000000000000000000000").is_ok());
+        assert!(Felt::from_hex("0x800000000000011000000000000000000000000000000000000000000000001").is_err());
+        assert!(Felt::from_hex("49d36570").is_err());
+        assert!(Felt::from_hex("0x").is_err());
+    }
+    
+    #[test]
+    fn test_u256_balance_split() {
+        // 2^128 + 5: low limb 5, high limb 1
+        let mut value = [0u8; 32];
+        value[15] = 1;
+        value[31] = 5;
+        
+        let [low, high] = u256_to_felts(value);
+        assert_eq!(low, Felt::from_u64(5));
+        assert_eq!(high, Felt::from_u64(1));
+        assert_eq!(felts_to_u256(&low, &high).unwrap(), value);
+        
+        // A limb wider than 128 bits is not a valid u256 half
+        assert!(felts_to_u256(&Felt::from_hex("0x100000000000000000000000000000000").unwrap(), &Felt::ZERO).is_err());
+    }
+    
+    #[test]
+    fn test_selector_and_calldata_assembly() {
+        assert_eq!(
+            selector_from_name("balanceOf").to_hex(),
+            "0x2e4263afad30923c891518314c3c95dbe830a16874e8abc5777a9a20b54c76e"
+        );
+        
+        let recipient = Felt::from_hex("0x1234").unwrap();
+        let [low, high] = u256_to_felts({
+            let mut amount = [0u8; 32];
+            amount[31] = 100;
+            amount
+        });
+        let call = StarkNetCall {
+            contract_address: Felt::from_hex(STRK_TOKEN).unwrap(),
+            entry_point: "transfer".to_string(),
+            calldata: vec![recipient, low, high],
+        };
+        
+        let calldata = execute_calldata(std::slice::from_ref(&call));
+        assert_eq!(calldata.len(), 1 + 3 + 3);
+        assert_eq!(calldata[0], Felt::from_u64(1));
+        assert_eq!(calldata[1], call.contract_address);
+        assert_eq!(calldata[2], selector_from_name("transfer"));
+        assert_eq!(calldata[3], Felt::from_u64(3));
+        assert_eq!(&calldata[4..], &[recipient, Felt::from_u64(100), Felt::ZERO]);
+        
+        let tx = InvokeTransactionV3::new(recipient, &[call], Felt::from_u64(7), ResourceBounds::default());
+        let encoded = serde_json::to_value(&tx).unwrap();
+        assert_eq!(encoded["version"], "0x3");
+        assert_eq!(encoded["nonce"], "0x7");
+        assert_eq!(encoded["resource_bounds"]["l1_gas"]["max_amount"], "0x2710");
+        
+        let decoded: InvokeTransactionV3 = serde_json::from_value(encoded).unwrap();
+        assert_eq!(decoded.calldata, calldata);
+        assert_eq!(decoded.resource_bounds, ResourceBounds::default());
+    }
+    
+    #[tokio::test]
+    #[ignore] // Requires network access to a StarkNet Sepolia RPC
+    async fn test_query_balance_sepolia() {
+        use super::super::chain_adapter::ChainAdapter;
+        
+        let adapter = RealStarkNetAdapter::new(
+            "https://starknet-sepolia.public.blastapi.io/rpc/v0_7".to_string(),
+            StarkNetNetwork::Sepolia,
+            ETH_TOKEN,
+            Arc::new(IntentTranslator::new()),
+        ).unwrap();
+        
+        assert!(adapter.query_balance(ETH_TOKEN, "STRK").await.is_ok());
+    }
+}
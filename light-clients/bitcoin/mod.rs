@@ -1,8 +1,11 @@
 // Bitcoin Light Client - SPV verification with quantum-safe enhancements
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use sha3::{Digest, Sha3_256};
 
-use super::{LightClient, LightClientError};
+use super::scrypt_chain::{block_hash, check_target, compact_target};
+use super::{HashMode, LightClient, LightClientError};
+use crate::core::crypto::merkle::{MerkleVerifier, Sha256dMerkle, Sha3dMerkle};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BitcoinHeader {
@@ -18,53 +21,182 @@ pub struct BitcoinHeader {
 pub struct BitcoinSPVProof {
     pub transaction: Vec<u8>,
     pub merkle_proof: Vec<[u8; 32]>,
+    #[serde(default)]
+    pub tx_index: u32, // position in the block, selects the side at each Merkle level
     pub block_header: BitcoinHeader,
     pub confirmations: u32,
 }
 
+/// Blocks between Bitcoin difficulty adjustments
+const RETARGET_INTERVAL: u64 = 2016;
+
+/// Difficulty a Bitcoin-family network allows a header to declare
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DifficultyRules {
+    pub(crate) pow_limit: u32, // easiest target the network allows, in compact form
+    pub(crate) retarget_interval: Option<u64>, // blocks between adjustments, `None` where bits aren't tied to the parent
+}
+
+impl DifficultyRules {
+    /// Whether a header at `height` may declare `bits` after a parent that declared `parent_bits`
+    ///
+    /// The target must be within the pow limit. Where the network retargets, the difficulty
+    /// must stay the same off a retarget boundary and may move by at most 4x on one; the
+    /// exact value needs the period's first timestamp, which a checkpointed client may not
+    /// have, so only the bound is checked.
+    pub(crate) fn allows(&self, parent_bits: Option<u32>, height: u64, bits: u32) -> bool {
+        let (Some(target), Some(limit)) = (compact_target(bits), compact_target(self.pow_limit)) else {
+            return false;
+        };
+        if target > limit {
+            return false;
+        }
+        
+        let (Some(parent_bits), Some(interval)) = (parent_bits, self.retarget_interval) else {
+            return true;
+        };
+        if !height.is_multiple_of(interval) {
+            return bits == parent_bits;
+        }
+        let (work, parent_work) = (header_work(bits), header_work(parent_bits));
+        work <= parent_work.saturating_mul(4) && parent_work <= work.saturating_mul(4)
+    }
+}
+
+/// Side branches forking more than this many blocks below the tip are dropped
+pub const MAX_FORK_DEPTH: u64 = 144;
+
+/// A header's timestamp must exceed the median of this many blocks ending at its parent
+const MEDIAN_TIME_SPAN: usize = 11;
+
+/// A known header with its position and the chainwork up to and including it
+#[derive(Debug, Clone)]
+struct HeaderEntry {
+    header: BitcoinHeader,
+    height: u64,
+    work: u128,
+}
+
 pub struct BitcoinLightClient {
     inner: LightClient,
-    headers: Vec<BitcoinHeader>,
+    headers: HashMap<[u8; 32], HeaderEntry>, // every known header, on the active chain or a side branch
+    active: Vec<[u8; 32]>,                   // hashes of the most-work chain, oldest first
+    first_height: u64,                       // height of active[0]
+    reorgs: u64,
     min_confirmations: u32,
+    hash_mode: HashMode,
+    difficulty: DifficultyRules,
 }
 
 impl BitcoinLightClient {
     pub fn new(chain_id: String, min_confirmations: u32) -> Self {
+        let difficulty = network_rules(&chain_id);
         Self {
             inner: LightClient::new(chain_id),
-            headers: Vec::new(),
+            headers: HashMap::new(),
+            active: Vec::new(),
+            first_height: 1,
+            reorgs: 0,
             min_confirmations,
+            hash_mode: HashMode::default(),
+            difficulty,
         }
     }
     
+    /// Start from a trusted recent header instead of syncing from genesis
+    ///
+    /// `cumulative_work` is the chain's total work up to and including `header` (Bitcoin
+    /// Core's `chainwork`); later headers must build on it and are not checked further back.
+    pub fn from_checkpoint(
+        chain_id: String,
+        min_confirmations: u32,
+        header: BitcoinHeader,
+        height: u64,
+        cumulative_work: u128,
+    ) -> Self {
+        let mut client = Self::new(chain_id, min_confirmations);
+        client.first_height = height;
+        client.insert_tip(HeaderEntry {
+            header,
+            height,
+            work: cumulative_work,
+        });
+        client
+    }
+    
+    /// Hash with `hash_mode`, which must not change once headers are added
+    pub fn with_hash_mode(mut self, hash_mode: HashMode) -> Self {
+        self.hash_mode = hash_mode;
+        self
+    }
+    
+    /// Override the easiest allowed target, e.g. for a test network not named by `chain_id`
+    pub fn with_pow_limit(mut self, bits: u32) -> Self {
+        self.difficulty.pow_limit = bits;
+        self
+    }
+    
+    /// Whether `header` has valid proof of work and extends the current tip
     pub fn verify_header(&self, header: &BitcoinHeader) -> Result<bool, LightClientError> {
+        Ok(self.verify_header_after(self.tip(), header))
+    }
+    
+    /// Check `header`'s difficulty and proof of work and that it follows `parent`, if any
+    fn verify_header_after(&self, parent: Option<&HeaderEntry>, header: &BitcoinHeader) -> bool {
+        let parent_bits = parent.map(|entry| entry.header.bits);
+        let height = parent.map_or(self.first_height, |entry| entry.height + 1);
+        if !self.difficulty.allows(parent_bits, height, header.bits) {
+            return false;
+        }
+        
         // Verify proof of work
         let header_hash = self.hash_header(header);
         
-        // Check if hash meets difficulty target
-        if !self.check_proof_of_work(&header_hash, header.bits) {
-            return Ok(false);
+        // Check if hash meets difficulty target; native hashes are in internal (little-endian) order
+        let meets_target = match self.hash_mode {
+            HashMode::Native => check_target(&header_hash, header.bits),
+            HashMode::QuantumSha3 => self.check_proof_of_work(&header_hash, header.bits),
+        };
+        if !meets_target {
+            return false;
         }
         
         // Verify previous block hash if we have headers
-        if let Some(latest) = self.headers.last() {
-            let latest_hash = self.hash_header(latest);
-            if header.prev_block_hash != latest_hash {
-                return Ok(false);
+        if let Some(parent) = parent {
+            let parent_hash = self.hash_header(&parent.header);
+            if header.prev_block_hash != parent_hash {
+                return false;
             }
             
-            // Verify timestamp is reasonable
-            if header.timestamp <= latest.timestamp {
-                return Ok(false);
+            // Timestamps may go backwards, but not to or below the median time past
+            if header.timestamp <= self.median_time_past(parent) {
+                return false;
             }
         }
         
-        Ok(true)
+        true
+    }
+    
+    /// Median timestamp of `parent` and its stored ancestors, up to `MEDIAN_TIME_SPAN` blocks
+    ///
+    /// Fewer blocks count right after a checkpoint, as the client knows nothing before it.
+    fn median_time_past(&self, parent: &HeaderEntry) -> u32 {
+        let mut timestamps = Vec::with_capacity(MEDIAN_TIME_SPAN);
+        let mut cursor = Some(&parent.header);
+        while let Some(header) = cursor.filter(|_| timestamps.len() < MEDIAN_TIME_SPAN) {
+            timestamps.push(header.timestamp);
+            cursor = self.headers.get(&header.prev_block_hash).map(|entry| &entry.header);
+        }
+        timestamps.sort_unstable();
+        timestamps[timestamps.len() / 2]
     }
     
     fn hash_header(&self, header: &BitcoinHeader) -> [u8; 32] {
-        // Bitcoin uses double SHA256
-        // For quantum resistance, we use SHA3-256
+        if self.hash_mode == HashMode::Native {
+            return block_hash(header);
+        }
+        
+        // Quantum mode: double SHA3-256 over the header fields
         let mut hasher = Sha3_256::new();
         
         hasher.update(&header.version.to_le_bytes());
@@ -137,6 +269,7 @@ impl BitcoinLightClient {
         // Verify transaction is in block using Merkle proof
         if !self.verify_merkle_proof(
             &proof.transaction,
+            proof.tx_index,
             &proof.merkle_proof,
             &proof.block_header.merkle_root,
         )? {
@@ -154,87 +287,168 @@ impl BitcoinLightClient {
     fn verify_merkle_proof(
         &self,
         transaction: &[u8],
+        tx_index: u32,
         proof: &[[u8; 32]],
         merkle_root: &[u8; 32],
     ) -> Result<bool, LightClientError> {
-        // Hash transaction
-        let mut current_hash = self.hash_transaction(transaction);
-        
-        // Climb up the Merkle tree with proper ordering
-        // In Bitcoin, the order is determined by the transaction's position in the block
-        // We need to track whether we're on the left or right side at each level
+        let txid = self.hash_transaction(transaction);
+        Ok(match self.hash_mode {
+            HashMode::Native => Sha256dMerkle.verify(txid, tx_index, proof, merkle_root),
+            HashMode::QuantumSha3 => Sha3dMerkle.verify(txid, tx_index, proof, merkle_root),
+        })
+    }
+    
+    fn hash_transaction(&self, transaction: &[u8]) -> [u8; 32] {
+        match self.hash_mode {
+            HashMode::Native => Sha256dMerkle::txid(transaction),
+            HashMode::QuantumSha3 => Sha3dMerkle::txid(transaction),
+        }
+    }
+    
+    /// Add a header extending any known header, following whichever chain has the most work
+    ///
+    /// Headers on a lighter branch are kept so that branch can take over once it becomes
+    /// heavier; ties keep the tip seen first. Length alone never decides. Branches forking more
+    /// than `MAX_FORK_DEPTH` blocks below the tip are dropped, and can't be extended after.
+    pub fn add_header(&mut self, header: BitcoinHeader) -> Result<(), LightClientError> {
+        let hash = self.hash_header(&header);
+        if self.headers.contains_key(&hash) {
+            return Ok(());
+        }
         
-        for (level, sibling) in proof.iter().enumerate() {
-            let mut hasher = Sha3_256::new();
-            
-            // Determine order based on hash comparison
-            // In Bitcoin Merkle trees, smaller hash goes first
-            // This is a deterministic way to order without needing the transaction index
-            let left_hash: &[u8; 32];
-            let right_hash: &[u8; 32];
-            
-            // Compare byte by byte
-            let mut current_smaller = false;
-            for i in 0..32 {
-                if current_hash[i] < sibling[i] {
-                    current_smaller = true;
-                    break;
-                } else if current_hash[i] > sibling[i] {
-                    break;
-                }
-            }
-            
-            if current_smaller {
-                left_hash = &current_hash;
-                right_hash = sibling;
-            } else {
-                left_hash = sibling;
-                right_hash = &current_hash;
+        // The first header of a client without a checkpoint is taken on its proof of work alone
+        let Some(tip) = self.tip() else {
+            if !self.verify_header_after(None, &header) {
+                return Err(LightClientError::InvalidProof);
             }
-            
-            // Hash the concatenation
-            hasher.update(left_hash);
-            hasher.update(right_hash);
-            
-            let result = hasher.finalize();
-            current_hash.copy_from_slice(&result);
-            
-            // For odd number of nodes at a level, Bitcoin duplicates the last node
-            // This is handled by the proof provider
-            let _ = level; // Suppress unused warning
+            let work = header_work(header.bits);
+            self.insert_tip(HeaderEntry { header, height: self.first_height, work });
+            return Ok(());
+        };
+        let tip_work = tip.work;
+        
+        let parent = self
+            .headers
+            .get(&header.prev_block_hash)
+            .ok_or(LightClientError::InvalidProof)?;
+        if !self.verify_header_after(Some(parent), &header) {
+            return Err(LightClientError::InvalidProof);
         }
+        let entry = HeaderEntry {
+            height: parent.height + 1,
+            work: parent.work.saturating_add(header_work(header.bits)),
+            header,
+        };
         
-        Ok(&current_hash == merkle_root)
+        if entry.work <= tip_work {
+            self.headers.insert(hash, entry);
+        } else if entry.header.prev_block_hash == *self.active.last().expect("a tip exists") {
+            self.insert_tip(entry);
+        } else {
+            self.headers.insert(hash, entry);
+            self.reorganize_to(hash);
+        }
+        self.prune_side_branches();
+        Ok(())
     }
     
-    fn hash_transaction(&self, transaction: &[u8]) -> [u8; 32] {
-        // Double SHA3-256 for quantum resistance
-        let mut hasher = Sha3_256::new();
-        hasher.update(transaction);
-        let first_hash = hasher.finalize();
+    /// Add consecutive headers in order, stopping at the first invalid one
+    pub fn add_headers(&mut self, headers: Vec<BitcoinHeader>) -> Result<(), LightClientError> {
+        headers.into_iter().try_for_each(|header| self.add_header(header))
+    }
+    
+    fn insert_tip(&mut self, entry: HeaderEntry) {
+        let hash = self.hash_header(&entry.header);
+        self.inner.latest_height = entry.height;
+        self.headers.insert(hash, entry);
+        self.active.push(hash);
+    }
+    
+    /// Make the branch ending at `new_tip` the active chain
+    fn reorganize_to(&mut self, new_tip: [u8; 32]) {
+        // Walk back to where the branch meets the active chain
+        let mut branch = Vec::new();
+        let mut cursor = new_tip;
+        while !self.is_active(&cursor) {
+            branch.push(cursor);
+            cursor = self.headers[&cursor].header.prev_block_hash;
+        }
+        branch.reverse();
         
-        let mut hasher2 = Sha3_256::new();
-        hasher2.update(&first_hash);
-        let result = hasher2.finalize();
+        let fork_height = self.headers[&cursor].height;
+        tracing::warn!(
+            "Bitcoin reorg: {} headers replaced by {} from height {}",
+            self.get_height() - fork_height,
+            branch.len(),
+            fork_height
+        );
         
-        let mut hash = [0u8; 32];
-        hash.copy_from_slice(&result);
-        hash
+        self.active.truncate((fork_height - self.first_height + 1) as usize);
+        self.active.extend(branch);
+        self.inner.latest_height = self.get_height();
+        self.reorgs += 1;
     }
     
-    pub fn add_header(&mut self, header: BitcoinHeader) -> Result<(), LightClientError> {
-        if !self.verify_header(&header)? {
-            return Err(LightClientError::InvalidProof);
+    /// Drop side-branch headers whose branch leaves the active chain more than
+    /// `MAX_FORK_DEPTH` blocks below the tip
+    ///
+    /// Every header of a branch meets the active chain at the same point, so a branch is
+    /// dropped whole and no kept header loses its parent.
+    fn prune_side_branches(&mut self) {
+        if self.headers.len() == self.active.len() {
+            return;
         }
         
-        self.headers.push(header.clone());
-        self.inner.latest_height = self.headers.len() as u64;
-        
-        Ok(())
+        let floor = self.get_height().saturating_sub(MAX_FORK_DEPTH);
+        let stale: Vec<[u8; 32]> = self
+            .headers
+            .keys()
+            .filter(|hash| !self.is_active(hash) && self.fork_height(**hash) < floor)
+            .copied()
+            .collect();
+        for hash in stale {
+            self.headers.remove(&hash);
+        }
+    }
+    
+    /// Height of the active header that the branch containing `hash` grows from
+    fn fork_height(&self, hash: [u8; 32]) -> u64 {
+        let mut cursor = hash;
+        while !self.is_active(&cursor) {
+            cursor = self.headers[&cursor].header.prev_block_hash;
+        }
+        self.headers[&cursor].height
+    }
+    
+    fn is_active(&self, hash: &[u8; 32]) -> bool {
+        self.headers
+            .get(hash)
+            .and_then(|entry| entry.height.checked_sub(self.first_height))
+            .and_then(|index| self.active.get(index as usize))
+            == Some(hash)
+    }
+    
+    fn tip(&self) -> Option<&HeaderEntry> {
+        self.active.last().map(|hash| &self.headers[hash])
     }
     
     pub fn get_height(&self) -> u64 {
-        self.headers.len() as u64
+        self.tip().map_or(0, |tip| tip.height)
+    }
+    
+    /// Total work of the active chain up to its tip
+    pub fn total_work(&self) -> u128 {
+        self.tip().map_or(0, |tip| tip.work)
+    }
+    
+    /// Hash of the most-work header, in internal byte order
+    pub fn current_tip_hash(&self) -> Option<[u8; 32]> {
+        self.active.last().copied()
+    }
+    
+    /// How many times a heavier side branch has replaced the active chain
+    pub fn reorg_count(&self) -> u64 {
+        self.reorgs
     }
     
     pub fn get_inner(&self) -> &LightClient {
@@ -242,6 +456,65 @@ impl BitcoinLightClient {
     }
 }
 
+/// Difficulty rules of the Bitcoin network named in `chain_id`
+///
+/// Testnet and regtest aren't held to the retarget rules; testnet allows minimum-difficulty
+/// blocks between retargets.
+fn network_rules(chain_id: &str) -> DifficultyRules {
+    let (pow_limit, retargets) = if chain_id.contains("regtest") {
+        (0x207fffff, false)
+    } else if chain_id.contains("signet") {
+        (0x1e0377ae, true)
+    } else if chain_id.contains("testnet") {
+        (0x1d00ffff, false)
+    } else {
+        (0x1d00ffff, true)
+    };
+    DifficultyRules {
+        pow_limit,
+        retarget_interval: retargets.then_some(RETARGET_INTERVAL),
+    }
+}
+
+/// Expected hashes to find a block at `bits`: 2^256 / target, saturating at `u128::MAX`
+///
+/// Bitcoin Core divides by target + 1; the difference is negligible at any real target.
+/// Zero, negative or overflowing compact targets do no work.
+pub fn header_work(bits: u32) -> u128 {
+    let exponent = bits >> 24;
+    let mut mantissa = (bits & 0x007fffff) as u128;
+    if bits & 0x00800000 != 0 || mantissa == 0 || exponent > 34 {
+        return 0;
+    }
+    
+    // target = mantissa * 2^shift
+    let shift = if exponent < 3 {
+        mantissa >>= 8 * (3 - exponent);
+        if mantissa == 0 {
+            return 0;
+        }
+        0
+    } else {
+        8 * (exponent - 3)
+    };
+    
+    // Long division of 2^(256 - shift) by the mantissa
+    let mut quotient: u128 = 0;
+    let mut remainder: u128 = 1;
+    for _ in 0..256u32.saturating_sub(shift) {
+        remainder <<= 1;
+        quotient = match quotient.checked_mul(2) {
+            Some(q) => q,
+            None => return u128::MAX,
+        };
+        if remainder >= mantissa {
+            remainder -= mantissa;
+            quotient += 1;
+        }
+    }
+    quotient
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +551,303 @@ mod tests {
         let hash = client.hash_transaction(&tx);
         assert_eq!(hash.len(), 32);
     }
+    
+    fn from_display_hex(s: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&hex::decode(s).unwrap());
+        bytes.reverse();
+        bytes
+    }
+    
+    fn genesis() -> BitcoinHeader {
+        BitcoinHeader {
+            version: 1,
+            prev_block_hash: [0u8; 32],
+            merkle_root: from_display_hex("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"),
+            timestamp: 1231006505,
+            bits: 0x1d00ffff,
+            nonce: 2083236893,
+        }
+    }
+    
+    #[test]
+    fn test_native_mode_reproduces_genesis_hash() {
+        let client = BitcoinLightClient::new("bitcoin-mainnet".to_string(), 6);
+        assert_eq!(
+            client.hash_header(&genesis()),
+            from_display_hex("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f")
+        );
+        assert!(client.verify_header(&genesis()).unwrap());
+        
+        // The SHA3 hash of a real header doesn't meet its target
+        let quantum = BitcoinLightClient::new("bitcoin-mainnet".to_string(), 6).with_hash_mode(HashMode::QuantumSha3);
+        assert_ne!(quantum.hash_header(&genesis()), client.hash_header(&genesis()));
+        assert!(!quantum.verify_header(&genesis()).unwrap());
+    }
+    
+    #[test]
+    fn test_native_mode_reproduces_genesis_txid() {
+        // Coinbase transaction of the genesis block, its only transaction
+        let coinbase = hex::decode(concat!(
+            "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff",
+            "4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72",
+            "206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff",
+            "0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f",
+            "61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000",
+        )).unwrap();
+        
+        let client = BitcoinLightClient::new("bitcoin-mainnet".to_string(), 6);
+        assert_eq!(client.hash_transaction(&coinbase), genesis().merkle_root);
+        assert!(client.verify_merkle_proof(&coinbase, 0, &[], &genesis().merkle_root).unwrap());
+    }
+    
+    #[test]
+    fn test_merkle_proof_uses_transaction_position() {
+        use crate::core::crypto::merkle::{merkle_branch, merkle_root};
+        
+        let client = BitcoinLightClient::new("bitcoin-mainnet".to_string(), 6);
+        let transactions: Vec<Vec<u8>> = (0u8..5).map(|i| vec![i; 60]).collect();
+        let txids: Vec<[u8; 32]> = transactions.iter().map(|tx| Sha256dMerkle::txid(tx)).collect();
+        let root = merkle_root(&txids, &Sha256dMerkle).unwrap();
+        
+        let branch = merkle_branch(&txids, 3, &Sha256dMerkle).unwrap();
+        assert!(client.verify_merkle_proof(&transactions[3], 3, &branch, &root).unwrap());
+        
+        // The same branch at the wrong position, or for another transaction, fails
+        assert!(!client.verify_merkle_proof(&transactions[3], 2, &branch, &root).unwrap());
+        assert!(!client.verify_merkle_proof(&transactions[2], 3, &branch, &root).unwrap());
+    }
+    
+    #[test]
+    fn test_header_work_matches_chainwork() {
+        // Genesis contributes 0x100010001 to Bitcoin Core's chainwork
+        assert_eq!(header_work(0x1d00ffff), 0x1_0001_0001);
+        assert_eq!(header_work(0x207fffff), 2);
+        assert_eq!(header_work(0x1d80ffff), 0); // negative target
+        assert_eq!(header_work(0x03000000), 0);
+    }
+    
+    /// Header on top of `parent` at `bits`, with the nonce ground until it meets its target
+    fn mine(client: &BitcoinLightClient, parent: &BitcoinHeader, bits: u32, tag: u8) -> BitcoinHeader {
+        mine_at(client, parent, bits, tag, parent.timestamp + 600)
+    }
+    
+    fn mine_at(client: &BitcoinLightClient, parent: &BitcoinHeader, bits: u32, tag: u8, timestamp: u32) -> BitcoinHeader {
+        let mut header = BitcoinHeader {
+            version: 0x20000000,
+            prev_block_hash: client.hash_header(parent),
+            merkle_root: [tag; 32],
+            timestamp,
+            bits,
+            nonce: 0,
+        };
+        while !check_target(&client.hash_header(&header), bits) {
+            header.nonce += 1;
+        }
+        header
+    }
+    
+    #[test]
+    fn test_higher_work_branch_beats_longer_one() {
+        const EASY: u32 = 0x207fffff; // work 2
+        const HARD: u32 = 0x200fffff; // work 16
+        
+        let checkpoint = genesis();
+        let mut client =
+            BitcoinLightClient::from_checkpoint("bitcoin-regtest".to_string(), 1, checkpoint.clone(), 800_000, 1_000);
+        assert_eq!(client.get_height(), 800_000);
+        assert_eq!(client.total_work(), 1_000);
+        
+        // Three easy blocks: longer, but only 6 work
+        let mut parent = checkpoint.clone();
+        for tag in 0..3 {
+            let header = mine(&client, &parent, EASY, tag);
+            client.add_header(header.clone()).unwrap();
+            parent = header;
+        }
+        assert_eq!(client.get_height(), 800_003);
+        assert_eq!(client.total_work(), 1_006);
+        
+        // Two hard blocks from the checkpoint: shorter, but 32 work
+        let hard1 = mine(&client, &checkpoint, HARD, 10);
+        let hard2 = mine(&client, &hard1, HARD, 11);
+        client.add_headers(vec![hard1, hard2.clone()]).unwrap();
+        assert_eq!(client.get_height(), 800_002);
+        assert_eq!(client.total_work(), 1_032);
+        assert_eq!(client.get_inner().latest_height, 800_002);
+        assert!(client.verify_header(&mine(&client, &hard2, EASY, 12)).unwrap());
+        
+        // The old easy branch can't win back with a lower total
+        let mut easy = Vec::new();
+        let mut parent = checkpoint.clone();
+        for tag in 20..24 {
+            let header = mine(&client, &parent, EASY, tag);
+            easy.push(header.clone());
+            parent = header;
+        }
+        client.add_headers(easy).unwrap();
+        assert_eq!(client.total_work(), 1_032);
+        assert_eq!(client.current_tip_hash(), Some(client.hash_header(&hard2)));
+        assert_eq!(client.reorg_count(), 1);
+        
+        // Headers that don't attach to any known header are refused
+        let unknown = BitcoinHeader { merkle_root: [0xee; 32], ..genesis() };
+        assert!(matches!(client.add_header(mine(&client, &unknown, EASY, 30)), Err(LightClientError::InvalidProof)));
+    }
+    
+    #[test]
+    fn test_side_branch_takes_over_once_heavier() {
+        const EASY: u32 = 0x207fffff; // work 2
+        const HARD: u32 = 0x200fffff; // work 16
+        
+        let checkpoint = genesis();
+        let mut client =
+            BitcoinLightClient::from_checkpoint("bitcoin-regtest".to_string(), 1, checkpoint.clone(), 100, 0);
+        
+        let a1 = mine(&client, &checkpoint, EASY, 1);
+        let a2 = mine(&client, &a1, EASY, 2);
+        client.add_headers(vec![a1, a2.clone()]).unwrap();
+        
+        // Branch b is fed one header at a time and is kept while it is lighter or tied
+        let b1 = mine(&client, &checkpoint, EASY, 11);
+        let b2 = mine(&client, &b1, EASY, 12);
+        let b3 = mine(&client, &b2, EASY, 13);
+        client.add_header(b1).unwrap();
+        client.add_header(b2).unwrap();
+        assert_eq!(client.current_tip_hash(), Some(client.hash_header(&a2)));
+        assert_eq!(client.reorg_count(), 0);
+        
+        client.add_header(b3.clone()).unwrap();
+        assert_eq!(client.current_tip_hash(), Some(client.hash_header(&b3)));
+        assert_eq!((client.get_height(), client.total_work()), (103, 6));
+        assert_eq!(client.reorg_count(), 1);
+        
+        // One hard block puts branch a back ahead despite being shorter
+        let a3 = mine(&client, &a2, HARD, 3);
+        client.add_header(a3.clone()).unwrap();
+        assert_eq!(client.current_tip_hash(), Some(client.hash_header(&a3)));
+        assert_eq!((client.get_height(), client.total_work()), (103, 20));
+        assert_eq!(client.reorg_count(), 2);
+        
+        // Re-adding a known header changes nothing
+        client.add_header(b3).unwrap();
+        assert_eq!(client.reorg_count(), 2);
+    }
+    
+    #[test]
+    fn test_bits_above_pow_limit_rejected() {
+        const EASY: u32 = 0x207fffff;
+        
+        // Regtest-easy bits meet their own target but not mainnet's limit
+        let checkpoint = genesis();
+        let mut client =
+            BitcoinLightClient::from_checkpoint("bitcoin-mainnet".to_string(), 1, checkpoint.clone(), 100, 0);
+        let easy = mine(&client, &checkpoint, EASY, 1);
+        assert!(!client.verify_header(&easy).unwrap());
+        assert!(matches!(client.add_header(easy), Err(LightClientError::InvalidProof)));
+        
+        // Including as the first header of a client without a checkpoint
+        let fresh = BitcoinLightClient::new("bitcoin-mainnet".to_string(), 1);
+        assert!(!fresh.verify_header(&mine(&fresh, &checkpoint, EASY, 2)).unwrap());
+    }
+    
+    #[test]
+    fn test_difficulty_only_changes_at_retarget_boundary() {
+        const EASY: u32 = 0x207fffff; // work 2
+        const HALF: u32 = 0x203fffff; // work 4
+        const HARD: u32 = 0x200fffff; // work 16
+        
+        let checkpoint = BitcoinHeader { bits: EASY, ..genesis() };
+        let mut client =
+            BitcoinLightClient::from_checkpoint("bitcoin-mainnet".to_string(), 1, checkpoint.clone(), 2014, 0)
+                .with_pow_limit(EASY);
+        
+        // Height 2015 is mid-period: the difficulty must not move
+        assert!(!client.verify_header(&mine(&client, &checkpoint, HALF, 1)).unwrap());
+        let same = mine(&client, &checkpoint, EASY, 2);
+        client.add_header(same.clone()).unwrap();
+        
+        // Height 2016 may retarget, by at most 4x
+        assert!(!client.verify_header(&mine(&client, &same, HARD, 3)).unwrap());
+        let retarget = mine(&client, &same, HALF, 4);
+        client.add_header(retarget).unwrap();
+        assert_eq!(client.get_height(), 2016);
+        
+        // Regtest isn't held to the retarget rules
+        let regtest =
+            BitcoinLightClient::from_checkpoint("bitcoin-regtest".to_string(), 1, checkpoint.clone(), 2014, 0);
+        assert!(regtest.verify_header(&mine(&regtest, &checkpoint, HARD, 5)).unwrap());
+    }
+    
+    #[test]
+    fn test_timestamp_checked_against_median_time_past() {
+        const EASY: u32 = 0x207fffff;
+        
+        let checkpoint = BitcoinHeader { bits: EASY, ..genesis() };
+        let mut client =
+            BitcoinLightClient::from_checkpoint("bitcoin-regtest".to_string(), 1, checkpoint.clone(), 100, 0);
+        
+        // Eleven blocks ten minutes apart put the median five blocks below the tip
+        let mut parent = checkpoint.clone();
+        for tag in 0..10 {
+            let header = mine(&client, &parent, EASY, tag);
+            client.add_header(header.clone()).unwrap();
+            parent = header;
+        }
+        let median = checkpoint.timestamp + 5 * 600;
+        
+        // A block stamped before its parent is still valid above the median
+        let earlier = mine_at(&client, &parent, EASY, 20, parent.timestamp - 1_200);
+        assert!(earlier.timestamp < parent.timestamp);
+        assert!(client.verify_header(&earlier).unwrap());
+        assert!(client.verify_header(&mine_at(&client, &parent, EASY, 21, median + 1)).unwrap());
+        assert!(!client.verify_header(&mine_at(&client, &parent, EASY, 22, median)).unwrap());
+        
+        // The next block is checked against a median that includes the earlier one
+        client.add_header(earlier.clone()).unwrap();
+        assert!(client.verify_header(&mine_at(&client, &earlier, EASY, 23, median + 601)).unwrap());
+        assert!(!client.verify_header(&mine_at(&client, &earlier, EASY, 24, median + 600)).unwrap());
+        
+        // Right after a checkpoint only the checkpoint itself counts
+        let fresh = BitcoinLightClient::from_checkpoint("bitcoin-regtest".to_string(), 1, checkpoint.clone(), 100, 0);
+        assert!(!fresh.verify_header(&mine_at(&fresh, &checkpoint, EASY, 25, checkpoint.timestamp)).unwrap());
+    }
+    
+    #[test]
+    fn test_side_branches_pruned_once_deep() {
+        const EASY: u32 = 0x207fffff;
+        
+        let checkpoint = genesis();
+        let mut client =
+            BitcoinLightClient::from_checkpoint("bitcoin-regtest".to_string(), 1, checkpoint.clone(), 100, 0);
+        
+        // A one-block side branch at height 101
+        let side = mine(&client, &checkpoint, EASY, 0xff);
+        let mut parent = checkpoint.clone();
+        for tag in 0..2 {
+            let header = mine(&client, &parent, EASY, tag);
+            client.add_header(header.clone()).unwrap();
+            parent = header;
+        }
+        client.add_header(side.clone()).unwrap();
+        assert_eq!(client.headers.len(), 4);
+        
+        // Kept while it forks no more than MAX_FORK_DEPTH below the tip
+        while client.get_height() < 100 + MAX_FORK_DEPTH {
+            let header = mine(&client, &parent, EASY, client.get_height() as u8);
+            client.add_header(header.clone()).unwrap();
+            parent = header;
+        }
+        assert!(client.headers.contains_key(&client.hash_header(&side)));
+        
+        let header = mine(&client, &parent, EASY, 0);
+        client.add_header(header).unwrap();
+        assert!(!client.headers.contains_key(&client.hash_header(&side)));
+        assert_eq!(client.headers.len(), client.active.len());
+        
+        // The dropped branch can't be extended, and a new fork that deep isn't kept
+        assert!(client.add_header(mine(&client, &side, EASY, 1)).is_err());
+        client.add_header(mine(&client, &checkpoint, EASY, 0xfe)).unwrap();
+        assert_eq!(client.headers.len(), client.active.len());
+    }
 }
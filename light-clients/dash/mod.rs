@@ -0,0 +1,191 @@
+// Dash Light Client - X11 proof-of-work header verification
+use std::sync::Arc;
+
+use super::bitcoin::{BitcoinHeader, DifficultyRules};
+use super::scrypt_chain::{check_target, serialize_header};
+use super::{LightClient, LightClientError};
+
+/// X11 chained hash: blake, bmw, groestl, skein, jh, keccak, luffa, cubehash, shavite, simd, echo
+///
+/// Each round is the 512-bit variant of the algorithm; the digest is the first 32 bytes of
+/// the final ECHO output. Implementations are supplied by the caller (e.g. bindings to sphlib).
+pub trait X11Hasher: Send + Sync {
+    fn x11(&self, data: &[u8]) -> [u8; 32];
+}
+
+pub struct DashLightClient {
+    inner: LightClient,
+    hasher: Arc<dyn X11Hasher>,
+    headers: Vec<BitcoinHeader>,
+    difficulty: DifficultyRules,
+}
+
+impl DashLightClient {
+    pub fn new(chain_id: String, hasher: Arc<dyn X11Hasher>) -> Self {
+        let difficulty = network_rules(&chain_id);
+        Self {
+            inner: LightClient::new(chain_id),
+            hasher,
+            headers: Vec::new(),
+            difficulty,
+        }
+    }
+    
+    pub fn verify_header(&self, header: &BitcoinHeader) -> Result<bool, LightClientError> {
+        // Chain by prev-hash to the latest accepted header
+        if let Some(latest) = self.headers.last() {
+            if header.prev_block_hash != self.block_hash(latest) {
+                return Ok(false);
+            }
+        }
+        
+        // Bits are only trusted within the pow limit and close to the parent's
+        let parent_bits = self.headers.last().map(|latest| latest.bits);
+        if !self.difficulty.allows(parent_bits, self.headers.len() as u64, header.bits) {
+            return Ok(false);
+        }
+        
+        Ok(check_target(&self.block_hash(header), header.bits))
+    }
+    
+    pub fn add_header(&mut self, header: BitcoinHeader) -> Result<(), LightClientError> {
+        if !self.verify_header(&header)? {
+            return Err(LightClientError::InvalidProof);
+        }
+        
+        self.headers.push(header);
+        self.inner.latest_height = self.headers.len() as u64;
+        
+        Ok(())
+    }
+    
+    /// Dash block hash: X11 of the 80-byte header (internal byte order), also the PoW hash
+    pub fn block_hash(&self, header: &BitcoinHeader) -> [u8; 32] {
+        self.hasher.x11(&serialize_header(header))
+    }
+    
+    pub fn get_height(&self) -> u64 {
+        self.headers.len() as u64
+    }
+    
+    pub fn get_inner(&self) -> &LightClient {
+        &self.inner
+    }
+}
+
+/// Difficulty rules of the Dash network named in `chain_id`
+///
+/// Dark Gravity Wave retargets every block from the last 24, so mainnet headers are only
+/// bounded to 4x of their parent. Testnet allows minimum-difficulty blocks, and regtest
+/// never retargets.
+fn network_rules(chain_id: &str) -> DifficultyRules {
+    if chain_id.contains("regtest") {
+        DifficultyRules { pow_limit: 0x207fffff, retarget_interval: None }
+    } else if chain_id.contains("testnet") {
+        DifficultyRules { pow_limit: 0x1e0fffff, retarget_interval: None }
+    } else {
+        DifficultyRules { pow_limit: 0x1e0fffff, retarget_interval: Some(1) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    
+    fn from_display_hex(s: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&hex::decode(s).unwrap());
+        bytes.reverse();
+        bytes
+    }
+    
+    fn dash_genesis() -> BitcoinHeader {
+        BitcoinHeader {
+            version: 1,
+            prev_block_hash: [0u8; 32],
+            merkle_root: from_display_hex("e0028eb9648db56b1ac77cf090b99048a8007e2bb64b68f092c03c7f56a662c7"),
+            timestamp: 1390095618,
+            bits: 0x1e0ffff0,
+            nonce: 28917698,
+        }
+    }
+    
+    /// Returns recorded X11 digests for known headers and an all-ones hash otherwise
+    struct RecordedX11(HashMap<Vec<u8>, [u8; 32]>);
+    
+    impl X11Hasher for RecordedX11 {
+        fn x11(&self, data: &[u8]) -> [u8; 32] {
+            self.0.get(data).copied().unwrap_or([0xff; 32])
+        }
+    }
+    
+    fn recorded_hasher() -> Arc<RecordedX11> {
+        let genesis_hash = from_display_hex("00000ffd590b1485b3caadc19b22e6379c733355108f107a430458cdf3407ab6");
+        Arc::new(RecordedX11(HashMap::from([(serialize_header(&dash_genesis()), genesis_hash)])))
+    }
+    
+    #[test]
+    fn test_dash_genesis_header() {
+        let client = DashLightClient::new("dash-mainnet".to_string(), recorded_hasher());
+        let genesis = dash_genesis();
+        
+        assert_eq!(
+            hex::encode(serialize_header(&genesis)),
+            concat!(
+                "01000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "c762a6567f3cc092f0684bb62b7e00a84890b990f07cc71a6bb58d64b98e02e0",
+                "022ddb52f0ff0f1ec23fb901",
+            )
+        );
+        assert!(client.verify_header(&genesis).unwrap());
+        
+        // A different nonce changes the hash and misses the target
+        let tampered = BitcoinHeader { nonce: genesis.nonce + 1, ..genesis };
+        assert!(!client.verify_header(&tampered).unwrap());
+    }
+    
+    #[test]
+    fn test_prev_hash_chaining() {
+        // The orphan meets its target on its own, so only the prev-hash link can reject it
+        let orphan = BitcoinHeader { prev_block_hash: [9u8; 32], ..dash_genesis() };
+        let mut hasher = RecordedX11(recorded_hasher().0.clone());
+        hasher.0.insert(serialize_header(&orphan), [0u8; 32]);
+        
+        let mut client = DashLightClient::new("dash-mainnet".to_string(), Arc::new(hasher));
+        client.add_header(dash_genesis()).unwrap();
+        assert_eq!(client.get_height(), 1);
+        
+        assert!(!client.verify_header(&orphan).unwrap());
+        assert!(client.add_header(orphan).is_err());
+        assert_eq!(client.get_inner().latest_height, 1);
+    }
+    
+    #[test]
+    fn test_self_declared_difficulty_bounded() {
+        // Every header below hashes to zero, so only the difficulty rules can reject one
+        let genesis_hash = recorded_hasher().0[&serialize_header(&dash_genesis())];
+        let child = |bits| BitcoinHeader { prev_block_hash: genesis_hash, bits, ..dash_genesis() };
+        let trivial = BitcoinHeader { bits: 0x207fffff, ..dash_genesis() };
+        let mut hasher = RecordedX11(recorded_hasher().0.clone());
+        for header in [trivial.clone(), child(0x1e03fffc), child(0x1e03fffb), child(0x1d3ffff0)] {
+            hasher.0.insert(serialize_header(&header), [0u8; 32]);
+        }
+        
+        let mut client = DashLightClient::new("dash-mainnet".to_string(), Arc::new(hasher));
+        assert!(!client.verify_header(&trivial).unwrap());
+        client.add_header(dash_genesis()).unwrap();
+        
+        // At most 4x the parent's work
+        assert!(client.verify_header(&child(0x1e03fffc)).unwrap());
+        assert!(!client.verify_header(&child(0x1e03fffb)).unwrap());
+        assert!(!client.verify_header(&child(0x1d3ffff0)).unwrap());
+        
+        // Regtest takes any bits up to its own limit
+        let regtest = DashLightClient::new("dash-regtest".to_string(), Arc::new(RecordedX11(HashMap::from([
+            (serialize_header(&trivial), [0u8; 32]),
+        ]))));
+        assert!(regtest.verify_header(&trivial).unwrap());
+    }
+}
@@ -1,10 +1,11 @@
 // Ethereum Light Client - Quantum-safe verification for Ethereum
 use serde::{Deserialize, Serialize};
-use sha3::{Digest, Sha3_256};
+use sha3::{Digest, Keccak256, Sha3_256};
 
-use super::{LightClient, LightClientError};
+use super::sync::ChainLightClient;
+use super::{HashMode, LightClient, LightClientError};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EthereumHeader {
     pub parent_hash: [u8; 32],
     pub state_root: [u8; 32],
@@ -15,6 +16,31 @@ pub struct EthereumHeader {
     pub gas_used: u64,
     pub timestamp: u64,
     pub extra_data: Vec<u8>,
+    // Remaining consensus fields, needed to reproduce the real block hash
+    #[serde(default)]
+    pub ommers_hash: [u8; 32],
+    #[serde(default)]
+    pub beneficiary: [u8; 20],
+    #[serde(default)]
+    pub logs_bloom: Vec<u8>, // 256 bytes; empty means all zero
+    #[serde(default)]
+    pub difficulty: u64,
+    #[serde(default)]
+    pub mix_hash: [u8; 32],
+    #[serde(default)]
+    pub nonce: [u8; 8],
+    #[serde(default)]
+    pub base_fee_per_gas: Option<u64>, // London
+    #[serde(default)]
+    pub withdrawals_root: Option<[u8; 32]>, // Shanghai
+    #[serde(default)]
+    pub blob_gas_used: Option<u64>, // Cancun
+    #[serde(default)]
+    pub excess_blob_gas: Option<u64>, // Cancun
+    #[serde(default)]
+    pub parent_beacon_block_root: Option<[u8; 32]>, // Cancun
+    #[serde(default)]
+    pub requests_hash: Option<[u8; 32]>, // Prague
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +53,7 @@ pub struct EthereumProof {
 pub struct EthereumLightClient {
     inner: LightClient,
     latest_header: Option<EthereumHeader>,
+    hash_mode: HashMode,
 }
 
 impl EthereumLightClient {
@@ -34,9 +61,15 @@ impl EthereumLightClient {
         Self {
             inner: LightClient::new(chain_id),
             latest_header: None,
+            hash_mode: HashMode::default(),
         }
     }
     
+    pub fn with_hash_mode(mut self, hash_mode: HashMode) -> Self {
+        self.hash_mode = hash_mode;
+        self
+    }
+    
     pub fn verify_header(&self, header: &EthereumHeader) -> Result<bool, LightClientError> {
         // Verify header hash
         let _header_hash = self.hash_header(header);
@@ -64,7 +97,12 @@ impl EthereumLightClient {
     }
     
     fn hash_header(&self, header: &EthereumHeader) -> [u8; 32] {
-        // Hash Ethereum header using SHA3-256 (Keccak256)
+        if self.hash_mode == HashMode::Native {
+            // Block hash: keccak256 of the RLP-encoded header
+            return Keccak256::digest(rlp_header(header)).into();
+        }
+        
+        // Quantum mode: SHA3-256 over the core header fields
         let mut hasher = Sha3_256::new();
         
         hasher.update(&header.parent_hash);
@@ -90,8 +128,6 @@ impl EthereumLightClient {
         state_root: &[u8; 32],
     ) -> Result<bool, LightClientError> {
         // Verify Merkle Patricia Trie proof using proper MPT verification
-        use sha3::Keccak256;
-        
         if proof.is_empty() {
             return Ok(false);
         }
@@ -142,6 +178,89 @@ impl EthereumLightClient {
     }
 }
 
+impl ChainLightClient for EthereumLightClient {
+    type Header = EthereumHeader;
+    
+    fn header_height(header: &EthereumHeader) -> u64 {
+        header.number
+    }
+    
+    fn current_height(&self) -> u64 {
+        self.inner.latest_height
+    }
+    
+    fn update_header(&mut self, header: EthereumHeader) -> Result<(), LightClientError> {
+        EthereumLightClient::update_header(self, header)
+    }
+}
+
+/// RLP list of the header fields in consensus order, fork fields only when present
+fn rlp_header(header: &EthereumHeader) -> Vec<u8> {
+    let bloom = if header.logs_bloom.is_empty() { vec![0u8; 256] } else { header.logs_bloom.clone() };
+    
+    let mut fields = vec![
+        rlp_bytes(&header.parent_hash),
+        rlp_bytes(&header.ommers_hash),
+        rlp_bytes(&header.beneficiary),
+        rlp_bytes(&header.state_root),
+        rlp_bytes(&header.transactions_root),
+        rlp_bytes(&header.receipts_root),
+        rlp_bytes(&bloom),
+        rlp_uint(header.difficulty),
+        rlp_uint(header.number),
+        rlp_uint(header.gas_limit),
+        rlp_uint(header.gas_used),
+        rlp_uint(header.timestamp),
+        rlp_bytes(&header.extra_data),
+        rlp_bytes(&header.mix_hash),
+        rlp_bytes(&header.nonce),
+    ];
+    
+    // Each fork's fields follow the previous ones, so stop at the first absent field
+    let optional = [
+        header.base_fee_per_gas.map(rlp_uint),
+        header.withdrawals_root.map(|root| rlp_bytes(&root)),
+        header.blob_gas_used.map(rlp_uint),
+        header.excess_blob_gas.map(rlp_uint),
+        header.parent_beacon_block_root.map(|root| rlp_bytes(&root)),
+        header.requests_hash.map(|hash| rlp_bytes(&hash)),
+    ];
+    fields.extend(optional.into_iter().map_while(|field| field));
+    
+    let payload = fields.concat();
+    let mut out = rlp_length_prefix(payload.len(), 0xc0);
+    out.extend_from_slice(&payload);
+    out
+}
+
+fn rlp_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+    
+    let mut out = rlp_length_prefix(bytes.len(), 0x80);
+    out.extend_from_slice(bytes);
+    out
+}
+
+fn rlp_uint(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    rlp_bytes(&bytes[first..])
+}
+
+fn rlp_length_prefix(len: usize, offset: u8) -> Vec<u8> {
+    if len < 56 {
+        return vec![offset + len as u8];
+    }
+    
+    let len_bytes = (len as u64).to_be_bytes();
+    let first = len_bytes.iter().position(|b| *b != 0).unwrap_or(len_bytes.len());
+    let mut out = vec![offset + 55 + (len_bytes.len() - first) as u8];
+    out.extend_from_slice(&len_bytes[first..]);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +285,7 @@ mod tests {
             gas_used: 5000000,
             timestamp: 1234567890,
             extra_data: vec![],
+            ..Default::default()
         };
         
         let hash = client.hash_header(&header);
@@ -185,10 +305,47 @@ mod tests {
             gas_used: 5000000,
             timestamp: 1234567890,
             extra_data: vec![],
+            ..Default::default()
         };
         
         let result = client.verify_header(&header);
         assert!(result.is_ok());
         assert!(result.unwrap());
     }
+    
+    fn hex32(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hex::decode(s).unwrap());
+        out
+    }
+    
+    #[test]
+    fn test_native_mode_reproduces_genesis_hash() {
+        // Ethereum mainnet genesis header
+        let empty_trie = hex32("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421");
+        let genesis = EthereumHeader {
+            parent_hash: [0u8; 32],
+            ommers_hash: hex32("1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"),
+            state_root: hex32("d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"),
+            transactions_root: empty_trie,
+            receipts_root: empty_trie,
+            difficulty: 0x400000000,
+            number: 0,
+            gas_limit: 5000,
+            gas_used: 0,
+            timestamp: 0,
+            extra_data: hex32("11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa").to_vec(),
+            nonce: [0, 0, 0, 0, 0, 0, 0, 0x42],
+            ..Default::default()
+        };
+        
+        let client = EthereumLightClient::new("ethereum-1".to_string());
+        assert_eq!(
+            client.hash_header(&genesis),
+            hex32("d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3")
+        );
+        
+        let quantum = EthereumLightClient::new("ethereum-1".to_string()).with_hash_mode(HashMode::QuantumSha3);
+        assert_ne!(quantum.hash_header(&genesis), client.hash_header(&genesis));
+    }
 }
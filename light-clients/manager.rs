@@ -1,4 +1,5 @@
 // Light Client Manager - Manages multiple light clients for different chains
+use futures::StreamExt;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -7,15 +8,23 @@ use super::{LightClient, StateProof, Validator, LightClientError};
 
 pub struct LightClientManager {
     clients: Arc<RwLock<HashMap<String, LightClient>>>,
+    max_workers: usize,
 }
 
 impl LightClientManager {
     pub fn new() -> Self {
         Self {
             clients: Arc::new(RwLock::new(HashMap::new())),
+            max_workers: std::thread::available_parallelism().map_or(4, |n| n.get()),
         }
     }
     
+    /// Most proofs `verify_batch` checks at once; defaults to the number of CPUs
+    pub fn with_max_workers(mut self, max_workers: usize) -> Self {
+        self.max_workers = max_workers.max(1);
+        self
+    }
+    
     pub async fn add_client(&self, chain_id: String, client: LightClient) {
         let mut clients = self.clients.write().await;
         clients.insert(chain_id, client);
@@ -35,6 +44,73 @@ impl LightClientManager {
         client.verify_state_proof(proof)
     }
     
+    /// Verify proofs from several chains that only mean something together, e.g. a lock
+    /// on one chain and the mint it authorizes on another
+    ///
+    /// Each entry is a chain id and a bincode-encoded `StateProof` for that chain's client.
+    /// All must verify; the first that doesn't is reported as `CrossChainProofFailed`.
+    /// An empty set proves nothing and returns `Ok(false)`.
+    pub async fn verify_cross_chain(&self, proofs: &[(String, Vec<u8>)]) -> Result<bool, LightClientError> {
+        if proofs.is_empty() {
+            return Ok(false);
+        }
+        
+        let clients = self.clients.read().await;
+        for (index, (chain_id, bytes)) in proofs.iter().enumerate() {
+            let failed = |reason: String| LightClientError::CrossChainProofFailed {
+                index,
+                chain_id: chain_id.clone(),
+                reason,
+            };
+            
+            let client = clients
+                .get(chain_id)
+                .ok_or_else(|| failed("no light client registered".to_string()))?;
+            let proof: StateProof = bincode::deserialize(bytes)
+                .map_err(|e| failed(format!("malformed proof: {}", e)))?;
+            
+            if !client.verify_state_proof(&proof).map_err(|e| failed(e.to_string()))? {
+                return Err(failed("not signed by enough voting power".to_string()));
+            }
+        }
+        
+        Ok(true)
+    }
+    
+    /// Verify independent proofs concurrently, one result per proof in input order
+    ///
+    /// Each entry is a chain id and a bincode-encoded `StateProof`, as for `verify_cross_chain`,
+    /// but entries don't depend on each other: a failure only affects its own result. Signature
+    /// checks are CPU-bound, so they run on the blocking pool, at most `max_workers` at a time.
+    /// Clients are read once up front; updates made while the batch runs don't affect it.
+    pub async fn verify_batch(&self, proofs: Vec<(String, Vec<u8>)>) -> Vec<Result<bool, LightClientError>> {
+        let jobs: Vec<_> = {
+            let clients = self.clients.read().await;
+            // One copy of each client, shared by every proof for its chain
+            let mut snapshot: HashMap<String, Arc<LightClient>> = HashMap::new();
+            proofs
+                .into_iter()
+                .map(|(chain_id, bytes)| {
+                    let client = clients.get(&chain_id).map(|client| {
+                        snapshot
+                            .entry(chain_id.clone())
+                            .or_insert_with(|| Arc::new(client.clone()))
+                            .clone()
+                    });
+                    (chain_id, client, bytes)
+                })
+                .collect()
+        };
+        
+        run_bounded(jobs, self.max_workers, |(chain_id, client, bytes)| {
+            let client = client.ok_or(LightClientError::UnknownChain(chain_id))?;
+            let proof: StateProof = bincode::deserialize(&bytes)
+                .map_err(|e| LightClientError::Serialization(e.to_string()))?;
+            client.verify_state_proof(&proof)
+        })
+        .await
+    }
+    
     pub async fn update_state(&self, chain_id: &str, proof: StateProof) -> Result<(), LightClientError> {
         let mut clients = self.clients.write().await;
         let client = clients
@@ -70,6 +146,28 @@ impl LightClientManager {
     }
 }
 
+/// Run `job` on every item on the blocking pool, at most `workers` at once, keeping input order
+async fn run_bounded<T, R, F>(items: Vec<T>, workers: usize, job: F) -> Vec<R>
+where
+    T: Send + 'static,
+    R: Send + 'static,
+    F: Fn(T) -> R + Send + Sync + 'static,
+{
+    let job = Arc::new(job);
+    futures::stream::iter(items)
+        .map(|item| {
+            let job = job.clone();
+            async move {
+                tokio::task::spawn_blocking(move || job(item))
+                    .await
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+            }
+        })
+        .buffered(workers)
+        .collect()
+        .await
+}
+
 impl Default for LightClientManager {
     fn default() -> Self {
         Self::new()
@@ -122,4 +220,122 @@ mod tests {
         let height = manager.get_latest_height("ethereum-1").await;
         assert_eq!(height, Some(12345));
     }
+    
+    /// A client with one validator, and a bincode-encoded proof it signed for `chain_id`
+    fn signed_client(chain_id: &str, clock: Arc<crate::core::clock::MockClock>) -> (LightClient, Vec<u8>) {
+        use crate::core::crypto::QuantumKeyManager;
+        use crate::light_clients::QuantumSignature;
+        
+        let (public_key, secret_key) = QuantumKeyManager::generate_keypair();
+        let mut client = LightClient::new(chain_id.to_string()).with_clock(clock);
+        client.add_validator(Validator {
+            address: vec![1],
+            public_key: public_key.as_bytes().to_vec(),
+            voting_power: 100,
+            classical_public_key: Vec::new(),
+            hybrid: false,
+        });
+        
+        let mut proof = StateProof {
+            height: 10,
+            state_root: chain_id.as_bytes().to_vec(),
+            signatures: vec![],
+            timestamp: 1_700_000_000,
+        };
+        proof.signatures.push(QuantumSignature {
+            validator_address: vec![1],
+            signature: QuantumKeyManager::sign(&secret_key, &client.construct_proof_message(&proof)),
+            classical_signature: None,
+        });
+        
+        (client, bincode::serialize(&proof).unwrap())
+    }
+    
+    #[tokio::test]
+    async fn test_verify_cross_chain_requires_every_proof() {
+        use crate::core::clock::MockClock;
+        
+        let clock = Arc::new(MockClock::new(1_700_000_000));
+        let manager = LightClientManager::new();
+        let mut proofs = Vec::new();
+        for chain_id in ["ethereum-1", "polkadot-0"] {
+            let (client, proof) = signed_client(chain_id, clock.clone());
+            proofs.push((chain_id.to_string(), proof));
+            manager.add_client(chain_id.to_string(), client).await;
+        }
+        
+        // Lock on ethereum and mint on polkadot both check out
+        assert!(manager.verify_cross_chain(&proofs).await.unwrap());
+        assert!(!manager.verify_cross_chain(&[]).await.unwrap());
+        
+        // A proof presented for the wrong chain is signed under another chain id
+        let mut swapped = proofs.clone();
+        swapped[1].0 = "ethereum-1".to_string();
+        assert!(matches!(
+            manager.verify_cross_chain(&swapped).await,
+            Err(LightClientError::CrossChainProofFailed { index: 1, ref chain_id, .. }) if chain_id == "ethereum-1"
+        ));
+        
+        let mut unknown = proofs.clone();
+        unknown[0].0 = "cosmos-1".to_string();
+        assert!(matches!(
+            manager.verify_cross_chain(&unknown).await,
+            Err(LightClientError::CrossChainProofFailed { index: 0, .. })
+        ));
+    }
+    
+    #[tokio::test]
+    async fn test_verify_batch_keeps_order_and_isolates_failures() {
+        use crate::core::clock::MockClock;
+        
+        let clock = Arc::new(MockClock::new(1_700_000_000));
+        let manager = LightClientManager::new().with_max_workers(2);
+        let mut proofs = HashMap::new();
+        for chain_id in ["ethereum-1", "polkadot-0", "cosmos-1"] {
+            let (client, proof) = signed_client(chain_id, clock.clone());
+            proofs.insert(chain_id, proof);
+            manager.add_client(chain_id.to_string(), client).await;
+        }
+        
+        let batch = vec![
+            ("cosmos-1".to_string(), proofs["cosmos-1"].clone()),
+            ("ethereum-1".to_string(), proofs["polkadot-0"].clone()), // signed for another chain
+            ("polkadot-0".to_string(), proofs["polkadot-0"].clone()),
+            ("bitcoin-1".to_string(), proofs["ethereum-1"].clone()),
+            ("ethereum-1".to_string(), vec![0xFF; 3]),
+            ("ethereum-1".to_string(), proofs["ethereum-1"].clone()),
+        ];
+        let results = manager.verify_batch(batch).await;
+        
+        assert_eq!(results.len(), 6);
+        assert!(matches!(results[0], Ok(true)));
+        assert!(matches!(results[1], Ok(false)));
+        assert!(matches!(results[2], Ok(true)));
+        assert!(matches!(results[3], Err(LightClientError::UnknownChain(ref chain_id)) if chain_id == "bitcoin-1"));
+        assert!(matches!(results[4], Err(LightClientError::Serialization(_))));
+        assert!(matches!(results[5], Ok(true)));
+        assert!(manager.verify_batch(Vec::new()).await.is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_run_bounded_overlaps_jobs_up_to_worker_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (counter, high_water) = (in_flight.clone(), peak.clone());
+        
+        let results = run_bounded((0..12).collect(), 3, move |i: u64| {
+            let now = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            high_water.fetch_max(now, Ordering::SeqCst);
+            // Finish out of order, so ordering can't come from completion times
+            std::thread::sleep(std::time::Duration::from_millis(40 - i * 3));
+            counter.fetch_sub(1, Ordering::SeqCst);
+            i * 10
+        })
+        .await;
+        
+        assert_eq!(results, (0..12).map(|i| i * 10).collect::<Vec<_>>());
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
 }
@@ -3,22 +3,80 @@
 
 pub mod ethereum;
 pub mod bitcoin;
+#[cfg(feature = "dot")]
 pub mod polkadot;
+pub mod scrypt_chain;
+pub mod dash;
+pub mod zcash;
 pub mod manager;
+pub mod sync;
 
+use ed25519_dalek::{Signature as Ed25519Signature, Verifier, VerifyingKey};
 use pqcrypto_dilithium::dilithium5;
 use pqcrypto_traits::sign::{PublicKey as PQPublicKey, SignedMessage};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use sha2::Sha512;
 use sha3::{Digest, Sha3_512};
 
 pub use manager::LightClientManager;
 
+use crate::core::clock::{system_clock, Clock};
+use crate::core::codec::Codec;
+
+/// Domain-separation label prepended to every state proof preimage
+pub const PROOF_DOMAIN_TAG: &[u8] = b"SENTIUM_STATE_PROOF_V1";
+
+/// Encoding version of the state proof message
+pub const PROOF_MESSAGE_VERSION: u8 = 1;
+
+/// Domain-separation label prepended to every key rotation preimage
+pub const ROTATION_DOMAIN_TAG: &[u8] = b"SENTIUM_KEY_ROTATION_V1";
+
+/// Default maximum age of a state proof before it is rejected as a replay
+pub const DEFAULT_MAX_PROOF_AGE_SECS: u64 = 3600;
+
+/// Allowance for proofs timestamped slightly ahead of the local clock
+pub const DEFAULT_MAX_CLOCK_SKEW_SECS: u64 = 30;
+
+/// Number of recent heights whose signed proofs are kept for equivocation checks
+pub const EQUIVOCATION_WINDOW: u64 = 256;
+
+/// Hash functions used for headers, transactions and signed proof messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HashMode {
+    /// The chain's own hash functions, reproducing real block and transaction hashes
+    #[default]
+    Native,
+    /// SHA3 in place of the chain's hashes, for Sentium-native flows
+    QuantumSha3,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LightClient {
     pub chain_id: String,
     pub latest_height: u64,
     pub state_root: Vec<u8>,
     pub validator_set: Vec<Validator>,
+    #[serde(default = "default_max_age_secs")]
+    pub max_age_secs: Option<u64>, // None disables the freshness check (historical verification)
+    #[serde(default = "default_max_clock_skew_secs")]
+    pub max_clock_skew_secs: u64,
+    #[serde(default)]
+    pub hash_mode: HashMode,
+    #[serde(skip)]
+    signed_history: BTreeMap<u64, StateProof>, // accepted proofs by height, for equivocation checks
+    #[serde(skip, default = "system_clock")]
+    clock: Arc<dyn Clock>,
+}
+
+fn default_max_age_secs() -> Option<u64> {
+    Some(DEFAULT_MAX_PROOF_AGE_SECS)
+}
+
+fn default_max_clock_skew_secs() -> u64 {
+    DEFAULT_MAX_CLOCK_SKEW_SECS
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +84,10 @@ pub struct Validator {
     pub address: Vec<u8>,
     pub public_key: Vec<u8>, // Dilithium5 public key
     pub voting_power: u64,
+    #[serde(default)]
+    pub classical_public_key: Vec<u8>, // Ed25519 public key (32 bytes), used in hybrid mode
+    #[serde(default)]
+    pub hybrid: bool, // Require both Ed25519 and Dilithium5 signatures
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,10 +98,58 @@ pub struct StateProof {
     pub timestamp: u64,
 }
 
+/// Compact multi-signature form of a `StateProof`
+///
+/// Dilithium5 has no signature aggregation, so every signer's signature is still carried.
+/// What's shared is the rest: the proof message digest appears once, and signer addresses
+/// are replaced by a bitmap over the light client's validator set (bit `i`, least
+/// significant first, is validator `i`). Signatures follow validator-set order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedStateProof {
+    pub height: u64,
+    pub state_root: Vec<u8>,
+    pub timestamp: u64,
+    pub message_hash: Vec<u8>, // construct_proof_message output all signatures cover
+    pub signer_bitmap: Vec<u8>,
+    pub signatures: Vec<Vec<u8>>, // Dilithium5 signatures, one per set bit
+    #[serde(default)]
+    pub classical_signatures: Vec<Vec<u8>>, // Ed25519 signatures, one per hybrid signer
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuantumSignature {
     pub validator_address: Vec<u8>,
-    pub signature: Vec<u8>, // Dilithium5 signature (4595 bytes)
+    pub signature: Vec<u8>, // Dilithium5 signature (4627 bytes)
+    #[serde(default)]
+    pub classical_signature: Option<Vec<u8>>, // Ed25519 signature (64 bytes), hybrid validators only
+}
+
+/// Classical + post-quantum signature pair for the transition period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HybridSignature {
+    pub classical: Vec<u8>, // Ed25519 signature (64 bytes)
+    pub pq: Vec<u8>,        // Dilithium5 signature (4627 bytes)
+}
+
+/// Evidence that a validator signed two conflicting proofs at the same height
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlashingReport {
+    pub chain_id: String,
+    pub height: u64,
+    pub validator: Vec<u8>, // validator address
+    pub evidence_a: QuantumSignature, // signature over the previously accepted proof
+    pub evidence_b: QuantumSignature, // signature over the conflicting proof
+}
+
+impl QuantumSignature {
+    /// Build a signature entry for a hybrid validator
+    pub fn hybrid(validator_address: Vec<u8>, signature: HybridSignature) -> Self {
+        Self {
+            validator_address,
+            signature: signature.pq,
+            classical_signature: Some(signature.classical),
+        }
+    }
 }
 
 impl LightClient {
@@ -49,21 +159,52 @@ impl LightClient {
             latest_height: 0,
             state_root: Vec::new(),
             validator_set: Vec::new(),
+            max_age_secs: default_max_age_secs(),
+            max_clock_skew_secs: default_max_clock_skew_secs(),
+            hash_mode: HashMode::default(),
+            signed_history: BTreeMap::new(),
+            clock: system_clock(),
         }
     }
     
+    /// Set the replay-protection window; `None` accepts proofs of any age
+    pub fn with_max_age(mut self, max_age_secs: Option<u64>) -> Self {
+        self.max_age_secs = max_age_secs;
+        self
+    }
+    
+    /// Digest used for signed proof messages
+    pub fn with_hash_mode(mut self, hash_mode: HashMode) -> Self {
+        self.hash_mode = hash_mode;
+        self
+    }
+    
+    /// Use a different time source for proof freshness checks
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Persistable state: height, root, validators and settings
+    pub fn snapshot(&self, codec: Codec) -> Result<Vec<u8>, LightClientError> {
+        codec.encode(self).map_err(|e| LightClientError::Serialization(e.to_string()))
+    }
+    
+    /// Client from a `snapshot`, on the system clock and with no equivocation history
+    pub fn restore(snapshot: &[u8], codec: Codec) -> Result<Self, LightClientError> {
+        codec.decode(snapshot).map_err(|e| LightClientError::Serialization(e.to_string()))
+    }
+    
     pub fn add_validator(&mut self, validator: Validator) {
         self.validator_set.push(validator);
     }
     
     pub fn verify_state_proof(&self, proof: &StateProof) -> Result<bool, LightClientError> {
-        // Verify that we have enough signatures (>2/3 voting power)
-        let total_power: u64 = self.validator_set.iter().map(|v| v.voting_power).sum();
-        if total_power == 0 {
-            return Err(LightClientError::NoValidators);
-        }
+        // Reject stale (replayed) or future-dated proofs
+        self.check_proof_freshness(proof.timestamp)?;
         
-        let threshold = (total_power * 2) / 3;
+        // Verify that we have enough signatures (>2/3 voting power)
+        let threshold = self.quorum_threshold()?;
         let mut signed_power = 0u64;
         
         // Construct message to verify
@@ -76,8 +217,102 @@ impl LightClient {
                 .find(|v| v.address == sig.validator_address)
                 .ok_or(LightClientError::UnknownValidator)?;
             
-            // Verify Dilithium5 signature
-            if self.verify_dilithium_signature(&message, &sig.signature, &validator.public_key)? {
+            if self.verify_validator_signature(&message, sig, validator)? {
+                signed_power += validator.voting_power;
+            }
+        }
+        
+        Ok(signed_power > threshold)
+    }
+    
+    /// Repack a proof as an `AggregatedStateProof` over the current validator set
+    ///
+    /// Signatures aren't checked here; `verify_aggregated_proof` accepts the result exactly
+    /// when `verify_state_proof` accepts the original.
+    pub fn aggregate_proof(&self, proof: &StateProof) -> Result<AggregatedStateProof, LightClientError> {
+        let mut signers: Vec<Option<&QuantumSignature>> = vec![None; self.validator_set.len()];
+        for sig in &proof.signatures {
+            let index = self.validator_set
+                .iter()
+                .position(|v| v.address == sig.validator_address)
+                .ok_or(LightClientError::UnknownValidator)?;
+            
+            // A bitmap can't express a validator signing twice
+            if signers[index].replace(sig).is_some() {
+                return Err(LightClientError::InvalidProof);
+            }
+        }
+        
+        let mut aggregated = AggregatedStateProof {
+            height: proof.height,
+            state_root: proof.state_root.clone(),
+            timestamp: proof.timestamp,
+            message_hash: self.construct_proof_message(proof),
+            signer_bitmap: vec![0u8; self.validator_set.len().div_ceil(8)],
+            signatures: Vec::new(),
+            classical_signatures: Vec::new(),
+        };
+        
+        for (index, sig) in signers.into_iter().enumerate() {
+            let sig = match sig {
+                Some(sig) => sig,
+                None => continue,
+            };
+            
+            aggregated.signer_bitmap[index / 8] |= 1 << (index % 8);
+            aggregated.signatures.push(sig.signature.clone());
+            if self.validator_set[index].hybrid {
+                // Without a classical signature the signer can't count; keep it so the
+                // verdict matches the original proof
+                aggregated
+                    .classical_signatures
+                    .push(sig.classical_signature.clone().unwrap_or_default());
+            }
+        }
+        
+        Ok(aggregated)
+    }
+    
+    /// Verify an `AggregatedStateProof`, checking its message once for all signers
+    pub fn verify_aggregated_proof(&self, proof: &AggregatedStateProof) -> Result<bool, LightClientError> {
+        self.check_proof_freshness(proof.timestamp)?;
+        let threshold = self.quorum_threshold()?;
+        
+        let message = self.construct_proof_message(&StateProof {
+            height: proof.height,
+            state_root: proof.state_root.clone(),
+            signatures: Vec::new(),
+            timestamp: proof.timestamp,
+        });
+        if message != proof.message_hash {
+            return Err(LightClientError::InvalidProof);
+        }
+        
+        // The bitmap must cover exactly the validator set and match the signature count
+        let validators = self.validator_set.len();
+        if proof.signer_bitmap.len() != validators.div_ceil(8) {
+            return Err(LightClientError::InvalidProof);
+        }
+        let signers: Vec<&Validator> = self.validator_set
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| proof.signer_bitmap[index / 8] & (1 << (index % 8)) != 0)
+            .map(|(_, validator)| validator)
+            .collect();
+        let set_bits: u32 = proof.signer_bitmap.iter().map(|byte| byte.count_ones()).sum();
+        let hybrid_signers = signers.iter().filter(|v| v.hybrid).count();
+        if set_bits as usize != signers.len()
+            || signers.len() != proof.signatures.len()
+            || hybrid_signers != proof.classical_signatures.len()
+        {
+            return Err(LightClientError::InvalidProof);
+        }
+        
+        let mut classical_signatures = proof.classical_signatures.iter();
+        let mut signed_power = 0u64;
+        for (validator, signature) in signers.into_iter().zip(&proof.signatures) {
+            let classical = if validator.hybrid { classical_signatures.next().map(Vec::as_slice) } else { None };
+            if self.verify_signature_parts(&message, signature, classical, validator)? {
                 signed_power += validator.voting_power;
             }
         }
@@ -85,18 +320,132 @@ impl LightClient {
         Ok(signed_power > threshold)
     }
     
-    fn construct_proof_message(&self, proof: &StateProof) -> Vec<u8> {
-        // Construct message: chain_id || height || state_root || timestamp
+    /// Voting power a proof's signers must exceed (2/3 of the total)
+    fn quorum_threshold(&self) -> Result<u64, LightClientError> {
+        let total_power: u64 = self.validator_set.iter().map(|v| v.voting_power).sum();
+        if total_power == 0 {
+            return Err(LightClientError::NoValidators);
+        }
+        
+        Ok((total_power * 2) / 3)
+    }
+    
+    /// Compare a proof against accepted proofs at the same height and report the first
+    /// validator that validly signed both, if any
+    pub fn check_equivocation(&self, proof: &StateProof) -> Option<SlashingReport> {
+        let accepted = self.signed_history.get(&proof.height)?;
+        if accepted.state_root == proof.state_root {
+            return None;
+        }
+        
+        let accepted_message = self.construct_proof_message(accepted);
+        let message = self.construct_proof_message(proof);
+        
+        for sig in &proof.signatures {
+            let validator = match self.validator_set.iter().find(|v| v.address == sig.validator_address) {
+                Some(validator) => validator,
+                None => continue,
+            };
+            
+            let previous = match accepted
+                .signatures
+                .iter()
+                .find(|s| s.validator_address == sig.validator_address)
+            {
+                Some(previous) => previous,
+                None => continue,
+            };
+            
+            // Only validly-signed conflicting pairs count as evidence
+            let both_valid = matches!(self.verify_validator_signature(&message, sig, validator), Ok(true))
+                && matches!(self.verify_validator_signature(&accepted_message, previous, validator), Ok(true));
+            
+            if both_valid {
+                tracing::warn!(
+                    "Equivocation by validator {} at height {} on {}",
+                    hex::encode(&sig.validator_address),
+                    proof.height,
+                    self.chain_id
+                );
+                
+                return Some(SlashingReport {
+                    chain_id: self.chain_id.clone(),
+                    height: proof.height,
+                    validator: sig.validator_address.clone(),
+                    evidence_a: previous.clone(),
+                    evidence_b: sig.clone(),
+                });
+            }
+        }
+        
+        None
+    }
+    
+    fn verify_validator_signature(
+        &self,
+        message: &[u8],
+        sig: &QuantumSignature,
+        validator: &Validator,
+    ) -> Result<bool, LightClientError> {
+        self.verify_signature_parts(message, &sig.signature, sig.classical_signature.as_deref(), validator)
+    }
+    
+    fn verify_signature_parts(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        classical_signature: Option<&[u8]>,
+        validator: &Validator,
+    ) -> Result<bool, LightClientError> {
+        if validator.hybrid {
+            // Hybrid validators must provide both signatures
+            match classical_signature {
+                Some(classical) => self.verify_hybrid_parts(message, classical, signature, validator),
+                None => Ok(false),
+            }
+        } else {
+            // Verify Dilithium5 signature
+            self.verify_dilithium_signature(message, signature, &validator.public_key)
+        }
+    }
+    
+    fn check_proof_freshness(&self, timestamp: u64) -> Result<(), LightClientError> {
+        let max_age = match self.max_age_secs {
+            Some(max_age) => max_age,
+            None => return Ok(()),
+        };
+        
+        let now = self.clock.now_secs();
+        
+        if timestamp > now.saturating_add(self.max_clock_skew_secs) {
+            return Err(LightClientError::ProofFromFuture);
+        }
+        
+        if timestamp < now.saturating_sub(max_age) {
+            return Err(LightClientError::ProofExpired);
+        }
+        
+        Ok(())
+    }
+    
+    pub fn construct_proof_message(&self, proof: &StateProof) -> Vec<u8> {
+        // Construct message:
+        // domain_tag || version || len(chain_id) || chain_id || height || len(state_root) || state_root || timestamp
         let mut message = Vec::new();
+        message.extend_from_slice(PROOF_DOMAIN_TAG);
+        message.push(PROOF_MESSAGE_VERSION);
+        message.extend_from_slice(&(self.chain_id.len() as u32).to_le_bytes());
         message.extend_from_slice(self.chain_id.as_bytes());
         message.extend_from_slice(&proof.height.to_le_bytes());
+        message.extend_from_slice(&(proof.state_root.len() as u32).to_le_bytes());
         message.extend_from_slice(&proof.state_root);
         message.extend_from_slice(&proof.timestamp.to_le_bytes());
         
-        // Hash the message with SHA3-512 for quantum resistance
-        let mut hasher = Sha3_512::new();
-        hasher.update(&message);
-        hasher.finalize().to_vec()
+        // SHA-512 natively (as Ed25519 stacks do), SHA3-512 in quantum mode
+        match self.hash_mode {
+            HashMode::Native => Sha512::digest(&message).to_vec(),
+            HashMode::QuantumSha3 => Sha3_512::digest(&message).to_vec(),
+        }
     }
     
     fn verify_dilithium_signature(
@@ -106,8 +455,8 @@ impl LightClient {
         public_key_bytes: &[u8],
     ) -> Result<bool, LightClientError> {
         // Verify Dilithium5 signature
-        // Dilithium5 signature is 4595 bytes
-        if signature.len() != 4595 {
+        // Signature size comes from the linked pqcrypto implementation (4627 bytes)
+        if signature.len() != dilithium5::signature_bytes() {
             return Err(LightClientError::InvalidSignatureSize);
         }
         
@@ -129,6 +478,48 @@ impl LightClient {
         }
     }
     
+    pub fn verify_hybrid_signature(
+        &self,
+        message: &[u8],
+        signature: &HybridSignature,
+        validator: &Validator,
+    ) -> Result<bool, LightClientError> {
+        self.verify_hybrid_parts(message, &signature.classical, &signature.pq, validator)
+    }
+    
+    fn verify_hybrid_parts(
+        &self,
+        message: &[u8],
+        classical: &[u8],
+        pq: &[u8],
+        validator: &Validator,
+    ) -> Result<bool, LightClientError> {
+        // Both the classical and the post-quantum signature must pass
+        let classical_valid =
+            self.verify_ed25519_signature(message, classical, &validator.classical_public_key)?;
+        let pq_valid = self.verify_dilithium_signature(message, pq, &validator.public_key)?;
+        
+        Ok(classical_valid && pq_valid)
+    }
+    
+    fn verify_ed25519_signature(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key_bytes: &[u8],
+    ) -> Result<bool, LightClientError> {
+        let key_bytes: [u8; 32] = public_key_bytes
+            .try_into()
+            .map_err(|_| LightClientError::InvalidPublicKey)?;
+        let public_key = VerifyingKey::from_bytes(&key_bytes)
+            .map_err(|_| LightClientError::InvalidPublicKey)?;
+        
+        let signature = Ed25519Signature::from_slice(signature)
+            .map_err(|_| LightClientError::InvalidSignatureSize)?;
+        
+        Ok(public_key.verify(message, &signature).is_ok())
+    }
+    
     pub fn update_state(&mut self, proof: StateProof) -> Result<(), LightClientError> {
         // Verify proof
         if !self.verify_state_proof(&proof)? {
@@ -142,7 +533,12 @@ impl LightClient {
         
         // Update state
         self.latest_height = proof.height;
-        self.state_root = proof.state_root;
+        self.state_root = proof.state_root.clone();
+        
+        // Keep recent signed proofs as equivocation evidence
+        self.signed_history.insert(proof.height, proof);
+        let cutoff = self.latest_height.saturating_sub(EQUIVOCATION_WINDOW);
+        self.signed_history = self.signed_history.split_off(&cutoff);
         
         Ok(())
     }
@@ -150,6 +546,52 @@ impl LightClient {
     pub fn update_validator_set(&mut self, new_validators: Vec<Validator>) {
         self.validator_set = new_validators;
     }
+    
+    /// Message a validator's current Dilithium5 key signs to hand over to `new_public_key`
+    ///
+    /// It names the key being replaced, so a rotation proof can't be replayed once that key
+    /// is gone.
+    pub fn construct_rotation_message(&self, address: &[u8], old_public_key: &[u8], new_public_key: &[u8]) -> Vec<u8> {
+        // domain_tag || len(chain_id) || chain_id || len(address) || address || len(old) || old || len(new) || new
+        let mut message = Vec::new();
+        message.extend_from_slice(ROTATION_DOMAIN_TAG);
+        for field in [self.chain_id.as_bytes(), address, old_public_key, new_public_key] {
+            message.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            message.extend_from_slice(field);
+        }
+        
+        match self.hash_mode {
+            HashMode::Native => Sha512::digest(&message).to_vec(),
+            HashMode::QuantumSha3 => Sha3_512::digest(&message).to_vec(),
+        }
+    }
+    
+    /// Replace a validator's Dilithium5 key, keeping its address and voting power
+    ///
+    /// `rotation_proof` is the old key's signature over `construct_rotation_message`, proving
+    /// the validator controls it. A hybrid validator's Ed25519 key is left unchanged.
+    pub fn rotate_validator(
+        &mut self,
+        old_address: &[u8],
+        new_public_key: Vec<u8>,
+        rotation_proof: &[u8],
+    ) -> Result<(), LightClientError> {
+        let index = self
+            .validator_set
+            .iter()
+            .position(|v| v.address == old_address)
+            .ok_or(LightClientError::UnknownValidator)?;
+        dilithium5::PublicKey::from_bytes(&new_public_key).map_err(|_| LightClientError::InvalidPublicKey)?;
+        
+        let old_public_key = &self.validator_set[index].public_key;
+        let message = self.construct_rotation_message(old_address, old_public_key, &new_public_key);
+        if !self.verify_dilithium_signature(&message, rotation_proof, old_public_key)? {
+            return Err(LightClientError::SignatureVerificationFailed);
+        }
+        
+        self.validator_set[index].public_key = new_public_key;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -177,6 +619,24 @@ pub enum LightClientError {
     
     #[error("Invalid height")]
     InvalidHeight,
+    
+    #[error("State proof expired")]
+    ProofExpired,
+    
+    #[error("State proof timestamp is in the future")]
+    ProofFromFuture,
+    
+    #[error("Invalid public input: {0}")]
+    InvalidPublicInput(String),
+    
+    #[error("Cross-chain proof {index} ({chain_id}) failed: {reason}")]
+    CrossChainProofFailed { index: usize, chain_id: String, reason: String },
+    
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+    
+    #[error("No light client for chain {0}")]
+    UnknownChain(String),
 }
 
 #[cfg(test)]
@@ -198,6 +658,8 @@ mod tests {
             address: vec![1, 2, 3, 4],
             public_key: vec![0u8; 2592], // Dilithium5 public key size
             voting_power: 100,
+            classical_public_key: Vec::new(),
+            hybrid: false,
         };
         
         client.add_validator(validator);
@@ -216,6 +678,294 @@ mod tests {
         
         let message = client.construct_proof_message(&proof);
         assert!(message.len() > 0);
-        assert_eq!(message.len(), 64); // SHA3-512 output
+        assert_eq!(message.len(), 64); // SHA-512 output
+        
+        let quantum = LightClient::new("test-chain".to_string()).with_hash_mode(HashMode::QuantumSha3);
+        let quantum_message = quantum.construct_proof_message(&proof);
+        assert_eq!(quantum_message.len(), 64); // SHA3-512 output
+        assert_ne!(quantum_message, message);
+    }
+    
+    #[test]
+    fn test_legacy_proof_message_rejected() {
+        use crate::core::crypto::QuantumKeyManager;
+        
+        let (public_key, secret_key) = QuantumKeyManager::generate_keypair();
+        
+        let mut client = LightClient::new("test-chain".to_string()).with_max_age(None);
+        client.add_validator(Validator {
+            address: vec![1, 2, 3, 4],
+            public_key: public_key.as_bytes().to_vec(),
+            voting_power: 100,
+            classical_public_key: Vec::new(),
+            hybrid: false,
+        });
+        
+        let mut proof = StateProof {
+            height: 100,
+            state_root: vec![1, 2, 3, 4],
+            signatures: vec![],
+            timestamp: 1234567890,
+        };
+        
+        // Pre-versioning scheme: chain_id || height || state_root || timestamp, no tag or lengths
+        let mut legacy = Vec::new();
+        legacy.extend_from_slice(client.chain_id.as_bytes());
+        legacy.extend_from_slice(&proof.height.to_le_bytes());
+        legacy.extend_from_slice(&proof.state_root);
+        legacy.extend_from_slice(&proof.timestamp.to_le_bytes());
+        let legacy_message = Sha3_512::digest(&legacy).to_vec();
+        
+        assert_ne!(legacy_message, client.construct_proof_message(&proof));
+        
+        proof.signatures.push(QuantumSignature {
+            validator_address: vec![1, 2, 3, 4],
+            signature: QuantumKeyManager::sign(&secret_key, &legacy_message),
+            classical_signature: None,
+        });
+        
+        assert!(!client.verify_state_proof(&proof).unwrap());
+    }
+    
+    fn now_secs() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+    
+    fn client_with_validator() -> LightClient {
+        let mut client = LightClient::new("test-chain".to_string());
+        client.add_validator(Validator {
+            address: vec![1, 2, 3, 4],
+            public_key: vec![0u8; 2592],
+            voting_power: 100,
+            classical_public_key: Vec::new(),
+            hybrid: false,
+        });
+        client
+    }
+    
+    #[test]
+    fn test_expired_proof_rejected() {
+        let client = client_with_validator();
+        let proof = StateProof {
+            height: 100,
+            state_root: vec![1, 2, 3, 4],
+            signatures: vec![],
+            timestamp: now_secs() - DEFAULT_MAX_PROOF_AGE_SECS - 60,
+        };
+        
+        assert!(matches!(client.verify_state_proof(&proof), Err(LightClientError::ProofExpired)));
+        
+        // Historical verification skips the freshness check
+        let historical = client.with_max_age(None);
+        assert!(!historical.verify_state_proof(&proof).unwrap());
+    }
+    
+    #[test]
+    fn test_future_proof_rejected() {
+        let client = client_with_validator();
+        let mut proof = StateProof {
+            height: 100,
+            state_root: vec![1, 2, 3, 4],
+            signatures: vec![],
+            timestamp: now_secs() + DEFAULT_MAX_CLOCK_SKEW_SECS + 60,
+        };
+        
+        assert!(matches!(client.verify_state_proof(&proof), Err(LightClientError::ProofFromFuture)));
+        
+        // Within the skew allowance the proof is checked normally
+        proof.timestamp = now_secs() + 5;
+        assert!(!client.verify_state_proof(&proof).unwrap());
+    }
+    
+    #[test]
+    fn test_proof_expires_as_clock_advances() {
+        use crate::core::clock::MockClock;
+        
+        let clock = Arc::new(MockClock::new(1_700_000_000));
+        let client = client_with_validator().with_clock(clock.clone());
+        let proof = StateProof {
+            height: 100,
+            state_root: vec![1, 2, 3, 4],
+            signatures: vec![],
+            timestamp: 1_700_000_000,
+        };
+        
+        // Fresh: the freshness check passes and the (unsigned) proof is simply not valid
+        assert!(!client.verify_state_proof(&proof).unwrap());
+        
+        clock.advance(DEFAULT_MAX_PROOF_AGE_SECS);
+        assert!(!client.verify_state_proof(&proof).unwrap());
+        
+        clock.advance(1);
+        assert!(matches!(client.verify_state_proof(&proof), Err(LightClientError::ProofExpired)));
+    }
+    
+    #[test]
+    fn test_equivocation_produces_slashing_report() {
+        use crate::core::crypto::QuantumKeyManager;
+        
+        let (public_key, secret_key) = QuantumKeyManager::generate_keypair();
+        
+        let mut client = LightClient::new("test-chain".to_string());
+        client.add_validator(Validator {
+            address: vec![1, 2, 3, 4],
+            public_key: public_key.as_bytes().to_vec(),
+            voting_power: 100,
+            classical_public_key: Vec::new(),
+            hybrid: false,
+        });
+        
+        let signed_proof = |state_root: Vec<u8>| {
+            let mut proof = StateProof {
+                height: 100,
+                state_root,
+                signatures: vec![],
+                timestamp: now_secs(),
+            };
+            proof.signatures.push(QuantumSignature {
+                validator_address: vec![1, 2, 3, 4],
+                signature: QuantumKeyManager::sign(&secret_key, &client.construct_proof_message(&proof)),
+                classical_signature: None,
+            });
+            proof
+        };
+        
+        let proof_a = signed_proof(vec![0xaa; 32]);
+        let proof_b = signed_proof(vec![0xbb; 32]);
+        
+        client.update_state(proof_a.clone()).unwrap();
+        assert!(client.check_equivocation(&proof_a).is_none());
+        
+        let report = client.check_equivocation(&proof_b).expect("equivocation not detected");
+        assert_eq!(report.chain_id, "test-chain");
+        assert_eq!(report.height, 100);
+        assert_eq!(report.validator, vec![1, 2, 3, 4]);
+        assert_eq!(report.evidence_a.signature, proof_a.signatures[0].signature);
+        assert_eq!(report.evidence_b.signature, proof_b.signatures[0].signature);
+    }
+    
+    #[test]
+    fn test_rotate_validator_requires_old_key() {
+        use crate::core::crypto::QuantumKeyManager;
+        
+        let (old_public, old_secret) = QuantumKeyManager::generate_keypair();
+        let (new_public, new_secret) = QuantumKeyManager::generate_keypair();
+        let (_, other_secret) = QuantumKeyManager::generate_keypair();
+        let address = vec![1, 2, 3, 4];
+        
+        let mut client = LightClient::new("test-chain".to_string());
+        client.add_validator(Validator {
+            address: address.clone(),
+            public_key: old_public.as_bytes().to_vec(),
+            voting_power: 100,
+            classical_public_key: Vec::new(),
+            hybrid: false,
+        });
+        let message = client.construct_rotation_message(&address, old_public.as_bytes(), new_public.as_bytes());
+        
+        // Signed by a key the validator doesn't hold
+        let forged = QuantumKeyManager::sign(&other_secret, &message);
+        assert!(matches!(
+            client.rotate_validator(&address, new_public.as_bytes().to_vec(), &forged),
+            Err(LightClientError::SignatureVerificationFailed)
+        ));
+        assert_eq!(client.validator_set[0].public_key, old_public.as_bytes());
+        
+        let proof = QuantumKeyManager::sign(&old_secret, &message);
+        assert!(matches!(
+            client.rotate_validator(&[9, 9], new_public.as_bytes().to_vec(), &proof),
+            Err(LightClientError::UnknownValidator)
+        ));
+        client.rotate_validator(&address, new_public.as_bytes().to_vec(), &proof).unwrap();
+        assert_eq!(client.validator_set[0].public_key, new_public.as_bytes());
+        assert_eq!(client.validator_set[0].voting_power, 100);
+        
+        // The proof names the replaced key, so it can't be used again
+        assert!(client.rotate_validator(&address, new_public.as_bytes().to_vec(), &proof).is_err());
+        
+        // Proofs are now checked against the new key
+        let mut proof = StateProof { height: 1, state_root: vec![0xaa; 32], signatures: vec![], timestamp: now_secs() };
+        let sign = |secret_key, proof: &StateProof| QuantumSignature {
+            validator_address: address.clone(),
+            signature: QuantumKeyManager::sign(secret_key, &client.construct_proof_message(proof)),
+            classical_signature: None,
+        };
+        proof.signatures = vec![sign(&old_secret, &proof)];
+        assert!(!client.verify_state_proof(&proof).unwrap());
+        proof.signatures = vec![sign(&new_secret, &proof)];
+        assert!(client.verify_state_proof(&proof).unwrap());
+    }
+    
+    #[test]
+    fn test_aggregated_proof_is_smaller_and_verifies_the_same() {
+        use crate::core::crypto::QuantumKeyManager;
+        
+        let mut client = LightClient::new("test-chain".to_string());
+        let keys: Vec<_> = (0..16u8)
+            .map(|i| {
+                let (public_key, secret_key) = QuantumKeyManager::generate_keypair();
+                client.add_validator(Validator {
+                    address: vec![i; 32],
+                    public_key: public_key.as_bytes().to_vec(),
+                    voting_power: 100,
+                    classical_public_key: Vec::new(),
+                    hybrid: false,
+                });
+                secret_key
+            })
+            .collect();
+        
+        let mut proof = StateProof { height: 7, state_root: vec![0xaa; 32], signatures: vec![], timestamp: now_secs() };
+        let message = client.construct_proof_message(&proof);
+        let signed_by = |count: usize| -> Vec<QuantumSignature> {
+            keys.iter()
+                .take(count)
+                .enumerate()
+                .map(|(i, secret_key)| QuantumSignature {
+                    validator_address: vec![i as u8; 32],
+                    signature: QuantumKeyManager::sign(secret_key, &message),
+                    classical_signature: None,
+                })
+                .collect()
+        };
+        
+        // 11 of 16 is over 2/3, 10 is not
+        for (signers, expected) in [(11, true), (10, false)] {
+            proof.signatures = signed_by(signers);
+            let aggregated = client.aggregate_proof(&proof).unwrap();
+            assert_eq!(aggregated.signer_bitmap.len(), 2);
+            assert_eq!(client.verify_state_proof(&proof).unwrap(), expected);
+            assert_eq!(client.verify_aggregated_proof(&aggregated).unwrap(), expected);
+        }
+        
+        proof.signatures = signed_by(16);
+        let aggregated = client.aggregate_proof(&proof).unwrap();
+        let full_size = bincode::serialize(&proof).unwrap().len();
+        let aggregated_size = bincode::serialize(&aggregated).unwrap().len();
+        assert!(aggregated_size < full_size, "{} >= {}", aggregated_size, full_size);
+        
+        // Listing a signer twice can't be represented
+        let mut duplicated = proof.clone();
+        duplicated.signatures.push(duplicated.signatures[0].clone());
+        assert!(matches!(client.aggregate_proof(&duplicated), Err(LightClientError::InvalidProof)));
+        
+        // Rejected: a digest for another root and a bitmap longer than the validator set
+        let mut wrong_message = aggregated.clone();
+        wrong_message.state_root = vec![0xbb; 32];
+        assert!(matches!(client.verify_aggregated_proof(&wrong_message), Err(LightClientError::InvalidProof)));
+        
+        let mut extra_byte = aggregated;
+        extra_byte.signer_bitmap.push(1);
+        extra_byte.signatures.push(vec![0; 4627]);
+        assert!(matches!(client.verify_aggregated_proof(&extra_byte), Err(LightClientError::InvalidProof)));
+        
+        // Crediting validator 11 with validator 10's signature drops below quorum
+        proof.signatures = signed_by(11);
+        let mut misattributed = client.aggregate_proof(&proof).unwrap();
+        misattributed.signer_bitmap[1] ^= 0b1100;
+        assert!(!client.verify_aggregated_proof(&misattributed).unwrap());
     }
 }
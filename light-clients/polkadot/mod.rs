@@ -1,8 +1,13 @@
 // Polkadot Light Client - GRANDPA finality verification with quantum-safe enhancements
+use parity_scale_codec::{Compact, Decode, Encode};
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Sha3_512};
 
-use super::{LightClient, LightClientError};
+use super::sync::ChainLightClient;
+use super::{HashMode, LightClient, LightClientError};
+
+/// Consensus engine id used by GRANDPA in header digests
+pub const GRANDPA_ENGINE_ID: [u8; 4] = *b"FRNK";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolkadotHeader {
@@ -48,10 +53,34 @@ pub struct Precommit {
     pub target_number: u64,
 }
 
+// GRANDPA authority set change, SCALE-encoded in `Consensus` digest items
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ScheduledChange {
+    pub next_authorities: Vec<([u8; 32], u64)>, // (ed25519 authority id, weight)
+    pub delay: u32,
+}
+
+// GRANDPA consensus log (sp_consensus_grandpa::ConsensusLog with u32 block numbers)
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum GrandpaConsensusLog {
+    #[codec(index = 1)]
+    ScheduledChange(ScheduledChange),
+    #[codec(index = 2)]
+    ForcedChange(u32, ScheduledChange),
+    #[codec(index = 3)]
+    OnDisabled(u64),
+    #[codec(index = 4)]
+    Pause(u32),
+    #[codec(index = 5)]
+    Resume(u32),
+}
+
 pub struct PolkadotLightClient {
     inner: LightClient,
+    hash_mode: HashMode,
     latest_header: Option<PolkadotHeader>,
     authority_set: Vec<Authority>,
+    pending_authority_change: Option<(u64, Vec<Authority>)>, // (activation block, authorities)
 }
 
 #[derive(Debug, Clone)]
@@ -64,20 +93,53 @@ impl PolkadotLightClient {
     pub fn new(chain_id: String) -> Self {
         Self {
             inner: LightClient::new(chain_id),
+            hash_mode: HashMode::default(),
             latest_header: None,
             authority_set: Vec::new(),
+            pending_authority_change: None,
         }
     }
     
+    pub fn with_hash_mode(mut self, hash_mode: HashMode) -> Self {
+        self.hash_mode = hash_mode;
+        self
+    }
+    
     pub fn set_authority_set(&mut self, authorities: Vec<Authority>) {
         self.authority_set = authorities;
     }
     
+    /// Extract a GRANDPA authority set change signalled in the header digest
+    pub fn extract_authority_change(&self, header: &PolkadotHeader) -> Option<Vec<Authority>> {
+        self.scheduled_authority_change(header)
+            .map(|change| Self::authorities_from_change(&change))
+    }
+    
+    fn scheduled_authority_change(&self, header: &PolkadotHeader) -> Option<ScheduledChange> {
+        header.digest.iter().find_map(|item| match item {
+            DigestItem::Consensus { consensus_engine_id, data } if *consensus_engine_id == GRANDPA_ENGINE_ID => {
+                match GrandpaConsensusLog::decode(&mut &data[..]) {
+                    Ok(GrandpaConsensusLog::ScheduledChange(change)) => Some(change),
+                    Ok(GrandpaConsensusLog::ForcedChange(_, change)) => Some(change),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    }
+    
+    fn authorities_from_change(change: &ScheduledChange) -> Vec<Authority> {
+        change
+            .next_authorities
+            .iter()
+            .map(|(id, weight)| Authority { id: id.to_vec(), weight: *weight })
+            .collect()
+    }
+    
     pub fn verify_header(&self, header: &PolkadotHeader) -> Result<bool, LightClientError> {
         // Verify header hash chain
         if let Some(latest) = &self.latest_header {
-            let latest_hash = self.hash_header(latest);
-            if header.parent_hash != latest_hash {
+            if header.parent_hash != self.hash_header(latest) {
                 return Ok(false);
             }
             
@@ -91,8 +153,14 @@ impl PolkadotLightClient {
     }
     
     fn hash_header(&self, header: &PolkadotHeader) -> [u8; 32] {
-        // Hash Polkadot header using SCALE encoding + BLAKE2
-        // For quantum resistance, we use SHA3-512 and truncate
+        match self.hash_mode {
+            HashMode::Native => self.hash_header_scale(header),
+            HashMode::QuantumSha3 => self.hash_header_sha3(header),
+        }
+    }
+    
+    /// Quantum-mode header hash: SHA3-512 over the header fields, truncated to 32 bytes
+    fn hash_header_sha3(&self, header: &PolkadotHeader) -> [u8; 32] {
         let mut hasher = Sha3_512::new();
         
         hasher.update(&header.parent_hash);
@@ -131,6 +199,43 @@ impl PolkadotLightClient {
         hash
     }
     
+    /// Substrate block hash: blake2_256 of the SCALE-encoded header
+    pub fn hash_header_scale(&self, header: &PolkadotHeader) -> [u8; 32] {
+        let mut encoded = Vec::new();
+        header.parent_hash.encode_to(&mut encoded);
+        Compact(header.number).encode_to(&mut encoded);
+        header.state_root.encode_to(&mut encoded);
+        header.extrinsics_root.encode_to(&mut encoded);
+        
+        // Digest items use the sp_runtime::generic::DigestItem discriminants
+        Compact(header.digest.len() as u32).encode_to(&mut encoded);
+        for item in &header.digest {
+            match item {
+                DigestItem::Other(data) => {
+                    encoded.push(0);
+                    data.encode_to(&mut encoded);
+                }
+                DigestItem::Consensus { consensus_engine_id, data } => {
+                    encoded.push(4);
+                    consensus_engine_id.encode_to(&mut encoded);
+                    data.encode_to(&mut encoded);
+                }
+                DigestItem::Seal { consensus_engine_id, data } => {
+                    encoded.push(5);
+                    consensus_engine_id.encode_to(&mut encoded);
+                    data.encode_to(&mut encoded);
+                }
+                DigestItem::PreRuntime { consensus_engine_id, data } => {
+                    encoded.push(6);
+                    consensus_engine_id.encode_to(&mut encoded);
+                    data.encode_to(&mut encoded);
+                }
+            }
+        }
+        
+        sp_core::hashing::blake2_256(&encoded)
+    }
+    
     pub fn verify_grandpa_justification(
         &self,
         justification: &GrandpaJustification,
@@ -208,6 +313,21 @@ impl PolkadotLightClient {
             }
         }
         
+        // Schedule any authority set change signalled by this verified header
+        if let Some(change) = self.scheduled_authority_change(&header) {
+            let activation = header.number + change.delay as u64;
+            self.pending_authority_change = Some((activation, Self::authorities_from_change(&change)));
+        }
+        
+        // Rotate authorities once the scheduled change is due
+        if let Some((activation, _)) = &self.pending_authority_change {
+            if header.number >= *activation {
+                if let Some((_, authorities)) = self.pending_authority_change.take() {
+                    self.authority_set = authorities;
+                }
+            }
+        }
+        
         // Update state
         self.latest_header = Some(header.clone());
         self.inner.latest_height = header.number;
@@ -262,6 +382,22 @@ impl PolkadotLightClient {
     }
 }
 
+impl ChainLightClient for PolkadotLightClient {
+    type Header = (PolkadotHeader, Option<GrandpaJustification>);
+    
+    fn header_height((header, _): &Self::Header) -> u64 {
+        header.number
+    }
+    
+    fn current_height(&self) -> u64 {
+        self.inner.latest_height
+    }
+    
+    fn update_header(&mut self, (header, justification): Self::Header) -> Result<(), LightClientError> {
+        PolkadotLightClient::update_header(self, header, justification)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,4 +437,115 @@ mod tests {
         let hash = client.hash_header(&header);
         assert_eq!(hash.len(), 32);
     }
+    
+    fn header_with_change(number: u64, delay: u32) -> PolkadotHeader {
+        let log = GrandpaConsensusLog::ScheduledChange(ScheduledChange {
+            next_authorities: vec![([7u8; 32], 1), ([8u8; 32], 2)],
+            delay,
+        });
+        
+        PolkadotHeader {
+            parent_hash: [0u8; 32],
+            number,
+            state_root: [1u8; 32],
+            extrinsics_root: [2u8; 32],
+            digest: vec![
+                DigestItem::PreRuntime { consensus_engine_id: *b"BABE", data: vec![1, 2, 3] },
+                DigestItem::Consensus { consensus_engine_id: GRANDPA_ENGINE_ID, data: log.encode() },
+            ],
+        }
+    }
+    
+    #[test]
+    fn test_extract_authority_change() {
+        let client = PolkadotLightClient::new("polkadot-0".to_string());
+        let header = header_with_change(100, 0);
+        
+        let authorities = client.extract_authority_change(&header).unwrap();
+        assert_eq!(authorities.len(), 2);
+        assert_eq!(authorities[0].id, vec![7u8; 32]);
+        assert_eq!(authorities[1].weight, 2);
+        
+        // Other engines' consensus items are ignored
+        let mut babe_only = header.clone();
+        babe_only.digest.retain(|item| !matches!(item, DigestItem::Consensus { .. }));
+        assert!(client.extract_authority_change(&babe_only).is_none());
+    }
+    
+    #[test]
+    fn test_update_header_rotates_authorities() {
+        let mut client = PolkadotLightClient::new("polkadot-0".to_string());
+        client.set_authority_set(vec![Authority { id: vec![1u8; 32], weight: 1 }]);
+        
+        client.update_header(header_with_change(1, 1), None).unwrap();
+        assert_eq!(client.authority_set[0].id, vec![1u8; 32]); // change pending
+        
+        let next = PolkadotHeader {
+            parent_hash: client.hash_header(client.latest_header.as_ref().unwrap()),
+            number: 2,
+            state_root: [1u8; 32],
+            extrinsics_root: [2u8; 32],
+            digest: vec![],
+        };
+        client.update_header(next, None).unwrap();
+        assert_eq!(client.authority_set.len(), 2);
+        assert_eq!(client.authority_set[0].id, vec![7u8; 32]);
+    }
+    
+    fn hex32(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hex::decode(s).unwrap());
+        out
+    }
+    
+    #[test]
+    fn test_hash_header_scale_matches_chain() {
+        // Polkadot relay chain genesis header
+        let client = PolkadotLightClient::new("polkadot-0".to_string());
+        let header = PolkadotHeader {
+            parent_hash: [0u8; 32],
+            number: 0,
+            state_root: hex32("29d0d972cd27cbc511e9589fcb7a4506d5eb6a9e8df205f00472e5ab354a4e17"),
+            extrinsics_root: hex32("03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314"),
+            digest: vec![],
+        };
+        
+        assert_eq!(
+            client.hash_header_scale(&header),
+            hex32("91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3")
+        );
+        
+        // Native is the default mode, so header linking uses the real block hash
+        assert_eq!(client.hash_header(&header), client.hash_header_scale(&header));
+    }
+    
+    #[test]
+    fn test_hash_mode_selects_parent_hash() {
+        let genesis = PolkadotHeader {
+            parent_hash: [0u8; 32],
+            number: 0,
+            state_root: [1u8; 32],
+            extrinsics_root: [2u8; 32],
+            digest: vec![DigestItem::Seal { consensus_engine_id: *b"BABE", data: vec![9; 64] }],
+        };
+        
+        let mut client = PolkadotLightClient::new("polkadot-0".to_string());
+        client.update_header(genesis.clone(), None).unwrap();
+        
+        let mut child = PolkadotHeader {
+            parent_hash: client.hash_header_scale(&genesis),
+            number: 1,
+            state_root: [3u8; 32],
+            extrinsics_root: [2u8; 32],
+            digest: vec![],
+        };
+        assert!(client.verify_header(&child).unwrap());
+        
+        // The quantum-mode hash is not accepted as parent in native mode
+        child.parent_hash = client.hash_header_sha3(&genesis);
+        assert!(!client.verify_header(&child).unwrap());
+        
+        let quantum = PolkadotLightClient::new("polkadot-0".to_string()).with_hash_mode(HashMode::QuantumSha3);
+        assert_eq!(quantum.hash_header(&genesis), client.hash_header_sha3(&genesis));
+    }
 }
@@ -0,0 +1,472 @@
+// Scrypt Chain Light Client - Header verification for Litecoin and Dogecoin (merged-mined AuxPoW)
+use scrypt::{scrypt, Params};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::bitcoin::{BitcoinHeader, DifficultyRules};
+use super::{LightClient, LightClientError};
+
+/// Merged-mining marker preceding the aux chain merkle root in the parent coinbase
+const MERGED_MINING_HEADER: [u8; 4] = [0xfa, 0xbe, 0x6d, 0x6d];
+
+/// Version bit marking a Dogecoin header as carrying AuxPoW
+const AUXPOW_VERSION_FLAG: u32 = 0x100;
+
+/// Dogecoin AuxPoW chain id, encoded in the upper 16 bits of the block version
+const DOGECOIN_CHAIN_ID: u32 = 0x62;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScryptChain {
+    Litecoin,
+    Dogecoin,
+}
+
+impl ScryptChain {
+    /// Scrypt parameters for proof of work: N=1024, r=1, p=1, 32-byte output
+    pub fn scrypt_params(&self) -> Params {
+        Params::new(10, 1, 1, 32).expect("valid scrypt parameters")
+    }
+    
+    pub fn supports_auxpow(&self) -> bool {
+        matches!(self, ScryptChain::Dogecoin)
+    }
+    
+    /// Difficulty rules of this chain's network named in `chain_id`
+    ///
+    /// Litecoin retargets every 2016 blocks like Bitcoin. Dogecoin has retargeted every
+    /// block since DigiShield, by less than the 4x bound checked here. Testnets allow
+    /// minimum-difficulty blocks, and regtest never retargets.
+    fn difficulty_rules(&self, chain_id: &str) -> DifficultyRules {
+        if chain_id.contains("regtest") {
+            return DifficultyRules { pow_limit: 0x207fffff, retarget_interval: None };
+        }
+        let retarget_interval = if chain_id.contains("testnet") {
+            None
+        } else {
+            match self {
+                ScryptChain::Litecoin => Some(2016),
+                ScryptChain::Dogecoin => Some(1),
+            }
+        };
+        DifficultyRules { pow_limit: 0x1e0fffff, retarget_interval }
+    }
+}
+
+/// Proof that a parent-chain block committed to this block (merged mining)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuxPow {
+    pub coinbase_tx: Vec<u8>,              // Serialized parent coinbase transaction
+    pub coinbase_branch: Vec<[u8; 32]>,    // Merkle branch from coinbase to parent merkle root
+    pub coinbase_index: u32,               // Must be 0: the coinbase is the first transaction
+    pub chain_merkle_branch: Vec<[u8; 32]>, // Branch from this block hash to the aux chain root
+    pub chain_index: u32,
+    pub parent_header: BitcoinHeader,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScryptBlockHeader {
+    pub header: BitcoinHeader,
+    pub auxpow: Option<AuxPow>,
+}
+
+pub struct ScryptLightClient {
+    inner: LightClient,
+    chain: ScryptChain,
+    headers: Vec<ScryptBlockHeader>, // from the genesis block, at height 0
+    difficulty: DifficultyRules,
+}
+
+impl ScryptLightClient {
+    pub fn new(chain_id: String, chain: ScryptChain) -> Self {
+        let difficulty = chain.difficulty_rules(&chain_id);
+        Self {
+            inner: LightClient::new(chain_id),
+            chain,
+            headers: Vec::new(),
+            difficulty,
+        }
+    }
+    
+    /// Accept targets up to `bits`, keeping the network's retarget rules
+    pub fn with_pow_limit(mut self, bits: u32) -> Self {
+        self.difficulty.pow_limit = bits;
+        self
+    }
+    
+    pub fn verify_header(&self, block: &ScryptBlockHeader) -> Result<bool, LightClientError> {
+        let header = &block.header;
+        
+        // Chain by prev-hash to the latest accepted header
+        if let Some(latest) = self.headers.last() {
+            if header.prev_block_hash != block_hash(&latest.header) {
+                return Ok(false);
+            }
+        }
+        
+        // The declared difficulty must be one the network allows after the parent
+        let parent_bits = self.headers.last().map(|latest| latest.header.bits);
+        if !self.difficulty.allows(parent_bits, self.headers.len() as u64, header.bits) {
+            return Ok(false);
+        }
+        
+        let is_auxpow = self.chain.supports_auxpow() && header.version & AUXPOW_VERSION_FLAG != 0;
+        
+        match (&block.auxpow, is_auxpow) {
+            (Some(auxpow), true) => {
+                // Merged-mined: the work is done on the parent header
+                if header.version >> 16 != DOGECOIN_CHAIN_ID {
+                    return Ok(false);
+                }
+                if !verify_auxpow(auxpow, &block_hash(header), DOGECOIN_CHAIN_ID) {
+                    return Ok(false);
+                }
+                
+                let pow_hash = self.pow_hash(&auxpow.parent_header)?;
+                Ok(check_target(&pow_hash, header.bits))
+            }
+            (None, false) => {
+                let pow_hash = self.pow_hash(header)?;
+                Ok(check_target(&pow_hash, header.bits))
+            }
+            // AuxPoW flag and payload must agree
+            _ => Ok(false),
+        }
+    }
+    
+    pub fn add_header(&mut self, block: ScryptBlockHeader) -> Result<(), LightClientError> {
+        if !self.verify_header(&block)? {
+            return Err(LightClientError::InvalidProof);
+        }
+        
+        self.headers.push(block);
+        self.inner.latest_height = self.headers.len() as u64;
+        
+        Ok(())
+    }
+    
+    /// Scrypt proof-of-work hash of an 80-byte header, salted with itself
+    pub fn pow_hash(&self, header: &BitcoinHeader) -> Result<[u8; 32], LightClientError> {
+        let bytes = serialize_header(header);
+        let mut output = [0u8; 32];
+        
+        scrypt(&bytes, &bytes, &self.chain.scrypt_params(), &mut output)
+            .map_err(|_| LightClientError::InvalidProof)?;
+        
+        Ok(output)
+    }
+    
+    pub fn get_height(&self) -> u64 {
+        self.headers.len() as u64
+    }
+    
+    pub fn get_inner(&self) -> &LightClient {
+        &self.inner
+    }
+}
+
+/// Block identifier: double SHA-256 of the 80-byte header (internal byte order)
+pub fn block_hash(header: &BitcoinHeader) -> [u8; 32] {
+    double_sha256(&serialize_header(header))
+}
+
+/// Standard 80-byte Bitcoin-family header serialization
+pub(crate) fn serialize_header(header: &BitcoinHeader) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(80);
+    bytes.extend_from_slice(&header.version.to_le_bytes());
+    bytes.extend_from_slice(&header.prev_block_hash);
+    bytes.extend_from_slice(&header.merkle_root);
+    bytes.extend_from_slice(&header.timestamp.to_le_bytes());
+    bytes.extend_from_slice(&header.bits.to_le_bytes());
+    bytes.extend_from_slice(&header.nonce.to_le_bytes());
+    bytes
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let first = Sha256::digest(data);
+    Sha256::digest(first).into()
+}
+
+/// Fold a merkle branch; `index` selects left/right at each level
+fn merkle_root_from_branch(leaf: [u8; 32], branch: &[[u8; 32]], mut index: u32) -> [u8; 32] {
+    let mut hash = leaf;
+    
+    for sibling in branch {
+        let mut concat = Vec::with_capacity(64);
+        if index & 1 == 1 {
+            concat.extend_from_slice(sibling);
+            concat.extend_from_slice(&hash);
+        } else {
+            concat.extend_from_slice(&hash);
+            concat.extend_from_slice(sibling);
+        }
+        hash = double_sha256(&concat);
+        index >>= 1;
+    }
+    
+    hash
+}
+
+/// Slot this chain must occupy in the aux merkle tree (Namecoin's getExpectedIndex)
+fn expected_chain_index(nonce: u32, chain_id: u32, merkle_height: usize) -> u32 {
+    let mut rand = nonce;
+    rand = rand.wrapping_mul(1103515245).wrapping_add(12345);
+    rand = rand.wrapping_add(chain_id);
+    rand = rand.wrapping_mul(1103515245).wrapping_add(12345);
+    
+    rand % (1u32 << merkle_height)
+}
+
+fn verify_auxpow(auxpow: &AuxPow, aux_block_hash: &[u8; 32], chain_id: u32) -> bool {
+    // The coinbase must be the first transaction of the parent block
+    if auxpow.coinbase_index != 0 || auxpow.chain_merkle_branch.len() > 30 {
+        return false;
+    }
+    
+    let coinbase_hash = double_sha256(&auxpow.coinbase_tx);
+    let parent_root = merkle_root_from_branch(coinbase_hash, &auxpow.coinbase_branch, auxpow.coinbase_index);
+    if parent_root != auxpow.parent_header.merkle_root {
+        return false;
+    }
+    
+    // The coinbase commits to the aux chain root in reversed byte order
+    let mut chain_root = merkle_root_from_branch(*aux_block_hash, &auxpow.chain_merkle_branch, auxpow.chain_index);
+    chain_root.reverse();
+    
+    let root_pos = match find(&auxpow.coinbase_tx, &chain_root) {
+        Some(pos) => pos,
+        None => return false,
+    };
+    
+    // Require the marker right before the root, and only one marker in the coinbase
+    let header_pos = find(&auxpow.coinbase_tx, &MERGED_MINING_HEADER);
+    if header_pos != Some(root_pos.wrapping_sub(MERGED_MINING_HEADER.len()))
+        || find(&auxpow.coinbase_tx[root_pos..], &MERGED_MINING_HEADER).is_some()
+    {
+        return false;
+    }
+    
+    // Root is followed by the merkle tree size and nonce
+    let tail = &auxpow.coinbase_tx[root_pos + 32..];
+    if tail.len() < 8 {
+        return false;
+    }
+    let size = u32::from_le_bytes([tail[0], tail[1], tail[2], tail[3]]);
+    let nonce = u32::from_le_bytes([tail[4], tail[5], tail[6], tail[7]]);
+    
+    let height = auxpow.chain_merkle_branch.len();
+    size == 1u32 << height && auxpow.chain_index == expected_chain_index(nonce, chain_id, height)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+/// Compare a little-endian hash against the compact `bits` target
+pub(crate) fn check_target(hash: &[u8; 32], bits: u32) -> bool {
+    let Some(target) = compact_target(bits) else {
+        return false;
+    };
+    
+    let mut hash_be = *hash;
+    hash_be.reverse();
+    
+    hash_be <= target
+}
+
+/// Big-endian 256-bit target of a compact `bits` value, `None` if zero, negative or too large
+pub(crate) fn compact_target(bits: u32) -> Option<[u8; 32]> {
+    let exponent = (bits >> 24) as usize;
+    let mantissa = bits & 0x007fffff;
+    
+    if mantissa == 0 || bits & 0x00800000 != 0 || !(3..=32).contains(&exponent) {
+        return None;
+    }
+    
+    // target = mantissa * 256^(exponent - 3)
+    let mut target = [0u8; 32];
+    let mantissa_bytes = mantissa.to_be_bytes();
+    for i in 0..3 {
+        target[32 - exponent + i] = mantissa_bytes[1 + i];
+    }
+    Some(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn from_display_hex(s: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&hex::decode(s).unwrap());
+        bytes.reverse();
+        bytes
+    }
+    
+    fn litecoin_genesis() -> BitcoinHeader {
+        BitcoinHeader {
+            version: 1,
+            prev_block_hash: [0u8; 32],
+            merkle_root: from_display_hex("97ddfbbae6be97fd6cdf3e7ca13232a3afff2353e29badfab7f73011edd4ced9"),
+            timestamp: 1317972665,
+            bits: 0x1e0ffff0,
+            nonce: 2084524493,
+        }
+    }
+    
+    #[test]
+    fn test_litecoin_genesis_header() {
+        let client = ScryptLightClient::new("litecoin-mainnet".to_string(), ScryptChain::Litecoin);
+        let header = litecoin_genesis();
+        
+        assert_eq!(
+            block_hash(&header),
+            from_display_hex("12a765e31ffd4059bada1e25190f6e98c99d9714d334efa41a195a7e7e04bfe2")
+        );
+        assert_eq!(
+            client.pow_hash(&header).unwrap(),
+            from_display_hex("0000050c34a64b415b6b15b37f2216634b5b1669cb9a2e38d76f7213b0671e00")
+        );
+        
+        let block = ScryptBlockHeader { header: header.clone(), auxpow: None };
+        assert!(client.verify_header(&block).unwrap());
+        
+        // A different nonce no longer meets the target
+        let mut tampered = block.clone();
+        tampered.header.nonce += 1;
+        assert!(!client.verify_header(&tampered).unwrap());
+    }
+    
+    #[test]
+    fn test_prev_hash_chaining() {
+        let mut client = ScryptLightClient::new("litecoin-mainnet".to_string(), ScryptChain::Litecoin);
+        client.add_header(ScryptBlockHeader { header: litecoin_genesis(), auxpow: None }).unwrap();
+        
+        let orphan = ScryptBlockHeader {
+            header: BitcoinHeader { prev_block_hash: [9u8; 32], ..litecoin_genesis() },
+            auxpow: None,
+        };
+        assert!(!client.verify_header(&orphan).unwrap());
+        assert_eq!(client.get_height(), 1);
+    }
+    
+    /// Build a merged-mined Dogecoin header whose parent block meets a regtest-easy target
+    fn dogecoin_auxpow_block(client: &ScryptLightClient) -> ScryptBlockHeader {
+        let header = BitcoinHeader {
+            version: (DOGECOIN_CHAIN_ID << 16) | AUXPOW_VERSION_FLAG | 4,
+            prev_block_hash: [0u8; 32],
+            merkle_root: [0x42; 32],
+            timestamp: 1700000000,
+            bits: 0x207fffff,
+            nonce: 0,
+        };
+        
+        // Single aux chain: the tree root is the block hash itself, at index 0 with size 1
+        let mut root = block_hash(&header);
+        root.reverse();
+        
+        let mut coinbase_tx = vec![0x01, 0x00, 0x00, 0x00, 0x01];
+        coinbase_tx.extend_from_slice(&MERGED_MINING_HEADER);
+        coinbase_tx.extend_from_slice(&root);
+        coinbase_tx.extend_from_slice(&1u32.to_le_bytes()); // merkle size
+        coinbase_tx.extend_from_slice(&0u32.to_le_bytes()); // merkle nonce
+        coinbase_tx.extend_from_slice(&[0xff; 8]);
+        
+        let mut parent_header = BitcoinHeader {
+            version: 0x20000000,
+            prev_block_hash: [0x11; 32],
+            merkle_root: double_sha256(&coinbase_tx), // coinbase is the only parent transaction
+            timestamp: 1700000000,
+            bits: 0x207fffff,
+            nonce: 0,
+        };
+        while !check_target(&client.pow_hash(&parent_header).unwrap(), header.bits) {
+            parent_header.nonce += 1;
+        }
+        
+        ScryptBlockHeader {
+            header,
+            auxpow: Some(AuxPow {
+                coinbase_tx,
+                coinbase_branch: vec![],
+                coinbase_index: 0,
+                chain_merkle_branch: vec![],
+                chain_index: 0,
+                parent_header,
+            }),
+        }
+    }
+    
+    #[test]
+    fn test_dogecoin_auxpow_header() {
+        let client = ScryptLightClient::new("dogecoin-regtest".to_string(), ScryptChain::Dogecoin);
+        let block = dogecoin_auxpow_block(&client);
+        
+        assert!(client.verify_header(&block).unwrap());
+        
+        // Coinbase no longer links to the parent merkle root
+        let mut tampered = block.clone();
+        tampered.auxpow.as_mut().unwrap().coinbase_tx.push(0);
+        assert!(!client.verify_header(&tampered).unwrap());
+        
+        // Committed root does not match this block
+        let mut other_block = block.clone();
+        other_block.header.timestamp += 1;
+        assert!(!client.verify_header(&other_block).unwrap());
+        
+        // Litecoin does not accept AuxPoW
+        let litecoin = ScryptLightClient::new("litecoin-regtest".to_string(), ScryptChain::Litecoin);
+        assert!(!litecoin.verify_header(&block).unwrap());
+    }
+    
+    #[test]
+    fn test_trivial_bits_rejected_on_mainnet() {
+        let regtest = ScryptLightClient::new("dogecoin-regtest".to_string(), ScryptChain::Dogecoin);
+        let block = dogecoin_auxpow_block(&regtest);
+        assert!(regtest.verify_header(&block).unwrap());
+        
+        // The same work claimed at regtest difficulty is far below the mainnet pow limit
+        let mainnet = ScryptLightClient::new("dogecoin-mainnet".to_string(), ScryptChain::Dogecoin);
+        assert!(!mainnet.verify_header(&block).unwrap());
+        
+        let litecoin = ScryptLightClient::new("litecoin-mainnet".to_string(), ScryptChain::Litecoin);
+        let easy = ScryptBlockHeader {
+            header: BitcoinHeader { bits: 0x207fffff, ..litecoin_genesis() },
+            auxpow: None,
+        };
+        assert!(!litecoin.verify_header(&easy).unwrap());
+        assert!(litecoin.verify_header(&ScryptBlockHeader { header: litecoin_genesis(), auxpow: None }).unwrap());
+    }
+    
+    /// Mine a header on `client`'s tip at `bits`
+    fn mine(client: &ScryptLightClient, prev_block_hash: [u8; 32], bits: u32) -> ScryptBlockHeader {
+        let mut header = BitcoinHeader {
+            version: 1,
+            prev_block_hash,
+            merkle_root: [0x42; 32],
+            timestamp: 1700000000,
+            bits,
+            nonce: 0,
+        };
+        while !check_target(&client.pow_hash(&header).unwrap(), bits) {
+            header.nonce += 1;
+        }
+        ScryptBlockHeader { header, auxpow: None }
+    }
+    
+    #[test]
+    fn test_litecoin_difficulty_only_changes_at_retarget_boundary() {
+        // Mainnet retarget rules with a pow limit cheap enough to mine here
+        let mut client = ScryptLightClient::new("litecoin-mainnet".to_string(), ScryptChain::Litecoin)
+            .with_pow_limit(0x207fffff);
+        let genesis = mine(&client, [0u8; 32], 0x207fffff);
+        let genesis_hash = block_hash(&genesis.header);
+        client.add_header(genesis).unwrap();
+        
+        let harder = mine(&client, genesis_hash, 0x2000ffff);
+        assert!(!client.verify_header(&harder).unwrap());
+        
+        let same = mine(&client, genesis_hash, 0x207fffff);
+        client.add_header(same).unwrap();
+        assert_eq!(client.get_height(), 2);
+    }
+}
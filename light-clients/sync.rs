@@ -0,0 +1,229 @@
+// Header Sync - Feeds a stream of headers into a light client in height order
+use futures::{Stream, StreamExt};
+use std::collections::BTreeMap;
+use tokio::sync::watch;
+
+use super::LightClientError;
+
+/// Headers buffered while waiting for a missing height before giving up on it
+pub const DEFAULT_REORDER_WINDOW: usize = 64;
+
+/// A light client that advances one header at a time
+pub trait ChainLightClient {
+    type Header;
+    
+    fn header_height(header: &Self::Header) -> u64;
+    
+    /// Height of the latest accepted header
+    fn current_height(&self) -> u64;
+    
+    fn update_header(&mut self, header: Self::Header) -> Result<(), LightClientError>;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncProgress {
+    pub current_height: u64,
+    pub headers_verified: u64,
+    pub headers_rejected: u64,
+}
+
+/// A header the client refused; the sync carries on past it
+#[derive(Debug)]
+pub struct SyncFailure {
+    pub height: u64,
+    pub error: LightClientError,
+}
+
+/// Drives a light client from an unordered header stream
+///
+/// Headers ahead of the next height wait in a reorder buffer. If the buffer outgrows its
+/// window the missing height is given up on and the lowest buffered header is tried
+/// anyway, so a gap surfaces as a failure instead of stalling the sync.
+pub struct HeaderSyncer<C: ChainLightClient> {
+    client: C,
+    next_height: u64,
+    reorder_window: usize,
+    buffer: BTreeMap<u64, C::Header>,
+    progress: SyncProgress,
+    progress_tx: watch::Sender<SyncProgress>,
+}
+
+impl<C: ChainLightClient> HeaderSyncer<C> {
+    pub fn new(client: C) -> Self {
+        let progress = SyncProgress {
+            current_height: client.current_height(),
+            ..Default::default()
+        };
+        
+        Self {
+            next_height: client.current_height() + 1,
+            client,
+            reorder_window: DEFAULT_REORDER_WINDOW,
+            buffer: BTreeMap::new(),
+            progress,
+            progress_tx: watch::channel(progress).0,
+        }
+    }
+    
+    /// Expect the first header at `height`, for clients that haven't accepted one yet
+    pub fn starting_at(mut self, height: u64) -> Self {
+        self.next_height = height;
+        self
+    }
+    
+    pub fn with_reorder_window(mut self, reorder_window: usize) -> Self {
+        self.reorder_window = reorder_window;
+        self
+    }
+    
+    /// Progress updates, one per header the client accepts or rejects
+    pub fn progress(&self) -> watch::Receiver<SyncProgress> {
+        self.progress_tx.subscribe()
+    }
+    
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+    
+    pub fn into_client(self) -> C {
+        self.client
+    }
+    
+    /// Feed every header from `headers`, returning the ones the client rejected
+    ///
+    /// Headers below the next expected height, and repeats of a buffered height, are
+    /// dropped. Whatever is still buffered when the stream ends is tried in order.
+    pub async fn sync<S>(&mut self, headers: S) -> Vec<SyncFailure>
+    where
+        S: Stream<Item = C::Header>,
+    {
+        let mut failures = Vec::new();
+        let mut headers = std::pin::pin!(headers);
+        
+        while let Some(header) = headers.next().await {
+            let height = C::header_height(&header);
+            if height < self.next_height {
+                tracing::debug!("Dropping header {}, already past it", height);
+                continue;
+            }
+            
+            self.buffer.entry(height).or_insert(header);
+            self.drain(false, &mut failures);
+        }
+        
+        self.drain(true, &mut failures);
+        failures
+    }
+    
+    fn drain(&mut self, flush: bool, failures: &mut Vec<SyncFailure>) {
+        loop {
+            let buffered = self.buffer.len();
+            let Some(entry) = self.buffer.first_entry() else {
+                break;
+            };
+            
+            let waiting_for_gap = *entry.key() != self.next_height && buffered <= self.reorder_window;
+            if waiting_for_gap && !flush {
+                break;
+            }
+            
+            let (height, header) = entry.remove_entry();
+            match self.client.update_header(header) {
+                Ok(()) => {
+                    self.next_height = height + 1;
+                    self.progress.headers_verified += 1;
+                }
+                Err(error) => {
+                    tracing::warn!("Header {} rejected: {}", height, error);
+                    self.progress.headers_rejected += 1;
+                    failures.push(SyncFailure { height, error });
+                }
+            }
+            
+            self.progress.current_height = self.client.current_height();
+            self.progress_tx.send_replace(self.progress);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Accepts only the next height, and only headers marked valid
+    #[derive(Default)]
+    struct StrictClient {
+        accepted: Vec<u64>,
+    }
+    
+    impl ChainLightClient for StrictClient {
+        type Header = (u64, bool); // (height, valid)
+        
+        fn header_height(header: &Self::Header) -> u64 {
+            header.0
+        }
+        
+        fn current_height(&self) -> u64 {
+            self.accepted.last().copied().unwrap_or(0)
+        }
+        
+        fn update_header(&mut self, (height, valid): Self::Header) -> Result<(), LightClientError> {
+            if height != self.current_height() + 1 {
+                return Err(LightClientError::InvalidHeight);
+            }
+            if !valid {
+                return Err(LightClientError::InvalidProof);
+            }
+            self.accepted.push(height);
+            Ok(())
+        }
+    }
+    
+    async fn sync(heights: &[u64]) -> (StrictClient, SyncProgress, Vec<SyncFailure>) {
+        let mut syncer = HeaderSyncer::new(StrictClient::default()).with_reorder_window(4);
+        let progress = syncer.progress();
+        
+        let headers = heights.iter().map(|&h| (h, true)).collect::<Vec<_>>();
+        let failures = syncer.sync(futures::stream::iter(headers)).await;
+        
+        let last = *progress.borrow();
+        (syncer.into_client(), last, failures)
+    }
+    
+    #[tokio::test]
+    async fn test_out_of_order_stream_matches_in_order_sync() {
+        let (in_order, in_order_progress, failures) = sync(&(1..=10).collect::<Vec<_>>()).await;
+        assert!(failures.is_empty());
+        
+        // Shuffled within the window, with a repeat and a stale header
+        let (shuffled, shuffled_progress, failures) = sync(&[2, 1, 4, 3, 3, 7, 5, 6, 2, 10, 8, 9]).await;
+        assert!(failures.is_empty());
+        
+        assert_eq!(shuffled.accepted, in_order.accepted);
+        assert_eq!(shuffled_progress, in_order_progress);
+        assert_eq!(
+            shuffled_progress,
+            SyncProgress { current_height: 10, headers_verified: 10, headers_rejected: 0 }
+        );
+    }
+    
+    #[tokio::test]
+    async fn test_rejected_and_missing_headers_do_not_abort_sync() {
+        let mut syncer = HeaderSyncer::new(StrictClient::default()).with_reorder_window(2);
+        
+        // 2 is bad at first and resent later; 6 never arrives
+        let headers = vec![
+            (1, true), (2, false), (3, true), (2, true), (4, true), (5, true), (7, true), (8, true), (9, true),
+        ];
+        let failures = syncer.sync(futures::stream::iter(headers)).await;
+        
+        let rejected: Vec<u64> = failures.iter().map(|f| f.height).collect();
+        assert_eq!(rejected, vec![2, 7, 8, 9]);
+        assert!(matches!(failures[0].error, LightClientError::InvalidProof));
+        assert!(matches!(failures[1].error, LightClientError::InvalidHeight));
+        assert_eq!(syncer.client().accepted, vec![1, 2, 3, 4, 5]);
+        
+        let progress = *syncer.progress().borrow();
+        assert_eq!(progress, SyncProgress { current_height: 5, headers_verified: 5, headers_rejected: 4 });
+    }
+}
@@ -0,0 +1,135 @@
+// Zcash Light Client - Groth16 verification for Sapling shielded proofs over BLS12-381
+use bellman::groth16::{self, Proof};
+use bls12_381::{Bls12, Scalar};
+
+use super::LightClientError;
+
+/// Size of a compressed Groth16 proof: A (48) || B (96) || C (48)
+pub const GROTH16_PROOF_SIZE: usize = 192;
+
+/// Size of a little-endian encoded BLS12-381 scalar public input
+pub const SCALAR_SIZE: usize = 32;
+
+/// Groth16 verifying key for a BLS12-381 circuit (Sapling spend or output)
+pub type VerifyingKey = groth16::VerifyingKey<Bls12>;
+
+/// Read a verifying key in bellman's serialization (e.g. extracted from sapling-spend.params)
+pub fn verifying_key_from_bytes(bytes: &[u8]) -> Result<VerifyingKey, LightClientError> {
+    VerifyingKey::read(bytes).map_err(|_| LightClientError::InvalidPublicKey)
+}
+
+/// Verify a Groth16 proof against little-endian scalar public inputs
+///
+/// A proof that doesn't decode to valid curve points is rejected with `Ok(false)`;
+/// malformed public inputs are an error.
+pub fn verify_groth16(
+    proof: &[u8; GROTH16_PROOF_SIZE],
+    public_inputs: &[Vec<u8>],
+    vk: &VerifyingKey,
+) -> Result<bool, LightClientError> {
+    // The verifying key has one IC point per public input plus the constant term
+    if public_inputs.len() + 1 != vk.ic.len() {
+        return Err(LightClientError::InvalidPublicInput(format!(
+            "expected {} inputs, got {}",
+            vk.ic.len() - 1,
+            public_inputs.len()
+        )));
+    }
+    
+    let inputs = public_inputs
+        .iter()
+        .map(|input| parse_scalar(input))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let proof = match Proof::<Bls12>::read(&proof[..]) {
+        Ok(proof) => proof,
+        Err(_) => return Ok(false),
+    };
+    
+    let pvk = groth16::prepare_verifying_key(vk);
+    Ok(groth16::verify_proof(&pvk, &proof, &inputs).is_ok())
+}
+
+fn parse_scalar(bytes: &[u8]) -> Result<Scalar, LightClientError> {
+    let repr: [u8; SCALAR_SIZE] = bytes.try_into().map_err(|_| {
+        LightClientError::InvalidPublicInput(format!("expected {} bytes, got {}", SCALAR_SIZE, bytes.len()))
+    })?;
+    
+    Option::from(Scalar::from_bytes(&repr))
+        .ok_or_else(|| LightClientError::InvalidPublicInput("not a canonical field element".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bellman::{Circuit, ConstraintSystem, SynthesisError};
+    use rand_core::OsRng;
+    
+    /// Proves knowledge of `x` such that `x * x = y`, with `y` public
+    struct SquareCircuit {
+        x: Option<Scalar>,
+    }
+    
+    impl Circuit<Scalar> for SquareCircuit {
+        fn synthesize<CS: ConstraintSystem<Scalar>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let x_value = self.x;
+            let x = cs.alloc(|| "x", || x_value.ok_or(SynthesisError::AssignmentMissing))?;
+            let y = cs.alloc_input(
+                || "y",
+                || x_value.map(|x| x * x).ok_or(SynthesisError::AssignmentMissing),
+            )?;
+            
+            cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+            Ok(())
+        }
+    }
+    
+    fn proof_and_key() -> ([u8; GROTH16_PROOF_SIZE], VerifyingKey) {
+        let params = groth16::generate_random_parameters::<Bls12, _, _>(SquareCircuit { x: None }, &mut OsRng)
+            .unwrap();
+        
+        let proof = groth16::create_random_proof(SquareCircuit { x: Some(Scalar::from(3u64)) }, &params, &mut OsRng)
+            .unwrap();
+        
+        let mut bytes = [0u8; GROTH16_PROOF_SIZE];
+        proof.write(&mut bytes[..]).unwrap();
+        
+        // Round-trip the key through its serialized form, as loaded from a params file
+        let mut vk_bytes = Vec::new();
+        params.vk.write(&mut vk_bytes).unwrap();
+        
+        (bytes, verifying_key_from_bytes(&vk_bytes).unwrap())
+    }
+    
+    #[test]
+    fn test_groth16_accepts_valid_and_rejects_flipped_proof() {
+        let (proof, vk) = proof_and_key();
+        let nine = vec![Scalar::from(9u64).to_bytes().to_vec()];
+        let ten = vec![Scalar::from(10u64).to_bytes().to_vec()];
+        
+        assert!(verify_groth16(&proof, &nine, &vk).unwrap());
+        assert!(!verify_groth16(&proof, &ten, &vk).unwrap());
+        
+        let mut flipped = proof;
+        flipped[100] ^= 0x01;
+        assert!(!verify_groth16(&flipped, &nine, &vk).unwrap());
+    }
+    
+    #[test]
+    fn test_groth16_malformed_inputs() {
+        let (proof, vk) = proof_and_key();
+        
+        assert!(matches!(
+            verify_groth16(&proof, &[], &vk),
+            Err(LightClientError::InvalidPublicInput(_))
+        ));
+        assert!(matches!(
+            verify_groth16(&proof, &[vec![0u8; 31]], &vk),
+            Err(LightClientError::InvalidPublicInput(_))
+        ));
+        assert!(matches!(
+            verify_groth16(&proof, &[vec![0xff; 32]], &vk),
+            Err(LightClientError::InvalidPublicInput(_))
+        ));
+    }
+}
@@ -0,0 +1,12 @@
+#!/bin/bash
+# Script to check that slim feature sets still compile
+# Runs the ignored tests in tests/feature_builds.rs, which each spawn cargo check
+
+set -e
+
+echo "==================================="
+echo "Sentium Bridge Feature Build Checks"
+echo "==================================="
+echo ""
+
+cargo test --test feature_builds -- --ignored "$@"
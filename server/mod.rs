@@ -0,0 +1,164 @@
+// Sentium Bridge - HTTP Server
+// This module exposes the router over a JSON HTTP API for non-Rust services
+
+use axum::extract::State;
+use axum::http::{Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, RequestId, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+
+use crate::core::router::{Intent, Router, RouterError};
+
+/// Header carrying the request id, generated if the caller doesn't send one
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceQuery {
+    pub chain: String,
+    pub address: String,
+    #[serde(default)]
+    pub asset: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyQuery {
+    pub chain: String,
+    pub proof: String, // hex-encoded proof bytes
+}
+
+/// Build the HTTP application around a router
+pub fn app(router: Arc<Router>) -> axum::Router {
+    let request_id_header = axum::http::HeaderName::from_static(REQUEST_ID_HEADER);
+    
+    axum::Router::new()
+        .route("/health", get(health))
+        .route("/schema", get(schema))
+        .route("/route", post(route_intent))
+        .route("/balance", post(query_balance))
+        .route("/verify", post(verify_state))
+        .with_state(router)
+        .layer(PropagateRequestIdLayer::new(request_id_header.clone()))
+        .layer(TraceLayer::new_for_http().make_span_with(|request: &Request<_>| {
+            let request_id = request
+                .extensions()
+                .get::<RequestId>()
+                .and_then(|id| id.header_value().to_str().ok())
+                .unwrap_or_default();
+            
+            tracing::info_span!(
+                "request",
+                request_id = %request_id,
+                method = %request.method(),
+                path = %request.uri().path(),
+            )
+        }))
+        .layer(SetRequestIdLayer::new(request_id_header, MakeRequestUuid))
+}
+
+/// Serve the API on the given address until the process exits
+pub async fn serve(router: Arc<Router>, addr: SocketAddr) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    tracing::info!("Sentium Bridge API listening on {}", addr);
+    
+    axum::serve(listener, app(router)).await
+}
+
+/// JSON Schema of the intent format accepted by `/route`
+async fn schema() -> Json<schemars::schema::RootSchema> {
+    Json(crate::core::router::schema::intent_schema())
+}
+
+async fn health(State(router): State<Arc<Router>>) -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "ok",
+        "chains": router.supported_chains().await,
+    }))
+}
+
+async fn route_intent(
+    State(router): State<Arc<Router>>,
+    Json(intent): Json<Intent>,
+) -> Result<Response, ApiError> {
+    let translated = router.route_intent(&intent).await?;
+    Ok(Json(translated).into_response())
+}
+
+async fn query_balance(
+    State(router): State<Arc<Router>>,
+    Json(query): Json<BalanceQuery>,
+) -> Result<Response, ApiError> {
+    let adapter = router
+        .adapter(&query.chain)
+        .await
+        .ok_or_else(|| RouterError::UnsupportedChain(query.chain.clone()))?;
+    
+    let balance = adapter.query_balance(&query.address, &query.asset).await?;
+    
+    Ok(Json(serde_json::json!({
+        "chain": query.chain,
+        "address": query.address,
+        "asset": query.asset,
+        "balance": balance,
+    }))
+    .into_response())
+}
+
+async fn verify_state(
+    State(router): State<Arc<Router>>,
+    Json(query): Json<VerifyQuery>,
+) -> Result<Response, ApiError> {
+    let adapter = router
+        .adapter(&query.chain)
+        .await
+        .ok_or_else(|| RouterError::UnsupportedChain(query.chain.clone()))?;
+    
+    let proof = hex::decode(query.proof.trim_start_matches("0x"))
+        .map_err(|e| RouterError::VerificationError(format!("Invalid proof hex: {}", e)))?;
+    
+    let valid = adapter.verify_state(&proof).await?;
+    
+    Ok(Json(serde_json::json!({
+        "chain": query.chain,
+        "valid": valid,
+    }))
+    .into_response())
+}
+
+/// Router errors rendered as JSON with an HTTP status
+struct ApiError(RouterError);
+
+impl From<RouterError> for ApiError {
+    fn from(e: RouterError) -> Self {
+        ApiError(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            RouterError::UnsupportedChain(_)
+            | RouterError::RoutingError(_)
+            | RouterError::TranslationError(_)
+            | RouterError::UnsupportedAction(_)
+            | RouterError::IntentExpired(_) => StatusCode::BAD_REQUEST,
+            RouterError::VerificationError(_) | RouterError::ChainIdMismatch { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            RouterError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            RouterError::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            RouterError::CircuitOpen { .. } | RouterError::ShuttingDown => StatusCode::SERVICE_UNAVAILABLE,
+            RouterError::ChainUnavailable(_) => StatusCode::BAD_GATEWAY,
+        };
+        
+        tracing::warn!("Request failed: {}", self.0);
+        
+        let mut response = (status, Json(serde_json::json!({ "error": self.0.to_string() }))).into_response();
+        if let RouterError::RateLimited { retry_after_secs, .. } | RouterError::CircuitOpen { retry_after_secs, .. } = &self.0 {
+            response.headers_mut().insert(axum::http::header::RETRY_AFTER, (*retry_after_secs).into());
+        }
+        response
+    }
+}
@@ -0,0 +1,162 @@
+// Sentium Bridge CLI
+// Command-line entry point for routing intents, querying balances and verifying state proofs
+
+use clap::{Parser, Subcommand};
+use sentium_bridge::core::config::BridgeConfig;
+use sentium_bridge::core::router::{ChainAdapter, Intent, Router, RouterError};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+// Exit codes (2 is used by clap for usage errors)
+const EXIT_FAILURE: u8 = 1;
+const EXIT_CONFIG_ERROR: u8 = 3;
+const EXIT_UNSUPPORTED_CHAIN: u8 = 4;
+
+#[derive(Parser)]
+#[command(name = "sentium-bridge", version, about = "Sentium Bridge command-line interface")]
+struct Cli {
+    /// Path to the bridge config file (TOML or YAML)
+    #[arg(long, short, default_value = "sentium-bridge.toml")]
+    config: PathBuf,
+    
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Translate and route an intent to the target chain
+    Route {
+        #[arg(long)]
+        from: String,
+        #[arg(long)]
+        to: String,
+        #[arg(long)]
+        action: String,
+        #[arg(long, default_value = "")]
+        params: String,
+    },
+    /// Query an address balance on a chain
+    Balance {
+        #[arg(long)]
+        chain: String,
+        #[arg(long)]
+        address: String,
+        #[arg(long, default_value = "")]
+        asset: String,
+    },
+    /// Verify a state proof read from a file
+    VerifyProof {
+        #[arg(long)]
+        chain: String,
+        #[arg(long)]
+        file: PathBuf,
+    },
+}
+
+enum CliError {
+    Config(String),
+    Router(RouterError),
+    Io(String),
+}
+
+impl CliError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Config(_) => EXIT_CONFIG_ERROR,
+            CliError::Router(RouterError::UnsupportedChain(_)) => EXIT_UNSUPPORTED_CHAIN,
+            CliError::Router(_) | CliError::Io(_) => EXIT_FAILURE,
+        }
+    }
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Config(msg) => write!(f, "Config error: {}", msg),
+            CliError::Router(e) => write!(f, "{}", e),
+            CliError::Io(msg) => write!(f, "I/O error: {}", msg),
+        }
+    }
+}
+
+impl From<RouterError> for CliError {
+    fn from(e: RouterError) -> Self {
+        CliError::Router(e)
+    }
+}
+
+fn load_router(path: &Path) -> Result<Router, CliError> {
+    let config = BridgeConfig::from_file(path).map_err(|e| CliError::Config(e.to_string()))?;
+    
+    Ok(Router::from_config(&config)?)
+}
+
+async fn adapter_for(router: &Router, chain: &str) -> Result<Arc<dyn ChainAdapter>, CliError> {
+    router
+        .adapter(chain)
+        .await
+        .ok_or_else(|| RouterError::UnsupportedChain(chain.to_string()).into())
+}
+
+async fn run(cli: Cli) -> Result<serde_json::Value, CliError> {
+    let router = load_router(&cli.config)?;
+    
+    match cli.command {
+        Command::Route { from, to, action, params } => {
+            let intent = Intent {
+                id: format!("cli-{}", std::process::id()),
+                from_chain: from,
+                to_chain: to,
+                action,
+                params: params.into_bytes(),
+                context: vec![],
+                expires_at: None,
+                signature: None,
+            };
+            
+            let translated = router.route_intent(&intent).await?;
+            serde_json::to_value(&translated).map_err(|e| CliError::Io(e.to_string()))
+        }
+        Command::Balance { chain, address, asset } => {
+            let adapter = adapter_for(&router, &chain).await?;
+            let balance = adapter.query_balance(&address, &asset).await?;
+            
+            Ok(serde_json::json!({
+                "chain": chain,
+                "address": address,
+                "asset": asset,
+                "balance": balance,
+            }))
+        }
+        Command::VerifyProof { chain, file } => {
+            let proof = std::fs::read(&file)
+                .map_err(|e| CliError::Io(format!("{}: {}", file.display(), e)))?;
+            
+            let adapter = adapter_for(&router, &chain).await?;
+            let valid = adapter.verify_state(&proof).await?;
+            
+            Ok(serde_json::json!({
+                "chain": chain,
+                "valid": valid,
+            }))
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    
+    match run(cli).await {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", serde_json::json!({ "error": e.to_string() }));
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
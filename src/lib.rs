@@ -9,13 +9,46 @@ pub mod core {
     pub mod context {
         include!("../core/context/mod.rs");
     }
+    
+    pub mod crypto {
+        include!("../core/crypto/mod.rs");
+    }
+    
+    pub mod config {
+        include!("../core/config/mod.rs");
+    }
+    
+    pub mod clock {
+        include!("../core/clock/mod.rs");
+    }
+    
+    pub mod codec {
+        include!("../core/codec/mod.rs");
+    }
 }
 
 pub mod light_clients {
     include!("../light-clients/mod.rs");
 }
 
+pub mod testing {
+    include!("../testing/mod.rs");
+}
+
+#[cfg(feature = "server")]
+pub mod server {
+    include!("../server/mod.rs");
+}
+
 // Re-exports for convenience
 pub use core::router::{Router, Intent, IntentTranslator, ChainAdapter};
+#[cfg(feature = "eth")]
+pub use core::router::EthereumAdapter;
+#[cfg(feature = "dot")]
+pub use core::router::PolkadotAdapter;
+#[cfg(feature = "btc")]
+pub use core::router::BitcoinAdapter;
+#[cfg(feature = "cosmos")]
+pub use core::router::CosmosAdapter;
 pub use core::context::{SemanticContext, ContextPreserver, UserPreferences};
-pub use light_clients::{LightClient, LightClientManager, StateProof};
+pub use light_clients::{AggregatedStateProof, LightClient, LightClientManager, StateProof};
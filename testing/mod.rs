@@ -0,0 +1,274 @@
+// Sentium Bridge - Test Utilities
+// This module provides a programmable in-memory ChainAdapter for deterministic tests
+
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::core::context::TransactionStatus;
+use crate::core::router::{Capabilities, ChainAdapter, Intent, RouterError, SimulationResult, TranslatedIntent};
+use crate::core::router::intent_translator::TranslationMetadata;
+
+/// A call recorded by a [`MockAdapter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockCall {
+    TranslateIntent { intent_id: String },
+    VerifyState { proof: Vec<u8> },
+    SubmitTransaction { tx_data: Vec<u8> },
+    QueryBalance { address: String, asset: String },
+    TransactionStatus { tx_hash: String },
+    SimulateTransaction { intent_id: String },
+    Close,
+}
+
+/// ChainAdapter with programmable responses and a call log, no networking
+pub struct MockAdapter {
+    chain_name: String,
+    chain_id: String,
+    balances: HashMap<String, u64>,
+    tx_statuses: HashMap<String, TransactionStatus>,
+    verify_result: bool,
+    gas_estimate: u64,
+    simulation: Option<SimulationResult>,
+    capabilities: Capabilities,
+    translate_delay: Option<Duration>,
+    submit_delay: Option<Duration>,
+    submit_results: Mutex<VecDeque<Result<String, RouterError>>>,
+    next_failure: Mutex<Option<RouterError>>,
+    calls: Mutex<Vec<MockCall>>,
+}
+
+impl MockAdapter {
+    pub fn new(chain_name: &str) -> Self {
+        Self {
+            chain_name: chain_name.to_string(),
+            chain_id: format!("{}-mock", chain_name),
+            balances: HashMap::new(),
+            tx_statuses: HashMap::new(),
+            verify_result: true,
+            gas_estimate: 0,
+            simulation: None,
+            capabilities: Capabilities::all(None, None),
+            translate_delay: None,
+            submit_delay: None,
+            submit_results: Mutex::new(VecDeque::new()),
+            next_failure: Mutex::new(None),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+    
+    pub fn with_chain_id(mut self, chain_id: &str) -> Self {
+        self.chain_id = chain_id.to_string();
+        self
+    }
+    
+    /// Balance returned by `query_balance` for this address (any asset)
+    pub fn with_balance(mut self, address: &str, amount: u64) -> Self {
+        self.balances.insert(address.to_string(), amount);
+        self
+    }
+    
+    /// Status `transaction_status` reports for this hash; other hashes are unknown
+    pub fn with_transaction_status(mut self, tx_hash: &str, status: TransactionStatus) -> Self {
+        self.tx_statuses.insert(tx_hash.to_string(), status);
+        self
+    }
+    
+    /// Result returned by `verify_state`
+    pub fn with_verify_result(mut self, valid: bool) -> Self {
+        self.verify_result = valid;
+        self
+    }
+    
+    /// Gas estimate reported in translated intents
+    pub fn with_gas_estimate(mut self, gas: u64) -> Self {
+        self.gas_estimate = gas;
+        self
+    }
+    
+    /// Result returned by `simulate_transaction`; unset, it succeeds using the gas estimate
+    pub fn with_simulation(mut self, result: SimulationResult) -> Self {
+        self.simulation = Some(result);
+        self
+    }
+    
+    /// Capabilities reported to the router
+    pub fn with_capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+    
+    /// Sleep this long in `translate_intent` before recording the call
+    pub fn with_translate_delay(mut self, delay: Duration) -> Self {
+        self.translate_delay = Some(delay);
+        self
+    }
+    
+    /// Sleep this long in `submit_transaction` before recording the call
+    pub fn with_submit_delay(mut self, delay: Duration) -> Self {
+        self.submit_delay = Some(delay);
+        self
+    }
+    
+    /// Queue a result for `submit_transaction`; queued results are returned in order
+    pub fn with_submit_result(self, result: Result<String, RouterError>) -> Self {
+        self.submit_results.lock().unwrap().push_back(result);
+        self
+    }
+    
+    /// Make the next adapter call fail with the given error
+    pub fn fail_next_query(&self, error: RouterError) {
+        *self.next_failure.lock().unwrap() = Some(error);
+    }
+    
+    /// All calls made to this adapter, oldest first
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.lock().unwrap().clone()
+    }
+    
+    fn record(&self, call: MockCall) -> Result<(), RouterError> {
+        self.calls.lock().unwrap().push(call);
+        
+        match self.next_failure.lock().unwrap().take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for MockAdapter {
+    fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+    
+    fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+    
+    async fn translate_intent(&self, intent: &Intent) -> Result<TranslatedIntent, RouterError> {
+        if let Some(delay) = self.translate_delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.record(MockCall::TranslateIntent { intent_id: intent.id.clone() })?;
+        
+        Ok(TranslatedIntent {
+            original_intent: intent.clone(),
+            target_format: intent.params.clone(),
+            translation_metadata: TranslationMetadata {
+                translator_version: "mock".to_string(),
+                timestamp: 0,
+                gas_estimate: self.gas_estimate,
+                translation_hash: TranslatedIntent::compute_hash(intent, &intent.params),
+                price_impact_bps: None,
+            },
+        })
+    }
+    
+    /// Mock translations carry the intent's params unchanged
+    fn verify_translation(&self, translated: &TranslatedIntent) -> Result<(), RouterError> {
+        if translated.target_format != translated.original_intent.params {
+            return Err(RouterError::VerificationError(format!(
+                "Payload for {} doesn't match its params",
+                translated.original_intent.id
+            )));
+        }
+        Ok(())
+    }
+    
+    async fn verify_state(&self, proof: &[u8]) -> Result<bool, RouterError> {
+        self.record(MockCall::VerifyState { proof: proof.to_vec() })?;
+        Ok(self.verify_result)
+    }
+    
+    async fn submit_transaction(&self, tx_data: &[u8]) -> Result<String, RouterError> {
+        if let Some(delay) = self.submit_delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.record(MockCall::SubmitTransaction { tx_data: tx_data.to_vec() })?;
+        
+        match self.submit_results.lock().unwrap().pop_front() {
+            Some(result) => result,
+            None => Ok(format!("0x{}", hex::encode(&tx_data[..tx_data.len().min(32)]))),
+        }
+    }
+    
+    async fn query_balance(&self, address: &str, asset: &str) -> Result<u64, RouterError> {
+        self.record(MockCall::QueryBalance {
+            address: address.to_string(),
+            asset: asset.to_string(),
+        })?;
+        
+        Ok(self.balances.get(address).copied().unwrap_or(0))
+    }
+    
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities.clone()
+    }
+    
+    async fn transaction_status(&self, tx_hash: &str) -> Result<TransactionStatus, RouterError> {
+        self.record(MockCall::TransactionStatus { tx_hash: tx_hash.to_string() })?;
+        
+        self.tx_statuses
+            .get(tx_hash)
+            .cloned()
+            .ok_or_else(|| RouterError::VerificationError(format!("Unknown transaction {}", tx_hash)))
+    }
+    
+    async fn simulate_transaction(&self, translated: &TranslatedIntent) -> Result<SimulationResult, RouterError> {
+        self.record(MockCall::SimulateTransaction { intent_id: translated.original_intent.id.clone() })?;
+        
+        Ok(self.simulation.clone().unwrap_or(SimulationResult {
+            success: true,
+            gas_used: self.gas_estimate,
+            amount_out: None,
+            revert_reason: None,
+        }))
+    }
+    
+    async fn close(&self) -> Result<(), RouterError> {
+        self.record(MockCall::Close)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_mock_balance_and_call_log() {
+        let adapter = MockAdapter::new("ethereum").with_balance("0xabc", 500);
+        
+        assert_eq!(adapter.query_balance("0xabc", "ETH").await.unwrap(), 500);
+        assert_eq!(adapter.query_balance("0xdef", "ETH").await.unwrap(), 0);
+        
+        assert_eq!(adapter.calls(), vec![
+            MockCall::QueryBalance { address: "0xabc".to_string(), asset: "ETH".to_string() },
+            MockCall::QueryBalance { address: "0xdef".to_string(), asset: "ETH".to_string() },
+        ]);
+    }
+    
+    #[tokio::test]
+    async fn test_mock_submit_results_in_order() {
+        let adapter = MockAdapter::new("bitcoin")
+            .with_submit_result(Ok("tx-1".to_string()))
+            .with_submit_result(Err(RouterError::RoutingError("mempool full".to_string())));
+        
+        assert_eq!(adapter.submit_transaction(&[1]).await.unwrap(), "tx-1");
+        assert!(adapter.submit_transaction(&[2]).await.is_err());
+        assert_eq!(adapter.submit_transaction(&[0xab]).await.unwrap(), "0xab");
+    }
+    
+    #[tokio::test]
+    async fn test_fail_next_query_only_once() {
+        let adapter = MockAdapter::new("cosmos");
+        adapter.fail_next_query(RouterError::VerificationError("injected".to_string()));
+        
+        assert!(matches!(
+            adapter.verify_state(&[]).await,
+            Err(RouterError::VerificationError(msg)) if msg == "injected"
+        ));
+        assert!(adapter.verify_state(&[]).await.unwrap());
+    }
+}
@@ -174,6 +174,8 @@ mod invalid_input_tests {
             action: "".to_string(), // Empty action
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let result = translator.translate(&intent);
@@ -192,6 +194,8 @@ mod invalid_input_tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let result = translator.translate(&intent);
@@ -465,6 +469,8 @@ mod concurrent_tests {
                     action: "transfer".to_string(),
                     params: vec![],
                     context: vec![],
+                    expires_at: None,
+                    signature: None,
                 };
                 
                 adapter_clone.translate_intent(&intent).await
@@ -55,6 +55,8 @@ mod ethereum_integration {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let translated = adapter.translate_intent(&intent).await;
@@ -127,6 +129,8 @@ mod polkadot_integration {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let translated = adapter.translate_intent(&intent).await;
@@ -198,6 +202,8 @@ mod bitcoin_integration {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let translated = adapter.translate_intent(&intent).await;
@@ -269,6 +275,8 @@ mod cosmos_integration {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let translated = adapter.translate_intent(&intent).await;
@@ -326,6 +334,8 @@ mod e2e_tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         // Translate for source chain
@@ -363,6 +373,8 @@ mod e2e_tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         // Translate for both chains
@@ -403,6 +415,8 @@ mod e2e_tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         let intent2 = Intent {
@@ -412,6 +426,8 @@ mod e2e_tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         // Verify each hop can be translated
@@ -483,6 +499,8 @@ mod performance_tests {
             action: "transfer".to_string(),
             params: vec![],
             context: vec![],
+            expires_at: None,
+            signature: None,
         };
         
         // Measure translation time
@@ -360,7 +360,7 @@ mod tron_tests {
 // Solana Adapter Tests - DEX instruction creation
 // ============================================================================
 
-#[cfg(test)]
+#[cfg(all(test, feature = "solana"))]
 mod solana_tests {
     use super::*;
     use solana_sdk::pubkey::Pubkey;
@@ -0,0 +1,134 @@
+// CLI tests for the sentium-bridge binary
+#[cfg(feature = "eth")]
+use std::io::{Read, Write};
+#[cfg(feature = "eth")]
+use std::net::TcpListener;
+use std::process::Command;
+
+fn bridge_cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_sentium-bridge"))
+}
+
+// Minimal JSON-RPC node answering every request with the given result
+#[cfg(feature = "eth")]
+fn spawn_mock_rpc(result: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(_) => continue,
+            };
+            
+            // Read headers, then the body according to Content-Length
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            let body = loop {
+                let n = stream.read(&mut buf).unwrap_or(0);
+                if n == 0 {
+                    break None;
+                }
+                request.extend_from_slice(&buf[..n]);
+                
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let content_length = text[..header_end]
+                        .lines()
+                        .find_map(|l| {
+                            let (name, value) = l.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())
+                                .flatten()
+                        })
+                        .unwrap_or(0);
+                    
+                    if request.len() >= header_end + 4 + content_length {
+                        break Some(request[header_end + 4..header_end + 4 + content_length].to_vec());
+                    }
+                }
+            };
+            
+            let Some(body) = body else { continue };
+            let rpc: serde_json::Value = serde_json::from_slice(&body).unwrap_or_default();
+            let response = serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": rpc["id"],
+                "result": result,
+            })
+            .to_string();
+            
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            );
+        }
+    });
+    
+    format!("http://{}", addr)
+}
+
+fn write_config(name: &str, contents: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("sentium-bridge-{}-{}.toml", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_cli_help() {
+    let output = bridge_cli().arg("--help").output().unwrap();
+    
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("route"));
+    assert!(stdout.contains("balance"));
+    assert!(stdout.contains("verify-proof"));
+}
+
+#[test]
+#[cfg(feature = "eth")]
+fn test_cli_balance_against_mock_node() {
+    let rpc_url = spawn_mock_rpc("0x2a");
+    let config = write_config("balance", &format!("[chains.ethereum]\nrpc_url = \"{}\"\n", rpc_url));
+    
+    let output = bridge_cli()
+        .arg("--config")
+        .arg(&config)
+        .args([
+            "balance",
+            "--chain",
+            "ethereum",
+            "--address",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "--asset",
+            "ETH",
+        ])
+        .output()
+        .unwrap();
+    
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["chain"], "ethereum");
+    assert_eq!(result["balance"], 42);
+    
+    let _ = std::fs::remove_file(config);
+}
+
+#[test]
+fn test_cli_unconfigured_chain_exit_code() {
+    let config = write_config("unconfigured", "[chains]\n");
+    
+    let output = bridge_cli()
+        .arg("--config")
+        .arg(&config)
+        .args(["balance", "--chain", "solana", "--address", "abc"])
+        .output()
+        .unwrap();
+    
+    assert_eq!(output.status.code(), Some(4));
+    
+    let _ = std::fs::remove_file(config);
+}
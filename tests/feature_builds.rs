@@ -0,0 +1,43 @@
+// CI-style checks that slim feature sets still compile
+// These invoke cargo and are slow, run with: scripts/check_feature_builds.sh
+use std::process::Command;
+
+/// Adapter features that gate library code, each checked on its own
+const ADAPTER_FEATURES: &[&str] = &["eth", "btc", "dot", "cosmos", "harmony", "somnia", "chia", "starknet"];
+
+fn cargo_check(args: &[&str]) -> bool {
+    let target_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("feature-builds");
+    
+    Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .arg("check")
+        .arg("--lib")
+        .args(args)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[test]
+#[ignore]
+fn test_builds_with_only_eth() {
+    assert!(cargo_check(&["--no-default-features", "--features", "eth"]));
+}
+
+#[test]
+#[ignore]
+fn test_builds_with_no_adapters() {
+    assert!(cargo_check(&["--no-default-features"]));
+}
+
+#[test]
+#[ignore]
+fn test_builds_with_each_adapter_feature_alone() {
+    let failed: Vec<&str> = ADAPTER_FEATURES
+        .iter()
+        .copied()
+        .filter(|feature| !cargo_check(&["--no-default-features", "--features", feature]))
+        .collect();
+    assert!(failed.is_empty(), "features that don't build alone: {:?}", failed);
+}
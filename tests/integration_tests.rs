@@ -1,7 +1,11 @@
 // Integration tests for Sentium Bridge Protocol
+#![cfg(all(feature = "eth", feature = "dot", feature = "btc"))]
+
 use sentium_bridge::core::router::{Router, Intent, IntentTranslator, EthereumAdapter, PolkadotAdapter};
-use sentium_bridge::light_clients::{LightClient, LightClientManager, StateProof, Validator, QuantumSignature};
+use sentium_bridge::light_clients::{LightClient, LightClientManager, StateProof, Validator, QuantumSignature, HybridSignature};
 use sentium_bridge::core::context::{SemanticContext, UserPreferences, RiskLevel, ContextPreserver, InMemoryStorage};
+use sentium_bridge::core::crypto::QuantumKeyManager;
+use ed25519_dalek::{Signer, SigningKey};
 use std::sync::Arc;
 
 #[tokio::test]
@@ -31,6 +35,8 @@ async fn test_end_to_end_cross_chain_intent() {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     // Route intent
@@ -54,6 +60,8 @@ async fn test_light_client_state_verification() {
         address: vec![1, 2, 3, 4],
         public_key: vec![0u8; 2592], // Dilithium5 public key size
         voting_power: 100,
+        classical_public_key: Vec::new(),
+        hybrid: false,
     };
     client.add_validator(validator);
     
@@ -73,6 +81,126 @@ async fn test_light_client_state_verification() {
     assert!(result.is_err() || !result.unwrap());
 }
 
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[test]
+fn test_signed_state_proof_round_trip() {
+    let (public_key, secret_key) = QuantumKeyManager::generate_keypair();
+    
+    let mut client = LightClient::new("test-chain".to_string());
+    client.add_validator(Validator {
+        address: vec![1, 2, 3, 4],
+        public_key: public_key.as_bytes().to_vec(),
+        voting_power: 100,
+        classical_public_key: Vec::new(),
+        hybrid: false,
+    });
+    
+    let mut proof = StateProof {
+        height: 100,
+        state_root: vec![1, 2, 3, 4],
+        signatures: vec![],
+        timestamp: now_secs(),
+    };
+    
+    // Sign the state proof message with the validator key
+    let message = client.construct_proof_message(&proof);
+    proof.signatures.push(QuantumSignature {
+        validator_address: vec![1, 2, 3, 4],
+        signature: QuantumKeyManager::sign(&secret_key, &message),
+        classical_signature: None,
+    });
+    
+    assert!(client.verify_state_proof(&proof).unwrap());
+    
+    // Any change to the signed fields invalidates the proof
+    proof.height = 101;
+    assert!(!client.verify_state_proof(&proof).unwrap());
+}
+
+fn hybrid_client_and_proof() -> (LightClient, StateProof, Vec<u8>, Vec<u8>) {
+    let (pq_public, pq_secret) = QuantumKeyManager::generate_keypair();
+    let classical_key = SigningKey::from_bytes(&[7u8; 32]);
+    
+    let mut client = LightClient::new("test-chain".to_string());
+    client.add_validator(Validator {
+        address: vec![1, 2, 3, 4],
+        public_key: pq_public.as_bytes().to_vec(),
+        voting_power: 100,
+        classical_public_key: classical_key.verifying_key().to_bytes().to_vec(),
+        hybrid: true,
+    });
+    
+    let proof = StateProof {
+        height: 100,
+        state_root: vec![1, 2, 3, 4],
+        signatures: vec![],
+        timestamp: now_secs(),
+    };
+    
+    let message = client.construct_proof_message(&proof);
+    let classical = classical_key.sign(&message).to_bytes().to_vec();
+    let pq = QuantumKeyManager::sign(&pq_secret, &message);
+    
+    (client, proof, classical, pq)
+}
+
+#[test]
+fn test_hybrid_proof_both_valid() {
+    let (client, mut proof, classical, pq) = hybrid_client_and_proof();
+    
+    proof.signatures.push(QuantumSignature::hybrid(
+        vec![1, 2, 3, 4],
+        HybridSignature { classical, pq },
+    ));
+    
+    assert!(client.verify_state_proof(&proof).unwrap());
+}
+
+#[test]
+fn test_hybrid_proof_classical_only_valid() {
+    let (client, mut proof, classical, _) = hybrid_client_and_proof();
+    
+    // Valid Ed25519 signature, Dilithium5 signature from an unrelated key
+    let (_, other_secret) = QuantumKeyManager::generate_keypair();
+    let pq = QuantumKeyManager::sign(&other_secret, b"unrelated");
+    
+    proof.signatures.push(QuantumSignature::hybrid(
+        vec![1, 2, 3, 4],
+        HybridSignature { classical, pq },
+    ));
+    
+    assert!(!client.verify_state_proof(&proof).unwrap());
+}
+
+#[test]
+fn test_hybrid_proof_pq_only_valid() {
+    let (client, mut proof, _, pq) = hybrid_client_and_proof();
+    
+    // Valid Dilithium5 signature, Ed25519 signature from an unrelated key
+    let other_key = SigningKey::from_bytes(&[9u8; 32]);
+    let classical = other_key.sign(b"unrelated").to_bytes().to_vec();
+    
+    proof.signatures.push(QuantumSignature::hybrid(
+        vec![1, 2, 3, 4],
+        HybridSignature { classical: classical.clone(), pq: pq.clone() },
+    ));
+    assert!(!client.verify_state_proof(&proof).unwrap());
+    
+    // A hybrid validator without a classical signature is rejected as well
+    proof.signatures = vec![QuantumSignature {
+        validator_address: vec![1, 2, 3, 4],
+        signature: pq,
+        classical_signature: None,
+    }];
+    assert!(!client.verify_state_proof(&proof).unwrap());
+}
+
 #[tokio::test]
 async fn test_context_preservation() {
     let storage = Arc::new(InMemoryStorage::new());
@@ -80,7 +208,7 @@ async fn test_context_preservation() {
     
     // Create context
     let prefs = UserPreferences {
-        slippage_tolerance: 0.01,
+        slippage_bps: 100,
         max_gas_price: 100,
         min_confirmations: 6,
         preferred_routes: vec![],
@@ -118,6 +246,8 @@ async fn test_multi_hop_routing() {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     // Find route
@@ -159,6 +289,8 @@ fn test_intent_translation_ethereum() {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     let result = translator.translate(&intent);
@@ -180,6 +312,8 @@ fn test_intent_translation_polkadot() {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     let result = translator.translate(&intent);
@@ -200,6 +334,8 @@ fn test_intent_translation_bitcoin() {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     let result = translator.translate(&intent);
@@ -217,6 +353,8 @@ fn test_unsupported_chain() {
         action: "transfer".to_string(),
         params: vec![],
         context: vec![],
+        expires_at: None,
+        signature: None,
     };
     
     let result = translator.translate(&intent);
@@ -226,7 +364,7 @@ fn test_unsupported_chain() {
 #[test]
 fn test_context_integrity() {
     let prefs = UserPreferences {
-        slippage_tolerance: 0.01,
+        slippage_bps: 100,
         max_gas_price: 100,
         min_confirmations: 6,
         preferred_routes: vec![],
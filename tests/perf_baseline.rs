@@ -0,0 +1,74 @@
+// Performance baseline for coin selection
+//
+// Wall-clock time varies too much between machines to assert on directly, so branch and
+// bound is timed against a reference workload of the same shape: totalling the amounts of
+// each combination it tries. A 2x slowdown in selection doubles the ratio and fails here.
+// Timing is only meaningful on an otherwise idle machine, so the check is ignored by default:
+// run it with `cargo test --release --test perf_baseline -- --ignored --test-threads=1`.
+// `cargo bench --bench verification_benchmarks` gives the absolute numbers.
+use sentium_bridge::core::router::chain_adapter::{SelectionStrategy, Utxo, UtxoSelector};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Branch and bound time over reference time, as measured in both debug and release builds
+const BASELINE_RATIO: f64 = 1.4;
+
+const ROUNDS: usize = 15;
+
+fn utxos(count: usize) -> Vec<Utxo> {
+    (0..count)
+        .map(|i| Utxo {
+            txid: format!("{:064x}", i),
+            vout: 0,
+            amount: 10_000 + (i as u64 * 7_919) % 50_000,
+            script_pubkey: vec![0x00, 0x14],
+        })
+        .collect()
+}
+
+/// Fastest of `ROUNDS` runs, which filters out scheduler noise better than the mean
+fn best_of(mut run: impl FnMut()) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+#[ignore] // Wall-clock timing; flaky alongside parallel tests or on a loaded CI box
+fn test_branch_and_bound_within_2x_of_baseline() {
+    let available = utxos(20);
+    let selector = UtxoSelector::new(SelectionStrategy::BranchAndBound);
+    
+    // 20 UTXOs have far more combinations than the search's 1000-iteration cap
+    let selection = selector.select_utxos(available.clone(), 10_000, 1).unwrap();
+    assert!(selection.total_input >= 10_000);
+    
+    let selection_time = best_of(|| {
+        black_box(selector.select_utxos(black_box(available.clone()), 10_000, 1).unwrap());
+    });
+    let amounts: Vec<u64> = available.iter().map(|u| u.amount).collect();
+    let reference_time = best_of(|| {
+        for mask in 1..=1000u64 {
+            let mut total = 0u64;
+            for (j, amount) in black_box(&amounts).iter().enumerate() {
+                if mask & (1 << j) != 0 {
+                    total += amount;
+                }
+            }
+            black_box(total);
+        }
+    });
+    
+    let ratio = selection_time.as_secs_f64() / reference_time.as_secs_f64();
+    assert!(
+        ratio < 2.0 * BASELINE_RATIO,
+        "coin selection regressed: {:.2}x the reference workload, baseline {:.2}x",
+        ratio,
+        BASELINE_RATIO
+    );
+}
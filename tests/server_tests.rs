@@ -0,0 +1,131 @@
+// HTTP API tests for the `server` feature
+#![cfg(all(feature = "server", feature = "eth"))]
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use sentium_bridge::core::config::BridgeConfig;
+use sentium_bridge::core::router::Router;
+use sentium_bridge::server::{app, REQUEST_ID_HEADER};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+fn test_app() -> axum::Router {
+    let config = BridgeConfig::from_toml_str("[chains.ethereum]\nrpc_url = \"http://localhost:8545\"\n").unwrap();
+    app(Arc::new(Router::from_config(&config).unwrap()))
+}
+
+fn json_request(uri: &str, body: serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+async fn body_json(response: axum::response::Response) -> serde_json::Value {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn test_health() {
+    let response = test_app()
+        .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().contains_key(REQUEST_ID_HEADER));
+    
+    let body = body_json(response).await;
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["chains"], serde_json::json!(["ethereum"]));
+}
+
+#[tokio::test]
+async fn test_schema_describes_intent() {
+    let response = test_app()
+        .oneshot(Request::builder().uri("/schema").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    
+    assert_eq!(response.status(), StatusCode::OK);
+    let schema = body_json(response).await;
+    assert_eq!(schema["title"], "Intent");
+    assert!(schema["definitions"]["ActionType"]["oneOf"].is_array());
+}
+
+#[tokio::test]
+async fn test_route_well_formed_intent() {
+    let intent = serde_json::json!({
+        "id": "http-intent-1",
+        "from_chain": "sentium",
+        "to_chain": "ethereum",
+        "action": "transfer",
+        "params": [],
+        "context": [],
+    });
+    
+    let mut request = json_request("/route", intent);
+    request.headers_mut().insert(REQUEST_ID_HEADER, "req-123".parse().unwrap());
+    
+    let response = test_app().oneshot(request).await.unwrap();
+    
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[REQUEST_ID_HEADER], "req-123");
+    
+    let body = body_json(response).await;
+    assert_eq!(body["original_intent"]["id"], "http-intent-1");
+}
+
+#[tokio::test]
+async fn test_route_bad_chain() {
+    let intent = serde_json::json!({
+        "id": "http-intent-2",
+        "from_chain": "sentium",
+        "to_chain": "not-a-chain",
+        "action": "transfer",
+        "params": [],
+        "context": [],
+    });
+    
+    let response = test_app().oneshot(json_request("/route", intent)).await.unwrap();
+    
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(body_json(response).await["error"].is_string());
+}
+
+#[tokio::test]
+async fn test_balance_unknown_chain() {
+    let query = serde_json::json!({ "chain": "solana", "address": "abc" });
+    
+    let response = test_app().oneshot(json_request("/balance", query)).await.unwrap();
+    
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_rate_limited_intent_gets_retry_after() {
+    use sentium_bridge::core::router::TokenBucketLimiter;
+    
+    let config = BridgeConfig::from_toml_str("[chains.ethereum]\nrpc_url = \"http://localhost:8545\"\n").unwrap();
+    let router = Router::from_config(&config).unwrap().with_rate_limiter(Arc::new(TokenBucketLimiter::new(1, 60)));
+    let app = app(Arc::new(router));
+    
+    let intent = serde_json::json!({
+        "id": "http-intent-3",
+        "from_chain": "sentium",
+        "to_chain": "ethereum",
+        "action": "transfer",
+        "params": [],
+        "context": [],
+    });
+    
+    let first = app.clone().oneshot(json_request("/route", intent.clone())).await.unwrap();
+    assert_eq!(first.status(), StatusCode::OK);
+    
+    let second = app.oneshot(json_request("/route", intent)).await.unwrap();
+    assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(second.headers()["retry-after"], "60");
+}
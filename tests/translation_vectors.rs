@@ -0,0 +1,194 @@
+// Golden test vectors for IntentTranslator output
+//
+// Each vector pins the exact bytes one chain's translation produces for fixed inputs, so an
+// encoding change (an EVM selector, a SCALE call index, a renamed JSON field) fails here
+// instead of on the destination chain. Only change a vector when the wire format is meant to.
+use sentium_bridge::core::clock::MockClock;
+use sentium_bridge::core::router::{ActionType, Intent, IntentTranslator};
+use std::sync::Arc;
+
+const NOW: u64 = 1_700_000_000;
+const EXPIRES_AT: u64 = 1_700_003_600;
+
+fn transfer(to: &str) -> ActionType {
+    ActionType::Transfer {
+        from: "0x00000000000000000000000000000000000000a1".to_string(),
+        to: to.to_string(),
+        amount: 1_250_000,
+        asset: "USDC".to_string(),
+        memo: None,
+    }
+}
+
+fn swap() -> ActionType {
+    ActionType::Swap {
+        asset_in: "USDC".to_string(),
+        asset_out: "ETH".to_string(),
+        amount_in: 2_000_000,
+        expected_amount_out: 1_000,
+        min_amount_out: 0, // set from the default slippage tolerance
+    }
+}
+
+fn stake(validator: &str) -> ActionType {
+    ActionType::Stake { asset: "DOT".to_string(), amount: 50_000, validator: validator.to_string() }
+}
+
+fn translate(to_chain: &str, action: ActionType) -> Result<Vec<u8>, String> {
+    let translator = IntentTranslator::new().with_clock(Arc::new(MockClock::new(NOW)));
+    let intent = Intent {
+        id: "vector-1".to_string(),
+        from_chain: "sentium".to_string(),
+        to_chain: to_chain.to_string(),
+        action: String::new(),
+        params: vec![],
+        context: vec![],
+        expires_at: Some(EXPIRES_AT),
+        signature: None,
+    }
+    .with_action(action);
+    
+    let translated = translator.translate(&intent).map_err(|e| e.to_string())?;
+    assert_eq!(translated.translation_metadata.timestamp, NOW);
+    assert!(translated.verify_hash());
+    Ok(translated.target_format)
+}
+
+fn translate_hex(to_chain: &str, action: ActionType) -> String {
+    hex::encode(translate(to_chain, action).unwrap())
+}
+
+fn translate_text(to_chain: &str, action: ActionType) -> String {
+    String::from_utf8(translate(to_chain, action).unwrap()).unwrap()
+}
+
+#[test]
+#[cfg(feature = "eth")]
+fn test_evm_vectors() {
+    // transfer(address,uint256)
+    assert_eq!(
+        translate_hex("ethereum", transfer("0x00000000000000000000000000000000000000b2")),
+        concat!(
+            "a9059cbb",
+            "00000000000000000000000000000000000000000000000000000000000000b2",
+            "00000000000000000000000000000000000000000000000000000000001312d0",
+        )
+    );
+    
+    // swapExactTokensForTokens: amountIn, amountOutMin (0.5% below 1000), deadline
+    assert_eq!(
+        translate_hex("ethereum", swap()),
+        concat!(
+            "38ed1739",
+            "00000000000000000000000000000000000000000000000000000000001e8480",
+            "00000000000000000000000000000000000000000000000000000000000003e3",
+            "000000000000000000000000000000000000000000000000000000006553ff10",
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "dot")]
+fn test_substrate_vectors() {
+    // Balances.transfer (pallet 5, call 0) to Alice, u64 amount
+    assert_eq!(
+        translate_hex("polkadot", transfer("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY")),
+        concat!(
+            "0500",
+            "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+            "d012130000000000",
+        )
+    );
+    
+    // Staking.bond (pallet 6, call 0) with Bob
+    assert_eq!(
+        translate_hex("polkadot", stake("5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty")),
+        concat!(
+            "0600",
+            "8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48",
+            "50c3000000000000",
+        )
+    );
+}
+
+#[test]
+fn test_cosmos_vectors() {
+    assert_eq!(
+        translate_text("cosmos", transfer("cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02")),
+        concat!(
+            r#"{"@type":"/cosmos.bank.v1beta1.MsgSend","amount":[{"amount":"1250000","denom":"usdc"}],"#,
+            r#""from_address":"0x00000000000000000000000000000000000000a1","#,
+            r#""to_address":"cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02"}"#,
+        )
+    );
+    
+    assert_eq!(
+        translate_text("cosmos", stake("cosmosvaloper1sjllsnramtg3ewxqwwrwjxfgc4n4ef9u2lcnj0")),
+        concat!(
+            r#"{"@type":"/cosmos.staking.v1beta1.MsgDelegate","amount":{"amount":"50000","denom":"dot"},"#,
+            r#""delegator_address":"delegator","#,
+            r#""validator_address":"cosmosvaloper1sjllsnramtg3ewxqwwrwjxfgc4n4ef9u2lcnj0"}"#,
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "btc")]
+fn test_bitcoin_vectors() {
+    // Version 2, no inputs yet, one P2WPKH output, lock time 0. With no inputs the encoder
+    // writes the segwit marker and flag so the empty input list isn't read as one
+    assert_eq!(
+        translate_hex("bitcoin", transfer("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")),
+        concat!(
+            "02000000",
+            "0001",
+            "00",
+            "01",
+            "d012130000000000",
+            "160014e8df018c7e326cc253faac7e46cdc51e68542c42",
+            "00000000",
+        )
+    );
+}
+
+#[test]
+fn test_sentium_vectors() {
+    // Unsigned envelope: magic, version, nonce 0, length-prefixed action JSON, then empty
+    // public key and signature
+    let envelope = |json: &str| {
+        let mut bytes = b"SNTM\x01".to_vec();
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&(json.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(json.as_bytes());
+        bytes.extend_from_slice(&[0; 8]);
+        bytes
+    };
+    
+    assert_eq!(
+        translate("sentium", transfer("sentium1x")).unwrap(),
+        envelope(r#"{"Transfer":{"from":"0x00000000000000000000000000000000000000a1","to":"sentium1x","amount":1250000,"asset":"USDC"}}"#)
+    );
+    assert_eq!(
+        translate("sentium", swap()).unwrap(),
+        envelope(r#"{"Swap":{"asset_in":"USDC","asset_out":"ETH","amount_in":2000000,"expected_amount_out":1000,"min_amount_out":995}}"#)
+    );
+    assert_eq!(
+        translate("sentium", stake("sentiumvaloper1x")).unwrap(),
+        envelope(r#"{"Stake":{"asset":"DOT","amount":50000,"validator":"sentiumvaloper1x"}}"#)
+    );
+}
+
+#[test]
+fn test_unsupported_actions_have_no_encoding() {
+    assert!(translate("cosmos", swap()).is_err());
+    
+    #[cfg(feature = "eth")]
+    assert!(translate("ethereum", stake("0x00000000000000000000000000000000000000c3")).is_err());
+    #[cfg(feature = "dot")]
+    assert!(translate("polkadot", swap()).is_err());
+    #[cfg(feature = "btc")]
+    {
+        assert!(translate("bitcoin", swap()).is_err());
+        assert!(translate("bitcoin", stake("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+// Fuzz tests for adapter proof parsing: arbitrary bytes must be rejected, never panic
+use futures::executor::block_on;
+use proptest::prelude::*;
+use sentium_bridge::core::router::{ChainAdapter, IntentTranslator, SentiumAdapter};
+use std::sync::Arc;
+
+/// Every adapter whose `verify_state` parses untrusted proof bytes
+fn adapters() -> Vec<Box<dyn ChainAdapter>> {
+    let translator = Arc::new(IntentTranslator::new());
+    #[allow(unused_mut)]
+    let mut adapters: Vec<Box<dyn ChainAdapter>> =
+        vec![Box::new(SentiumAdapter::new("http://localhost:9933".to_string(), translator.clone()))];
+    
+    #[cfg(feature = "eth")]
+    adapters.push(Box::new(sentium_bridge::core::router::EthereumAdapter::new(
+        "http://localhost:8545".to_string(),
+        translator.clone(),
+    )));
+    #[cfg(feature = "dot")]
+    adapters.push(Box::new(sentium_bridge::core::router::PolkadotAdapter::new(
+        "ws://localhost:9944".to_string(),
+        translator.clone(),
+    )));
+    #[cfg(feature = "btc")]
+    adapters.push(Box::new(sentium_bridge::core::router::BitcoinAdapter::new(
+        "http://localhost:8332".to_string(),
+        bitcoin::Network::Bitcoin,
+        translator.clone(),
+    )));
+    #[cfg(feature = "cosmos")]
+    adapters.push(Box::new(sentium_bridge::core::router::CosmosAdapter::new(
+        "http://localhost:26657".to_string(),
+        translator,
+    )));
+    
+    adapters
+}
+
+fn assert_no_panic(proof: &[u8]) {
+    // verify_state never touches the network, so a plain executor is enough
+    for adapter in adapters() {
+        let _ = block_on(adapter.verify_state(proof));
+    }
+}
+
+/// Length prefixes clustered around the real field size and the u32 extremes
+fn claimed_len(actual: usize) -> impl Strategy<Value = u32> {
+    let actual = actual as u32;
+    prop_oneof![
+        Just(actual),
+        (0..4u32).prop_map(move |d| actual.saturating_add(d)),
+        (0..4u32).prop_map(move |d| actual.saturating_sub(d)),
+        Just(u32::MAX),
+        (0..8u32).prop_map(|d| u32::MAX - d),
+        any::<u32>(),
+    ]
+}
+
+/// A 32-byte root followed by length-prefixed fields, in either byte order
+fn framed_proof() -> impl Strategy<Value = Vec<u8>> {
+    let field = prop::collection::vec(any::<u8>(), 0..96)
+        .prop_flat_map(|body| (claimed_len(body.len()), any::<bool>(), Just(body)));
+    
+    (any::<[u8; 32]>(), prop::collection::vec(field, 0..5)).prop_map(|(root, fields)| {
+        let mut proof = root.to_vec();
+        for (len, big_endian, body) in fields {
+            let prefix = if big_endian { len.to_be_bytes() } else { len.to_le_bytes() };
+            proof.extend_from_slice(&prefix);
+            proof.extend_from_slice(&body);
+        }
+        proof
+    })
+}
+
+/// Inputs that crashed a parser once, kept so the fix stays in place
+///
+/// A length prefix near `u32::MAX` overflowed the `offset + len` bounds checks on 32-bit
+/// targets (e.g. wasm32), panicking in debug builds and wrapping past the check in release.
+fn regression_corpus() -> Vec<Vec<u8>> {
+    let framed = |prefix: [u8; 4]| {
+        let mut proof = vec![0u8; 32];
+        proof.extend_from_slice(&prefix);
+        proof.extend_from_slice(&[0xAB; 8]);
+        proof
+    };
+    
+    vec![
+        // Ethereum node / Cosmos op length
+        framed(u32::MAX.to_be_bytes()),
+        framed((u32::MAX - 35).to_be_bytes()),
+        // Polkadot key length, then value length after an empty key
+        framed(u32::MAX.to_le_bytes()),
+        [&[0u8; 32][..], &0u32.to_le_bytes(), &u32::MAX.to_le_bytes(), &[0xAB; 8]].concat(),
+    ]
+}
+
+#[test]
+fn test_regression_corpus() {
+    for proof in regression_corpus() {
+        assert_no_panic(&proof);
+    }
+}
+
+proptest! {
+    #[test]
+    fn prop_random_bytes_never_panic(proof in prop::collection::vec(any::<u8>(), 0..512)) {
+        assert_no_panic(&proof);
+    }
+    
+    #[test]
+    fn prop_framed_proofs_never_panic(proof in framed_proof()) {
+        assert_no_panic(&proof);
+    }
+    
+    // Streaming parsers must agree with the buffered ones on every input
+    #[test]
+    fn prop_streaming_matches_buffered(proof in prop_oneof![framed_proof(), prop::collection::vec(any::<u8>(), 0..128)]) {
+        for adapter in adapters() {
+            let buffered = block_on(adapter.verify_state(&proof)).ok();
+            let streamed = block_on(adapter.verify_state_streaming(&mut proof.as_slice())).ok();
+            prop_assert_eq!(buffered, streamed, "{}", adapter.chain_name());
+        }
+    }
+    
+    // Long enough to reach the Sentium key and signature split
+    #[test]
+    fn prop_long_proofs_never_panic(proof in prop::collection::vec(any::<u8>(), 7_100..7_300)) {
+        assert_no_panic(&proof);
+    }
+}